@@ -0,0 +1,43 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+quick_error!
+{
+	/// Represents all the things that can go wrong when bundling a stylesheet's imports.
+	///
+	/// Each variant has the chain of imports that led to the failure, starting with the URL of the stylesheet being bundled.
+	#[derive(Debug)]
+	pub enum BundleError
+	{
+		/// An imported stylesheet could not be loaded.
+		Load(url: String, import_chain: Vec<String>, cause: ::std::io::Error)
+		{
+			cause(cause)
+			description("an imported stylesheet could not be loaded")
+			display("Could not load {:?} (imported by {}): '{}'", url, import_chain.join(" -> "), cause)
+		}
+		
+		/// An imported stylesheet could not be parsed.
+		Parse(url: String, import_chain: Vec<String>, cause: CssError)
+		{
+			cause(cause)
+			description("an imported stylesheet could not be parsed")
+			display("Parse error in {:?} (imported by {}) was {}", url, import_chain.join(" -> "), cause)
+		}
+		
+		/// A stylesheet imports itself, directly or indirectly; the last URL in the chain is the one that was already being imported.
+		Cycle(import_chain: Vec<String>)
+		{
+			description("stylesheet imports are cyclic")
+			display("Stylesheet imports are cyclic: {}", import_chain.join(" -> "))
+		}
+		
+		/// An imported stylesheet has `@namespace` rules; these can not be inlined, as they only apply to the stylesheet they are in.
+		NamespaceInImportedStylesheet(url: String, import_chain: Vec<String>)
+		{
+			description("an imported stylesheet has @namespace rules")
+			display("Imported stylesheet {:?} (imported by {}) has @namespace rules, which can not be bundled", url, import_chain.join(" -> "))
+		}
+	}
+}
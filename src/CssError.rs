@@ -0,0 +1,250 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Why CSS could not be parsed.
+///
+/// Unlike `ParseError`, this does not borrow from the CSS that was parsed, so it can be returned from anywhere and kept.
+/// Displays as a readable message followed by a snippet of the CSS with a caret pointing at where the error occurred, eg for use in build logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssError
+{
+	/// The CSS is not syntactically valid, eg an unexpected token or the CSS ended too soon.
+	Syntax
+	{
+		/// A readable description of the error.
+		message: String,
+		
+		/// Where the error occurred (lines are zero-based, columns one-based).
+		location: SourceLocation,
+		
+		/// The line of CSS with the error, with a caret under where it occurred.
+		snippet: String,
+	},
+	
+	/// The CSS is syntactically valid, but is not a valid (or supported) rule, selector, descriptor or value.
+	Invalid
+	{
+		/// A readable description of the error.
+		message: String,
+		
+		/// Where the error occurred (lines are zero-based, columns one-based).
+		location: SourceLocation,
+		
+		/// The line of CSS with the error, with a caret under where it occurred.
+		snippet: String,
+	},
+}
+
+impl fmt::Display for CssError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		let location = self.location();
+		write!(f, "{} at line {}, column {}", self.message(), location.line + 1, location.column)?;
+		let snippet = self.snippet();
+		if !snippet.is_empty()
+		{
+			write!(f, "\n{}", snippet)?;
+		}
+		Ok(())
+	}
+}
+
+impl ::std::error::Error for CssError
+{
+}
+
+impl CssError
+{
+	/// Lines longer than this are shortened in snippets, which matters for minified CSS.
+	const MaximumSnippetCharacters: usize = 80;
+	
+	/// Creates a new instance from a parse error for `css`.
+	pub fn new<'i>(error: ParseError<'i, CustomParseError<'i>>, css: &str) -> Self
+	{
+		Self::from_kind(error.kind, error.location, css)
+	}
+	
+	/// Creates a new instance from a diagnostic recorded when parsing `css`.
+	#[inline(always)]
+	pub fn from_diagnostic<'i>(diagnostic: Diagnostic<'i>, css: &str) -> Self
+	{
+		Self::from_kind(diagnostic.kind, diagnostic.location, css)
+	}
+	
+	/// A readable description of the error.
+	#[inline(always)]
+	pub fn message(&self) -> &str
+	{
+		match *self
+		{
+			CssError::Syntax { ref message, .. } => message,
+			CssError::Invalid { ref message, .. } => message,
+		}
+	}
+	
+	/// Where the error occurred (lines are zero-based, columns one-based).
+	#[inline(always)]
+	pub fn location(&self) -> SourceLocation
+	{
+		match *self
+		{
+			CssError::Syntax { location, .. } => location,
+			CssError::Invalid { location, .. } => location,
+		}
+	}
+	
+	/// The line of CSS with the error, with a caret under where it occurred.
+	#[inline(always)]
+	pub fn snippet(&self) -> &str
+	{
+		match *self
+		{
+			CssError::Syntax { ref snippet, .. } => snippet,
+			CssError::Invalid { ref snippet, .. } => snippet,
+		}
+	}
+	
+	/// Parses all of `css` using `parse`.
+	pub(crate) fn parse_entirely<'i, T, F: for<'t> FnOnce(&mut Parser<'i, 't>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(css: &'i str, parse: F) -> Result<T, Self>
+	{
+		const LineNumberingIsZeroBased: u32 = 0;
+		
+		let mut parserInput = ParserInput::new_with_line_number_offset(css, LineNumberingIsZeroBased);
+		let mut input = Parser::new(&mut parserInput);
+		
+		input.parse_entirely(parse).map_err(|error| Self::new(error, css))
+	}
+	
+	fn from_kind<'i, K: Into<CustomParseError<'i>>>(kind: K, location: SourceLocation, css: &str) -> Self
+	{
+		use self::BasicParseErrorKind::*;
+		
+		let snippet = Self::snippet_for(css, location);
+		let kind = kind.into();
+		let message = kind.to_string();
+		
+		match kind
+		{
+			CustomParseError::Basic(UnexpectedToken(_)) | CustomParseError::Basic(EndOfInput) => CssError::Syntax { message, location, snippet },
+			_ => CssError::Invalid { message, location, snippet },
+		}
+	}
+	
+	/// The line at `location` with a caret under its column, eg:-
+	///
+	/// ```text
+	///  3 | a { color: red; @media }
+	///    |                 ^
+	/// ```
+	fn snippet_for(css: &str, location: SourceLocation) -> String
+	{
+		let line = match css.lines().nth(location.line as usize)
+		{
+			None => return String::new(),
+			Some(line) => line,
+		};
+		
+		let characters = line.chars().collect::<Vec<_>>();
+		
+		// cssparser counts columns in UTF-16 code units, so characters outside the Basic Multilingual Plane (such as emoji) are two columns wide.
+		let utf16Column = (location.column as usize).saturating_sub(1);
+		let mut utf16Units = 0;
+		let column = characters.iter().take_while(|character|
+		{
+			let start = utf16Units;
+			utf16Units += character.len_utf16();
+			start < utf16Column
+		}).count();
+		
+		let half = Self::MaximumSnippetCharacters / 2;
+		let (from, to) = if characters.len() <= Self::MaximumSnippetCharacters
+		{
+			(0, characters.len())
+		}
+		else
+		{
+			let from = column.saturating_sub(half);
+			(from, min(from + Self::MaximumSnippetCharacters, characters.len()))
+		};
+		let ellipsis = if from == 0 { "" } else { "..." };
+		
+		let lineNumber = format!("{}", location.line + 1);
+		let gutter = " ".repeat(lineNumber.len());
+		
+		let mut snippet = format!(" {} | {}", lineNumber, ellipsis);
+		snippet.extend(&characters[from .. to]);
+		if to != characters.len()
+		{
+			snippet.push_str("...");
+		}
+		
+		snippet.push_str(&format!("\n {} | {}", gutter, " ".repeat(ellipsis.len())));
+		for &character in &characters[from .. column]
+		{
+			snippet.push(if character == '\t' { '\t' } else { ' ' });
+		}
+		snippet.push('^');
+		snippet
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn snippet_caret_is_placed_correctly_after_characters_outside_the_basic_multilingual_plane()
+	{
+		let error = CssError::parse_entirely("'\u{1F600}'x", |input|
+		{
+			input.expect_string()?;
+			input.expect_ident_matching("y")?;
+			Ok(())
+		}).unwrap_err();
+		
+		assert_eq!(error.snippet(), " 1 | '\u{1F600}'x\n   |    ^");
+	}
+	
+	fn recover(css: &str) -> (String, Vec<Diagnostic<'_>>)
+	{
+		let (stylesheet, diagnostics) = Stylesheet::parse_with_options(css, &ParseOptions { recover: true, ..ParseOptions::default() }).unwrap();
+		(stylesheet.to_css_string(false), diagnostics)
+	}
+	
+	fn spans<'i>(diagnostics: &[Diagnostic<'i>]) -> Vec<&'i str>
+	{
+		diagnostics.iter().map(|diagnostic| diagnostic.span).collect()
+	}
+	
+	#[test]
+	fn recovery_skips_the_whole_of_a_rule_with_an_invalid_prelude()
+	{
+		let (css, diagnostics) = recover("a{b:c} .. {x:y} d{e:f}");
+		assert_eq!(css, "a{b:c}d{e:f}");
+		assert_eq!(spans(&diagnostics), vec![".. {x:y}"]);
+		match diagnostics[0].kind
+		{
+			CustomParseError::SpecificSelectorParseError(_) => (),
+			ref kind => panic!("Unexpected kind {:?}", kind),
+		}
+		
+		let (css, diagnostics) = recover("a{b:c}}d{e:f}g{h:i}");
+		assert_eq!(css, "a{b:c}g{h:i}");
+		assert_eq!(spans(&diagnostics), vec!["}d{e:f}"]);
+	}
+	
+	#[test]
+	fn recovery_skips_invalid_rules_inside_blocks_and_invalid_at_rules()
+	{
+		let (css, diagnostics) = recover("@media print{a{b:c} .. {x:y} d{e:f}}@keyframes k{from{b:c} bogus{x:y} to{e:f}}@page auto{margin:0}");
+		assert_eq!(css, "@media print{a{b:c}d{e:f}}@keyframes k{from{b:c}to{e:f}}");
+		assert_eq!(spans(&diagnostics), vec![".. {x:y}", "bogus{x:y}", "@page auto{margin:0}"]);
+		
+		let error = CssError::from_diagnostic(diagnostics.into_iter().next().unwrap(), "");
+		assert_eq!(error.message(), "expected a class name after '.', not '.'");
+	}
+}
@@ -6,6 +6,9 @@
 #[derive(Debug)]
 pub enum CustomParseError<'i>
 {
+	// Syntax
+	Basic(BasicParseErrorKind<'i>),
+	
 	// @-rule
 	UnsupportedAtRule(CowRcStr<'i>),
 	InvalidParseState,
@@ -31,6 +34,7 @@ pub enum CustomParseError<'i>
 	
 	// @document
 	DocumentAtRuleUrlMatchingFunctionWasInvalid,
+	DocumentAtRuleRegExpWasInvalid(CowRcStr<'i>, &'static str),
 	BadUrlInDeclarationValueBlock(CowRcStr<'i>),
 	BadStringInDeclarationValueBlock(CowRcStr<'i>),
 	UnbalancedCloseParenthesisInDeclarationValueBlock,
@@ -58,6 +62,10 @@ pub enum CustomParseError<'i>
 	ImportantIsNotAllowedInKeyframePropertyDeclarationValues,
 	UnexpectedTokenWhenParsingZoom(Token<'i>),
 	
+	// @layer
+	InvalidLayerName(CowRcStr<'i>),
+	AtRuleLayerBlockCanNotHaveMoreThanOneName,
+	
 	// @media
 	InvalidMediaType(CowRcStr<'i>),
 	DeprecatedMediaType(CowRcStr<'i>),
@@ -76,6 +84,7 @@ pub enum CustomParseError<'i>
 	
 	// @page
 	InvalidPageSelectorPseudoClass(CowRcStr<'i>),
+	UnsupportedPageMarginAtRule(CowRcStr<'i>),
 	FontRelativeLengthsAreNotAllowedInAPageAtRule,
 	ViewportLengthsAreNotAllowedInAPageAtRule,
 	
@@ -107,6 +116,215 @@ pub enum CustomParseError<'i>
 	// expressions (calc(), var(), attr())
 	UnknownFunctionInValueExpression(CowRcStr<'i>),
 	CssVariablesInVarExpressionsMustStartWithTwoDashes(CowRcStr<'i>),
+	CalcFunctionCanNotCombineANumberAndADimension,
+	CalcFunctionResultMustBeANumber,
+	CalcFunctionResultCanNotBeANumber,
+	
+	// property values
+	InvalidPosition,
+	BackgroundColorMustBeInTheFinalLayer,
+	VisualBoxIsNotAllowedHere(&'static str),
+	GradientMustHaveAtLeastTwoColourStops,
+	GradientInterpolationHintMustBeBetweenColourStops,
+	InvalidGradientShape,
+	UnexpectedImageFunction(CowRcStr<'i>),
+	UnexpectedTransformFunction(CowRcStr<'i>),
+	UnexpectedEasingFunction(CowRcStr<'i>),
+	CubicBezierXMustBeBetweenZeroAndOne(f32),
+	NotEnoughSteps(i32),
+	LinearEasingFunctionMustHaveAtLeastTwoStops,
+	TransitionPropertyNoneMustBeOnItsOwn,
+	UnexpectedGridFunction(CowRcStr<'i>),
+	FlexibleTrackSizeIsNotAllowedHere,
+	GridRepeatCountMustBeAtLeastOne(i32),
+	GridTrackListCanHaveOnlyOneAutoRepeat,
+	GridAutoRepeatMustOnlyHaveFixedSizes,
+	InvalidGridTemplateAreasString(CowRcStr<'i>),
+	GridTemplateAreasRowsMustHaveTheSameNumberOfColumns,
+	GridTemplateAreaMustBeRectangular(String),
+	GridLineCanNotBeZero,
+	GridSpanMustBeAtLeastOne(i32),
+	AlignmentKeywordIsNotAllowed(&'static str, CowRcStr<'i>),
+	BaselinePositionCanNotBeCombinedWithOtherKeywords(&'static str),
+	DisplayListItemMustHaveAFlowInnerDisplayType,
+}
+
+impl<'i> fmt::Display for CustomParseError<'i>
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		use self::CustomParseError::*;
+		
+		match *self
+		{
+			Basic(BasicParseErrorKind::UnexpectedToken(ref token)) => write!(f, "unexpected '{}'", token.to_css_string()),
+			Basic(BasicParseErrorKind::EndOfInput) => write!(f, "unexpected end of CSS"),
+			Basic(BasicParseErrorKind::AtRuleInvalid(ref name)) => write!(f, "'@{}' is not a valid at-rule here", name),
+			Basic(BasicParseErrorKind::AtRuleBodyInvalid) => write!(f, "the at-rule's body is not valid"),
+			Basic(BasicParseErrorKind::QualifiedRuleInvalid) => write!(f, "the rule is not valid"),
+			
+			UnsupportedAtRule(ref name) => write!(f, "the at-rule '@{}' is not supported", name),
+			InvalidParseState => write!(f, "a rule is not allowed here"),
+			
+			UnexpectedCharsetAtRule => write!(f, "'@charset' is only allowed at the very start of a stylesheet"),
+			
+			UnsupportedCounterStyleProperty(ref name) => write!(f, "'{}' is not a descriptor of '@counter-style'", name),
+			InvalidCounterStyleWithoutSymbols(ref system) => write!(f, "a '@counter-style' with 'system: {}' must have a 'symbols' descriptor", system.to_css_string()),
+			InvalidCounterStyleNotEnoughSymbols(ref system) => write!(f, "a '@counter-style' with 'system: {}' does not have enough symbols", system.to_css_string()),
+			InvalidCounterStyleWithoutAdditiveSymbols => write!(f, "a '@counter-style' with 'system: additive' must have an 'additive-symbols' descriptor"),
+			InvalidCounterStyleExtendsWithSymbols => write!(f, "a '@counter-style' with 'system: extends' can not have a 'symbols' descriptor"),
+			InvalidCounterStyleExtendsWithAdditiveSymbols => write!(f, "a '@counter-style' with 'system: extends' can not have an 'additive-symbols' descriptor"),
+			CounterStyleSystemIsNotKnown(ref system) => write!(f, "'{}' is not a known counter style system", system),
+			CounterStyleSymbolsCanNotBeEmpty => write!(f, "counter style symbols can not be empty"),
+			CounterStyleRangesCanNotHaveStartGreaterThanEnd(start, end) => write!(f, "a counter style range can not start ({}) after it ends ({})", start, end),
+			CounterStylePadMinLengthCanNotBeNegative(length) => write!(f, "a counter style pad length can not be negative (it was {})", length),
+			CounterStyleAdditiveTupleWeightCanNotBeNegative(weight) => write!(f, "a counter style additive symbol weight can not be negative (it was {})", weight),
+			CounterStyleAdditiveSymbolsCanNotHaveASecondWeightEqualToOrGreaterThanTheFirst => write!(f, "counter style additive symbols must be in descending order of weight"),
+			DecimalOrDiscIsNotAllowedInACounterStyleIdentInACounterStyleAtRule => write!(f, "'decimal' and 'disc' can not be used as the name of a '@counter-style'"),
+			NoneIsNotAllowedInACounterStyleIdent => write!(f, "'none' can not be used as the name of a counter style"),
+			
+			DocumentAtRuleUrlMatchingFunctionWasInvalid => write!(f, "expected a 'url()', 'url-prefix()', 'domain()' or 'regexp()' function in '@document'"),
+			DocumentAtRuleRegExpWasInvalid(ref regExp, reason) => write!(f, "the regular expression '{}' in '@document' is invalid: {}", regExp, reason),
+			BadUrlInDeclarationValueBlock(ref url) => write!(f, "the url '{}' is badly formed", url),
+			BadStringInDeclarationValueBlock(ref string) => write!(f, "the string '{}' is not terminated", string),
+			UnbalancedCloseParenthesisInDeclarationValueBlock => write!(f, "a ')' does not have a matching '('"),
+			UnbalancedCloseSquareBracketInDeclarationValueBlock => write!(f, "a ']' does not have a matching '['"),
+			UnbalancedCloseCurlyBracketInDeclarationValueBlock => write!(f, "a '}}' does not have a matching '{{'"),
+			
+			UnsupportedFontFaceProperty(ref name) => write!(f, "'{}' is not a descriptor of '@font-face'", name),
+			
+			InvalidFontLanguageOverrideIdentifier(ref identifier) => write!(f, "'{}' is not a valid font language override", identifier),
+			InvalidFontLanguageOverrideOpenTypeLanguageTag(ref tag) => write!(f, "'{}' is not a valid OpenType language tag", tag),
+			FontFeatureSettingOpenTypeFeatureTagMustBeFourCharacters(ref tag) => write!(f, "the OpenType feature tag '{}' must be exactly four characters", tag),
+			FontFeatureSettingOpenTypeFeatureTagMustBePrintableAscii(ref tag) => write!(f, "the OpenType feature tag '{}' must only contain printable ASCII characters", tag),
+			FontFeatureSettingIfNotAnIntegerMustBeOnOrOff(ref value) => write!(f, "a font feature setting must be an integer, 'on' or 'off', not '{}'", value),
+			FontFeatureSettingIntegerMustBePositive(value) => write!(f, "a font feature setting can not be negative (it was {})", value),
+			FontFaceAtRuleFontWeightWasNotAValidIdentifierOrInteger => write!(f, "'font-weight' in '@font-face' must be 'normal', 'bold' or a number"),
+			FontFaceAtRuleFontFamilyCanNotBeGeneric => write!(f, "'font-family' in '@font-face' can not be a generic family, such as 'serif'"),
+			
+			AtRuleImportMustBeBeforeAnyRuleExceptAtRuleCharset => write!(f, "'@import' must come before all other rules except '@charset' and '@layer' statements"),
+			
+			KeyframePercentageWasNotBetweenZeroAndOneInclusive(percentage) => write!(f, "a keyframe selector must be between 0% and 100% (it was {}%)", percentage * 100.0),
+			ImportantIsNotAllowedInKeyframePropertyDeclarationValues => write!(f, "'!important' is not allowed in a keyframe"),
+			UnexpectedTokenWhenParsingZoom(ref token) => write!(f, "expected a zoom, not '{}'", token.to_css_string()),
+			
+			InvalidLayerName(ref name) => write!(f, "'{}' is not a valid layer name", name),
+			AtRuleLayerBlockCanNotHaveMoreThanOneName => write!(f, "a '@layer' with a block can only have one name"),
+			
+			InvalidMediaType(ref mediaType) => write!(f, "'{}' can not be used as a media type", mediaType),
+			DeprecatedMediaType(ref mediaType) => write!(f, "the media type '{}' is deprecated", mediaType),
+			UnrecognisedMediaType(ref mediaType) => write!(f, "'{}' is not a known media type", mediaType),
+			DeprecatedMediaQueryExpression(ref name) => write!(f, "the media feature '{}' is deprecated", name),
+			UnsupportedMediaQueryExpression(ref name) => write!(f, "the media feature '{}' is not supported", name),
+			RatioNumeratorCanNotBeNegativeOrZero(value) => write!(f, "the numerator of a ratio must be positive (it was {})", value),
+			RatioDivisorCanNotBeNegativeOrZero(value) => write!(f, "the denominator of a ratio must be positive (it was {})", value),
+			MediaGridMustBeEitherZeroOrOne(value) => write!(f, "the media feature 'grid' must be 0 or 1 (it was {})", value),
+			MediaTransform3DMustBeEitherZeroOrOne(value) => write!(f, "the media feature 'transform-3d' must be 0 or 1 (it was {})", value),
+			MediaTypeIsOnlyOptionalIfQualifiedIsNotSpecified => write!(f, "a media type is required after 'only' or 'not'"),
+			
+			AtRuleNamespaceMustBeBeforeAnyRuleExceptAtRuleCharsetAndAtRuleImport => write!(f, "'@namespace' must come before all other rules except '@charset', '@import' and '@layer' statements"),
+			UnexpectedTokenForAtNamespaceRuleNamespaceValue(ref token) => write!(f, "expected a namespace url, not '{}'", token.to_css_string()),
+			
+			InvalidPageSelectorPseudoClass(ref name) => write!(f, "':{}' is not a valid page selector pseudo-class", name),
+			UnsupportedPageMarginAtRule(ref name) => write!(f, "'@{}' is not a page-margin rule", name),
+			FontRelativeLengthsAreNotAllowedInAPageAtRule => write!(f, "font-relative lengths (such as 'em') are not allowed in '@page'"),
+			ViewportLengthsAreNotAllowedInAPageAtRule => write!(f, "viewport lengths (such as 'vw') are not allowed in '@page'"),
+			
+			InvalidSupportsCondition(ref name) => write!(f, "'{}' is not valid in a supports condition", name),
+			
+			UnexpectedViewportProperty(ref name) => write!(f, "'{}' is not a descriptor of '@viewport'", name),
+			
+			SpecificSelectorParseError(ref error) => selector_parse_error_message(error, f),
+			ThereAreNoSelectors => write!(f, "a style rule must have at least one selector"),
+			SelectorIsInvalidInContext(ref selector) => write!(f, "the selector '{}' is not valid here", selector),
+			UnsupportedPseudoClassOrElement(ref name) => write!(f, "the pseudo-class or pseudo-element '{}' is not supported", name),
+			NonTreeStructuralPseudoClassScopeIsObsoleteAsOfFirefox55 => write!(f, "':scope' is obsolete"),
+			
+			UnexpectedCustomIdent(ref ident) => write!(f, "'{}' is not allowed here", ident),
+			CustomIdentWasExcluded(ref ident) => write!(f, "'{}' is a reserved word and can not be used as a name here", ident),
+			
+			CouldNotParseCssSignedNumber(ref error, value) => write!(f, "{} is not a valid number: {}", value, error),
+			CouldNotParseCssUnsignedNumber(ref error, value) => write!(f, "{} is not a valid non-negative number: {}", value, error),
+			CouldNotParseDimensionLessNumber(value) => write!(f, "{} is not a valid number", value),
+			CouldNotParseDimension(value, ref unit) => write!(f, "{}{} is not a valid dimension", value, unit),
+			UnsignedIntegersCanNotBeNegative(value) => write!(f, "expected a non-negative integer, not {}", value),
+			UnsignedIntegersCanNotBeFloats(value) => write!(f, "expected an integer, not {}", value),
+			
+			UnknownFunctionInValueExpression(ref name) => write!(f, "'{}()' is not a known function", name),
+			CssVariablesInVarExpressionsMustStartWithTwoDashes(ref name) => write!(f, "the custom property name '{}' in 'var()' must start with '--'", name),
+			CalcFunctionCanNotCombineANumberAndADimension => write!(f, "'calc()' can not add a number to a dimension, multiply two dimensions or divide by a dimension"),
+			CalcFunctionResultMustBeANumber => write!(f, "the result of 'calc()' must be a number here"),
+			CalcFunctionResultCanNotBeANumber => write!(f, "the result of 'calc()' can not be a number here"),
+			
+			InvalidPosition => write!(f, "expected a position, such as 'left top' or '10px 50%'"),
+			BackgroundColorMustBeInTheFinalLayer => write!(f, "a background color can only be given in the final layer of 'background'"),
+			VisualBoxIsNotAllowedHere(name) => write!(f, "'{}' is not allowed here", name),
+			GradientMustHaveAtLeastTwoColourStops => write!(f, "a gradient must have at least two colour stops"),
+			GradientInterpolationHintMustBeBetweenColourStops => write!(f, "a gradient interpolation hint must be between two colour stops"),
+			InvalidGradientShape => write!(f, "a circle's size must be one length or extent keyword, and an ellipse's two lengths or percentages or an extent keyword"),
+			UnexpectedImageFunction(ref name) => write!(f, "the function '{}()' is not an image", name),
+			UnexpectedTransformFunction(ref name) => write!(f, "the function '{}()' is not a transform function", name),
+			UnexpectedEasingFunction(ref name) => write!(f, "the function '{}()' is not an easing function", name),
+			CubicBezierXMustBeBetweenZeroAndOne(value) => write!(f, "the x values of 'cubic-bezier()' must be between 0 and 1, not {}", value),
+			NotEnoughSteps(value) => write!(f, "'steps()' must have at least one step, or two with 'jump-none', not {}", value),
+			LinearEasingFunctionMustHaveAtLeastTwoStops => write!(f, "'linear()' must have at least two stops"),
+			TransitionPropertyNoneMustBeOnItsOwn => write!(f, "'none' can only be used on its own in 'transition-property'"),
+			UnexpectedGridFunction(ref name) => write!(f, "the function '{}()' is not a grid track size", name),
+			FlexibleTrackSizeIsNotAllowedHere => write!(f, "a flexible ('fr') track size is not allowed here"),
+			GridRepeatCountMustBeAtLeastOne(value) => write!(f, "the number of repetitions in 'repeat()' must be at least one, not {}", value),
+			GridTrackListCanHaveOnlyOneAutoRepeat => write!(f, "a track list can only have one 'repeat(auto-fill, ...)' or 'repeat(auto-fit, ...)'"),
+			GridAutoRepeatMustOnlyHaveFixedSizes => write!(f, "a track list with 'repeat(auto-fill, ...)' or 'repeat(auto-fit, ...)' can only have fixed track sizes"),
+			InvalidGridTemplateAreasString(ref string) => write!(f, "'{}' is not a valid row of grid template areas", string),
+			GridTemplateAreasRowsMustHaveTheSameNumberOfColumns => write!(f, "every row of grid template areas must have the same number of columns"),
+			GridTemplateAreaMustBeRectangular(ref name) => write!(f, "the grid template area '{}' is not a rectangle", name),
+			GridLineCanNotBeZero => write!(f, "a grid line number can not be zero"),
+			GridSpanMustBeAtLeastOne(value) => write!(f, "a grid line must span at least one line, not {}", value),
+			AlignmentKeywordIsNotAllowed(property, ref keyword) => write!(f, "'{}' is not a valid value of '{}'", keyword, property),
+			BaselinePositionCanNotBeCombinedWithOtherKeywords(property) => write!(f, "a baseline position can not be combined with other keywords in '{}'", property),
+			DisplayListItemMustHaveAFlowInnerDisplayType => write!(f, "'display: list-item' can only be combined with the inner display types 'flow' and 'flow-root'"),
+		}
+	}
+}
+
+fn selector_parse_error_message<'i>(error: &SelectorParseError<'i, CustomParseError<'i>>, f: &mut fmt::Formatter) -> fmt::Result
+{
+	use self::SelectorParseError::*;
+	
+	match *error
+	{
+		PseudoElementInComplexSelector => write!(f, "a pseudo-element must be at the end of a selector"),
+		NoQualifiedNameInAttributeSelector(ref token) => write!(f, "expected an attribute name, not '{}'", token.to_css_string()),
+		EmptySelector => write!(f, "a selector can not be empty"),
+		DanglingCombinator => write!(f, "a combinator must be followed by a selector"),
+		NonSimpleSelectorInNegation => write!(f, "':not()' can only contain simple selectors"),
+		UnexpectedTokenInAttributeSelector(ref token) => write!(f, "unexpected '{}' in an attribute selector", token.to_css_string()),
+		PseudoElementExpectedColon(ref token) => write!(f, "expected ':' before a pseudo-element, not '{}'", token.to_css_string()),
+		PseudoElementExpectedIdent(ref token) => write!(f, "expected a pseudo-element name, not '{}'", token.to_css_string()),
+		NoIdentForPseudo(ref token) => write!(f, "expected a pseudo-class name, not '{}'", token.to_css_string()),
+		UnsupportedPseudoClassOrElement(ref name) => write!(f, "the pseudo-class or pseudo-element '{}' is not supported", name),
+		UnexpectedIdent(ref ident) => write!(f, "'{}' is not allowed here", ident),
+		ExpectedNamespace(ref prefix) => write!(f, "the namespace prefix '{}' has not been declared with '@namespace'", prefix),
+		ExpectedBarInAttr(ref token) => write!(f, "expected '|' in an attribute selector, not '{}'", token.to_css_string()),
+		BadValueInAttr(ref token) => write!(f, "'{}' is not a valid attribute selector value", token.to_css_string()),
+		InvalidQualNameInAttr(ref token) => write!(f, "'{}' is not a valid attribute name", token.to_css_string()),
+		ExplicitNamespaceUnexpectedToken(ref token) => write!(f, "expected a name after a namespace prefix, not '{}'", token.to_css_string()),
+		ClassNeedsIdent(ref token) => write!(f, "expected a class name after '.', not '{}'", token.to_css_string()),
+		EmptyNegation => write!(f, "':not()' can not be empty"),
+		Custom(ref error) => fmt::Display::fmt(error, f),
+	}
+}
+
+impl<'i> From<ParseErrorKind<'i, CustomParseError<'i>>> for CustomParseError<'i>
+{
+	#[inline(always)]
+	fn from(kind: ParseErrorKind<'i, CustomParseError<'i>>) -> Self
+	{
+		match kind
+		{
+			ParseErrorKind::Basic(kind) => CustomParseError::Basic(kind),
+			ParseErrorKind::Custom(error) => error,
+		}
+	}
 }
 
 impl<'i> From<SelectorParseError<'i, CustomParseError<'i>>> for CustomParseError<'i> {
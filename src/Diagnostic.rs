@@ -0,0 +1,31 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A rule or declaration that was skipped because it could not be parsed, when parsing with `ParseOptions::recover`.
+#[derive(Debug)]
+pub struct Diagnostic<'i>
+{
+	/// Why the rule or declaration could not be parsed; syntax errors, such as an unexpected token, are `CustomParseError::Basic`.
+	pub kind: CustomParseError<'i>,
+	
+	/// Where the error occurred (lines are zero-based, columns one-based).
+	pub location: SourceLocation,
+	
+	/// The original CSS of the skipped rule or declaration.
+	pub span: &'i str,
+}
+
+impl<'i> Diagnostic<'i>
+{
+	#[inline(always)]
+	pub(crate) fn new(error: ParseError<'i, CustomParseError<'i>>, span: &'i str) -> Self
+	{
+		Self
+		{
+			kind: CustomParseError::from(error.kind),
+			location: error.location,
+			span,
+		}
+	}
+}
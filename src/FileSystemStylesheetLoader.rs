@@ -0,0 +1,31 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Loads stylesheets from the file system.
+/// URLs are treated as paths, relative to the current working directory unless absolute; `file://` URLs are also supported.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FileSystemStylesheetLoader;
+
+impl StylesheetLoader for FileSystemStylesheetLoader
+{
+	fn load(&self, resolved_url: &str) -> ::std::io::Result<String>
+	{
+		let path = if let Some(path) = resolved_url.strip_prefix("file://")
+		{
+			path
+		}
+		else if url_has_scheme(resolved_url)
+		{
+			return Err(::std::io::Error::new(::std::io::ErrorKind::InvalidInput, format!("Can not load '{}' from the file system", resolved_url)));
+		}
+		else
+		{
+			resolved_url
+		};
+		
+		let mut css = String::new();
+		File::open(path)?.read_to_string(&mut css)?;
+		Ok(css)
+	}
+}
@@ -0,0 +1,23 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A likely mistake in a stylesheet, found by `Stylesheet::lint()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Lint
+{
+	/// What the mistake is.
+	pub kind: LintKind,
+	
+	/// Where the property declaration with the mistake came from, if recorded when parsing (see `ParseOptions::source_ranges`).
+	pub source_range: Option<SourceRange>,
+}
+
+impl fmt::Display for Lint
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		fmt::Display::fmt(&self.kind, f)
+	}
+}
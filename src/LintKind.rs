@@ -0,0 +1,48 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The kinds of mistake found by `Stylesheet::lint()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LintKind
+{
+	/// An `animation` or `animation-name` uses a name for which there is no `@keyframes` rule in the stylesheet.
+	AnimationNameWithoutKeyframes(Atom),
+	
+	/// A box alignment property (such as `justify-items` or `place-content`) has a value that is not valid for it, such as `justify-items: left baseline`.
+	InvalidBoxAlignment
+	{
+		property: Atom,
+		
+		value: String,
+		
+		/// Why the value is not valid.
+		reason: String,
+	},
+	
+	/// A `grid-template-areas` (or the areas in a `grid-template` or `grid`) is not valid, such as `"a b" "b a"`, where the area `a` is not a rectangle.
+	InvalidGridTemplateAreas
+	{
+		property: Atom,
+		
+		value: String,
+		
+		/// Why the value is not valid.
+		reason: String,
+	},
+}
+
+impl fmt::Display for LintKind
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		use self::LintKind::*;
+		
+		match *self
+		{
+			AnimationNameWithoutKeyframes(ref name) => write!(f, "there is no '@keyframes' rule for the animation name '{}'", name),
+			InvalidBoxAlignment { ref property, ref value, ref reason } => write!(f, "'{}: {}' is not valid: {}", property, value, reason),
+			InvalidGridTemplateAreas { ref property, ref value, ref reason } => write!(f, "'{}: {}' is not valid: {}", property, value, reason),
+		}
+	}
+}
@@ -0,0 +1,25 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Options for `Stylesheet::minify()`, for optimisations that go beyond serializing in the shortest form.
+/// All are off by default.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct MinifyOptions
+{
+	/// Replace a `transform`'s functions with an equivalent `matrix()` (or `matrix3d()`) where that is shorter, eg `rotate(45deg) translate(10px, 20px) scale(2)`.
+	/// Only transforms with absolute lengths are replaced.
+	/// Off by default, as transitions and animations interpolate matrices differently to other transform functions.
+	pub transforms_to_matrix: bool,
+	
+	/// Simplify `calc()` functions, eg `calc(10px + 5px)` becomes `15px` and `calc(1em + 10px + 1em)` becomes `calc(2em + 10px)`; see `CalcExpression::simplify()`.
+	/// In properties without a typed value (such as `width` or `margin`), only `calc()` functions of non-negative lengths and percentages are simplified, and those using `var()` are left as they are.
+	/// A length of zero next to a percentage is kept, as `calc(50% + 0px)` does not always behave as `50%`.
+	/// Off by default, as absolute lengths are converted to pixels, which can be longer (`calc(1pt + 1em)` becomes `calc(1.33333px + 1em)`) and lose precision.
+	pub simplify_calc_functions: bool,
+	
+	/// Replace each `var()` of a custom property defined exactly once in the stylesheet, on a top-level `:root` or `html` rule, with its value, and remove the definition; eg `:root { --brand: #0a66c2 } a { color: var(--brand) }` becomes `a { color: #0a66c2 }`.
+	/// Nothing is inlined if the custom property is also defined elsewhere, including in another selector or inside `@media`, or if the stylesheet has `@import` rules.
+	/// Off by default, as custom properties can also be set by scripts or inline styles, which the stylesheet does not show.
+	pub inline_custom_properties: bool,
+}
@@ -0,0 +1,23 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Options for `Stylesheet::parse_with_options()`.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ParseOptions
+{
+	/// Skip rules and declarations that can not be parsed, following the CSS Syntax error-recovery rules, and report them as diagnostics.
+	/// If false, parsing fails on the first error.
+	pub recover: bool,
+	
+	/// Record the `SourceRange` of each rule, style rule selector list and property declaration.
+	/// Off by default, as it costs time and memory that minifiers do not need.
+	pub source_ranges: bool,
+	
+	/// Keep the original CSS, so that `Stylesheet::to_css_lossless()` can write out rules and property declarations that have not been modified exactly as they were, comments and whitespace included.
+	/// Implies `source_ranges`.
+	pub lossless: bool,
+	
+	/// Discard important comments (`/*! ... */`, typically licence banners); by default, they are kept as `CssRule::Comment` and serialized.
+	pub strip_important_comments: bool,
+}
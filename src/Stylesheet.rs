@@ -15,6 +15,12 @@ pub struct Stylesheet
 	
 	/// An optional source URL for this stylesheet.
 	pub source_url: Option<String>,
+	
+	/// The original CSS, if parsed with `ParseOptions::lossless`.
+	pub original_css: Option<OriginalCss>,
+	
+	/// The RTLCSS-style control comments in the original CSS, if it had any; honoured by `transform_direction()`.
+	pub rtl_control_comments: Option<RtlControlComments>,
 }
 
 impl HasCssRules for Stylesheet
@@ -105,6 +111,96 @@ impl Stylesheet
 		Ok(())
 	}
 	
+	/// Serializes a Stylesheet as for `to_css()`, and creates a source map from the serialized CSS back to the original.
+	/// Only rules and property declarations with a source range (see `ParseOptions::source_ranges`) are mapped.
+	/// `source` names the original CSS in the source map.
+	/// If the original CSS was itself generated (eg by a preprocessor), pass its source map as `input_source_map`; the created source map then maps back to the sources of that instead.
+	pub fn to_css_with_source_map<W: fmt::Write>(&self, destination: &mut W, include_source_urls: bool, source: &str, input_source_map: Option<&SourceMap>) -> Result<SourceMap, fmt::Error>
+	{
+		use ::std::fmt::Write;
+		
+		let mut writer = SourceMapWriter::new(destination, input_source_map);
+		
+		if include_source_urls
+		{
+			if let Some(ref source_map_url) = self.source_map_url
+			{
+				writeln!(writer, "//# sourceMappingURL=<{}>", source_map_url)?;
+			}
+			
+			if let Some(ref source_url) = self.source_url
+			{
+				writeln!(writer, "//# sourceURL=<{}>", source_url)?;
+			}
+		}
+		
+		self.rules.to_css_with_source_map(&mut writer)?;
+		
+		Ok(writer.into_source_map(source))
+	}
+	
+	/// Serializes as for `to_css()` (without source URLs), except that rules and property declarations that have not been modified since parsing with `ParseOptions::lossless` are written out exactly as they were, including the comments and whitespace before them.
+	/// Modified and new rules and property declarations are serialized as for `to_css()`.
+	/// If not parsed with `ParseOptions::lossless`, this is the same as `to_css(destination, false)`.
+	pub fn to_css_lossless<W: fmt::Write>(&self, destination: &mut W) -> fmt::Result
+	{
+		match self.original_css
+		{
+			None => self.to_css(destination, false),
+			Some(ref original_css) => LosslessWriter::new(destination, original_css).write_stylesheet(&self.rules),
+		}
+	}
+	
+	/// Replaces `@import` rules with the rules of the stylesheets they import, recursively, using `loader` to find and load them.
+	///
+	/// `stylesheet_url` is the URL (or path) of this stylesheet; imports are resolved relative to it.
+	/// Imports with a layer, `supports()` condition or media queries are wrapped in `@layer`, `@supports` and `@media` rules.
+	/// Relative URLs in `url()`s in imported stylesheets are rebased to be relative to `stylesheet_url`.
+	pub fn bundle<L: StylesheetLoader>(self, stylesheet_url: &str, loader: &L) -> Result<Self, BundleError>
+	{
+		let rules = StylesheetBundler::new(loader, stylesheet_url).bundle_rules(self.rules)?;
+		
+		Ok
+		(
+			Self
+			{
+				rules,
+				source_map_url: self.source_map_url,
+				source_url: self.source_url,
+				original_css: None,
+				rtl_control_comments: None,
+			}
+		)
+	}
+	
+	/// Applies the optimisations in `options`; see `MinifyOptions`.
+	#[inline(always)]
+	pub fn minify(&mut self, options: &MinifyOptions)
+	{
+		self.rules.minify(options)
+	}
+	
+	/// Changes the horizontal direction of the property declarations; see `DirectionTransform`.
+	///
+	/// RTLCSS-style control comments in the parsed CSS are honoured: a rule or property declaration preceded by `/* rtl:ignore */` (or, for a property declaration, containing it) is left alone, as are those between `/* rtl:begin:ignore */` and `/* rtl:end:ignore */`.
+	#[inline(always)]
+	pub fn transform_direction(&mut self, transform: DirectionTransform)
+	{
+		match self.rtl_control_comments
+		{
+			None => self.rules.transform_direction(transform),
+			Some(ref rtl_control_comments) => self.rules.transform_direction_unless_ignored(transform, Some(rtl_control_comments)),
+		}
+	}
+	
+	/// Finds likely mistakes, such as an `animation-name` for which there is no `@keyframes` rule; see `LintKind`.
+	/// Only property values parsed into typed values, and box alignment values, are checked.
+	#[inline(always)]
+	pub fn lint(&self) -> Vec<Lint>
+	{
+		self.rules.lint()
+	}
+	
 	/// Loads and parses a Stylesheet.
 	#[inline(always)]
 	pub fn from_file_path<P: AsRef<Path>>(html_document_file_path: P) -> Result<Self, StylesheetError>
@@ -116,22 +212,7 @@ impl Stylesheet
 		let mut css = String::with_capacity(metadata.len() as usize);
 		file.read_to_string(&mut css).context(path)?;
 		
-		let result = Self::parse(&css);
-		
-		match result
-		{
-			Ok(stylesheet) => Ok(stylesheet),
-			Err(cause) => Err(StylesheetError::Parse
-			(
-				path.to_path_buf(),
-				cause.location,
-				match cause.kind {
-					ParseErrorKind::Basic(e) => format!("{:?}", e),
-					ParseErrorKind::Custom(e) => format!("{:?}", e),
-				},
-			)),
-			
-		}
+		Self::parse(&css).map_err(|error| StylesheetError::Parse(path.to_path_buf(), CssError::new(error, &css)))
 	}
 	
 	/// Parses a string of CSS to produce a stylesheet.
@@ -140,7 +221,143 @@ impl Stylesheet
 	/// Does not use a stream of bytes as parsing CSS involves going backwards and forwards a lot... CSS parsing is somewhat evil and is not particularly efficient.
 	/// The parser does apply a few small modifications to the incoming CSS, normalizing some pseudo-class, psuedo-element and media query names.
 	/// The parser does not parse properties as such, simply keeping them as a CSS string. Hopefully it will one day - there are only 200 odd specialist rules to implement.
+	#[inline(always)]
 	pub fn parse(css: &str) -> Result<Self, ParseError<CustomParseError>>
+	{
+		Self::parse_with_options(css, &ParseOptions::default()).map(|(stylesheet, _diagnostics)| stylesheet)
+	}
+	
+	/// Parses a string of CSS to produce a stylesheet, as for `parse()`, but on up to `number_of_threads` threads; if zero, the available parallelism is used.
+	/// Intended for large stylesheets, the CSS is split at the boundaries of its top-level rules by a fast pre-scan, and each run of rules is parsed on its own thread.
+	/// Rules up to and including the last `@import` or `@namespace` rule are parsed first, so that every thread knows the namespace prefixes.
+	/// The result is identical to that of `parse()`; if there is more than one error, the first one in the CSS is returned.
+	/// Small stylesheets, and those that can not be split reliably, are parsed on the current thread.
+	pub fn parse_parallel(css: &str, number_of_threads: usize) -> Result<Self, CssError>
+	{
+		const MinimumBytesPerThread: usize = 64 * 1024;
+		
+		let number_of_threads = match number_of_threads
+		{
+			0 => available_parallelism().map(NonZeroUsize::get).unwrap_or(1),
+			_ => number_of_threads,
+		};
+		
+		let sequential = || Self::parse(css).map_err(|error| CssError::new(error, css));
+		
+		// Control comments are found using where rules were in all of the CSS, which the runs of rules do not record.
+		if number_of_threads < 2 || css.len() < 2 * MinimumBytesPerThread || RtlControlComments::find(css).is_some()
+		{
+			return sequential();
+		}
+		
+		let boundaries = match TopLevelRuleScanner::scan(css)
+		{
+			None => return sequential(),
+			Some(boundaries) => boundaries,
+		};
+		
+		let head_end = boundaries.iter().rev().find(|boundary| boundary.is_import_or_namespace).map(|boundary| boundary.end).unwrap_or(0);
+		
+		let runs = Self::runs_of_top_level_rules(css, head_end, &boundaries, number_of_threads, MinimumBytesPerThread);
+		if runs.len() < 2
+		{
+			return sequential();
+		}
+		
+		let head = ParsedRunOfTopLevelRules::parse(css, 0 .. head_end, 0, 0, State::Start, &Namespaces::default())?;
+		let state = head.state;
+		let namespaces = head.namespaces;
+		
+		let parsed_runs = scope(|scope|
+		{
+			let namespaces = &namespaces;
+			let handles: Vec<_> = runs.into_iter().map(|(range, line, column_offset)| scope.spawn(move || ParsedRunOfTopLevelRules::parse(css, range, line, column_offset, state, namespaces))).collect();
+			handles.into_iter().map(|handle| handle.join().unwrap_or_else(|panic| resume_unwind(panic))).collect::<Vec<_>>()
+		});
+		
+		let mut rules = head.rules;
+		let mut source_map_url = head.source_map_url;
+		let mut source_url = head.source_url;
+		for parsed_run in parsed_runs
+		{
+			let mut parsed_run = parsed_run?;
+			rules.append(&mut parsed_run.rules);
+			if parsed_run.source_map_url.is_some()
+			{
+				source_map_url = parsed_run.source_map_url;
+			}
+			if parsed_run.source_url.is_some()
+			{
+				source_url = parsed_run.source_url;
+			}
+		}
+		
+		Ok
+		(
+			Self
+			{
+				rules: CssRules(rules),
+				source_map_url,
+				source_url,
+				original_css: None,
+				rtl_control_comments: None,
+			}
+		)
+	}
+	
+	/// Splits the top-level rules after `head_end` into runs of roughly equal size, with the (zero-based) line number and UTF-16 column offset of the start of each run.
+	fn runs_of_top_level_rules(css: &str, head_end: usize, boundaries: &[TopLevelRuleBoundary], number_of_threads: usize, minimum_bytes_per_thread: usize) -> Vec<(Range<usize>, u32, u32)>
+	{
+		let bytes_per_thread = max((css.len() - head_end) / number_of_threads, minimum_bytes_per_thread);
+		
+		let mut runs = Vec::with_capacity(number_of_threads);
+		let mut line = 0;
+		let mut line_start = 0;
+		let mut scanned_up_to = 0;
+		let mut run_start = head_end;
+		let last_index = boundaries.len() - 1;
+		for (index, boundary) in boundaries.iter().enumerate().filter(|&(_, boundary)| boundary.end > head_end)
+		{
+			let is_last = index == last_index;
+			if boundary.end - run_start < bytes_per_thread && !is_last
+			{
+				continue;
+			}
+			
+			let bytes = css.as_bytes();
+			while scanned_up_to < run_start
+			{
+				match bytes[scanned_up_to]
+				{
+					b'\r' if bytes.get(scanned_up_to + 1) == Some(&b'\n') => (),
+					b'\n' | b'\r' | b'\x0C' =>
+					{
+						line += 1;
+						line_start = scanned_up_to + 1;
+					}
+					_ => (),
+				}
+				scanned_up_to += 1;
+			}
+			let column_offset = css[line_start .. run_start].encode_utf16().count() as u32;
+			
+			let run_end = if is_last
+			{
+				css.len()
+			}
+			else
+			{
+				boundary.end
+			};
+			runs.push((run_start .. run_end, line, column_offset));
+			run_start = run_end;
+		}
+		runs
+	}
+	
+	/// Parses a string of CSS to produce a stylesheet, as for `parse()`.
+	/// If `options.recover` is true, rules and declarations that can not be parsed are skipped and returned as diagnostics (and an error is never returned); otherwise, the diagnostics are always empty.
+	pub fn parse_with_options<'i>(css: &'i str, options: &ParseOptions) -> Result<(Self, Vec<Diagnostic<'i>>), ParseError<'i, CustomParseError<'i>>>
 	{
 		const LineNumberingIsZeroBased: u32 = 0;
 		
@@ -149,38 +366,78 @@ impl Stylesheet
 		
 		let mut rules = Vec::new();
 		
+		let diagnostics = if options.recover
+		{
+			Some(Rc::new(RefCell::new(Vec::new())))
+		}
+		else
+		{
+			None
+		};
+		
+		// Finding which rules and property declarations the control comments apply to needs the same record of where they were as lossless parsing does.
+		let mut rtl_control_comments = RtlControlComments::find(css);
+		
+		let original_css = if options.lossless || rtl_control_comments.is_some()
+		{
+			Some(Rc::new(RefCell::new(OriginalCss::default())))
+		}
+		else
+		{
+			None
+		};
+		
 		let topLevelRuleParser = TopLevelRuleParser
 		{
 			context: ParserContext
 			{
 				rule_type: None,
 				parsing_mode: ParsingMode::Default,
+				diagnostics: diagnostics.clone(),
+				source_ranges: options.source_ranges || original_css.is_some(),
+				original_css: original_css.clone(),
+				strip_important_comments: options.strip_important_comments,
 			},
 			state: State::Start,
 			namespaces: Namespaces::empty(),
 		};
 		
+		topLevelRuleParser.parse_rules(&mut input, &mut rules)?;
+		
+		let rules = CssRules(rules);
+		
+		let mut original_css = original_css.map(|original_css|
 		{
-			let mut iter = RuleListParser::new_for_stylesheet(&mut input, topLevelRuleParser);
-			
-			while let Some(result) = iter.next()
-			{
-				match result
-				{
-					Ok(rule) => rules.push(rule),
-					Err((preciseParseError, _)) => return Err(preciseParseError),
-				}
-			}
+			let mut original_css = Rc::try_unwrap(original_css).expect("All parser contexts should have been dropped").into_inner();
+			original_css.finish(css, &rules);
+			original_css
+		});
+		
+		if let Some(ref mut rtl_control_comments) = rtl_control_comments
+		{
+			let recorded = if options.lossless { original_css.clone() } else { original_css.take() };
+			rtl_control_comments.record_original_css(recorded.expect("Original CSS is recorded when there are control comments"));
 		}
 		
+		let diagnostics = match diagnostics
+		{
+			None => Vec::new(),
+			Some(diagnostics) => Rc::try_unwrap(diagnostics).expect("All parser contexts should have been dropped").into_inner(),
+		};
+		
 		Ok
 		(
-			Self
-			{
-				rules: CssRules(rules),
-				source_map_url: input.current_source_map_url().map(String::from),
-				source_url: input.current_source_url().map(String::from),
-			}
+			(
+				Self
+				{
+					rules,
+					source_map_url: input.current_source_map_url().map(String::from),
+					source_url: input.current_source_url().map(String::from),
+					original_css,
+					rtl_control_comments,
+				},
+				diagnostics,
+			)
 		)
 	}
 }
@@ -0,0 +1,302 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Inlines the rules of imported stylesheets, recursively.
+pub(crate) struct StylesheetBundler<'a, L: 'a + StylesheetLoader>
+{
+	loader: &'a L,
+	
+	/// The first entry is the URL of the stylesheet being bundled; the last is the URL of the stylesheet currently being inlined.
+	import_chain: Vec<String>,
+}
+
+impl<'a, L: 'a + StylesheetLoader> StylesheetBundler<'a, L>
+{
+	#[inline(always)]
+	pub(crate) fn new(loader: &'a L, stylesheet_url: &str) -> Self
+	{
+		Self
+		{
+			loader,
+			import_chain: vec![stylesheet_url.to_owned()],
+		}
+	}
+	
+	pub(crate) fn bundle_rules(&mut self, rules: CssRules) -> Result<CssRules, BundleError>
+	{
+		let isImported = self.import_chain.len() > 1;
+		
+		let mut bundled = Vec::with_capacity(rules.0.len());
+		for rule in rules.0
+		{
+			match rule
+			{
+				CssRule::Import(importAtRule) => bundled.extend(self.bundle_import(importAtRule)?),
+				
+				CssRule::Namespace(_) if isImported => return Err(BundleError::NamespaceInImportedStylesheet(self.current_url().to_owned(), self.import_chain_before_current())),
+				
+				mut rule =>
+				{
+					if isImported
+					{
+						self.rebase_rule(&mut rule);
+					}
+					bundled.push(rule);
+				}
+			}
+		}
+		Ok(CssRules(bundled))
+	}
+	
+	fn bundle_import(&mut self, importAtRule: ImportAtRule) -> Result<Vec<CssRule>, BundleError>
+	{
+		let url = self.loader.resolve(self.current_url(), &importAtRule.url.0);
+		
+		if self.import_chain.contains(&url)
+		{
+			let mut import_chain = self.import_chain.clone();
+			import_chain.push(url);
+			return Err(BundleError::Cycle(import_chain));
+		}
+		
+		let css = match self.loader.load(&url)
+		{
+			Ok(css) => css,
+			Err(cause) => return Err(BundleError::Load(url, self.import_chain.clone(), cause)),
+		};
+		
+		let rules = match Stylesheet::parse(&css)
+		{
+			Ok(stylesheet) => stylesheet.rules,
+			Err(cause) => return Err(BundleError::Parse(url, self.import_chain.clone(), CssError::new(cause, &css))),
+		};
+		
+		self.import_chain.push(url);
+		let rules = self.bundle_rules(rules);
+		self.import_chain.pop();
+		let mut rules = rules?;
+		
+		// `@import url layer supports() media` is equivalent to `@media media { @supports supports() { @layer layer { ... } } }`.
+		if let Some(layer) = importAtRule.layer
+		{
+			rules = CssRules(vec![CssRule::LayerBlock(LayerBlockAtRule
+			{
+				name: match layer
+				{
+					ImportLayer::Anonymous => None,
+					ImportLayer::Named(name) => Some(name),
+				},
+				rules,
+				source_range: importAtRule.source_range,
+			})]);
+		}
+		
+		if let Some(condition) = importAtRule.supports
+		{
+			rules = CssRules(vec![CssRule::Supports(SupportsAtRule
+			{
+				condition,
+				rules,
+				source_range: importAtRule.source_range,
+			})]);
+		}
+		
+		if importAtRule.media_list.is_not_empty()
+		{
+			rules = CssRules(vec![CssRule::Media(MediaAtRule
+			{
+				media_queries: importAtRule.media_list,
+				rules,
+				source_range: importAtRule.source_range,
+			})]);
+		}
+		
+		Ok(rules.0)
+	}
+	
+	#[inline(always)]
+	fn current_url(&self) -> &str
+	{
+		self.import_chain.last().unwrap()
+	}
+	
+	#[inline(always)]
+	fn import_chain_before_current(&self) -> Vec<String>
+	{
+		self.import_chain[.. self.import_chain.len() - 1].to_vec()
+	}
+	
+	/// Rebases relative URLs in a rule from an imported stylesheet so that they are relative to the stylesheet being bundled.
+	fn rebase_rule(&self, rule: &mut CssRule)
+	{
+		use self::CssRule::*;
+		
+		match *rule
+		{
+			Document(ref mut rule) => self.rebase_rules(&mut rule.rules),
+			
+			FontFace(ref mut rule) => if let Some(ref mut sources) = rule.sources
+			{
+				for source in sources.iter_mut()
+				{
+					if let Source::Url(ref mut urlSource) = *source
+					{
+						if let Some(rebased) = self.rebase_url(&urlSource.url.0)
+						{
+							urlSource.url.0 = rebased;
+						}
+					}
+				}
+			},
+			
+			Keyframes(ref mut rule) => for keyframe in rule.keyframes.iter_mut()
+			{
+				self.rebase_property_declarations(&mut keyframe.property_declarations);
+			},
+			
+			LayerBlock(ref mut rule) => self.rebase_rules(&mut rule.rules),
+			
+			Media(ref mut rule) => self.rebase_rules(&mut rule.rules),
+			
+			Page(ref mut rule) =>
+			{
+				self.rebase_property_declarations(&mut rule.property_declarations);
+				for margin_rule in rule.margin_rules.iter_mut()
+				{
+					self.rebase_property_declarations(&mut margin_rule.property_declarations);
+				}
+			}
+			
+			Style(ref mut rule) => self.rebase_property_declarations(&mut rule.property_declarations),
+			
+			Supports(ref mut rule) => self.rebase_rules(&mut rule.rules),
+			
+			Comment(_) | CounterStyle(_) | FontFeatureValues(_) | Import(_) | LayerStatement(_) | Namespace(_) | Viewport(_) => (),
+		}
+	}
+	
+	#[inline(always)]
+	fn rebase_rules(&self, rules: &mut CssRules)
+	{
+		for rule in rules.0.iter_mut()
+		{
+			self.rebase_rule(rule);
+		}
+	}
+	
+	fn rebase_property_declarations<I: HasImportance>(&self, property_declarations: &mut PropertyDeclarations<I>)
+	{
+		for property_declaration in property_declarations.0.iter_mut()
+		{
+			match property_declaration.value
+			{
+				UnparsedPropertyValue::SpecifiedValue(ref mut specified_value) => if let Some(rebased) = self.rebase_urls_in_css(&specified_value.originalCss)
+				{
+					specified_value.originalCss = rebased;
+				},
+				
+				UnparsedPropertyValue::Typed(ref mut typed_value) => typed_value.urls_mut(&mut |url|
+				{
+					if let Some(rebased) = self.rebase_url(&url.0)
+					{
+						url.0 = rebased;
+					}
+				}),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	/// Returns None if there were no URLs to rebase.
+	fn rebase_urls_in_css(&self, css: &str) -> Option<String>
+	{
+		let mut replacements = Vec::new();
+		{
+			let mut parserInput = ParserInput::new(css);
+			let mut input = Parser::new(&mut parserInput);
+			self.find_urls_to_rebase(&mut input, &mut replacements);
+		}
+		
+		if replacements.is_empty()
+		{
+			return None;
+		}
+		
+		let mut rebased = String::with_capacity(css.len());
+		let mut position = 0;
+		for (start, end, url) in replacements
+		{
+			rebased.push_str(&css[position .. start]);
+			rebased.push_str("url(");
+			serialize_string(&url, &mut rebased).unwrap();
+			rebased.push(')');
+			position = end;
+		}
+		rebased.push_str(&css[position ..]);
+		Some(rebased)
+	}
+	
+	/// Finds `url()` tokens and functions; records their byte range and rebased URL.
+	fn find_urls_to_rebase<'i, 't>(&self, input: &mut Parser<'i, 't>, replacements: &mut Vec<(usize, usize, String)>)
+	{
+		loop
+		{
+			let start = input.position().byte_index();
+			let token = match input.next_including_whitespace_and_comments()
+			{
+				Err(_) => return,
+				Ok(token) => token.clone(),
+			};
+			
+			match token
+			{
+				Token::UnquotedUrl(ref url) => if let Some(rebased) = self.rebase_url(url)
+				{
+					replacements.push((start, input.position().byte_index(), rebased));
+				},
+				
+				Token::Function(ref name) if name.eq_ignore_ascii_case("url") =>
+				{
+					let url: Result<_, ParseError<()>> = input.parse_nested_block(|input|
+					{
+						let url = input.expect_string()?.as_ref().to_owned();
+						input.expect_exhausted()?;
+						Ok(url)
+					});
+					if let Ok(url) = url
+					{
+						if let Some(rebased) = self.rebase_url(&url)
+						{
+							replacements.push((start, input.position().byte_index(), rebased));
+						}
+					}
+				}
+				
+				Token::Function(_) | Token::ParenthesisBlock | Token::SquareBracketBlock | Token::CurlyBracketBlock =>
+				{
+					let _: Result<(), ParseError<()>> = input.parse_nested_block(|input|
+					{
+						self.find_urls_to_rebase(input, replacements);
+						Ok(())
+					});
+				}
+				
+				_ => (),
+			}
+		}
+	}
+	
+	/// Returns None if `url` does not need rebasing, eg because it is absolute or a fragment.
+	fn rebase_url(&self, url: &str) -> Option<String>
+	{
+		if url.is_empty() || url.starts_with('#') || url.starts_with('/') || url_has_scheme(url)
+		{
+			return None;
+		}
+		
+		let resolved = self.loader.resolve(self.current_url(), url);
+		Some(relative_url(&self.import_chain[0], &resolved))
+	}
+}
@@ -27,10 +27,11 @@ quick_error!
 		}
 		
 		/// An error occurred during a parse.
-		Parse(path: PathBuf, source_location: SourceLocation, reason: String)
+		Parse(path: PathBuf, cause: CssError)
 		{
-			description(&reason)
-			display("Parse error with {:?} at '{:?}' was '{}'", path, source_location, &reason)
+			cause(cause)
+			description("Parse error")
+			display("Parse error with {:?} was {}", path, cause)
 		}
 	}
 }
@@ -0,0 +1,19 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Loads the stylesheets referenced by `@import` rules, for `Stylesheet::bundle()`.
+pub trait StylesheetLoader
+{
+	/// Resolves `url`, as found in an `@import` rule or `url()` in the stylesheet at `base_url`.
+	/// The result is used to detect cycles, to load the stylesheet and as the base URL for anything it contains.
+	/// The default implementation resolves relative paths; absolute URLs are returned unchanged.
+	#[inline(always)]
+	fn resolve(&self, base_url: &str, url: &str) -> String
+	{
+		resolve_relative_url(base_url, url)
+	}
+	
+	/// Loads the CSS at `resolved_url`, as returned by `resolve()`.
+	fn load(&self, resolved_url: &str) -> ::std::io::Result<String>;
+}
@@ -0,0 +1,197 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A colour value, such as `red`, `#ff0000`, `rgb(255, 0, 0)` or `currentcolor`.
+/// Colours are held as RGBA, so they can be written in their shortest form, which may be a named colour; colours that are not opaque are written as `rgba()` (or `transparent`).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Colour
+{
+	currentcolor,
+	
+	RGBA
+	{
+		red: u8,
+		green: u8,
+		blue: u8,
+		alpha: u8,
+	},
+}
+
+impl ToCss for Colour
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Colour::*;
+		
+		match *self
+		{
+			currentcolor => dest.write_str("currentcolor"),
+			
+			RGBA { red: 0, green: 0, blue: 0, alpha: 0 } => dest.write_str("transparent"),
+			
+			// Hexadecimal colours with an alpha component (eg `#00000080`) are not supported by older browsers.
+			RGBA { red, green, blue, alpha } if alpha != 0xFF =>
+			{
+				write!(dest, "rgba({},{},{},", red, green, blue)?;
+				Self::alpha_to_css(alpha, dest)?;
+				dest.write_char(')')
+			}
+			
+			RGBA { red, green, blue, .. } =>
+			{
+				if let Some(&(name, _)) = Self::ShorterNames.iter().find(|&&(_, rgb)| rgb == (red, green, blue))
+				{
+					return dest.write_str(name)
+				}
+				
+				#[inline(always)]
+				fn canBeShortened(component: u8) -> bool
+				{
+					component >> 4 == component & 0x0F
+				}
+				
+				if canBeShortened(red) && canBeShortened(green) && canBeShortened(blue)
+				{
+					write!(dest, "#{:x}{:x}{:x}", red & 0x0F, green & 0x0F, blue & 0x0F)
+				}
+				else
+				{
+					write!(dest, "#{:02x}{:02x}{:02x}", red, green, blue)
+				}
+			}
+		}
+	}
+}
+
+impl Default for Colour
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Colour::transparent
+	}
+}
+
+impl Parse for Colour
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Colour::*;
+		
+		match Color::parse(input)?
+		{
+			Color::CurrentColor => Ok(currentcolor),
+			Color::RGBA(rgba) => Ok(RGBA { red: rgba.red, green: rgba.green, blue: rgba.blue, alpha: rgba.alpha }),
+		}
+	}
+}
+
+impl Colour
+{
+	pub const transparent: Colour = Colour::RGBA { red: 0, green: 0, blue: 0, alpha: 0 };
+	
+	/// Named colours that are shorter than their hexadecimal form.
+	const ShorterNames: &'static [(&'static str, (u8, u8, u8))] = &
+	[
+		("azure", (0xF0, 0xFF, 0xFF)),
+		("beige", (0xF5, 0xF5, 0xDC)),
+		("bisque", (0xFF, 0xE4, 0xC4)),
+		("brown", (0xA5, 0x2A, 0x2A)),
+		("coral", (0xFF, 0x7F, 0x50)),
+		("gold", (0xFF, 0xD7, 0x00)),
+		("gray", (0x80, 0x80, 0x80)),
+		("green", (0x00, 0x80, 0x00)),
+		("indigo", (0x4B, 0x00, 0x82)),
+		("ivory", (0xFF, 0xFF, 0xF0)),
+		("khaki", (0xF0, 0xE6, 0x8C)),
+		("linen", (0xFA, 0xF0, 0xE6)),
+		("maroon", (0x80, 0x00, 0x00)),
+		("navy", (0x00, 0x00, 0x80)),
+		("olive", (0x80, 0x80, 0x00)),
+		("orange", (0xFF, 0xA5, 0x00)),
+		("orchid", (0xDA, 0x70, 0xD6)),
+		("peru", (0xCD, 0x85, 0x3F)),
+		("pink", (0xFF, 0xC0, 0xCB)),
+		("plum", (0xDD, 0xA0, 0xDD)),
+		("purple", (0x80, 0x00, 0x80)),
+		("red", (0xFF, 0x00, 0x00)),
+		("salmon", (0xFA, 0x80, 0x72)),
+		("sienna", (0xA0, 0x52, 0x2D)),
+		("silver", (0xC0, 0xC0, 0xC0)),
+		("snow", (0xFF, 0xFA, 0xFA)),
+		("tan", (0xD2, 0xB4, 0x8C)),
+		("teal", (0x00, 0x80, 0x80)),
+		("tomato", (0xFF, 0x63, 0x47)),
+		("violet", (0xEE, 0x82, 0xEE)),
+		("wheat", (0xF5, 0xDE, 0xB3)),
+	];
+	
+	/// Writes an alpha component with the fewest decimal places that parse back to the same value (eg `.5` rather than `.502`, which is what `0x80` is exactly).
+	fn alpha_to_css<W: fmt::Write>(alpha: u8, dest: &mut W) -> fmt::Result
+	{
+		let opacity = alpha as f32 / 255.;
+		
+		// Two alphas differ by more than 0.001, so three decimal places are always enough.
+		for decimalPlaces in 1 .. 3
+		{
+			let rounded = format!("{:.*}", decimalPlaces, opacity).parse::<f32>().unwrap();
+			if (rounded * 255.).round() as u8 == alpha
+			{
+				return serialize_number(rounded, dest)
+			}
+		}
+		
+		serialize_number(format!("{:.3}", opacity).parse::<f32>().unwrap(), dest)
+	}
+	
+	#[inline(always)]
+	pub fn isTransparent(&self) -> bool
+	{
+		match *self
+		{
+			Colour::RGBA { alpha, .. } => alpha == 0,
+			_ => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod colour_tests
+{
+	use super::*;
+	
+	fn roundTrip(css: &str) -> String
+	{
+		Stylesheet::parse(&format!("a{{background-color:{}}}", css)).unwrap().to_css_string(false)
+	}
+	
+	#[test]
+	fn opaque_colours_are_written_in_their_shortest_form()
+	{
+		assert_eq!(roundTrip("rgb(255,0,0)"), "a{background-color:red}");
+		assert_eq!(roundTrip("#ffffff"), "a{background-color:#fff}");
+		assert_eq!(roundTrip("rgba(18,52,86,1)"), "a{background-color:#123456}");
+	}
+	
+	#[test]
+	fn colours_with_an_alpha_component_are_not_written_as_hexadecimal()
+	{
+		assert_eq!(roundTrip("transparent"), "a{background-color:transparent}");
+		assert_eq!(roundTrip("rgba(0,0,0,.5)"), "a{background-color:rgba(0,0,0,.5)}");
+		assert_eq!(roundTrip("rgba(0, 0, 0, 0.05)"), "a{background-color:rgba(0,0,0,.05)}");
+		assert_eq!(roundTrip("#ff000080"), "a{background-color:rgba(255,0,0,.5)}");
+		assert_eq!(roundTrip("rgba(255,0,0,0)"), "a{background-color:rgba(255,0,0,0)}");
+	}
+	
+	#[test]
+	fn every_alpha_is_written_so_that_it_parses_back_to_the_same_value()
+	{
+		for alpha in 0 ..= 0xFF
+		{
+			let colour = Colour::RGBA { red: 1, green: 2, blue: 3, alpha };
+			let css = format!("a{{background-color:{}}}", colour.to_css_string());
+			assert_eq!(roundTrip(&css[19 .. css.len() - 1]), css);
+		}
+	}
+}
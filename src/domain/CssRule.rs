@@ -6,6 +6,9 @@
 #[derive(Debug, Clone)]
 pub enum CssRule
 {
+	/// An important comment, `/*! ... */`
+	Comment(ImportantComment),
+	
 	/// @counter-style
 	CounterStyle(CounterStyleAtRule),
 	
@@ -24,6 +27,12 @@ pub enum CssRule
 	/// @keyframes
 	Keyframes(KeyframesAtRule),
 	
+	/// @layer with a block
+	LayerBlock(LayerBlockAtRule),
+	
+	/// @layer without a block
+	LayerStatement(LayerStatementAtRule),
+	
 	/// @media
 	Media(MediaAtRule),
 	
@@ -52,6 +61,8 @@ impl ToCss for CssRule
 		
 		match *self
 		{
+			Comment(ref rule) => rule.to_css(dest),
+			
 			CounterStyle(ref rule) => rule.to_css(dest),
 			
 			Document(ref rule) => rule.to_css(dest),
@@ -64,6 +75,10 @@ impl ToCss for CssRule
 			
 			Keyframes(ref rule) => rule.to_css(dest),
 			
+			LayerBlock(ref rule) => rule.to_css(dest),
+			
+			LayerStatement(ref rule) => rule.to_css(dest),
+			
 			Media(ref rule) => rule.to_css(dest),
 			
 			Namespace(ref rule) => rule.to_css(dest),
@@ -89,6 +104,8 @@ impl CssRule
 		
 		match *self
 		{
+			Comment(_) => CssRuleType::Comment,
+			
 			CounterStyle(_) => CssRuleType::CounterStyle,
 			
 			Document(_)  => CssRuleType::Document,
@@ -101,6 +118,10 @@ impl CssRule
 			
 			Keyframes(_) => CssRuleType::Keyframes,
 			
+			LayerBlock(_) => CssRuleType::LayerBlock,
+			
+			LayerStatement(_) => CssRuleType::LayerStatement,
+			
 			Media(_) => CssRuleType::Media,
 			
 			Namespace(_) => CssRuleType::Namespace,
@@ -114,4 +135,84 @@ impl CssRule
 			Viewport(_) => CssRuleType::Viewport,
 		}
 	}
+	
+	/// Where this rule came from in the original CSS, if recorded when parsing (see `ParseOptions::source_ranges`).
+	#[inline(always)]
+	pub fn source_range(&self) -> Option<SourceRange>
+	{
+		use self::CssRule::*;
+		
+		match *self
+		{
+			Comment(ref rule) => rule.source_range,
+			
+			CounterStyle(ref rule) => rule.source_range,
+			
+			Document(ref rule) => rule.source_range,
+			
+			FontFace(ref rule) => rule.source_range,
+			
+			FontFeatureValues(ref rule) => rule.source_range,
+			
+			Import(ref rule) => rule.source_range,
+			
+			Keyframes(ref rule) => rule.source_range,
+			
+			LayerBlock(ref rule) => rule.source_range,
+			
+			LayerStatement(ref rule) => rule.source_range,
+			
+			Media(ref rule) => rule.source_range,
+			
+			Namespace(ref rule) => rule.source_range,
+			
+			Page(ref rule) => rule.source_range,
+			
+			Style(ref rule) => rule.source_range,
+			
+			Supports(ref rule) => rule.source_range,
+			
+			Viewport(ref rule) => rule.source_range,
+		}
+	}
+	
+	/// Mutable reference to where this rule came from in the original CSS.
+	#[inline(always)]
+	pub fn source_range_mut(&mut self) -> &mut Option<SourceRange>
+	{
+		use self::CssRule::*;
+		
+		match *self
+		{
+			Comment(ref mut rule) => &mut rule.source_range,
+			
+			CounterStyle(ref mut rule) => &mut rule.source_range,
+			
+			Document(ref mut rule) => &mut rule.source_range,
+			
+			FontFace(ref mut rule) => &mut rule.source_range,
+			
+			FontFeatureValues(ref mut rule) => &mut rule.source_range,
+			
+			Import(ref mut rule) => &mut rule.source_range,
+			
+			Keyframes(ref mut rule) => &mut rule.source_range,
+			
+			LayerBlock(ref mut rule) => &mut rule.source_range,
+			
+			LayerStatement(ref mut rule) => &mut rule.source_range,
+			
+			Media(ref mut rule) => &mut rule.source_range,
+			
+			Namespace(ref mut rule) => &mut rule.source_range,
+			
+			Page(ref mut rule) => &mut rule.source_range,
+			
+			Style(ref mut rule) => &mut rule.source_range,
+			
+			Supports(ref mut rule) => &mut rule.source_range,
+			
+			Viewport(ref mut rule) => &mut rule.source_range,
+		}
+	}
 }
@@ -41,12 +41,19 @@ pub enum CssRuleType
 	
 	// https://drafts.csswg.org/css-device-adapt/#css-rule-interface
 	Viewport = 15,
+	
+	// https://drafts.csswg.org/css-cascade-5/#layer-apis does not define type constants for layer rules; these values are specific to this crate.
+	LayerBlock = 16,
+	LayerStatement = 17,
+	
+	// Important comments (`/*! ... */`) are not rules in CSSOM; this value is specific to this crate.
+	Comment = 18,
 }
 
 impl CssRuleType
 {
 	#[inline(always)]
-	pub(crate) fn context(self, nestedRuleParser: &NestedRuleParser) -> ParserContext
+	pub(crate) fn context<'i>(self, nestedRuleParser: &NestedRuleParser<'_, 'i>) -> ParserContext<'i>
 	{
 		nestedRuleParser.context_new_with_rule_type(self)
 	}
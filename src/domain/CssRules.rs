@@ -100,6 +100,423 @@ impl CssRules
 		}
 	}
 	
+	/// Resolves the property declarations that apply to a page, and its page-margin boxes, from the `@page` rules in this list.
+	///
+	/// `page_name` is the page's type (named page), if any, and `page_index` is zero-based.
+	/// `@page` rules nested inside conditional rules such as `@media` are not considered.
+	#[inline(always)]
+	pub fn page_rules_for<'a>(&'a self, page_name: Option<&str>, page_index: usize, side: PageSide) -> PageDeclarations<'a>
+	{
+		use self::CssRule::Page;
+		
+		let page_rules = self.0.iter().filter_map(|css_rule| match *css_rule
+		{
+			Page(ref page_rule) => Some(page_rule),
+			_ => None,
+		});
+		
+		PageDeclarations::resolve(page_rules, page_name, page_index, side)
+	}
+	
+	/// Applies the optimisations in `options` to the property values in these rules.
+	pub fn minify(&mut self, options: &MinifyOptions)
+	{
+		if options.inline_custom_properties
+		{
+			self.inline_custom_properties()
+		}
+		
+		if options.simplify_calc_functions
+		{
+			self.property_values_mut(&mut |vendor_prefix, name, value|
+			{
+				let simplified = match *value
+				{
+					UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) if !name.starts_with("--") => specifiedValue.simplify_calc_functions().and_then(|css| UnparsedPropertyValue::parse(vendor_prefix, name, &css).ok()),
+					_ => None,
+				};
+				
+				if let Some(simplified) = simplified
+				{
+					*value = simplified;
+				}
+			});
+			self.typed_property_values_mut(&mut |typed_value| typed_value.simplify_calc_functions());
+		}
+		
+		if options.transforms_to_matrix
+		{
+			self.typed_property_values_mut(&mut |typed_value| if let TypedPropertyValue::Transform(ref mut transform) = *typed_value
+			{
+				transform.shorten_to_matrix()
+			});
+		}
+	}
+	
+	/// Replaces legacy gradients, such as `-webkit-linear-gradient(top, red, blue)`, with their standard equivalents, such as `linear-gradient(red, blue)`.
+	/// Only property values parsed into typed values are changed, and gradients without an exact standard equivalent, such as most uses of `-webkit-gradient()`, are left alone.
+	#[inline(always)]
+	pub fn standardise_legacy_gradients(&mut self)
+	{
+		self.typed_property_values_mut(&mut |typed_value| typed_value.images_mut(&mut |image| image.standardise_legacy_gradients()))
+	}
+	
+	/// Calls `visitor` with each typed property value in these rules, including those of rules nested inside `@media`, `@supports`, `@document` and `@layer`, of keyframes and of `@page` and its page-margin boxes.
+	pub fn typed_property_values_mut<Visitor: FnMut(&mut TypedPropertyValue)>(&mut self, visitor: &mut Visitor)
+	{
+		use self::CssRule::*;
+		
+		for css_rule in self.0.iter_mut()
+		{
+			match *css_rule
+			{
+				Document(ref mut rule) => rule.rules.typed_property_values_mut(visitor),
+				
+				Keyframes(ref mut rule) => for keyframe in rule.keyframes.iter_mut()
+				{
+					keyframe.property_declarations.typed_values_mut(visitor)
+				},
+				
+				LayerBlock(ref mut rule) => rule.rules.typed_property_values_mut(visitor),
+				
+				Media(ref mut rule) => rule.rules.typed_property_values_mut(visitor),
+				
+				Page(ref mut rule) =>
+				{
+					rule.property_declarations.typed_values_mut(visitor);
+					for margin_rule in rule.margin_rules.iter_mut()
+					{
+						margin_rule.property_declarations.typed_values_mut(visitor)
+					}
+				}
+				
+				Style(ref mut rule) => rule.property_declarations.typed_values_mut(visitor),
+				
+				Supports(ref mut rule) => rule.rules.typed_property_values_mut(visitor),
+				
+				Comment(_) | CounterStyle(_) | FontFace(_) | FontFeatureValues(_) | Import(_) | LayerStatement(_) | Namespace(_) | Viewport(_) => (),
+			}
+		}
+	}
+	
+	/// Replaces each `var()` of a custom property defined exactly once, on a top-level `:root` or `html` rule, with its value, and removes the definition; see `MinifyOptions::inline_custom_properties`.
+	/// Definitions that are still referenced afterwards, for instance because the value a `var()` was in could not be re-parsed, are kept.
+	pub fn inline_custom_properties(&mut self)
+	{
+		use self::CssRule::*;
+		
+		if self.0.iter().any(|css_rule| matches!(*css_rule, Import(_)))
+		{
+			return
+		}
+		
+		let mut definitions = HashMap::new();
+		self.property_values_mut(&mut |_vendor_prefix, name, _value| if name.starts_with("--")
+		{
+			*definitions.entry(name.clone()).or_insert(0usize) += 1;
+		});
+		
+		let mut candidates = HashMap::new();
+		for css_rule in self.0.iter()
+		{
+			if let Style(ref rule) = *css_rule
+			{
+				if Self::isRootStyleRule(rule)
+				{
+					for property_declaration in rule.property_declarations.0.iter()
+					{
+						if let UnparsedPropertyValue::SpecifiedValue(ref value) = property_declaration.value
+						{
+							if property_declaration.hasACustomPropertyName() && definitions.get(&property_declaration.name) == Some(&1)
+							{
+								candidates.insert(property_declaration.name.clone(), (property_declaration, value));
+							}
+						}
+					}
+				}
+			}
+		}
+		
+		// A custom property that references one that is not a candidate has a value that can differ between elements.
+		loop
+		{
+			let dependsOnNonCandidates = candidates.iter().filter(|&(_, &(_, value))| value.references.iter().any(|reference| !candidates.contains_key(reference))).map(|(name, _)| name.clone()).collect::<Vec<_>>();
+			if dependsOnNonCandidates.is_empty()
+			{
+				break
+			}
+			for name in dependsOnNonCandidates
+			{
+				candidates.remove(&name);
+			}
+		}
+		
+		let resolved = CustomPropertyGraph::new(candidates.values().map(|&(property_declaration, _)| property_declaration)).resolve(&ResolvedCustomProperties::default());
+		if resolved.is_empty()
+		{
+			return
+		}
+		
+		let mut stillReferenced = HashSet::new();
+		self.property_values_mut(&mut |vendor_prefix, name, value|
+		{
+			let substituted = match *value
+			{
+				UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) if specifiedValue.references.iter().any(|reference| resolved.get(reference).is_some()) =>
+				{
+					resolved.substitute_known(specifiedValue).and_then(|substituted| UnparsedPropertyValue::parse(vendor_prefix, name, substituted.as_css()).ok())
+				}
+				
+				_ => None,
+			};
+			
+			if let Some(substituted) = substituted
+			{
+				*value = substituted;
+			}
+			
+			if let UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) = *value
+			{
+				stillReferenced.extend(specifiedValue.references.iter().cloned());
+			}
+		});
+		
+		let isInlined = |name: &Atom| resolved.get(name).is_some() && !stillReferenced.contains(name);
+		self.0.retain(|css_rule|
+		{
+			if let Style(ref rule) = *css_rule
+			{
+				if Self::isRootStyleRule(rule) && !rule.property_declarations.is_empty() && rule.property_declarations.0.iter().all(|property_declaration| isInlined(&property_declaration.name))
+				{
+					return false
+				}
+			}
+			true
+		});
+		for css_rule in self.0.iter_mut()
+		{
+			if let Style(ref mut rule) = *css_rule
+			{
+				if Self::isRootStyleRule(rule)
+				{
+					rule.property_declarations.0.retain(|property_declaration| !isInlined(&property_declaration.name));
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn isRootStyleRule(rule: &StyleRule) -> bool
+	{
+		if rule.selectors.0.len() != 1
+		{
+			return false
+		}
+		let selector = rule.selectors.0[0].to_css_string();
+		selector.eq_ignore_ascii_case(":root") || selector.eq_ignore_ascii_case("html")
+	}
+	
+	/// Calls `visitor` with the vendor prefix, name and value of each property declaration in these rules; the same rules are visited as by `typed_property_values_mut()`.
+	pub fn property_values_mut<Visitor: FnMut(&Option<VendorPrefix>, &Atom, &mut UnparsedPropertyValue)>(&mut self, visitor: &mut Visitor)
+	{
+		use self::CssRule::*;
+		
+		for css_rule in self.0.iter_mut()
+		{
+			match *css_rule
+			{
+				Document(ref mut rule) => rule.rules.property_values_mut(visitor),
+				
+				Keyframes(ref mut rule) => for keyframe in rule.keyframes.iter_mut()
+				{
+					keyframe.property_declarations.values_mut(visitor)
+				},
+				
+				LayerBlock(ref mut rule) => rule.rules.property_values_mut(visitor),
+				
+				Media(ref mut rule) => rule.rules.property_values_mut(visitor),
+				
+				Page(ref mut rule) =>
+				{
+					rule.property_declarations.values_mut(visitor);
+					for margin_rule in rule.margin_rules.iter_mut()
+					{
+						margin_rule.property_declarations.values_mut(visitor)
+					}
+				}
+				
+				Style(ref mut rule) => rule.property_declarations.values_mut(visitor),
+				
+				Supports(ref mut rule) => rule.rules.property_values_mut(visitor),
+				
+				Comment(_) | CounterStyle(_) | FontFace(_) | FontFeatureValues(_) | Import(_) | LayerStatement(_) | Namespace(_) | Viewport(_) => (),
+			}
+		}
+	}
+	
+	/// Changes the horizontal direction of the property declarations in these rules; the same rules are visited as by `typed_property_values_mut()`.
+	#[inline(always)]
+	pub fn transform_direction(&mut self, transform: DirectionTransform)
+	{
+		self.transform_direction_unless_ignored(transform, None)
+	}
+	
+	/// As `transform_direction()`, but leaving alone the rules and property declarations that `control_comments` ignore.
+	pub(crate) fn transform_direction_unless_ignored(&mut self, transform: DirectionTransform, control_comments: Option<&RtlControlComments>)
+	{
+		use self::CssRule::*;
+		
+		for css_rule in self.0.iter_mut()
+		{
+			if control_comments.is_some_and(|control_comments| control_comments.ignores_rule(css_rule.source_range()))
+			{
+				continue
+			}
+			
+			match *css_rule
+			{
+				Document(ref mut rule) => rule.rules.transform_direction_unless_ignored(transform, control_comments),
+				
+				Keyframes(ref mut rule) => for keyframe in rule.keyframes.iter_mut()
+				{
+					keyframe.property_declarations.transform_direction(transform, control_comments)
+				},
+				
+				LayerBlock(ref mut rule) => rule.rules.transform_direction_unless_ignored(transform, control_comments),
+				
+				Media(ref mut rule) => rule.rules.transform_direction_unless_ignored(transform, control_comments),
+				
+				Page(ref mut rule) =>
+				{
+					rule.property_declarations.transform_direction(transform, control_comments);
+					for margin_rule in rule.margin_rules.iter_mut()
+					{
+						margin_rule.property_declarations.transform_direction(transform, control_comments)
+					}
+				}
+				
+				Style(ref mut rule) => rule.property_declarations.transform_direction(transform, control_comments),
+				
+				Supports(ref mut rule) => rule.rules.transform_direction_unless_ignored(transform, control_comments),
+				
+				Comment(_) | CounterStyle(_) | FontFace(_) | FontFeatureValues(_) | Import(_) | LayerStatement(_) | Namespace(_) | Viewport(_) => (),
+			}
+		}
+	}
+	
+	/// Calls `visitor` with each typed property value in these rules, and where its declaration came from; the same rules are visited as by `typed_property_values_mut()`.
+	pub fn typed_property_values<Visitor: FnMut(&TypedPropertyValue, Option<SourceRange>)>(&self, visitor: &mut Visitor)
+	{
+		use self::CssRule::*;
+		
+		for css_rule in self.0.iter()
+		{
+			match *css_rule
+			{
+				Document(ref rule) => rule.rules.typed_property_values(visitor),
+				
+				Keyframes(ref rule) => for keyframe in rule.keyframes.iter()
+				{
+					keyframe.property_declarations.typed_values(visitor)
+				},
+				
+				LayerBlock(ref rule) => rule.rules.typed_property_values(visitor),
+				
+				Media(ref rule) => rule.rules.typed_property_values(visitor),
+				
+				Page(ref rule) =>
+				{
+					rule.property_declarations.typed_values(visitor);
+					for margin_rule in rule.margin_rules.iter()
+					{
+						margin_rule.property_declarations.typed_values(visitor)
+					}
+				}
+				
+				Style(ref rule) => rule.property_declarations.typed_values(visitor),
+				
+				Supports(ref rule) => rule.rules.typed_property_values(visitor),
+				
+				Comment(_) | CounterStyle(_) | FontFace(_) | FontFeatureValues(_) | Import(_) | LayerStatement(_) | Namespace(_) | Viewport(_) => (),
+			}
+		}
+	}
+	
+	/// Finds likely mistakes in the property declarations of these rules; see `LintKind`.
+	pub fn lint(&self) -> Vec<Lint>
+	{
+		let mut keyframes_names = HashSet::new();
+		self.keyframes_names(&mut keyframes_names);
+		
+		let mut lints = Vec::new();
+		self.lint_property_declarations(&keyframes_names, &mut lints);
+		lints
+	}
+	
+	fn lint_property_declarations(&self, keyframes_names: &HashSet<Atom>, lints: &mut Vec<Lint>)
+	{
+		use self::CssRule::*;
+		
+		for css_rule in self.0.iter()
+		{
+			match *css_rule
+			{
+				Document(ref rule) => rule.rules.lint_property_declarations(keyframes_names, lints),
+				
+				Keyframes(ref rule) => for keyframe in rule.keyframes.iter()
+				{
+					keyframe.property_declarations.lint(keyframes_names, lints)
+				},
+				
+				LayerBlock(ref rule) => rule.rules.lint_property_declarations(keyframes_names, lints),
+				
+				Media(ref rule) => rule.rules.lint_property_declarations(keyframes_names, lints),
+				
+				Page(ref rule) =>
+				{
+					rule.property_declarations.lint(keyframes_names, lints);
+					for margin_rule in rule.margin_rules.iter()
+					{
+						margin_rule.property_declarations.lint(keyframes_names, lints)
+					}
+				}
+				
+				Style(ref rule) => rule.property_declarations.lint(keyframes_names, lints),
+				
+				Supports(ref rule) => rule.rules.lint_property_declarations(keyframes_names, lints),
+				
+				Comment(_) | CounterStyle(_) | FontFace(_) | FontFeatureValues(_) | Import(_) | LayerStatement(_) | Namespace(_) | Viewport(_) => (),
+			}
+		}
+	}
+	
+	/// Adds the names of the `@keyframes` rules (including vendor-prefixed ones) in these rules, and in rules nested inside `@media`, `@supports`, `@document` and `@layer`, to `names`.
+	pub fn keyframes_names(&self, names: &mut HashSet<Atom>)
+	{
+		use self::CssRule::*;
+		
+		for css_rule in self.0.iter()
+		{
+			match *css_rule
+			{
+				Keyframes(ref rule) =>
+				{
+					names.insert(rule.name.as_atom().clone());
+				}
+				
+				Document(ref rule) => rule.rules.keyframes_names(names),
+				
+				LayerBlock(ref rule) => rule.rules.keyframes_names(names),
+				
+				Media(ref rule) => rule.rules.keyframes_names(names),
+				
+				Supports(ref rule) => rule.rules.keyframes_names(names),
+				
+				_ => (),
+			}
+		}
+	}
+	
 	/// Whether this CSS rules is empty.
 	pub fn is_empty(&self) -> bool
 	{
@@ -3,7 +3,7 @@
 
 
 /// https://drafts.csswg.org/css-values-4/#custom-idents
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, Hash, PartialEq)]
 pub struct CustomIdent(pub Atom);
 
 impl CustomIdent
@@ -0,0 +1,61 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// An important comment, `/*! ... */`, such as a licence banner, that should survive minification.
+/// Kept when found at the top level of a stylesheet or in a list of rules, unless parsing with `ParseOptions::strip_important_comments`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ImportantComment
+{
+	/// The text between `/*!` and `*/`.
+	pub text: String,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
+}
+
+impl ToCss for ImportantComment
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str("/*!")?;
+		dest.write_str(&self.text)?;
+		dest.write_str("*/")
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn parse(css: &str, strip_important_comments: bool) -> String
+	{
+		let (stylesheet, _diagnostics) = Stylesheet::parse_with_options(css, &ParseOptions { strip_important_comments, ..ParseOptions::default() }).unwrap();
+		stylesheet.to_css_string(false)
+	}
+	
+	#[test]
+	fn important_comments_are_kept()
+	{
+		assert_eq!(parse("/*! banner */a{color:red}/* comment */b{color:red}", false), "/*! banner */a{color:red}b{color:red}");
+		assert_eq!(parse("@media print{/*! banner */a{color:red}}", false), "@media print{/*! banner */a{color:red}}");
+		assert_eq!(parse("@charset \"UTF-8\";/*! banner */a{color:red}", false), "/*! banner */a{color:red}");
+		assert_eq!(parse("/*! before */ @charset \"UTF-8\"; /*! after */ a{color:red}", false), "/*! before *//*! after */a{color:red}");
+	}
+	
+	#[test]
+	fn important_comments_are_stripped()
+	{
+		assert_eq!(parse("/*! banner */a{color:red}@media print{/*! banner */a{color:red}}", true), "a{color:red}@media print{a{color:red}}");
+		assert_eq!(parse("@charset \"UTF-8\";/*! banner */a{color:red}", true), "a{color:red}");
+	}
+	
+	#[test]
+	fn only_the_first_charset_rule_is_skipped()
+	{
+		assert!(Stylesheet::parse("@charset \"UTF-8\";/*! banner */@charset \"UTF-8\";a{color:red}").is_err());
+		assert!(Stylesheet::parse("a{color:red}@charset \"UTF-8\";").is_err());
+	}
+}
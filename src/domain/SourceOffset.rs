@@ -0,0 +1,32 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A point in the original CSS.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceOffset
+{
+	/// Zero-based line number, as for `cssparser::SourceLocation`.
+	pub line: u32,
+	
+	/// One-based column number, as for `cssparser::SourceLocation`.
+	pub column: u32,
+	
+	/// Zero-based offset in bytes from the start of the CSS.
+	pub byte_offset: usize,
+}
+
+impl SourceOffset
+{
+	#[inline(always)]
+	pub(crate) fn from_parser_state(state: &ParserState) -> Self
+	{
+		let location = state.source_location();
+		Self
+		{
+			line: location.line,
+			column: location.column,
+			byte_offset: state.position().byte_index(),
+		}
+	}
+}
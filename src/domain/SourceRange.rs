@@ -0,0 +1,28 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Where a rule, selector list or property declaration came from in the original CSS.
+/// Only recorded if parsing with `ParseOptions::source_ranges`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceRange
+{
+	/// The start, inclusive.
+	pub start: SourceOffset,
+	
+	/// The end, exclusive.
+	pub end: SourceOffset,
+}
+
+impl SourceRange
+{
+	#[inline(always)]
+	pub(crate) fn new(start: &ParserState, input: &Parser) -> Self
+	{
+		Self
+		{
+			start: SourceOffset::from_parser_state(start),
+			end: SourceOffset::from_parser_state(&input.state()),
+		}
+	}
+}
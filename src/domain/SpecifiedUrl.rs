@@ -3,6 +3,7 @@
 
 
 /// A specified url() value; should be resolved relative to the stylesheet containing it
+/// Serialized unquoted unless the URL contains characters that would then need escaping.
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct SpecifiedUrl(pub String);
 
@@ -11,7 +12,14 @@ impl ToCss for SpecifiedUrl
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
 		dest.write_str("url(")?;
-		dest.write_str(&self.0)?;
+		if self.0.chars().any(|character| matches!(character, '"' | '\'' | '(' | ')' | '\\' | ' ' | '\x00' ..= '\x1F' | '\x7F'))
+		{
+			serialize_string(&self.0, dest)?;
+		}
+		else
+		{
+			dest.write_str(&self.0)?;
+		}
 		dest.write_str(")")
 	}
 }
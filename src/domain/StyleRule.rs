@@ -11,6 +11,12 @@ pub struct StyleRule
 	
 	/// The declaration block with the properties it contains.
 	pub property_declarations: PropertyDeclarations<Importance>,
+	
+	/// Where the list of selectors came from; it extends to the opening `{` of the declaration block.
+	pub selectors_source_range: Option<SourceRange>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for StyleRule
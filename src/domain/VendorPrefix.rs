@@ -81,7 +81,11 @@ impl VendorPrefix
 			{
 				let mut split = remainder.splitn(2, '-');
 				let prefix = split.next().unwrap();
-				let unprefixedRemainder = split.next().unwrap();
+				let unprefixedRemainder = match split.next()
+				{
+					None => return (None, asciiLowerCaseName.clone()),
+					Some(unprefixedRemainder) => unprefixedRemainder,
+				};
 				
 				use self::VendorPrefix::*;
 				
@@ -37,6 +37,9 @@ pub struct CounterStyleAtRule
 	
 	/// https://drafts.csswg.org/css-counter-styles/#counter-style-speak-as
 	pub speak_as: Option<SpeakAs>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for CounterStyleAtRule
@@ -102,6 +105,7 @@ impl CounterStyleAtRule
 			symbols: None,
 			additive_symbols: None,
 			speak_as: None,
+			source_range: None,
 		}
 	}
 	
@@ -239,7 +243,7 @@ impl CounterStyleAtRule
 	}
 	
 	/// Parse the body (inside `{}`) of an @counter-style rule
-	pub(crate) fn parse_body<'i, 't>(name: CounterStyleIdent, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<CounterStyleAtRule, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i, 't>(name: CounterStyleIdent, context: &ParserContext<'i>, input: &mut Parser<'i, 't>) -> Result<CounterStyleAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut rule = CounterStyleAtRule::empty(name);
 		
@@ -252,7 +256,7 @@ impl CounterStyleAtRule
 			let mut iterator = DeclarationListParser::new(input, parser);
 			while let Some(declaration) = iterator.next()
 			{
-				declaration.map_err(|(error, _)| error)?;
+				context.recover(declaration)?;
 			}
 		}
 		
@@ -5,6 +5,23 @@
 /// A trait that is used when evaluating CSS rules that are decided using document attributes
 pub trait Document
 {
+	/// The URL of the document being styled, if known.
+	/// The default implementation returns `None`.
+	#[inline(always)]
+	fn documentUrl(&self) -> Option<&str>
+	{
+		None
+	}
+	
 	/// Used when evaluating @document rules
-	fn documentMatchesUrl(&self, urlMatchingFunction: &UrlMatchingFunction) -> bool;
+	/// The default implementation evaluates all four URL matching functions against `documentUrl()`; if there is no URL, nothing matches.
+	#[inline(always)]
+	fn documentMatchesUrl(&self, urlMatchingFunction: &UrlMatchingFunction) -> bool
+	{
+		match self.documentUrl()
+		{
+			None => false,
+			Some(documentUrl) => urlMatchingFunction.matches(documentUrl),
+		}
+	}
 }
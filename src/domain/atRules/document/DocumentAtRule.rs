@@ -13,6 +13,9 @@ pub struct DocumentAtRule
 	
 	/// Child rules
 	pub rules: CssRules,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl HasCssRules for DocumentAtRule
@@ -0,0 +1,124 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A compiled regular expression, as used by the `regexp()` URL matching function of a `@document` rule.
+///
+/// The syntax is that of JavaScript regular expressions without flags; backreferences are not supported.
+/// A URL only matches if the regular expression matches its entirety.
+///
+/// https://www.w3.org/TR/2012/WD-css3-conditional-20120911/#url-of-doc
+#[derive(Clone, Debug)]
+pub struct RegExp
+{
+	source: String,
+	instructions: Vec<RegExpInstruction>,
+}
+
+impl ToCss for RegExp
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		serialize_string(&self.source, dest)
+	}
+}
+
+impl PartialEq for RegExp
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.source == other.source
+	}
+}
+
+impl Eq for RegExp
+{
+}
+
+impl RegExp
+{
+	/// Compiles a regular expression.
+	/// On failure, returns a description of why the source is invalid.
+	pub fn compile(source: &str) -> Result<Self, &'static str>
+	{
+		let node = RegExpParser::parse(source)?;
+		
+		let mut instructions = Vec::new();
+		node.compile(&mut instructions)?;
+		instructions.push(RegExpInstruction::Match);
+		
+		Ok
+		(
+			Self
+			{
+				source: source.to_owned(),
+				instructions,
+			}
+		)
+	}
+	
+	/// The regular expression as written in CSS (unescaped).
+	#[inline(always)]
+	pub fn source(&self) -> &str
+	{
+		&self.source
+	}
+	
+	/// Does this regular expression match all of `input`?
+	#[inline(always)]
+	pub fn is_full_match(&self, input: &str) -> bool
+	{
+		let input: Vec<char> = input.chars().collect();
+		RegExpMatcher::is_full_match(&self.instructions, &input)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn is_full_match(source: &str, input: &str) -> bool
+	{
+		RegExp::compile(source).unwrap().is_full_match(input)
+	}
+	
+	#[test]
+	fn long_urls_are_matched_without_recursing_for_each_character()
+	{
+		let url = format!("https://example.com/{}", "a".repeat(10_000));
+		assert!(is_full_match("https://.*", &url));
+		assert!(!is_full_match("https://.*b", &url));
+	}
+	
+	#[test]
+	fn nested_repetition_does_not_take_exponential_time()
+	{
+		let input = format!("{}c", "a".repeat(10_000));
+		assert!(!is_full_match("(a+)+b", &input));
+		assert!(is_full_match("(a+)+c", &input));
+		assert!(is_full_match("(a*)*c", &input));
+	}
+	
+	#[test]
+	fn alternations_repetitions_and_assertions_are_matched()
+	{
+		assert!(is_full_match("^https?://(www\\.)?example\\.(com|org)/$", "https://example.org/"));
+		assert!(!is_full_match("^https?://(www\\.)?example\\.(com|org)/$", "ftp://example.org/"));
+		assert!(is_full_match("x{2,3}", "xxx"));
+		assert!(!is_full_match("x{2,3}", "xxxx"));
+		assert!(is_full_match("(?!ftp)[a-z]+:.*", "http://"));
+		assert!(!is_full_match("(?!ftp)[a-z]+:.*", "ftp://"));
+		assert!(is_full_match("a\\b.*", "a b"));
+		assert!(!is_full_match("a\\b.*", "ab"));
+	}
+	
+	#[test]
+	fn oversized_regular_expressions_are_rejected()
+	{
+		assert!(RegExp::compile("(a{1000}){1000}").is_err());
+		assert!(RegExp::compile(&format!("{}a{}", "(".repeat(1000), ")".repeat(1000))).is_err());
+		assert!(RegExp::compile("(?:){4294967295}").is_ok());
+	}
+}
@@ -0,0 +1,79 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An item within a regular expression character class, eg `a`, `a-z` or `\d` within `[a-z\d]`.
+#[derive(Clone, Debug)]
+pub(crate) enum RegExpCharacterClassItem
+{
+	/// A single character.
+	Character(char),
+	
+	/// An inclusive range of characters, eg `a-z`.
+	Range(char, char),
+	
+	/// `\d`
+	Digit,
+	
+	/// `\D`
+	NotDigit,
+	
+	/// `\w`
+	Word,
+	
+	/// `\W`
+	NotWord,
+	
+	/// `\s`
+	Whitespace,
+	
+	/// `\S`
+	NotWhitespace,
+}
+
+impl RegExpCharacterClassItem
+{
+	#[inline(always)]
+	pub(crate) fn matches(&self, character: char) -> bool
+	{
+		use self::RegExpCharacterClassItem::*;
+		
+		match *self
+		{
+			Character(expected) => character == expected,
+			Range(from, to) => character >= from && character <= to,
+			Digit => Self::is_digit(character),
+			NotDigit => !Self::is_digit(character),
+			Word => Self::is_word(character),
+			NotWord => !Self::is_word(character),
+			Whitespace => Self::is_whitespace(character),
+			NotWhitespace => !Self::is_whitespace(character),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn is_digit(character: char) -> bool
+	{
+		character.is_ascii_digit()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn is_word(character: char) -> bool
+	{
+		character.is_ascii_alphanumeric() || character == '_'
+	}
+	
+	/// JavaScript's definition is WhiteSpace and LineTerminator, which is Unicode's White_Space property plus U+FEFF.
+	#[inline(always)]
+	pub(crate) fn is_whitespace(character: char) -> bool
+	{
+		character.is_whitespace() || character == '\u{FEFF}'
+	}
+	
+	/// https://tc39.es/ecma262/#sec-line-terminators
+	#[inline(always)]
+	pub(crate) fn is_line_terminator(character: char) -> bool
+	{
+		matches!(character, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+	}
+}
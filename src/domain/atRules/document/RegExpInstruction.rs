@@ -0,0 +1,69 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An instruction of a compiled regular expression; indices are those of other instructions.
+///
+/// Instructions either consume a character, or are followed without consuming any (eg `Split` or `StartOfInput`).
+#[derive(Clone, Debug)]
+pub(crate) enum RegExpInstruction
+{
+	/// Consumes a literal character.
+	Character(char),
+	
+	/// Consumes any character except a line terminator.
+	AnyCharacterExceptLineTerminator,
+	
+	/// Consumes a character in (or, if negated, not in) a character class.
+	CharacterClass
+	{
+		negated: bool,
+		items: Vec<RegExpCharacterClassItem>,
+	},
+	
+	/// Continues only at the start of the input.
+	StartOfInput,
+	
+	/// Continues only at the end of the input.
+	EndOfInput,
+	
+	/// Continues only at a word boundary (true) or not at a word boundary (false).
+	WordBoundary(bool),
+	
+	/// Continues at `end` only if the instructions following this one, up to a `Match`, do (positive) or do not match at the current position.
+	LookAhead
+	{
+		positive: bool,
+		end: usize,
+	},
+	
+	/// Continues at both instructions.
+	Split(usize, usize),
+	
+	/// Continues at another instruction.
+	Jump(usize),
+	
+	/// The regular expression (or a look-ahead) has matched.
+	Match,
+}
+
+impl RegExpInstruction
+{
+	/// Does this instruction consume `character`? Always false for instructions that do not consume characters.
+	#[inline(always)]
+	pub(crate) fn consumes(&self, character: char) -> bool
+	{
+		use self::RegExpInstruction::*;
+		
+		match *self
+		{
+			Character(expected) => character == expected,
+			
+			AnyCharacterExceptLineTerminator => !RegExpCharacterClassItem::is_line_terminator(character),
+			
+			CharacterClass { negated, ref items } => items.iter().any(|item| item.matches(character)) != negated,
+			
+			StartOfInput | EndOfInput | WordBoundary(_) | LookAhead { .. } | Split(..) | Jump(_) | Match => false,
+		}
+	}
+}
@@ -0,0 +1,149 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Matches compiled regular expression instructions against input by simulating all possible paths through the instructions at once (a Pike VM).
+///
+/// Unlike backtracking, this takes time proportional to the length of the input multiplied by the number of instructions, and never recurses except to evaluate (nested) look-aheads.
+pub(crate) struct RegExpMatcher<'a>
+{
+	instructions: &'a [RegExpInstruction],
+	input: &'a [char],
+	lookAheadResults: HashMap<(usize, usize), bool>,
+}
+
+impl<'a> RegExpMatcher<'a>
+{
+	/// Does `instructions` match all of `input`?
+	#[inline(always)]
+	pub(crate) fn is_full_match(instructions: &'a [RegExpInstruction], input: &'a [char]) -> bool
+	{
+		let mut matcher = Self
+		{
+			instructions,
+			input,
+			lookAheadResults: HashMap::new(),
+		};
+		
+		matcher.matches(0, 0, true)
+	}
+	
+	/// Do the instructions starting at `instruction` match the input starting at `position`, reaching a `Match` at the end of the input (`toEndOfInput`) or anywhere?
+	fn matches(&mut self, instruction: usize, position: usize, toEndOfInput: bool) -> bool
+	{
+		let mut threads = Vec::new();
+		let mut nextThreads = Vec::new();
+		let mut visited = vec![usize::MAX; self.instructions.len()];
+		let mut pending = Vec::new();
+		
+		if self.add_thread(&mut threads, &mut visited, &mut pending, instruction, position, toEndOfInput)
+		{
+			return true;
+		}
+		
+		let mut position = position;
+		while let Some(&character) = self.input.get(position)
+		{
+			if threads.is_empty()
+			{
+				return false;
+			}
+			
+			nextThreads.clear();
+			for &thread in threads.iter()
+			{
+				if self.instructions[thread].consumes(character) && self.add_thread(&mut nextThreads, &mut visited, &mut pending, thread + 1, position + 1, toEndOfInput)
+				{
+					return true;
+				}
+			}
+			
+			swap(&mut threads, &mut nextThreads);
+			position += 1;
+		}
+		
+		false
+	}
+	
+	/// Follows every instruction that does not consume a character from `instruction`, adding those that do to `threads`.
+	/// Returns true if a `Match` is reached.
+	///
+	/// `visited` records the position at which each instruction was last added, so that an instruction is only followed once per position (and loops that consume nothing terminate).
+	fn add_thread(&mut self, threads: &mut Vec<usize>, visited: &mut [usize], pending: &mut Vec<usize>, instruction: usize, position: usize, toEndOfInput: bool) -> bool
+	{
+		use self::RegExpInstruction::*;
+		
+		pending.clear();
+		pending.push(instruction);
+		
+		while let Some(instruction) = pending.pop()
+		{
+			if visited[instruction] == position
+			{
+				continue;
+			}
+			visited[instruction] = position;
+			
+			match self.instructions[instruction]
+			{
+				Character(_) | AnyCharacterExceptLineTerminator | CharacterClass { .. } => threads.push(instruction),
+				
+				StartOfInput => if position == 0
+				{
+					pending.push(instruction + 1);
+				},
+				
+				EndOfInput => if position == self.input.len()
+				{
+					pending.push(instruction + 1);
+				},
+				
+				WordBoundary(isBoundary) =>
+				{
+					let before = position > 0 && RegExpCharacterClassItem::is_word(self.input[position - 1]);
+					let after = position < self.input.len() && RegExpCharacterClassItem::is_word(self.input[position]);
+					if (before != after) == isBoundary
+					{
+						pending.push(instruction + 1);
+					}
+				}
+				
+				LookAhead { positive, end } =>
+				{
+					let matched = match self.lookAheadResults.get(&(instruction, position))
+					{
+						Some(&matched) => matched,
+						None =>
+						{
+							// Evaluated with its own `pending` and `visited`, as those for this position are in use.
+							let matched = self.matches(instruction + 1, position, false);
+							self.lookAheadResults.insert((instruction, position), matched);
+							matched
+						}
+					};
+					
+					if matched == positive
+					{
+						pending.push(end);
+					}
+				}
+				
+				// Pushed in reverse so that the first is followed first, although the order does not affect whether there is a match.
+				Split(first, second) =>
+				{
+					pending.push(second);
+					pending.push(first);
+				}
+				
+				Jump(target) => pending.push(target),
+				
+				Match => if !toEndOfInput || position == self.input.len()
+				{
+					return true;
+				},
+			}
+		}
+		
+		false
+	}
+}
@@ -0,0 +1,177 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A node in a parsed regular expression.
+///
+/// Nodes are compiled to `RegExpInstruction`s for matching.
+#[derive(Clone, Debug)]
+pub(crate) enum RegExpNode
+{
+	/// Matches the empty string.
+	Empty,
+	
+	/// A literal character.
+	Character(char),
+	
+	/// `.`; any character except a line terminator.
+	AnyCharacterExceptLineTerminator,
+	
+	/// `[...]` or `[^...]`, or a character class escape such as `\d` outside of brackets.
+	CharacterClass
+	{
+		negated: bool,
+		items: Vec<RegExpCharacterClassItem>,
+	},
+	
+	/// `^`
+	StartOfInput,
+	
+	/// `$`
+	EndOfInput,
+	
+	/// `\b` (true) or `\B` (false).
+	WordBoundary(bool),
+	
+	/// `(?=...)` (true) or `(?!...)` (false).
+	LookAhead(bool, Box<RegExpNode>),
+	
+	/// A sequence of nodes.
+	Concatenation(Vec<RegExpNode>),
+	
+	/// `a|b|c`.
+	Alternation(Vec<RegExpNode>),
+	
+	/// `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}`, optionally lazy (eg `*?`).
+	///
+	/// Laziness makes no difference to whether a regular expression matches, so it is not recorded.
+	Repetition
+	{
+		node: Box<RegExpNode>,
+		minimum: u32,
+		maximum: Option<u32>,
+	},
+}
+
+impl RegExpNode
+{
+	/// Compiled regular expressions larger than this are rejected, as matching time is proportional to the number of instructions.
+	const MaximumInstructions: usize = 10_000;
+	
+	/// Appends the instructions that match this node to `instructions`.
+	pub(crate) fn compile(&self, instructions: &mut Vec<RegExpInstruction>) -> Result<(), &'static str>
+	{
+		use self::RegExpNode::*;
+		
+		if instructions.len() > Self::MaximumInstructions
+		{
+			return Err("regular expression is too large");
+		}
+		
+		match *self
+		{
+			Empty => (),
+			
+			Character(character) => instructions.push(RegExpInstruction::Character(character)),
+			
+			AnyCharacterExceptLineTerminator => instructions.push(RegExpInstruction::AnyCharacterExceptLineTerminator),
+			
+			CharacterClass { negated, ref items } => instructions.push(RegExpInstruction::CharacterClass { negated, items: items.clone() }),
+			
+			StartOfInput => instructions.push(RegExpInstruction::StartOfInput),
+			
+			EndOfInput => instructions.push(RegExpInstruction::EndOfInput),
+			
+			WordBoundary(isBoundary) => instructions.push(RegExpInstruction::WordBoundary(isBoundary)),
+			
+			LookAhead(positive, ref node) =>
+			{
+				let lookAhead = instructions.len();
+				instructions.push(RegExpInstruction::LookAhead { positive, end: lookAhead });
+				node.compile(instructions)?;
+				instructions.push(RegExpInstruction::Match);
+				instructions[lookAhead] = RegExpInstruction::LookAhead { positive, end: instructions.len() };
+			}
+			
+			Concatenation(ref nodes) =>
+			{
+				for node in nodes.iter()
+				{
+					node.compile(instructions)?;
+				}
+			}
+			
+			Alternation(ref nodes) =>
+			{
+				if let Some((last, others)) = nodes.split_last()
+				{
+					let mut jumps = Vec::with_capacity(others.len());
+					for node in others.iter()
+					{
+						let split = instructions.len();
+						instructions.push(RegExpInstruction::Split(split + 1, split));
+						node.compile(instructions)?;
+						jumps.push(instructions.len());
+						instructions.push(RegExpInstruction::Jump(split));
+						instructions[split] = RegExpInstruction::Split(split + 1, instructions.len());
+					}
+					
+					last.compile(instructions)?;
+					
+					let end = instructions.len();
+					for jump in jumps
+					{
+						instructions[jump] = RegExpInstruction::Jump(end);
+					}
+				}
+			}
+			
+			Repetition { ref node, minimum, maximum } =>
+			{
+				for _ in 0 .. minimum
+				{
+					let start = instructions.len();
+					node.compile(instructions)?;
+					
+					// Repeating a node that compiles to nothing (eg `(?:){1000}`) would otherwise never exceed the maximum number of instructions.
+					if instructions.len() == start
+					{
+						return Ok(());
+					}
+				}
+				
+				match maximum
+				{
+					None =>
+					{
+						let split = instructions.len();
+						instructions.push(RegExpInstruction::Split(split + 1, split));
+						node.compile(instructions)?;
+						instructions.push(RegExpInstruction::Jump(split));
+						instructions[split] = RegExpInstruction::Split(split + 1, instructions.len());
+					}
+					
+					Some(maximum) =>
+					{
+						let mut splits = Vec::new();
+						for _ in minimum .. maximum
+						{
+							let split = instructions.len();
+							splits.push(split);
+							instructions.push(RegExpInstruction::Split(split + 1, split));
+							node.compile(instructions)?;
+						}
+						
+						let end = instructions.len();
+						for split in splits
+						{
+							instructions[split] = RegExpInstruction::Split(split + 1, end);
+						}
+					}
+				}
+			}
+		}
+		
+		Ok(())
+	}
+}
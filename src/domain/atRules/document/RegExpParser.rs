@@ -0,0 +1,538 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Compiles the source of a regular expression into a `RegExpNode`.
+///
+/// https://tc39.es/ecma262/#sec-patterns (including the Annex B extensions browsers implement, such as a literal `{` or `]`).
+pub(crate) struct RegExpParser
+{
+	characters: Vec<char>,
+	position: usize,
+	depth: usize,
+}
+
+impl RegExpParser
+{
+	/// Groups nested more deeply than this are rejected, as parsing and compiling recurse once for each group.
+	const MaximumDepth: usize = 100;
+	
+	pub(crate) fn parse(source: &str) -> Result<RegExpNode, &'static str>
+	{
+		let mut parser = Self
+		{
+			characters: source.chars().collect(),
+			position: 0,
+			depth: 0,
+		};
+		
+		let node = parser.parse_disjunction()?;
+		
+		match parser.peek()
+		{
+			None => Ok(node),
+			Some(')') => Err("unmatched ')'"),
+			Some(_) => Err("unexpected character"),
+		}
+	}
+	
+	#[inline(always)]
+	fn peek(&self) -> Option<char>
+	{
+		self.characters.get(self.position).cloned()
+	}
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<char>
+	{
+		let character = self.peek();
+		if character.is_some()
+		{
+			self.position += 1;
+		}
+		character
+	}
+	
+	#[inline(always)]
+	fn eat(&mut self, expected: char) -> bool
+	{
+		if self.peek() == Some(expected)
+		{
+			self.position += 1;
+			true
+		}
+		else
+		{
+			false
+		}
+	}
+	
+	#[inline(always)]
+	fn eat_str(&mut self, expected: &str) -> bool
+	{
+		let start = self.position;
+		for expectedCharacter in expected.chars()
+		{
+			if !self.eat(expectedCharacter)
+			{
+				self.position = start;
+				return false;
+			}
+		}
+		true
+	}
+	
+	fn parse_disjunction(&mut self) -> Result<RegExpNode, &'static str>
+	{
+		let mut alternatives = vec![self.parse_alternative()?];
+		while self.eat('|')
+		{
+			alternatives.push(self.parse_alternative()?);
+		}
+		
+		if alternatives.len() == 1
+		{
+			Ok(alternatives.pop().unwrap())
+		}
+		else
+		{
+			Ok(RegExpNode::Alternation(alternatives))
+		}
+	}
+	
+	fn parse_alternative(&mut self) -> Result<RegExpNode, &'static str>
+	{
+		let mut terms = Vec::new();
+		loop
+		{
+			match self.peek()
+			{
+				None | Some('|') | Some(')') => break,
+				_ => terms.push(self.parse_term()?),
+			}
+		}
+		
+		match terms.len()
+		{
+			0 => Ok(RegExpNode::Empty),
+			1 => Ok(terms.pop().unwrap()),
+			_ => Ok(RegExpNode::Concatenation(terms)),
+		}
+	}
+	
+	fn parse_term(&mut self) -> Result<RegExpNode, &'static str>
+	{
+		use self::RegExpNode::*;
+		
+		if self.eat('^')
+		{
+			return Ok(StartOfInput);
+		}
+		
+		if self.eat('$')
+		{
+			return Ok(EndOfInput);
+		}
+		
+		if self.eat_str("\\b")
+		{
+			return Ok(WordBoundary(true));
+		}
+		
+		if self.eat_str("\\B")
+		{
+			return Ok(WordBoundary(false));
+		}
+		
+		let lookAhead = if self.eat_str("(?=")
+		{
+			Some(true)
+		}
+		else if self.eat_str("(?!")
+		{
+			Some(false)
+		}
+		else
+		{
+			None
+		};
+		
+		if let Some(positive) = lookAhead
+		{
+			let node = self.parse_group_body()?;
+			// Annex B permits quantifying a look-ahead, but doing so is pointless as it never consumes input.
+			let lookAhead = LookAhead(positive, Box::new(node));
+			return self.parse_optional_quantifier(lookAhead);
+		}
+		
+		let atom = self.parse_atom()?;
+		self.parse_optional_quantifier(atom)
+	}
+	
+	fn parse_group_body(&mut self) -> Result<RegExpNode, &'static str>
+	{
+		if self.depth == Self::MaximumDepth
+		{
+			return Err("groups nested too deeply");
+		}
+		
+		self.depth += 1;
+		let node = self.parse_disjunction()?;
+		self.depth -= 1;
+		
+		if self.eat(')')
+		{
+			Ok(node)
+		}
+		else
+		{
+			Err("unterminated group")
+		}
+	}
+	
+	fn parse_atom(&mut self) -> Result<RegExpNode, &'static str>
+	{
+		use self::RegExpNode::*;
+		
+		match self.next()
+		{
+			None => Err("unexpected end of regular expression"),
+			
+			Some('.') => Ok(AnyCharacterExceptLineTerminator),
+			
+			Some('(') =>
+			{
+				if self.eat_str("?:")
+				{
+				}
+				else if self.eat_str("?<")
+				{
+					// Named capture groups are treated as ordinary groups as nothing is captured
+					loop
+					{
+						match self.next()
+						{
+							None => return Err("unterminated group name"),
+							Some('>') => break,
+							Some(character) if character == '$' || character == '_' || character.is_alphanumeric() => (),
+							Some(_) => return Err("invalid group name"),
+						}
+					}
+				}
+				else if self.peek() == Some('?')
+				{
+					return Err("invalid group");
+				}
+				
+				self.parse_group_body()
+			}
+			
+			Some('[') => self.parse_character_class(),
+			
+			Some('\\') => self.parse_atom_escape(),
+			
+			Some('*') | Some('+') | Some('?') => Err("nothing to repeat"),
+			
+			Some('{') if self.is_at_quantifier_bounds() => Err("nothing to repeat"),
+			
+			Some(character) => Ok(Character(character)),
+		}
+	}
+	
+	fn parse_atom_escape(&mut self) -> Result<RegExpNode, &'static str>
+	{
+		use self::RegExpCharacterClassItem::*;
+		
+		let item = match self.peek()
+		{
+			Some('d') => Digit,
+			Some('D') => NotDigit,
+			Some('w') => Word,
+			Some('W') => NotWord,
+			Some('s') => Whitespace,
+			Some('S') => NotWhitespace,
+			Some('1'..='9') => return Err("backreferences are not supported"),
+			_ => return self.parse_character_escape(false).map(RegExpNode::Character),
+		};
+		self.position += 1;
+		
+		Ok
+		(
+			RegExpNode::CharacterClass
+			{
+				negated: false,
+				items: vec![item],
+			}
+		)
+	}
+	
+	/// Parses the escape after a `\`, returning the character it represents.
+	fn parse_character_escape(&mut self, inCharacterClass: bool) -> Result<char, &'static str>
+	{
+		let character = match self.next()
+		{
+			None => return Err("\\ at end of regular expression"),
+			Some(character) => character,
+		};
+		
+		let escaped = match character
+		{
+			't' => '\t',
+			'n' => '\n',
+			'v' => '\u{000B}',
+			'f' => '\u{000C}',
+			'r' => '\r',
+			'0' if !self.peek().is_some_and(|character| character.is_ascii_digit()) => '\0',
+			'b' if inCharacterClass => '\u{0008}',
+			'c' =>
+			{
+				match self.peek()
+				{
+					Some(letter) if letter.is_ascii_alphabetic() =>
+					{
+						self.position += 1;
+						((letter as u32) % 32) as u8 as char
+					}
+					
+					// Annex B: `\c` not followed by a letter is a literal backslash followed by `c`.
+					_ =>
+					{
+						self.position -= 1;
+						'\\'
+					}
+				}
+			}
+			'x' => self.parse_hexadecimal_escape(2).unwrap_or('x'),
+			'u' => self.parse_hexadecimal_escape(4).unwrap_or('u'),
+			
+			// Annex B identity escapes; this includes `\/`, `\.`, `\-` and so on.
+			other => other,
+		};
+		
+		Ok(escaped)
+	}
+	
+	fn parse_hexadecimal_escape(&mut self, numberOfDigits: usize) -> Option<char>
+	{
+		let start = self.position;
+		let end = start + numberOfDigits;
+		if end > self.characters.len()
+		{
+			return None;
+		}
+		
+		let mut codePoint = 0;
+		for &character in &self.characters[start..end]
+		{
+			codePoint = codePoint * 16 + character.to_digit(16)?;
+		}
+		self.position = end;
+		
+		// Lone surrogates can not be represented as a char, and so can never match
+		Some(::std::char::from_u32(codePoint).unwrap_or('\u{FFFD}'))
+	}
+	
+	fn parse_character_class(&mut self) -> Result<RegExpNode, &'static str>
+	{
+		use self::RegExpCharacterClassItem::*;
+		
+		let negated = self.eat('^');
+		let mut items = Vec::new();
+		
+		loop
+		{
+			let from = match self.parse_character_class_atom()?
+			{
+				None => break,
+				Some(from) => from,
+			};
+			
+			let isRange = match from
+			{
+				Character(_) => self.peek() == Some('-') && self.characters.get(self.position + 1).is_some_and(|&character| character != ']'),
+				_ => false,
+			};
+			
+			if !isRange
+			{
+				items.push(from);
+				continue;
+			}
+			
+			self.position += 1;
+			
+			match (from, self.parse_character_class_atom()?)
+			{
+				(Character(from), Some(Character(to))) =>
+				{
+					if from > to
+					{
+						return Err("range out of order in character class");
+					}
+					items.push(Range(from, to));
+				}
+				
+				// Annex B: a range with a class escape, such as `[a-\d]`, is a literal `-`.
+				(from, Some(to)) =>
+				{
+					items.push(from);
+					items.push(Character('-'));
+					items.push(to);
+				}
+				
+				(_, None) => unreachable!("checked that the range did not end with ']'"),
+			}
+		}
+		
+		Ok
+		(
+			RegExpNode::CharacterClass
+			{
+				negated,
+				items,
+			}
+		)
+	}
+	
+	/// Returns `None` at the closing `]`.
+	fn parse_character_class_atom(&mut self) -> Result<Option<RegExpCharacterClassItem>, &'static str>
+	{
+		use self::RegExpCharacterClassItem::*;
+		
+		match self.next()
+		{
+			None => Err("unterminated character class"),
+			
+			Some(']') => Ok(None),
+			
+			Some('\\') =>
+			{
+				let item = match self.peek()
+				{
+					Some('d') => Digit,
+					Some('D') => NotDigit,
+					Some('w') => Word,
+					Some('W') => NotWord,
+					Some('s') => Whitespace,
+					Some('S') => NotWhitespace,
+					_ => return self.parse_character_escape(true).map(|character| Some(Character(character))),
+				};
+				self.position += 1;
+				Ok(Some(item))
+			}
+			
+			Some(character) => Ok(Some(Character(character))),
+		}
+	}
+	
+	fn parse_optional_quantifier(&mut self, atom: RegExpNode) -> Result<RegExpNode, &'static str>
+	{
+		let (minimum, maximum) = match self.peek()
+		{
+			Some('*') =>
+			{
+				self.position += 1;
+				(0, None)
+			}
+			
+			Some('+') =>
+			{
+				self.position += 1;
+				(1, None)
+			}
+			
+			Some('?') =>
+			{
+				self.position += 1;
+				(0, Some(1))
+			}
+			
+			Some('{') if self.is_at_quantifier_bounds() =>
+			{
+				self.position += 1;
+				let minimum = self.parse_decimal()?;
+				let maximum = if self.eat(',')
+				{
+					if self.peek() == Some('}')
+					{
+						None
+					}
+					else
+					{
+						Some(self.parse_decimal()?)
+					}
+				}
+				else
+				{
+					Some(minimum)
+				};
+				self.position += 1;
+				
+				if let Some(maximum) = maximum
+				{
+					if minimum > maximum
+					{
+						return Err("numbers out of order in {} quantifier");
+					}
+				}
+				
+				(minimum, maximum)
+			}
+			
+			_ => return Ok(atom),
+		};
+		
+		// A lazy quantifier (eg `*?`) matches the same inputs as a greedy one.
+		self.eat('?');
+		
+		Ok
+		(
+			RegExpNode::Repetition
+			{
+				node: Box::new(atom),
+				minimum,
+				maximum,
+			}
+		)
+	}
+	
+	/// Is the parser positioned at `{n}`, `{n,}` or `{n,m}`? If not, Annex B treats `{` as a literal.
+	fn is_at_quantifier_bounds(&self) -> bool
+	{
+		let mut position = if self.peek() == Some('{') { self.position + 1 } else { self.position };
+		
+		let digits = |position: &mut usize|
+		{
+			let start = *position;
+			while self.characters.get(*position).is_some_and(|character| character.is_ascii_digit())
+			{
+				*position += 1;
+			}
+			*position != start
+		};
+		
+		if !digits(&mut position)
+		{
+			return false;
+		}
+		
+		if self.characters.get(position) == Some(&',')
+		{
+			position += 1;
+			digits(&mut position);
+		}
+		
+		self.characters.get(position) == Some(&'}')
+	}
+	
+	fn parse_decimal(&mut self) -> Result<u32, &'static str>
+	{
+		let mut value: u32 = 0;
+		while let Some(digit) = self.peek().and_then(|character| character.to_digit(10))
+		{
+			self.position += 1;
+			value = value.checked_mul(10).and_then(|value| value.checked_add(digit)).ok_or("quantifier too large")?;
+		}
+		Ok(value)
+	}
+}
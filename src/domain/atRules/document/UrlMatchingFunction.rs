@@ -21,7 +21,7 @@ pub enum UrlMatchingFunction
 	
 	/// Regular expression matching function.
 	/// It evaluates to true whenever the regular expression matches the entirety of the URL of the document being styled.
-	RegExp(String),
+	RegExp(RegExp),
 }
 
 macro_rules! parse_quoted_or_unquoted_string
@@ -81,7 +81,7 @@ impl ToCss for UrlMatchingFunction
 			RegExp(ref regex) =>
 			{
 				dest.write_str("regexp(")?;
-				serialize_string(regex, dest)?;
+				regex.to_css(dest)?;
 				dest.write_char(')')
 			},
 		}
@@ -105,7 +105,12 @@ impl UrlMatchingFunction
 		{
 			input.parse_nested_block(|input|
 			{
-				Ok(UrlMatchingFunction::RegExp(input.expect_string()?.as_ref().to_owned()))
+				let source = input.expect_string_cloned()?;
+				match RegExp::compile(&source)
+				{
+					Ok(regex) => Ok(UrlMatchingFunction::RegExp(regex)),
+					Err(reason) => Err(input.new_custom_error(CustomParseError::DocumentAtRuleRegExpWasInvalid(source, reason))),
+				}
 			})
 		}
 		else if let Ok(url) = input.r#try(|input| SpecifiedUrl::parse(context, input))
@@ -123,4 +128,56 @@ impl UrlMatchingFunction
 	{
 		document.documentMatchesUrl(self)
 	}
+	
+	/// Does this URL matching function match the URL of a document?
+	pub fn matches(&self, documentUrl: &str) -> bool
+	{
+		use self::UrlMatchingFunction::*;
+		
+		match *self
+		{
+			Url(ref url) => documentUrl == url.0,
+			
+			UrlPrefix(ref url_prefix) => documentUrl.starts_with(url_prefix.as_str()),
+			
+			Domain(ref domain) => match Self::host(documentUrl)
+			{
+				None => false,
+				Some(host) =>
+				{
+					let host = host.to_ascii_lowercase();
+					let domain = domain.to_ascii_lowercase();
+					host == domain || (host.ends_with(&domain) && host[.. host.len() - domain.len()].ends_with('.'))
+				}
+			},
+			
+			RegExp(ref regex) => regex.is_full_match(documentUrl),
+		}
+	}
+	
+	/// The host subcomponent of an URL, if any, without any user information or port.
+	fn host(url: &str) -> Option<&str>
+	{
+		let afterScheme = &url[url.find("://")? + 3 ..];
+		let authority = &afterScheme[.. afterScheme.find(['/', '?', '#']).unwrap_or(afterScheme.len())];
+		let hostAndPort = &authority[authority.rfind('@').map_or(0, |index| index + 1) ..];
+		
+		let host = if hostAndPort.starts_with('[')
+		{
+			&hostAndPort[.. hostAndPort.find(']').map_or(hostAndPort.len(), |index| index + 1)]
+		}
+		else
+		{
+			&hostAndPort[.. hostAndPort.find(':').unwrap_or(hostAndPort.len())]
+		};
+		
+		if host.is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(host)
+		}
+	}
 }
@@ -3,9 +3,17 @@
 
 
 use super::*;
+use ::std::collections::HashMap;
+use ::std::mem::swap;
 
 
 include!("Document.rs");
 include!("DocumentAtRule.rs");
 include!("DocumentCondition.rs");
+include!("RegExp.rs");
+include!("RegExpCharacterClassItem.rs");
+include!("RegExpInstruction.rs");
+include!("RegExpMatcher.rs");
+include!("RegExpNode.rs");
+include!("RegExpParser.rs");
 include!("UrlMatchingFunction.rs");
@@ -34,6 +34,9 @@ pub struct FontFaceAtRule
 	
 	/// The language override of this font face.
 	pub language_override: Option<FontLanguageOverride>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for FontFaceAtRule
@@ -131,11 +134,12 @@ impl FontFaceAtRule
 			unicode_range: None,
 			feature_settings: None,
 			language_override: None,
+			source_range: None,
 		}
 	}
 
 	/// Parse the block inside a `@font-face` rule.
-	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<FontFaceAtRule, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext<'i>, input: &mut Parser<'i, 't>) -> Result<FontFaceAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut rule = Self::empty();
 		
@@ -149,7 +153,7 @@ impl FontFaceAtRule
 			let mut iter = DeclarationListParser::new(input, parser);
 			while let Some(declaration) = iter.next()
 			{
-				declaration.map_err(|(error, _)| error)?;
+				context.recover(declaration)?;
 			}
 		}
 		
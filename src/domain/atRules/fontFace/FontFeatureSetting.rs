@@ -41,10 +41,8 @@ impl FontFeatureSetting
 				Ok(FontFeatureSetting(openTypeFeatureTag, integer as u32))
 			}
 		}
-		else
+		else if let Ok(ident) = input.r#try(|input| input.expect_ident_cloned())
 		{
-			let ident = input.expect_ident()?;
-			
 			match_ignore_ascii_case!
 			{
 				&ident,
@@ -53,11 +51,13 @@ impl FontFeatureSetting
 				
 				"off" => Ok(FontFeatureSetting(openTypeFeatureTag, 0)),
 				
-				_ => {
-					let ident = ident.clone();
-					Err(input.new_custom_error(CustomParseError::FontFeatureSettingIfNotAnIntegerMustBeOnOrOff(ident)))
-				},
+				_ => Err(input.new_custom_error(CustomParseError::FontFeatureSettingIfNotAnIntegerMustBeOnOrOff(ident))),
 			}
 		}
+		else
+		{
+			// The value defaults to 1 (on).
+			Ok(FontFeatureSetting(openTypeFeatureTag, 1))
+		}
 	}
 }
@@ -2,7 +2,7 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct FontFeatureSettings(pub BTreeMap<String, u32>);
 
 impl ToCss for FontFeatureSettings
@@ -15,8 +15,17 @@ impl ToCss for FontFeatureSettings
 		}
 		else
 		{
+			let mut isFirst = true;
 			for (openTypeFeatureTag, integer) in self.0.iter()
 			{
+				if isFirst
+				{
+					isFirst = false;
+				}
+				else
+				{
+					dest.write_char(',')?;
+				}
 				serialize_string(openTypeFeatureTag, dest)?;
 				let integer = *integer;
 				if integer != 1
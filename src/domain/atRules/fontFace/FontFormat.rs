@@ -0,0 +1,18 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+// A font format keyword, as used in `@supports font-format()`.
+//
+// https://drafts.csswg.org/css-fonts-4/#font-format-values
+define_css_keyword_enum!
+(
+	FontFormat:
+	"collection" => collection,
+	"embedded-opentype" => embedded_opentype,
+	"opentype" => opentype,
+	"svg" => svg,
+	"truetype" => truetype,
+	"woff" => woff,
+	"woff2" => woff2
+);
@@ -0,0 +1,22 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+// A font technology keyword, as used in `@supports font-tech()`.
+//
+// https://drafts.csswg.org/css-fonts-4/#font-tech-values
+define_css_keyword_enum!
+(
+	FontTechnology:
+	"features-opentype" => features_opentype,
+	"features-aat" => features_aat,
+	"features-graphite" => features_graphite,
+	"color-colrv0" => color_COLRv0,
+	"color-colrv1" => color_COLRv1,
+	"color-svg" => color_SVG,
+	"color-sbix" => color_sbix,
+	"color-cbdt" => color_CBDT,
+	"variations" => variations,
+	"palettes" => palettes,
+	"incremental" => incremental
+);
@@ -15,9 +15,11 @@ include!("FontFaceAtRule.rs");
 include!("FontFamily.rs");
 include!("FontFeatureSetting.rs");
 include!("FontFeatureSettings.rs");
+include!("FontFormat.rs");
 include!("FontLanguageOverride.rs");
 include!("FontStretch.rs");
 include!("FontStyle.rs");
+include!("FontTechnology.rs");
 include!("FontUrlSource.rs");
 include!("FontWeight.rs");
 include!("GenericFontFamilyName.rs");
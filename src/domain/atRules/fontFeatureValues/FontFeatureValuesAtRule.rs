@@ -30,6 +30,9 @@ pub struct FontFeatureValuesAtRule
 	
 	/// A @styleset block. Specifies a feature name that will work with the character-variant() functional notation of font-variant-alternates. The value can be a list.
 	pub styleset: Vec<FontFeatureValuesDeclaration<VectorValues>>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for FontFeatureValuesAtRule
@@ -58,10 +61,11 @@ impl FontFeatureValuesAtRule
 			annotation: vec![],
 			character_variant: vec![],
 			styleset: vec![],
+			source_range: None,
 		}
 	}
 	
-	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, family_names: Vec<FamilyName>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext<'i>, input: &mut Parser<'i, 't>, family_names: Vec<FamilyName>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut fontFeatureValuesRule = Self::new(family_names);
 		
@@ -73,7 +77,7 @@ impl FontFeatureValuesAtRule
 			});
 			while let Some(possiblePreciseParseError) = iterator.next()
 			{
-				possiblePreciseParseError.map_err(|(error, _)| error)?;
+				context.recover(possiblePreciseParseError)?;
 			}
 		}
 		
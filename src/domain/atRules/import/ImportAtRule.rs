@@ -11,7 +11,17 @@ pub struct ImportAtRule
 	/// The `<url>` this `@import` rule is loading.
 	pub url: SpecifiedUrl,
 	
+	/// The cascade layer, if any, to import into.
+	pub layer: Option<ImportLayer>,
+	
+	/// The `supports()` condition, if any, that must be met for the import to apply.
+	pub supports: Option<SupportsCondition>,
+	
+	/// The media queries, if any, that must match for the import to apply.
 	pub media_list: MediaList,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for ImportAtRule
@@ -21,6 +31,23 @@ impl ToCss for ImportAtRule
 		dest.write_str("@import ")?;
 		self.url.to_css(dest)?;
 		
+		if let Some(ref layer) = self.layer
+		{
+			dest.write_char(' ')?;
+			layer.to_css(dest)?;
+		}
+		
+		if let Some(ref supports) = self.supports
+		{
+			dest.write_str(" supports(")?;
+			match *supports
+			{
+				SupportsCondition::Declaration(ref declaration) => declaration.to_css(dest)?,
+				_ => supports.to_css(dest)?,
+			}
+			dest.write_char(')')?;
+		}
+		
 		if self.media_list.is_not_empty()
 		{
 			dest.write_char(' ')?;
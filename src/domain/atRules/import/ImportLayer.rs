@@ -0,0 +1,50 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The cascade layer an `@import` rule imports into.
+///
+/// https://drafts.csswg.org/css-cascade-5/#at-import
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ImportLayer
+{
+	/// `layer`
+	Anonymous,
+	
+	/// `layer(name)`
+	Named(LayerName),
+}
+
+impl ToCss for ImportLayer
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			ImportLayer::Anonymous => dest.write_str("layer"),
+			
+			ImportLayer::Named(ref name) =>
+			{
+				dest.write_str("layer(")?;
+				name.to_css(dest)?;
+				dest.write_char(')')
+			}
+		}
+	}
+}
+
+impl ImportLayer
+{
+	#[inline(always)]
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		match input.next()?.clone()
+		{
+			Token::Ident(ref ident) if ident.eq_ignore_ascii_case("layer") => Ok(ImportLayer::Anonymous),
+			
+			Token::Function(ref name) if name.eq_ignore_ascii_case("layer") => input.parse_nested_block(|input| LayerName::parse(input)).map(ImportLayer::Named),
+			
+			unexpectedToken => Err(input.new_unexpected_token_error(unexpectedToken)),
+		}
+	}
+}
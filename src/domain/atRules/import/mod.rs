@@ -3,5 +3,8 @@
 
 
 use super::*;
+use super::layer::LayerName;
+use super::supports::SupportsCondition;
 
 include!("ImportAtRule.rs");
+include!("ImportLayer.rs");
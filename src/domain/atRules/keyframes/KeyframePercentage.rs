@@ -25,6 +25,10 @@ impl ToCss for KeyframePercentage
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
 		if self.0.is_zero()
+		{
+			dest.write_str("from")
+		}
+		else if self.0 == PercentageUnit::OneHundredPercent
 		{
 			dest.write_str("to")
 		}
@@ -63,3 +67,16 @@ impl KeyframePercentage
 		Ok(percentage)
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn zero_and_one_hundred_percent_are_serialized_as_from_and_to()
+	{
+		let stylesheet = Stylesheet::parse("@keyframes x{0%{top:0}50%{top:1px}100%{top:2px}}").unwrap();
+		assert_eq!(stylesheet.to_css_string(false), "@keyframes x{from{top:0}50%{top:1px}to{top:2px}}");
+	}
+}
@@ -15,6 +15,9 @@ pub struct KeyframesAtRule
 	
 	/// The keyframes specified for this CSS rule.
 	pub keyframes: Vec<Keyframe>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for KeyframesAtRule
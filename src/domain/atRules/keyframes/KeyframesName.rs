@@ -49,6 +49,24 @@ impl Eq for KeyframesName
 {
 }
 
+impl PartialOrd for KeyframesName
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for KeyframesName
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.as_atom().cmp(other.as_atom())
+	}
+}
+
 impl Hash for KeyframesName
 {
 	fn hash<H: Hasher>(&self, state: &mut H)
@@ -64,7 +82,15 @@ impl ToCss for KeyframesName
 		match *self
 		{
 			KeyframesName::Ident(ref ident) => ident.to_css(dest),
-			KeyframesName::QuotedString(ref atom) => atom.to_css(dest),
+			// A string that is also a valid identifier is written as the (shorter) identifier.
+			KeyframesName::QuotedString(ref atom) => if !atom.is_empty() && CustomIdent::from_ident(&CowRcStr::from(&atom[..]), &["none"]).is_ok()
+			{
+				atom.to_css(dest)
+			}
+			else
+			{
+				serialize_string(atom, dest)
+			},
 		}
 	}
 }
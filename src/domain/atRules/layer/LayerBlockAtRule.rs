@@ -0,0 +1,68 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An [`@layer`][layer] block, which assigns its rules to a cascade layer.
+///
+/// [layer]: https://drafts.csswg.org/css-cascade-5/#layer-block
+#[derive(Debug, Clone)]
+pub struct LayerBlockAtRule
+{
+	/// The layer name; absent for an anonymous layer.
+	pub name: Option<LayerName>,
+	
+	/// The nested rules in this layer.
+	pub rules: CssRules,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
+}
+
+impl HasCssRules for LayerBlockAtRule
+{
+	#[inline(always)]
+	fn css_rules(&self) -> &CssRules
+	{
+		&self.rules
+	}
+	
+	#[inline(always)]
+	fn css_rules_mut(&mut self) -> &mut CssRules
+	{
+		&mut self.rules
+	}
+	
+	#[inline(always)]
+	fn css_rules_slice(&self) -> &[CssRule]
+	{
+		&self.rules.0[..]
+	}
+	
+	#[inline(always)]
+	fn css_rules_vec(&self) -> &Vec<CssRule>
+	{
+		&self.rules.0
+	}
+	
+	#[inline(always)]
+	fn css_rules_vec_mut(&mut self) -> &mut Vec<CssRule>
+	{
+		&mut self.rules.0
+	}
+}
+
+impl ToCss for LayerBlockAtRule
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str("@layer")?;
+		if let Some(ref name) = self.name
+		{
+			dest.write_char(' ')?;
+			name.to_css(dest)?;
+		}
+		dest.write_char('{')?;
+		self.rules.to_css(dest)?;
+		dest.write_char('}')
+	}
+}
@@ -0,0 +1,90 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A cascade layer name, such as `framework.base`.
+///
+/// https://drafts.csswg.org/css-cascade-5/#typedef-layer-name
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LayerName(pub Vec<Atom>);
+
+impl ToCss for LayerName
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut first = true;
+		for segment in self.0.iter()
+		{
+			if first
+			{
+				first = false;
+			}
+			else
+			{
+				dest.write_char('.')?;
+			}
+			serialize_identifier(segment, dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl LayerName
+{
+	/// Parses a layer name; no whitespace is permitted around the `.` separators.
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let first = Self::parse_segment(input.expect_ident()?);
+		let mut segments = vec![first.map_err(|error| input.new_custom_error(error))?];
+		
+		loop
+		{
+			let dotAndSegment: Result<_, ParseError<CustomParseError>> = input.r#try(|input|
+			{
+				match input.next_including_whitespace()?.clone()
+				{
+					Token::Delim('.') => (),
+					unexpectedToken => return Err(input.new_unexpected_token_error(unexpectedToken)),
+				}
+				
+				match input.next_including_whitespace()?.clone()
+				{
+					Token::Ident(ref segment) => Self::parse_segment(segment).map_err(|error| input.new_custom_error(error)),
+					unexpectedToken => Err(input.new_unexpected_token_error(unexpectedToken)),
+				}
+			});
+			
+			match dotAndSegment
+			{
+				Ok(segment) => segments.push(segment),
+				Err(_) => return Ok(LayerName(segments)),
+			}
+		}
+	}
+	
+	/// Parses a comma-separated list of layer names, which may be empty.
+	pub(crate) fn parse_layer_name_list<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Vec<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.is_exhausted()
+		{
+			Ok(Vec::new())
+		}
+		else
+		{
+			input.parse_comma_separated(Self::parse)
+		}
+	}
+	
+	#[inline(always)]
+	fn parse_segment<'i>(segment: &CowRcStr<'i>) -> Result<Atom, CustomParseError<'i>>
+	{
+		match_ignore_ascii_case!
+		{
+			segment,
+			
+			"initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => Err(CustomParseError::InvalidLayerName(segment.clone())),
+			
+			_ => Ok(Atom::from(segment)),
+		}
+	}
+}
@@ -0,0 +1,38 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An [`@layer`][layer] statement, which declares the order of cascade layers.
+///
+/// [layer]: https://drafts.csswg.org/css-cascade-5/#layer-empty
+#[derive(Debug, Clone)]
+pub struct LayerStatementAtRule
+{
+	/// The layer names; never empty.
+	pub names: Vec<LayerName>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
+}
+
+impl ToCss for LayerStatementAtRule
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str("@layer ")?;
+		let mut first = true;
+		for name in self.names.iter()
+		{
+			if first
+			{
+				first = false;
+			}
+			else
+			{
+				dest.write_char(',')?;
+			}
+			name.to_css(dest)?;
+		}
+		dest.write_char(';')
+	}
+}
@@ -13,6 +13,9 @@ pub struct MediaAtRule
 	
 	/// The nested rules to this media rule.
 	pub rules: CssRules,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl HasCssRules for MediaAtRule
@@ -48,6 +48,14 @@ impl MediaList
 		}
 	}
 	
+	/// Parses a media query list, such as `screen and (min-width: 100px), print`.
+	/// Unlike in a stylesheet, an invalid media query is an error.
+	#[inline(always)]
+	pub fn parse(css: &str) -> Result<Self, CssError>
+	{
+		CssError::parse_entirely(css, |input| Self::parse_media_query_list(&ParserContext::new(CssRuleType::Media), input, false))
+	}
+	
 	/// Parse a media query list from CSS.
 	///
 	/// Always returns a media query list. If any invalid media query is found, the media query list is only filled with the equivalent of "not all", see:-
@@ -11,6 +11,7 @@ pub mod fontFace;
 pub mod fontFeatureValues;
 pub mod import;
 pub mod keyframes;
+pub mod layer;
 pub mod media;
 pub mod namespace;
 pub mod page;
@@ -12,6 +12,9 @@ pub struct NamespaceAtRule
 	
 	/// The actual namespace url.
 	pub url: NamespaceUrl,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for NamespaceAtRule
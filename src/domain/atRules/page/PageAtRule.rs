@@ -8,24 +8,34 @@
 #[derive(Default, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct PageAtRule
 {
-	pub page_selector_pseudo_class: Option<PageSelectorPseudoClass>,
+	/// The page selectors; if empty, this rule applies to all pages.
+	pub page_selectors: Vec<PageSelector>,
 	
 	/// The declaration block this page rule contains.
 	pub property_declarations: PropertyDeclarations<Importance>,
+	
+	/// The page-margin rules, eg `@top-left`, this page rule contains.
+	pub margin_rules: Vec<PageMarginAtRule>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ToCss for PageAtRule
 {
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
-		dest.write_str("@page")?;
-		if let Some(ref page_selector_pseudo_class) = self.page_selector_pseudo_class
-		{
-			dest.write_char(' ')?;
-			page_selector_pseudo_class.to_css(dest)?;
-		}
+		self.page_selectors_to_css(dest)?;
 		dest.write_char('{')?;
 		self.property_declarations.to_css(dest)?;
+		if !self.property_declarations.is_empty() && !self.margin_rules.is_empty()
+		{
+			dest.write_char(';')?;
+		}
+		for margin_rule in self.margin_rules.iter()
+		{
+			margin_rule.to_css(dest)?;
+		}
 		dest.write_char('}')
 	}
 }
@@ -62,3 +72,115 @@ impl HasPropertyDeclarations<Importance> for PageAtRule
 		&mut self.property_declarations.0
 	}
 }
+
+impl PageAtRule
+{
+	/// The specificity of the most specific page selector that matches a page, or `None` if this rule does not apply to the page.
+	///
+	/// A rule without page selectors applies to all pages with the lowest possible specificity.
+	pub fn matching_specificity(&self, page_name: Option<&str>, page_index: usize, side: PageSide) -> Option<(u32, u32, u32)>
+	{
+		if self.page_selectors.is_empty()
+		{
+			return Some((0, 0, 0));
+		}
+		
+		self.page_selectors.iter().filter(|page_selector| page_selector.matches(page_name, page_index, side)).map(PageSelector::specificity).max()
+	}
+	
+	/// Writes `@page` and the page selectors.
+	pub(crate) fn page_selectors_to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str("@page")?;
+		let mut page_selectors = self.page_selectors.iter();
+		if let Some(first) = page_selectors.next()
+		{
+			// Always written with a space, as in `@page :first`, and as the specification writes it.
+			dest.write_char(' ')?;
+			first.to_css(dest)?;
+			for page_selector in page_selectors
+			{
+				dest.write_char(',')?;
+				page_selector.to_css(dest)?;
+			}
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext<'i>, input: &mut Parser<'i, 't>, page_selectors: Vec<PageSelector>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut rule = Self
+		{
+			page_selectors,
+			property_declarations: PropertyDeclarations::default(),
+			margin_rules: Vec::new(),
+			source_range: None,
+		};
+		
+		{
+			let parser = PageAtRuleParser
+			{
+				context,
+				rule: &mut rule,
+			};
+			
+			let mut iter = DeclarationListParser::new(input, parser);
+			loop
+			{
+				let start = context.source_range_start(iter.input, |token| *token == Token::Semicolon);
+				let numberOfPropertyDeclarations = iter.parser.rule.property_declarations.0.len();
+				let declaration = match iter.next()
+				{
+					None => break,
+					Some(declaration) => declaration,
+				};
+				context.recover(declaration)?;
+				
+				if iter.parser.rule.property_declarations.0.len() > numberOfPropertyDeclarations
+				{
+					iter.parser.rule.property_declarations.0.last_mut().unwrap().source_range = ParserContext::source_range(start, iter.input);
+				}
+			}
+		}
+		
+		Ok(rule)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn named_pages_and_page_margin_rules_are_round_tripped()
+	{
+		let roundTrip = |css: &str| Stylesheet::parse(css).unwrap().to_css_string(false);
+		
+		assert_eq!(roundTrip("@page chapter:first{margin:1in;@top-left{content:\"Chapter\"}@bottom-right-corner{content:counter(page)}}"), "@page chapter:first{margin:1in;@top-left{content:\"Chapter\"}@bottom-right-corner{content:counter(page)}}");
+		assert_eq!(roundTrip("@page chapter,index:left{margin:1in}"), "@page chapter,index:left{margin:1in}");
+		assert_eq!(roundTrip("@page :first{margin:1in}"), "@page :first{margin:1in}");
+		assert_eq!(roundTrip("@page :left,:right{margin:1in}"), "@page :left,:right{margin:1in}");
+		assert_eq!(roundTrip("@page{margin:1in}"), "@page{margin:1in}");
+		assert!(Stylesheet::parse("@page auto{margin:1in}").is_err());
+	}
+	
+	#[test]
+	fn page_rules_are_cascaded_by_specificity_then_source_order()
+	{
+		let stylesheet = Stylesheet::parse("@page chapter{margin:3in;@top-left{content:\"Chapter\"}}@page :first{margin:2in !important}@page{margin:1in;@top-left{content:\"Book\"}}").unwrap();
+		
+		let declarations = |page_name: Option<&str>, page_index: usize|
+		{
+			let page = stylesheet.rules.page_rules_for(page_name, page_index, PageSide::right);
+			let property_declarations = page.property_declarations.iter().map(|property_declaration| property_declaration.to_css_string()).collect::<Vec<_>>();
+			let top_left = page.margin_property_declarations.get(&PageMarginBox::top_left).map(|property_declarations| property_declarations.iter().map(|property_declaration| property_declaration.to_css_string()).collect::<Vec<_>>()).unwrap_or_default();
+			(property_declarations, top_left)
+		};
+		
+		assert_eq!(declarations(None, 1), (vec!["margin:1in;".to_owned()], vec!["content:\"Book\";".to_owned()]));
+		assert_eq!(declarations(Some("chapter"), 1), (vec!["margin:1in;".to_owned(), "margin:3in;".to_owned()], vec!["content:\"Book\";".to_owned(), "content:\"Chapter\";".to_owned()]));
+		assert_eq!(declarations(Some("chapter"), 0), (vec!["margin:1in;".to_owned(), "margin:3in;".to_owned(), "margin:2in !important;".to_owned()], vec!["content:\"Book\";".to_owned(), "content:\"Chapter\";".to_owned()]));
+	}
+}
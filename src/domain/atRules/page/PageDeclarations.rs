@@ -0,0 +1,50 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The property declarations that apply to a page and its page-margin boxes, in cascade order (least significant first).
+///
+/// Created by `CssRules::page_rules_for()`.
+#[derive(Default, Debug, Clone)]
+pub struct PageDeclarations<'a>
+{
+	/// Declarations for the page box.
+	pub property_declarations: Vec<&'a PropertyDeclaration<Importance>>,
+	
+	/// Declarations for each page-margin box that has at least one.
+	pub margin_property_declarations: BTreeMap<PageMarginBox, Vec<&'a PropertyDeclaration<Importance>>>,
+}
+
+impl<'a> PageDeclarations<'a>
+{
+	/// Collects the declarations from `page_rules`, which should be in source order, for a page.
+	///
+	/// Rules are ordered by specificity and then by source order; within that order, normal declarations precede `!important` ones.
+	/// https://drafts.csswg.org/css-page-3/#cascading-and-page-context
+	pub fn resolve<I: Iterator<Item=&'a PageAtRule>>(page_rules: I, page_name: Option<&str>, page_index: usize, side: PageSide) -> Self
+	{
+		let mut matching_page_rules: Vec<((u32, u32, u32), &'a PageAtRule)> = page_rules.filter_map(|page_rule| page_rule.matching_specificity(page_name, page_index, side).map(|specificity| (specificity, page_rule))).collect();
+		
+		// A stable sort, so source order is preserved for equal specificities
+		matching_page_rules.sort_by_key(|&(specificity, _)| specificity);
+		
+		let mut this = Self::default();
+		
+		for &isImportant in [false, true].iter()
+		{
+			for &(_, page_rule) in matching_page_rules.iter()
+			{
+				this.property_declarations.extend(page_rule.property_declarations.0.iter().filter(|property_declaration| property_declaration.importance.isImportant() == isImportant));
+				
+				for margin_rule in page_rule.margin_rules.iter()
+				{
+					this.margin_property_declarations.entry(margin_rule.margin_box).or_insert_with(Vec::new).extend(margin_rule.property_declarations.0.iter().filter(|property_declaration| property_declaration.importance.isImportant() == isImportant));
+				}
+			}
+		}
+		
+		this.margin_property_declarations.retain(|_, property_declarations| !property_declarations.is_empty());
+		
+		this
+	}
+}
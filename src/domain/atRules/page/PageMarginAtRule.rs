@@ -0,0 +1,61 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A page-margin at-rule within a `@page` rule, eg `@top-left { content: "Chapter" }`.
+///
+/// https://drafts.csswg.org/css-page-3/#margin-at-rules
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PageMarginAtRule
+{
+	/// Which margin box.
+	pub margin_box: PageMarginBox,
+	
+	/// The declaration block this page-margin rule contains.
+	pub property_declarations: PropertyDeclarations<Importance>,
+}
+
+impl ToCss for PageMarginAtRule
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_char('@')?;
+		self.margin_box.to_css(dest)?;
+		dest.write_char('{')?;
+		self.property_declarations.to_css(dest)?;
+		dest.write_char('}')
+	}
+}
+
+impl HasPropertyDeclarations<Importance> for PageMarginAtRule
+{
+	#[inline(always)]
+	fn property_declarations(&self) -> &PropertyDeclarations<Importance>
+	{
+		&self.property_declarations
+	}
+	
+	#[inline(always)]
+	fn property_declarations_mut(&mut self) -> &mut PropertyDeclarations<Importance>
+	{
+		&mut self.property_declarations
+	}
+	
+	#[inline(always)]
+	fn property_declarations_slice(&self) -> &[PropertyDeclaration<Importance>]
+	{
+		&self.property_declarations.0[..]
+	}
+	
+	#[inline(always)]
+	fn property_declarations_vec(&self) -> &Vec<PropertyDeclaration<Importance>>
+	{
+		&self.property_declarations.0
+	}
+	
+	#[inline(always)]
+	fn property_declarations_vec_mut(&mut self) -> &mut Vec<PropertyDeclaration<Importance>>
+	{
+		&mut self.property_declarations.0
+	}
+}
@@ -0,0 +1,27 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+// One of the sixteen page-margin boxes, named as the at-rule without the `@`.
+//
+// https://drafts.csswg.org/css-page-3/#margin-at-rules
+define_css_keyword_enum!
+(
+	PageMarginBox:
+	"top-left-corner" => top_left_corner,
+	"top-left" => top_left,
+	"top-center" => top_center,
+	"top-right" => top_right,
+	"top-right-corner" => top_right_corner,
+	"bottom-left-corner" => bottom_left_corner,
+	"bottom-left" => bottom_left,
+	"bottom-center" => bottom_center,
+	"bottom-right" => bottom_right,
+	"bottom-right-corner" => bottom_right_corner,
+	"left-top" => left_top,
+	"left-middle" => left_middle,
+	"left-bottom" => left_bottom,
+	"right-top" => right_top,
+	"right-middle" => right_middle,
+	"right-bottom" => right_bottom
+);
@@ -0,0 +1,127 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A page selector, eg `chapter:first` or `:left`.
+///
+/// https://drafts.csswg.org/css-page-3/#page-selectors
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PageSelector
+{
+	/// The page type (named page), eg `chapter` in `@page chapter:first`.
+	pub page_name: Option<CustomIdent>,
+	
+	/// The page pseudo-classes, eg `:first` in `@page chapter:first`.
+	pub pseudo_classes: Vec<PageSelectorPseudoClass>,
+}
+
+impl ToCss for PageSelector
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if let Some(ref page_name) = self.page_name
+		{
+			page_name.to_css(dest)?;
+		}
+		
+		for pseudo_class in self.pseudo_classes.iter()
+		{
+			pseudo_class.to_css(dest)?;
+		}
+		
+		Ok(())
+	}
+}
+
+impl PageSelector
+{
+	/// https://drafts.csswg.org/css-page-3/#cascading-and-page-context
+	///
+	/// Specificity is (page name, count of `:first` and `:blank`, count of `:left`, `:right`, `:recto` and `:verso`).
+	#[inline(always)]
+	pub fn specificity(&self) -> (u32, u32, u32)
+	{
+		use self::PageSelectorPseudoClass::*;
+		
+		let mut specificity = (if self.page_name.is_some() { 1 } else { 0 }, 0, 0);
+		for pseudo_class in self.pseudo_classes.iter()
+		{
+			match *pseudo_class
+			{
+				first | blank => specificity.1 += 1,
+				left | right | recto | verso => specificity.2 += 1,
+			}
+		}
+		specificity
+	}
+	
+	/// Does this page selector match a page?
+	///
+	/// `page_index` is zero-based, so the first page has an index of zero.
+	/// `:blank` never matches as blank pages are created by the layout engine.
+	/// Pages are assumed to progress left-to-right, so `:recto` is the same as `:right` and `:verso` is the same as `:left`.
+	pub fn matches(&self, page_name: Option<&str>, page_index: usize, side: PageSide) -> bool
+	{
+		use self::PageSelectorPseudoClass::*;
+		
+		if let Some(ref our_page_name) = self.page_name
+		{
+			if page_name != Some(&(our_page_name.0)[..])
+			{
+				return false;
+			}
+		}
+		
+		self.pseudo_classes.iter().all(|pseudo_class|
+		{
+			match *pseudo_class
+			{
+				blank => false,
+				first => page_index == 0,
+				left | verso => side == PageSide::left,
+				right | recto => side == PageSide::right,
+			}
+		})
+	}
+	
+	/// Parses a comma-separated list of page selectors, which may be empty.
+	pub(crate) fn parse_page_selector_list<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Vec<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.is_exhausted()
+		{
+			return Ok(vec![]);
+		}
+		
+		input.parse_comma_separated(|input| Self::parse(input))
+	}
+	
+	fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let page_name = match input.r#try(|input| input.expect_ident_cloned())
+		{
+			Ok(ident) => Some(CustomIdent::from_ident(&ident, &["auto"]).map_err(|error| input.new_custom_error(error))?),
+			Err(_) => None,
+		};
+		
+		let mut pseudo_classes = Vec::new();
+		while let Some(pseudo_class) = PageSelectorPseudoClass::parse(input)?
+		{
+			pseudo_classes.push(pseudo_class);
+		}
+		
+		if page_name.is_none() && pseudo_classes.is_empty()
+		{
+			let token = input.next()?.clone();
+			return Err(input.new_unexpected_token_error(token));
+		}
+		
+		Ok
+		(
+			Self
+			{
+				page_name,
+				pseudo_classes,
+			}
+		)
+	}
+}
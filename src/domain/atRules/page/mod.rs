@@ -3,9 +3,16 @@
 
 
 use super::*;
+use super::parsers::PageAtRuleParser;
 use ::either::Either;
 use ::either::Either::*;
+use ::std::collections::BTreeMap;
 
 
 include!("PageAtRule.rs");
+include!("PageDeclarations.rs");
+include!("PageMarginAtRule.rs");
+include!("PageMarginBox.rs");
+include!("PageSelector.rs");
 include!("PageSelectorPseudoClass.rs");
+include!("PageSide.rs");
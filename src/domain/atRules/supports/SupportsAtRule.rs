@@ -13,6 +13,9 @@ pub struct SupportsAtRule
 	
 	/// Child rules
 	pub rules: CssRules,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl HasCssRules for SupportsAtRule
@@ -59,3 +62,13 @@ impl ToCss for SupportsAtRule
 		dest.write_char('}')
 	}
 }
+
+impl SupportsAtRule
+{
+	/// Evaluate a supports condition.
+	#[inline(always)]
+	pub fn evaluate<E: SupportsEnvironment>(&self, environment: &E) -> bool
+	{
+		self.condition.evaluate(environment)
+	}
+}
@@ -22,6 +22,21 @@ pub enum SupportsCondition
 	/// `property-ident: value` (value can be any tokens)
 	Declaration(SupportsPropertyDeclaration),
 	
+	/// `selector(complex-selector)`
+	///
+	/// https://drafts.csswg.org/css-conditional-4/#at-supports-ext
+	SelectorFunction(OurSelector),
+	
+	/// `font-tech(font-tech)`
+	///
+	/// https://drafts.csswg.org/css-conditional-5/#at-supports-ext
+	FontTechnologyFunction(FontTechnology),
+	
+	/// `font-format(font-format)`
+	///
+	/// https://drafts.csswg.org/css-conditional-5/#at-supports-ext
+	FontFormatFunction(FontFormat),
+	
 	/// `(any tokens)` or `func(any tokens)`
 	FutureSyntax(String),
 }
@@ -88,6 +103,27 @@ impl ToCss for SupportsCondition
 				dest.write_str(")")
 			}
 			
+			SelectorFunction(ref selector) =>
+			{
+				dest.write_str("selector(")?;
+				selector.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
+			FontTechnologyFunction(fontTechnology) =>
+			{
+				dest.write_str("font-tech(")?;
+				fontTechnology.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
+			FontFormatFunction(fontFormat) =>
+			{
+				dest.write_str("font-format(")?;
+				fontFormat.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
 			FutureSyntax(ref value) => dest.write_str(&value),
 		}
 	}
@@ -95,18 +131,53 @@ impl ToCss for SupportsCondition
 
 impl SupportsCondition
 {
+	/// Evaluate a supports condition.
+	///
+	/// `FutureSyntax` is never supported; this includes `selector()` functions with selectors this crate can not parse.
+	/// https://drafts.csswg.org/css-conditional-3/#support-definition
+	pub fn evaluate<E: SupportsEnvironment>(&self, environment: &E) -> bool
+	{
+		match *self
+		{
+			Not(ref condition) => !condition.evaluate(environment),
+			
+			Parenthesized(ref condition) => condition.evaluate(environment),
+			
+			And(ref conditions) => conditions.iter().all(|condition| condition.evaluate(environment)),
+			
+			Or(ref conditions) => conditions.iter().any(|condition| condition.evaluate(environment)),
+			
+			Declaration(ref declaration) => declaration.evaluate(environment),
+			
+			SelectorFunction(ref selector) => environment.selectorIsSupported(selector),
+			
+			FontTechnologyFunction(fontTechnology) => environment.fontTechnologyIsSupported(fontTechnology),
+			
+			FontFormatFunction(fontFormat) => environment.fontFormatIsSupported(fontFormat),
+			
+			FutureSyntax(_) => false,
+		}
+	}
+	
+	/// Parses a supports condition, such as `(display: grid) and (not (display: inline-grid))`.
+	#[inline(always)]
+	pub fn parse(css: &str) -> Result<Self, CssError>
+	{
+		CssError::parse_entirely(css, |input| Self::parse_condition(&Namespaces::empty(), input))
+	}
+	
 	/// Parse a condition
 	///
 	/// https://drafts.csswg.org/css-conditional/#supports_condition
-	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_condition<'i, 't>(namespaces: &Rc<Namespaces>, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
 		if let Ok(_) = input.r#try(|i| i.expect_ident_matching("not"))
 		{
-			let inner = Self::parse_in_parentheses(input)?;
+			let inner = Self::parse_in_parentheses(namespaces, input)?;
 			return Ok(Not(Box::new(inner)));
 		}
 		
-		let in_parentheses = Self::parse_in_parentheses(input)?;
+		let in_parentheses = Self::parse_in_parentheses(namespaces, input)?;
 		
 		let (keyword, wrapper) = match input.next()
 		{
@@ -137,7 +208,7 @@ impl SupportsCondition
 		conditions.push(in_parentheses);
 		loop
 		{
-			conditions.push(Self::parse_in_parentheses(input)?);
+			conditions.push(Self::parse_in_parentheses(namespaces, input)?);
 			if input.r#try(|input| input.expect_ident_matching(keyword)).is_err()
 			{
 				// Did not find the expected keyword.
@@ -149,7 +220,7 @@ impl SupportsCondition
 	}
 	
 	/// https://drafts.csswg.org/css-conditional-3/#supports_condition_in_parentheses
-	fn parse_in_parentheses<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	fn parse_in_parentheses<'i, 't>(namespaces: &Rc<Namespaces>, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
 		// Whitespace is normally taken care of in `Parser::next` but we want to not include it in `pos` for the SupportsCondition::FutureSyntax cases.
 		while input.r#try(Parser::expect_whitespace).is_ok()
@@ -164,7 +235,7 @@ impl SupportsCondition
 			{
 				let nested = input.r#try(|input|
 				{
-					input.parse_nested_block(|i| Self::parse_condition_or_declaration(i))
+					input.parse_nested_block(|i| Self::parse_condition_or_declaration(namespaces, i))
 				});
 				
 				if nested.is_ok()
@@ -173,8 +244,32 @@ impl SupportsCondition
 				}
 			}
 			
-			Function(_) =>
+			Function(ref name) =>
 			{
+				// Anything that fails to parse, such as a selector using an unsupported pseudo-class, is not supported and so is kept as FutureSyntax.
+				let function = input.r#try(|input|
+				{
+					input.parse_nested_block(|input|
+					{
+						match_ignore_ascii_case!
+						{
+							&name,
+							
+							"selector" => Self::parse_selector_function(namespaces, input),
+							
+							"font-tech" => Ok(FontTechnologyFunction(FontTechnology::parse(input)?)),
+							
+							"font-format" => Ok(FontFormatFunction(FontFormat::parse(input)?)),
+							
+							_ => Err(input.new_custom_error(CustomParseError::InvalidSupportsCondition(name.clone()))),
+						}
+					})
+				});
+				
+				if function.is_ok()
+				{
+					return function;
+				}
 			}
 			
 			unexpectedToken => return Err(input.new_unexpected_token_error(unexpectedToken)),
@@ -185,9 +280,9 @@ impl SupportsCondition
 	
 	/// supports_condition | declaration
 	/// https://drafts.csswg.org/css-conditional/#dom-css-supports-conditiontext-conditiontext
-	fn parse_condition_or_declaration<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_condition_or_declaration<'i, 't>(namespaces: &Rc<Namespaces>, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
-		if let Ok(condition) = input.r#try(Self::parse)
+		if let Ok(condition) = input.r#try(|input| Self::parse_condition(namespaces, input))
 		{
 			Ok(Parenthesized(Box::new(condition)))
 		}
@@ -196,4 +291,90 @@ impl SupportsCondition
 			SupportsPropertyDeclaration::parse(input).map(Declaration)
 		}
 	}
+	
+	/// https://drafts.csswg.org/css-conditional-4/#typedef-supports-selector-fn
+	fn parse_selector_function<'i, 't>(namespaces: &Rc<Namespaces>, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut selectors = OurSelectorParser::with_stylesheet_selector_parser(namespaces, |ourSelectorParser| ourSelectorParser.parse(input))?;
+		
+		// A selector list is not a complex selector
+		if selectors.0.len() != 1
+		{
+			return Err(input.new_custom_error(CustomParseError::InvalidSupportsCondition("selector".into())));
+		}
+		
+		Ok(SelectorFunction(selectors.0.pop().unwrap()))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	struct GridButNotSubgrid;
+	
+	impl SupportsEnvironment for GridButNotSubgrid
+	{
+		fn propertyDeclarationIsSupported(&self, propertyName: &str, value: &str) -> bool
+		{
+			propertyName == "display" && value == "grid"
+		}
+		
+		fn selectorIsSupported(&self, selector: &OurSelector) -> bool
+		{
+			!selector.to_css_string().contains(":has(")
+		}
+		
+		fn fontTechnologyIsSupported(&self, fontTechnology: FontTechnology) -> bool
+		{
+			fontTechnology == FontTechnology::variations
+		}
+		
+		fn fontFormatIsSupported(&self, fontFormat: FontFormat) -> bool
+		{
+			fontFormat == FontFormat::woff2
+		}
+	}
+	
+	fn evaluate(css: &str) -> bool
+	{
+		SupportsCondition::parse(css).unwrap().evaluate(&GridButNotSubgrid)
+	}
+	
+	#[test]
+	fn conditions_are_evaluated_against_the_environment()
+	{
+		assert!(evaluate("(display: grid)"));
+		assert!(evaluate("( display :  grid )"));
+		assert!(!evaluate("(display: subgrid)"));
+		assert!(evaluate("not (display: subgrid)"));
+		assert!(evaluate("(display: subgrid) or (display: grid)"));
+		assert!(!evaluate("(display: grid) and (display: subgrid)"));
+		assert!(evaluate("((display: grid) and (not (display: subgrid)))"));
+		assert!(!evaluate("(unknown syntax)"));
+		assert!(evaluate("not unknown(syntax)"));
+	}	
+	#[test]
+	fn selector_font_tech_and_font_format_functions_are_round_tripped()
+	{
+		let roundTrip = |css: &str| SupportsCondition::parse(css).unwrap().to_css_string();
+		
+		assert_eq!(roundTrip("selector(a>b)"), "selector(a > b)");
+		assert_eq!(roundTrip("font-tech(color-COLRv1)"), "font-tech(color-colrv1)");
+		assert_eq!(roundTrip("font-format(woff2) and selector(:is(a, b))"), "font-format(woff2) and selector(:is(a, b))");
+		assert_eq!(roundTrip("selector(a, b)"), "selector(a, b)");
+		
+		assert_eq!(Stylesheet::parse("@supports selector(a>b){a{color:red}}").unwrap().to_css_string(false), "@supports selector(a > b){a{color:red}}");
+	}
+	
+	#[test]
+	fn selector_font_tech_and_font_format_functions_are_evaluated()
+	{
+		assert!(evaluate("selector(a > b)"));
+		assert!(!evaluate("selector(a:has(b))"));
+		assert!(!evaluate("selector(a, b)"));
+		assert!(evaluate("font-tech(variations) and font-format(woff2)"));
+		assert!(!evaluate("font-tech(palettes) or font-format(woff)"));
+	}
 }
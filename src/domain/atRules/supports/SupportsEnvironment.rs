@@ -0,0 +1,25 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A trait that is used when evaluating CSS rules that are decided using the features supported by a user agent.
+/// Implementations might use a browser-support table or try to parse the property declaration's value.
+pub trait SupportsEnvironment
+{
+	/// Used when evaluating @supports rules
+	/// Does this environment support this property (eg `display`) with this value (eg `grid`)?
+	/// The property name is as written in the stylesheet, including any vendor prefix; the value has leading and trailing whitespace removed and may contain `!important`.
+	fn propertyDeclarationIsSupported(&self, propertyName: &str, value: &str) -> bool;
+	
+	/// Used when evaluating @supports rules
+	/// Does this environment support this selector? Only selectors this crate can parse are ever asked about.
+	fn selectorIsSupported(&self, selector: &OurSelector) -> bool;
+	
+	/// Used when evaluating @supports rules
+	/// Does this environment support this font technology?
+	fn fontTechnologyIsSupported(&self, fontTechnology: FontTechnology) -> bool;
+	
+	/// Used when evaluating @supports rules
+	/// Does this environment support this font format?
+	fn fontFormatIsSupported(&self, fontFormat: FontFormat) -> bool;
+}
@@ -25,4 +25,37 @@ impl SupportsPropertyDeclaration
 		consume_any_value(input)?;
 		Ok(SupportsPropertyDeclaration(input.slice_from(pos).to_owned()))
 	}
+	
+	/// The property name, eg `display` in `display: grid`.
+	#[inline(always)]
+	pub fn property_name(&self) -> &str
+	{
+		self.split().0
+	}
+	
+	/// The value, eg `grid` in `display: grid`.
+	#[inline(always)]
+	pub fn value(&self) -> &str
+	{
+		self.split().1
+	}
+	
+	/// Evaluate this declaration.
+	#[inline(always)]
+	pub fn evaluate<E: SupportsEnvironment>(&self, environment: &E) -> bool
+	{
+		let (propertyName, value) = self.split();
+		environment.propertyDeclarationIsSupported(propertyName, value)
+	}
+	
+	// Parsing guarantees an identifier followed by a colon; an escaped colon in the identifier is not a concern as it can not be a valid property name.
+	#[inline(always)]
+	fn split(&self) -> (&str, &str)
+	{
+		match self.0.find(':')
+		{
+			Some(index) => (self.0[..index].trim(), self.0[index + 1..].trim()),
+			None => (self.0.trim(), ""),
+		}
+	}
 }
@@ -3,11 +3,14 @@
 
 
 use super::*;
+use super::parsers::OurSelectorParser;
 use self::SupportsCondition::*;
 use ::cssparser::Token::*;
+use ::std::rc::Rc;
 
 
 include!("consume_any_value.rs");
 include!("SupportsAtRule.rs");
 include!("SupportsCondition.rs");
+include!("SupportsEnvironment.rs");
 include!("SupportsPropertyDeclaration.rs");
@@ -9,13 +9,16 @@ pub struct ViewportAtRule
 	pub vendor_prefix: Option<VendorPrefix>,
 	
 	/// The declarations contained in this @viewport rule.
-	pub declarations: Vec<ViewportDescriptorDeclaration>
+	pub declarations: Vec<ViewportDescriptorDeclaration>,
+	
+	/// Where this rule came from, if recorded when parsing.
+	pub source_range: Option<SourceRange>,
 }
 
 impl ViewportAtRule
 {
 	/// Parse a single @viewport rule.
-	pub(crate) fn parse_body<'i, 't>(vendor_prefix: Option<VendorPrefix>, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i, 't>(vendor_prefix: Option<VendorPrefix>, context: &ParserContext<'i>, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
 		let parser = ViewportAtRuleParser { context };
 		
@@ -23,9 +26,12 @@ impl ViewportAtRule
 		let mut parser = DeclarationListParser::new(input, parser);
 		while let Some(result) = parser.next()
 		{
-			declarations.push(result.map_err(|(error, _)| error)?);
+			if let Some(declaration) = context.recover(result)?
+			{
+				declarations.push(declaration);
+			}
 		}
-		Ok(Self { vendor_prefix, declarations })
+		Ok(Self { vendor_prefix, declarations, source_range: None })
 	}
 }
 
@@ -66,6 +66,7 @@ macro_rules! __define_css_keyword_enum__actual
 		impl $name
 		{
 			/// Parse this property from a CSS input stream.
+			#[allow(dead_code)]
 			pub(crate) fn parse<'i, 't>(input: &mut ::cssparser::Parser<'i, 't>) -> Result<$name, ::cssparser::ParseError<'i, $crate::CustomParseError<'i>>>
 			{
 				let ident = input.expect_ident()?;
@@ -86,16 +87,23 @@ macro_rules! __define_css_keyword_enum__actual
 					_ => Err(ident.clone())
 				}
 			}
+			
+			/// The CSS keyword.
+			#[allow(dead_code)]
+			pub fn to_str(&self) -> &'static str
+			{
+				match *self
+				{
+					$( $name::$variant => $css ),+
+				}
+			}
 		}
 
 		impl ToCss for $name
 		{
 			fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 			{
-				match *self
-				{
-					$( $name::$variant => dest.write_str($css) ),+
-				}
+				dest.write_str(self.to_str())
 			}
 		}
 	}
@@ -43,7 +43,7 @@ impl<U: Unit> Expression<U> for AttrExpression
 	#[inline(always)]
 	fn evaluate<Conversion: FontRelativeLengthConversion<U::Number> + ViewportPercentageLengthConversion<U::Number> + PercentageConversion<U::Number> + AttributeConversion<U> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<U::Number>
 	{
-		self.to_unit(conversion).map(|unit| unit.to_CssNumber())
+		self.to_unit(conversion).map(|unit| unit.to_canonical_dimension_value(conversion))
 	}
 }
 
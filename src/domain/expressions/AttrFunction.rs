@@ -3,7 +3,7 @@
 
 
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct AttrFunction(pub Rc<AttrExpression>);
+pub struct AttrFunction(pub Arc<AttrExpression>);
 
 impl ToCss for AttrFunction
 {
@@ -86,7 +86,7 @@ impl<U: Unit> Expression<U> for CalcExpression<U>
 	/// Evaluate the calc() expression, returning the numeric value of the canonical dimension
 	/// Division by zero is handled by returning the maximum possible f32 value
 	/// Subtractions for UnsignedCssNumber that are negative are handled by returning 0.0
-	/// Note: We are quite lenient with calculations of unit-less and unit-having quantities, eg 100px * 100px is evaluated to 10,000px, not 10,000px^2, and 50 + 100px is evaluated to 150px
+	/// Note: We are quite lenient with calculations of unit-less and unit-having quantities, eg 100px * 100px is evaluated to 10,000px, not 10,000px^2, and 50 + 100px is evaluated to 150px; such expressions are rejected when parsing, but can still be constructed
 	#[inline(always)]
 	fn evaluate<Conversion: FontRelativeLengthConversion<U::Number> + ViewportPercentageLengthConversion<U::Number> + PercentageConversion<U::Number> + AttributeConversion<U> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<U::Number>
 	{
@@ -129,24 +129,161 @@ impl<U: Unit> Expression<U> for CalcExpression<U>
 
 impl<U: Unit> CalcExpression<U>
 {
+	/// Simplifies this expression, following https://drafts.csswg.org/css-values-4/#calc-simplification: like units are summed, absolute lengths (and angles, times and resolutions) are converted to their canonical units, and multiplication and division by numbers are distributed.
+	/// The result is a sum in canonical order, eg `calc(2 * (1em + 1em) - 1in)` becomes `8em - 96px`; if nothing but one value is left, such as for `calc(10px + 5px)`, it is just that value.
+	/// Terms that can not be simplified, such as `var()`, are kept, but an expression that can not be written once simplified (eg because a negative number is not allowed) is returned unchanged.
+	#[inline(always)]
+	pub fn simplify(&self) -> Self
+	{
+		self.to_sum().to_expression().unwrap_or_else(|| self.clone())
+	}
+	
+	fn to_sum(&self) -> CalcSum<U>
+	{
+		use self::CalcExpression::*;
+		
+		match *self
+		{
+			CalculablePropertyValue(ref value) => CalcSum::from_value(value),
+			
+			Number(number) => CalcSum::of(CalcTerm::Number(number.to_f32())),
+			
+			Parentheses(ref subExpression) => subExpression.to_sum(),
+			
+			Addition(ref lhs, ref rhs) =>
+			{
+				let mut sum = lhs.to_sum();
+				sum.add(rhs.to_sum());
+				sum
+			}
+			
+			Subtraction(ref lhs, ref rhs) =>
+			{
+				let mut sum = lhs.to_sum();
+				let mut subtrahend = rhs.to_sum();
+				subtrahend.scale(-1.0);
+				sum.add(subtrahend);
+				sum
+			}
+			
+			Multiplication(ref lhs, ref rhs) =>
+			{
+				let (mut lhs, mut rhs) = (lhs.to_sum(), rhs.to_sum());
+				if let Some(factor) = lhs.number()
+				{
+					rhs.scale(factor);
+					rhs
+				}
+				else if let Some(factor) = rhs.number()
+				{
+					lhs.scale(factor);
+					lhs
+				}
+				else
+				{
+					Self::unsimplified(Multiplication, &lhs, &rhs, self)
+				}
+			}
+			
+			Division(ref lhs, ref rhs) =>
+			{
+				let (mut lhs, rhs) = (lhs.to_sum(), rhs.to_sum());
+				match rhs.number()
+				{
+					Some(divisor) if divisor != 0.0 =>
+					{
+						lhs.scale(1.0 / divisor);
+						lhs
+					}
+					
+					_ => Self::unsimplified(Division, &lhs, &rhs, self),
+				}
+			}
+		}
+	}
+	
+	/// A product or quotient of simplified sums that can not be distributed.
+	#[inline(always)]
+	fn unsimplified(operator: fn(Box<Self>, Box<Self>) -> Self, lhs: &CalcSum<U>, rhs: &CalcSum<U>, original: &Self) -> CalcSum<U>
+	{
+		let expression = match (lhs.to_operand(), rhs.to_operand())
+		{
+			(Some(lhs), Some(rhs)) => operator(Box::new(lhs), Box::new(rhs)),
+			_ => original.clone(),
+		};
+		CalcSum::of(CalcTerm::Other(1.0, expression))
+	}
+	
 	/// Parse a top-level `calc` expression, with all nested sub-expressions.
-	/// DOES NOT simplify expressions. This is because simplification is harder than it ought to be:-
-	/// * Percentages can be treated as multiples of 'x', eg 50% => 0.5x, BUT
-	/// * Zero percentages have to be preserved, so detecting 'divide by zero' at parse time isn't easy
-	/// * Calc expressions additionally have other unknown quantities when dealing with units:-
-	///   * 4 kinds of font relative units
-	///   * 4 kinds of viewport proportion units
-	///   * Any number of `var()` and `attr()` sub-expressions (although the latter SHOULD have a known type)
-	/// * It would be possible to simplify some terms, even then, but the coding cost of doing so (with 9 unknown quantities) seems a bit painful
-	///    * Absolute units could be converted to their canonical unit (eg pixels for AbsoluteLength)
-	///    * Single-term entries in parentheses and expressions could be eliminated
-	///    * nested expressions() could be converted to just ()
+	/// The expression is kept as written; see `simplify()`.
 	///
 	/// This is in charge of parsing, for example, `2 + 3 * 100%`.
 	#[inline(always)]
 	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
-		input.parse_nested_block(|input| Self::parse_sum(context, input))
+		input.parse_nested_block(|input|
+		{
+			let expression = Self::parse_sum(context, input)?;
+			expression.is_number().map_err(|error| input.new_custom_error(error))?;
+			Ok(expression)
+		})
+	}
+	
+	/// As `parse()`, but for a `calc()` that is not inside another, whose result must be valid for `U`; eg `calc(2 * 3)` is not a length.
+	#[inline(always)]
+	pub(crate) fn parse_outermost<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let expression = Self::parse(context, input)?;
+		match expression.is_number()
+		{
+			Ok(Some(isNumber)) if !U::is_valid_calc_function_result(isNumber) => Err(input.new_custom_error(if isNumber { CustomParseError::CalcFunctionResultCanNotBeANumber } else { CustomParseError::CalcFunctionResultMustBeANumber })),
+			_ => Ok(expression),
+		}
+	}
+	
+	/// Checks that only like types are added and subtracted, and that at least one side of a multiplication, and the divisor of a division, is a number (so `calc(10px + 2)` and `calc(2px * 3px)` are not valid).
+	/// Returns whether this is a number, or `None` if that depends on a `var()` or `attr()`.
+	pub(crate) fn is_number<'i>(&self) -> Result<Option<bool>, CustomParseError<'i>>
+	{
+		use self::CalcExpression::*;
+		use self::CustomParseError::CalcFunctionCanNotCombineANumberAndADimension;
+		
+		match *self
+		{
+			CalculablePropertyValue(ref value) => match *value
+			{
+				self::CalculablePropertyValue::Constant(ref constant) => Ok(Some(constant.is_number())),
+				self::CalculablePropertyValue::Percentage(_) => Ok(Some(false)),
+				self::CalculablePropertyValue::Calc(ref function) => function.0.is_number(),
+				self::CalculablePropertyValue::Attr(_) | self::CalculablePropertyValue::Var(_) => Ok(None),
+			},
+			
+			Number(_) => Ok(Some(true)),
+			
+			Parentheses(ref subExpression) => subExpression.is_number(),
+			
+			Addition(ref lhs, ref rhs) | Subtraction(ref lhs, ref rhs) => match (lhs.is_number()?, rhs.is_number()?)
+			{
+				(Some(lhs), Some(rhs)) if lhs != rhs => Err(CalcFunctionCanNotCombineANumberAndADimension),
+				(None, rhs) => Ok(rhs),
+				(lhs, _) => Ok(lhs),
+			},
+			
+			Multiplication(ref lhs, ref rhs) => match (lhs.is_number()?, rhs.is_number()?)
+			{
+				(Some(false), Some(false)) => Err(CalcFunctionCanNotCombineANumberAndADimension),
+				(Some(true), other) | (other, Some(true)) => Ok(other),
+				// The `var()` or `attr()` must be a number if the other side is not.
+				(Some(false), None) | (None, Some(false)) => Ok(Some(false)),
+				(None, None) => Ok(None),
+			},
+			
+			Division(ref lhs, ref rhs) => match rhs.is_number()?
+			{
+				Some(false) => Err(CalcFunctionCanNotCombineANumberAndADimension),
+				_ => lhs.is_number(),
+			},
+		}
 	}
 	
 	/// Parse a `calc` expression, and all the sum that may follow, and stop as soon as a non-sum expression is found.
@@ -162,9 +299,9 @@ impl<U: Unit> CalcExpression<U>
 		loop
 		{
 			let stateToResetParseToIfNotSum = input.state();
-			match *input.next_including_whitespace()?
+			match input.next_including_whitespace()
 			{
-				WhiteSpace(_) =>
+				Ok(&WhiteSpace(_)) =>
 				{
 					// a trailing whitespace
 					if input.is_exhausted()
@@ -223,14 +360,14 @@ impl<U: Unit> CalcExpression<U>
 		loop
 		{
 			let stateToResetParseToIfNotProduct = input.state();
-			match *input.next()?
+			match input.next()
 			{
-				Delim('*') =>
+				Ok(&Delim('*')) =>
 				{
 					currentProduct = Multiplication(Box::new(currentProduct), Box::new(Self::parse_one(context, input)?));
 				}
 				
-				Delim('/') =>
+				Ok(&Delim('/')) =>
 				{
 					currentProduct = Division(Box::new(currentProduct), Box::new(Self::parse_one(context, input)?));
 				}
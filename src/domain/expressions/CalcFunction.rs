@@ -3,14 +3,14 @@
 
 
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct CalcFunction<U: Unit>(pub Rc<CalcExpression<U>>);
+pub struct CalcFunction<U: Unit>(pub Arc<CalcExpression<U>>);
 
 impl<U: Unit> Default for CalcFunction<U>
 {
 	#[inline(always)]
 	fn default() -> Self
 	{
-		CalcFunction(Rc::new(CalcExpression::default()))
+		CalcFunction(Arc::new(CalcExpression::default()))
 	}
 }
 
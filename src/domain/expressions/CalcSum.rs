@@ -0,0 +1,131 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A sum of terms, to which `CalcExpression::simplify()` reduces an expression: https://drafts.csswg.org/css-values-4/#calc-simplification
+#[derive(Debug, Clone)]
+struct CalcSum<U: Unit>
+{
+	/// Like terms are always combined, so there is at most one term of each unit.
+	terms: Vec<CalcTerm<U>>,
+}
+
+impl<U: Unit> CalcSum<U>
+{
+	#[inline(always)]
+	fn of(term: CalcTerm<U>) -> Self
+	{
+		Self
+		{
+			terms: vec![term],
+		}
+	}
+	
+	fn from_value(value: &CalculablePropertyValue<U>) -> Self
+	{
+		use self::CalculablePropertyValue::*;
+		
+		match *value
+		{
+			Constant(constant) =>
+			{
+				let canonical = constant.to_canonical_dimension();
+				let coefficient = canonical.to_CssNumber();
+				Self::of(CalcTerm::Dimension(coefficient.to_f32(), canonical.with_number(U::Number::One)))
+			}
+			
+			Percentage(percentage) => Self::of(CalcTerm::Percentage(percentage.to_f32())),
+			
+			Calc(ref function) => function.0.to_sum(),
+			
+			Attr(_) | Var(_) => Self::of(CalcTerm::Other(1.0, CalcExpression::CalculablePropertyValue(value.clone()))),
+		}
+	}
+	
+	/// The value of this sum, if it is just a number.
+	#[inline(always)]
+	fn number(&self) -> Option<f32>
+	{
+		match self.terms[..]
+		{
+			[CalcTerm::Number(number)] => Some(number),
+			[CalcTerm::Dimension(number, one)] if one.is_number() => Some(number),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn add(&mut self, other: Self)
+	{
+		for term in other.terms
+		{
+			match self.terms.iter().position(|existing| existing.isLike(&term))
+			{
+				None => self.terms.push(term),
+				Some(index) => self.terms[index] = self.terms[index].combine(&term),
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn scale(&mut self, factor: f32)
+	{
+		for term in self.terms.iter_mut()
+		{
+			term.scale(factor)
+		}
+	}
+	
+	/// Writes this sum as an expression in the canonical order: numbers, then percentages, then dimensions (ordered by unit), then anything else.
+	/// Terms of zero are dropped, except for percentages, as `calc(10px + 0%)` does not always behave as `10px`, and dimensions next to a percentage, as `calc(50% + 0px)` does not always behave as `50%`.
+	/// Returns `None` if a term can not be represented, such as a negative number when only positive numbers are valid.
+	fn to_expression(&self) -> Option<CalcExpression<U>>
+	{
+		use self::CalcExpression::*;
+		
+		let isNextToAPercentage = self.terms.iter().any(|term| term.isPercentage());
+		let mut terms = self.terms.iter().filter(|term| !term.isDroppableZero(isNextToAPercentage)).collect::<Vec<_>>();
+		if terms.is_empty()
+		{
+			terms.push(&self.terms[0]);
+		}
+		terms.sort_by_key(|term| term.order());
+		
+		// A negative term is written as a subtraction, which it can not be if it comes first.
+		if terms[0].coefficient() < 0.0 && U::Number::new(terms[0].coefficient()).is_err()
+		{
+			let firstPositive = terms.iter().position(|term| term.coefficient() >= 0.0)?;
+			let term = terms.remove(firstPositive);
+			terms.insert(0, term);
+		}
+		
+		let mut terms = terms.drain(..);
+		let first = terms.next().unwrap();
+		let mut expression = first.to_expression(first.coefficient())?;
+		for term in terms
+		{
+			let coefficient = term.coefficient();
+			expression = if coefficient < 0.0
+			{
+				Subtraction(Box::new(expression), Box::new(term.to_expression(-coefficient)?))
+			}
+			else
+			{
+				Addition(Box::new(expression), Box::new(term.to_expression(coefficient)?))
+			};
+		}
+		Some(expression)
+	}
+	
+	/// As `to_expression()`, but in parentheses if a sum, so that it can be multiplied or divided.
+	#[inline(always)]
+	fn to_operand(&self) -> Option<CalcExpression<U>>
+	{
+		let expression = self.to_expression()?;
+		match expression
+		{
+			CalcExpression::Addition(..) | CalcExpression::Subtraction(..) => Some(CalcExpression::Parentheses(Box::new(expression))),
+			_ => Some(expression),
+		}
+	}
+}
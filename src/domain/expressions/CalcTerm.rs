@@ -0,0 +1,142 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A term of a `CalcSum`; each has a coefficient, which it is a multiple of.
+#[derive(Debug, Clone)]
+enum CalcTerm<U: Unit>
+{
+	/// A number, such as `2` in `calc(2 * 1px)`.
+	Number(f32),
+	
+	/// A multiple of one of a unit, which is in its canonical unit (so `1in` is `96` times `1px`); for units that are numbers, such as `CssSignedNumber`, a multiple of one.
+	Dimension(f32, U),
+	
+	/// A fraction, such as `0.5` for `50%`.
+	Percentage(f32),
+	
+	/// A multiple of something that can not be simplified, such as `var(--width)` or `1em / 1em`.
+	Other(f32, CalcExpression<U>),
+}
+
+impl<U: Unit> CalcTerm<U>
+{
+	#[inline(always)]
+	fn coefficient(&self) -> f32
+	{
+		use self::CalcTerm::*;
+		
+		match *self
+		{
+			Number(coefficient) | Dimension(coefficient, _) | Percentage(coefficient) | Other(coefficient, _) => coefficient,
+		}
+	}
+	
+	#[inline(always)]
+	fn scale(&mut self, factor: f32)
+	{
+		use self::CalcTerm::*;
+		
+		match *self
+		{
+			Number(ref mut coefficient) | Dimension(ref mut coefficient, _) | Percentage(ref mut coefficient) | Other(ref mut coefficient, _) => *coefficient *= factor,
+		}
+	}
+	
+	/// Whether this and `other` can be combined into one term.
+	#[inline(always)]
+	fn isLike(&self, other: &Self) -> bool
+	{
+		use self::CalcTerm::*;
+		
+		match (self, other)
+		{
+			(Number(_), Number(_)) | (Percentage(_), Percentage(_)) => true,
+			(Number(_), Dimension(_, one)) | (Dimension(_, one), Number(_)) => one.is_number(),
+			(Dimension(_, one), Dimension(_, otherOne)) => one == otherOne,
+			(Other(_, expression), Other(_, otherExpression)) => expression == otherExpression,
+			_ => false,
+		}
+	}
+	
+	/// Combines this with a like term.
+	#[inline(always)]
+	fn combine(&self, other: &Self) -> Self
+	{
+		let mut combined = match *other
+		{
+			CalcTerm::Dimension(..) => other.clone(),
+			_ => self.clone(),
+		};
+		match combined
+		{
+			CalcTerm::Number(ref mut coefficient) | CalcTerm::Dimension(ref mut coefficient, _) | CalcTerm::Percentage(ref mut coefficient) | CalcTerm::Other(ref mut coefficient, _) => *coefficient = self.coefficient() + other.coefficient(),
+		}
+		combined
+	}
+	
+	#[inline(always)]
+	fn isPercentage(&self) -> bool
+	{
+		match *self
+		{
+			CalcTerm::Percentage(_) => true,
+			CalcTerm::Dimension(_, one) => one.is_percentage(),
+			_ => false,
+		}
+	}
+	
+	/// A zero dimension next to a percentage is kept, as `calc(50% + 0px)` does not always behave as `50%` (eg for a `flex-basis`).
+	#[inline(always)]
+	fn isDroppableZero(&self, isNextToAPercentage: bool) -> bool
+	{
+		match *self
+		{
+			CalcTerm::Number(coefficient) => coefficient == 0.0,
+			CalcTerm::Dimension(coefficient, one) => coefficient == 0.0 && !one.is_percentage() && (one.is_number() || !isNextToAPercentage),
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn order(&self) -> (u8, String)
+	{
+		use self::CalcTerm::*;
+		
+		match *self
+		{
+			Number(_) => (0, String::new()),
+			Dimension(_, one) if one.is_number() => (0, String::new()),
+			Percentage(_) => (1, String::new()),
+			Dimension(_, one) => (2, one.to_css_string()),
+			Other(..) => (3, String::new()),
+		}
+	}
+	
+	/// This term with its coefficient replaced by `coefficient`, which is always positive unless this is the first term.
+	fn to_expression(&self, coefficient: f32) -> Option<CalcExpression<U>>
+	{
+		use self::CalcExpression::CalculablePropertyValue as Value;
+		use self::CalculablePropertyValue::*;
+		
+		let number = U::Number::new(coefficient).ok()?;
+		let expression = match *self
+		{
+			CalcTerm::Number(_) => CalcExpression::Number(number),
+			
+			CalcTerm::Dimension(_, one) => Value(Constant(one.with_number(number))),
+			
+			CalcTerm::Percentage(_) => Value(Percentage(PercentageUnit(number))),
+			
+			CalcTerm::Other(_, ref expression) => if coefficient == 1.0
+			{
+				expression.clone()
+			}
+			else
+			{
+				CalcExpression::Multiplication(Box::new(CalcExpression::Number(number)), Box::new(expression.clone()))
+			},
+		};
+		Some(expression)
+	}
+}
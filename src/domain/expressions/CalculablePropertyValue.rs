@@ -46,6 +46,46 @@ impl<U: Unit> ToCss for CalculablePropertyValue<U>
 	}
 }
 
+impl<U: Unit> SimplifyCalcFunctions for CalculablePropertyValue<U>
+{
+	/// If the `calc()` function simplifies to a single value, such as `calc(1in - 6px)` to `90px`, the `calc()` is removed.
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		use self::CalculablePropertyValue::*;
+		
+		let simplified = match *self
+		{
+			Calc(ref function) => function.0.simplify(),
+			_ => return,
+		};
+		
+		*self = match simplified
+		{
+			CalcExpression::CalculablePropertyValue(value @ Constant(_)) | CalcExpression::CalculablePropertyValue(value @ Percentage(_)) => value,
+			expression => Calc(CalcFunction(Arc::new(expression))),
+		};
+	}
+}
+
+impl<U: Unit> CalculablePropertyValue<U>
+{
+	/// The number this is, if it is a `calc()` function that is just a number, such as `calc(1.5)`; `line-height`, for example, can then be a number rather than a length.
+	#[inline(always)]
+	pub fn calc_number(&self) -> Option<U::Number>
+	{
+		match *self
+		{
+			CalculablePropertyValue::Calc(ref function) => match *function.0
+			{
+				CalcExpression::Number(number) => Some(number),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+}
+
 impl<U: Unit> Expression<U> for CalculablePropertyValue<U>
 {
 	/// Evaluate the CalculablePropertyValue by returning the numeric value of the canonical dimension
@@ -58,7 +98,7 @@ impl<U: Unit> Expression<U> for CalculablePropertyValue<U>
 		
 		match *self
 		{
-			Constant(ref constant) => Some(constant.to_CssNumber()),
+			Constant(ref constant) => Some(constant.to_canonical_dimension_value(conversion)),
 			
 			Percentage(ref percentage) => Some(percentage.to_absolute_value(conversion)),
 			
@@ -70,3 +110,131 @@ impl<U: Unit> Expression<U> for CalculablePropertyValue<U>
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn parse(css: &str) -> CalculablePropertyValue<LengthUnit<CssSignedNumber>>
+	{
+		let mut parserInput = ParserInput::new(css);
+		let mut input = Parser::new(&mut parserInput);
+		input.parse_entirely(|input| LengthUnit::<CssSignedNumber>::parse_one_outside_calc_function(&ParserContext::new(CssRuleType::Style), input)).unwrap()
+	}
+	
+	fn evaluate(css: &str) -> f32
+	{
+		let mut conversion = SimplisticExampleOfConversion::<LengthUnit<CssSignedNumber>>
+		{
+			em: CssSignedNumber::new(16.0).unwrap(),
+			..Default::default()
+		};
+		conversion.attributesWithLowerCaseNames.insert("data-width".to_owned(), "2".to_owned());
+		conversion.cssVariablesWithLowerCaseNamesWithoutLeadingDoubleDashToRawCss.insert("width".to_owned(), "3em".to_owned());
+		
+		parse(css).evaluate(&conversion).unwrap().to_f32()
+	}
+	
+	#[test]
+	fn constants_evaluate_to_their_canonical_value()
+	{
+		assert_eq!(evaluate("10px"), 10.0);
+		assert_eq!(evaluate("2em"), 32.0);
+		assert_eq!(evaluate("1in"), 96.0);
+	}
+	
+	#[test]
+	fn attr_evaluates_to_its_canonical_value()
+	{
+		assert_eq!(evaluate("attr(data-width em)"), 32.0);
+	}
+	
+	#[test]
+	fn var_evaluates_to_its_canonical_value()
+	{
+		assert_eq!(evaluate("var(--width)"), 48.0);
+	}	
+	#[test]
+	fn calc_sums_and_products_are_parsed()
+	{
+		assert_eq!(parse("calc(1px + 2px * 3 - 4px / 2)").to_css_string(), "calc(1px + 2px*3 - 4px/2)");
+		assert_eq!(evaluate("calc(1em + 10px)"), 26.0);
+		assert_eq!(evaluate("calc((1px + 2px) * 3)"), 9.0);
+	}	
+	#[test]
+	fn attr_and_calc_are_parsed_inside_calc()
+	{
+		assert_eq!(evaluate("calc(attr(data-width em) + 1px)"), 33.0);
+		assert_eq!(evaluate("calc(2 * calc(1px + 2px))"), 6.0);
+	}
+	
+	#[test]
+	fn calc_functions_are_simplified()
+	{
+		fn simplify(css: &str) -> String
+		{
+			let mut value = parse(css);
+			value.simplify_calc_functions();
+			value.to_css_string()
+		}
+		
+		assert_eq!(simplify("calc(1in - 6px)"), "90px");
+		assert_eq!(simplify("calc(1em + 10px + 1em)"), "calc(2em + 10px)");
+		assert_eq!(simplify("calc(2 * (1em - 5px))"), "calc(2em - 10px)");
+		assert_eq!(simplify("calc(2 * 3px - 1px)"), "5px");
+	}
+	
+	#[test]
+	fn calc_functions_are_simplified_when_minifying()
+	{
+		let simplify = |css: &str|
+		{
+			let mut stylesheet = Stylesheet::parse(css).unwrap();
+			stylesheet.minify(&MinifyOptions { simplify_calc_functions: true, ..Default::default() });
+			stylesheet.to_css_string(false)
+		};
+		
+		assert_eq!(simplify("a{font-size:calc(10px + 5px)}"), "a{font-size:15px}");
+		assert_eq!(simplify("a{line-height:calc(1 + 0.5)}"), "a{line-height:1.5}");
+		assert_eq!(simplify("a{grid-template-columns:calc(10px + 10px) minmax(calc(1em + 1em), 1fr)}"), "a{grid-template-columns:20px minmax(2em,1fr)}");
+		assert_eq!(simplify("a{width:calc(10px + 5px)}"), "a{width:15px}");
+		assert_eq!(simplify("a{margin:calc(1em + 1em) auto calc(100% - 10px + 10px)}"), "a{margin:2em auto calc(100% + 0px)}");
+		assert_eq!(simplify("a{width:calc(-5px)}"), "a{width:calc(-5px)}");
+		assert_eq!(simplify("a{width:calc(var(--a) + 1px + 1px)}"), "a{width:calc(var(--a) + 1px + 1px)}");
+	}
+	
+	#[test]
+	fn zero_lengths_next_to_percentages_are_kept_when_simplifying()
+	{
+		let simplify = |css: &str|
+		{
+			let mut stylesheet = Stylesheet::parse(css).unwrap();
+			stylesheet.minify(&MinifyOptions { simplify_calc_functions: true, ..Default::default() });
+			stylesheet.to_css_string(false)
+		};
+		
+		assert_eq!(simplify("a{flex-basis:calc(50% - 0px)}"), "a{flex-basis:calc(50% + 0px)}");
+		assert_eq!(simplify("a{background-position:calc(100% - 10px + 10px) 0}"), "a{background-position:calc(100% + 0px) 0}");
+		assert_eq!(simplify("a{background-position:calc(10px + 0%) 0}"), "a{background-position:calc(0% + 10px) 0}");
+		assert_eq!(simplify("a{font-size:calc(10px - 10px + 1em)}"), "a{font-size:1em}");
+	}
+	
+	#[test]
+	fn calc_functions_combining_numbers_and_dimensions_are_not_typed()
+	{
+		for css in &["a{font-size:calc(2px*3px)}", "a{font-size:calc(10px + 2)}", "a{font-size:calc(10px/2px)}", "a{font-size:calc(2*3)}"]
+		{
+			let stylesheet = Stylesheet::parse(css).unwrap();
+			assert_eq!(stylesheet.to_css_string(false), *css);
+			
+			let mut isSpecifiedValue = false;
+			let mut stylesheet = stylesheet;
+			stylesheet.rules.property_values_mut(&mut |_vendor_prefix, _name, value| isSpecifiedValue = matches!(*value, UnparsedPropertyValue::SpecifiedValue(_)));
+			assert!(isSpecifiedValue, "{}", css);
+			
+			stylesheet.minify(&MinifyOptions { simplify_calc_functions: true, ..Default::default() });
+			assert_eq!(stylesheet.to_css_string(false), *css);
+		}
+	}
+}
@@ -35,11 +35,11 @@ impl FunctionParser
 	{
 		match *self
 		{
-			attr => Ok(Attr(AttrFunction(Rc::new(AttrExpression::parse(context, input)?)))),
+			attr => Ok(Attr(AttrFunction(Arc::new(AttrExpression::parse(context, input)?)))),
 			
-			calc => Ok(Calc(CalcFunction(Rc::new(CalcExpression::parse(context, input)?)))),
+			calc => Ok(Calc(CalcFunction(Arc::new(CalcExpression::parse_outermost(context, input)?)))),
 			
-			var => Ok(Var(VarFunction(Rc::new(VarExpression::parse(context, input)?)))),
+			var => Ok(Var(VarFunction(Arc::new(VarExpression::parse(context, input)?)))),
 			
 			_ => panic!("Should not be called in this context"),
 		}
@@ -50,11 +50,11 @@ impl FunctionParser
 	{
 		match *self
 		{
-			attr => Ok(Left(Calc(CalcFunction(Rc::new(CalcExpression::parse(context, input)?))))),
+			attr => Ok(Left(Attr(AttrFunction(Arc::new(AttrExpression::parse(context, input)?))))),
 			
-			calc => Ok(Left(Attr(AttrFunction(Rc::new(AttrExpression::parse(context, input)?))))),
+			calc => Ok(Left(Calc(CalcFunction(Arc::new(CalcExpression::parse(context, input)?))))),
 			
-			var => Ok(Left(Var(VarFunction(Rc::new(VarExpression::parse(context, input)?))))),
+			var => Ok(Left(Var(VarFunction(Arc::new(VarExpression::parse(context, input)?))))),
 			
 			parentheses => CalcExpression::parse_parentheses(context, input),
 		}
@@ -0,0 +1,22 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value that can contain `calc()` functions, which can be simplified; see `CalcExpression::simplify()`.
+pub trait SimplifyCalcFunctions
+{
+	/// Simplifies each `calc()` function in this value.
+	fn simplify_calc_functions(&mut self);
+}
+
+impl<T: SimplifyCalcFunctions> SimplifyCalcFunctions for Option<T>
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let Some(ref mut value) = *self
+		{
+			value.simplify_calc_functions()
+		}
+	}
+}
@@ -38,10 +38,10 @@ impl<U: Unit> Expression<U> for VarExpression
 	{
 		match conversion.cssVariableValue(&self.custom_property_lower_case_name_without_double_dash)
 		{
-			Some(value_css) => U::from_raw_css_for_var_expression_evaluation(value_css, self.is_not_in_page_rule).map(|unit| unit.to_CssNumber()),
+			Some(value_css) => U::from_raw_css_for_var_expression_evaluation(value_css, self.is_not_in_page_rule).map(|unit| unit.to_canonical_dimension_value(conversion)),
 			None => if let Some(ref value_css) = self.default_value_css
 			{
-				U::from_raw_css_for_var_expression_evaluation(value_css, self.is_not_in_page_rule).map(|unit| unit.to_CssNumber())
+				U::from_raw_css_for_var_expression_evaluation(value_css, self.is_not_in_page_rule).map(|unit| unit.to_canonical_dimension_value(conversion))
 			}
 			else
 			{
@@ -3,7 +3,7 @@
 
 
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct VarFunction(pub Rc<VarExpression>);
+pub struct VarFunction(pub Arc<VarExpression>);
 
 impl ToCss for VarFunction
 {
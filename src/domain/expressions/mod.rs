@@ -8,16 +8,19 @@ use self::domain::units::conversions::*;
 use self::domain::expressions::CalculablePropertyValue::*;
 use ::either::Either;
 use ::either::Either::*;
-use ::std::rc::Rc;
+use ::std::sync::Arc;
 
 
 include!("AttrExpression.rs");
 include!("AttrFunction.rs");
 include!("CalcExpression.rs");
 include!("CalcFunction.rs");
+include!("CalcSum.rs");
+include!("CalcTerm.rs");
 include!("CalculablePropertyValue.rs");
 include!("Expression.rs");
 include!("FunctionParser.rs");
+include!("SimplifyCalcFunctions.rs");
 include!("TypeOrUnit.rs");
 include!("VarExpression.rs");
 include!("VarFunction.rs");
@@ -12,6 +12,7 @@ use self::atRules::fontFace::*;
 use self::atRules::fontFeatureValues::*;
 use self::atRules::import::*;
 use self::atRules::keyframes::*;
+use self::atRules::layer::*;
 use self::atRules::media::*;
 use self::atRules::namespace::*;
 use self::atRules::page::*;
@@ -29,6 +30,8 @@ use ::std::cmp::Eq;
 use ::std::cmp::PartialOrd;
 use ::std::cmp::Ord;
 use ::std::cmp::Ordering;
+use ::std::collections::HashMap;
+use ::std::collections::HashSet;
 use ::std::collections::hash_map::DefaultHasher;
 use ::std::convert::From;
 use ::std::fmt;
@@ -51,6 +54,7 @@ pub mod selectors;
 
 
 include!("Atom.rs");
+include!("Colour.rs");
 include!("CounterStyleIdent.rs");
 include!("CssRule.rs");
 include!("CssRules.rs");
@@ -59,7 +63,10 @@ include!("CustomIdent.rs");
 include!("HasCssRules.rs");
 include!("HasPropertyDeclarations.rs");
 include!("HasVendorPrefix.rs");
+include!("ImportantComment.rs");
 include!("RulesMutateError.rs");
+include!("SourceOffset.rs");
+include!("SourceRange.rs");
 include!("SpecifiedUrl.rs");
 include!("StyleRule.rs");
 include!("VendorPrefix.rs");
@@ -42,14 +42,11 @@ pub trait CssNumber: Sized
 		{
 			if value == NegativeZero
 			{
-				if Self::can_be_negative()
-				{
-					Ok(Self::_construct(PositiveZero))
-				}
-				else
-				{
-					Err(NegativeNumberMayNotBeAllowed)
-				}
+				Ok(Self::_construct(PositiveZero))
+			}
+			else if !Self::can_be_negative() && value.is_sign_negative()
+			{
+				Err(NegativeNumberMayNotBeAllowed)
 			}
 			else
 			{
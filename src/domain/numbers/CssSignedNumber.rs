@@ -51,7 +51,7 @@ impl ToCss for CssSignedNumber
 	#[inline(always)]
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
-		self.to_f32().to_css(dest)
+		serialize_number(self.to_f32(), dest)
 	}
 }
 
@@ -428,6 +428,12 @@ impl Unit for CssSignedNumber
 		self.to_CssNumber()
 	}
 	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		number
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
 	{
@@ -420,6 +420,12 @@ impl Unit for CssUnsignedInteger
 		self.to_CssNumber()
 	}
 	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		number
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
 	{
@@ -51,7 +51,7 @@ impl ToCss for CssUnsignedNumber
 	#[inline(always)]
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
-		self.to_f32().to_css(dest)
+		serialize_number(self.to_f32(), dest)
 	}
 }
 
@@ -318,7 +318,7 @@ impl CssNumber for CssUnsignedNumber
 	
 	const Maximum: Self = CssUnsignedNumber(::std::f32::MAX);
 	
-	const Minimum: Self = CssUnsignedNumber(::std::f32::MIN);
+	const Minimum: Self = CssUnsignedNumber(0.0);
 	
 	const DotsPerInch: Self = CssUnsignedNumber(96.0);
 	
@@ -434,6 +434,12 @@ impl Unit for CssUnsignedNumber
 		self.to_CssNumber()
 	}
 	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		number
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
 	{
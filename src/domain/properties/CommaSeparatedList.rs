@@ -0,0 +1,56 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A comma-separated list of values, such as the layers of a `background-image`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct CommaSeparatedList<T>(pub Vec<T>);
+
+impl<T: ToCss> ToCss for CommaSeparatedList<T>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut iterator = self.0.iter();
+		if let Some(first) = iterator.next()
+		{
+			first.to_css(dest)?;
+			for value in iterator
+			{
+				dest.write_char(',')?;
+				value.to_css(dest)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<T: Parse> Parse for CommaSeparatedList<T>
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| T::parse(context, input)).map(CommaSeparatedList)
+	}
+}
+
+impl<T> Deref for CommaSeparatedList<T>
+{
+	type Target = Vec<T>;
+	
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		&self.0
+	}
+}
+
+impl<T: SimplifyCalcFunctions> SimplifyCalcFunctions for CommaSeparatedList<T>
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for value in self.0.iter_mut()
+		{
+			value.simplify_calc_functions()
+		}
+	}
+}
@@ -0,0 +1,265 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The custom properties of an element and their dependencies on each other through `var()` functions: https://drafts.csswg.org/css-variables/#cycles
+///
+/// Build one from an element's cascaded declarations, then use `resolve()` to get the computed values of its custom properties, which can substitute the `var()` functions in its other properties.
+#[derive(Default, Debug, Clone)]
+pub struct CustomPropertyGraph
+{
+	/// The cascaded value of each custom property declared for the element.
+	values: HashMap<Atom, UnparsedPropertyValue>,
+}
+
+impl CustomPropertyGraph
+{
+	/// Builds the graph from the property declarations that apply to an element, in cascade order (so a later declaration wins over an earlier one of the same importance, and an important declaration wins over any normal one).
+	/// Declarations of properties other than custom properties are ignored.
+	pub fn new<'a, I: 'a + HasImportance, Declarations: IntoIterator<Item = &'a PropertyDeclaration<I>>>(declarations: Declarations) -> Self
+	{
+		let mut cascaded: HashMap<Atom, (bool, &'a UnparsedPropertyValue)> = HashMap::new();
+		for declaration in declarations
+		{
+			if !declaration.hasACustomPropertyName()
+			{
+				continue
+			}
+			
+			let isImportant = declaration.importance.isImportant();
+			let overridesEarlier = match cascaded.get(&declaration.name)
+			{
+				Some(&(earlierIsImportant, _)) => isImportant || !earlierIsImportant,
+				None => true,
+			};
+			if overridesEarlier
+			{
+				cascaded.insert(declaration.name.clone(), (isImportant, &declaration.value));
+			}
+		}
+		
+		Self
+		{
+			values: cascaded.into_iter().map(|(name, (_, value))| (name, value.clone())).collect(),
+		}
+	}
+	
+	/// The names of the custom properties declared for the element.
+	#[inline(always)]
+	pub fn names(&self) -> ::std::collections::hash_map::Keys<'_, Atom, UnparsedPropertyValue>
+	{
+		self.values.keys()
+	}
+	
+	/// The custom properties that the custom property `name` references in `var()` functions (including in their fallbacks).
+	/// Returns `None` if `name` is not declared for the element or does not use `var()`.
+	#[inline(always)]
+	pub fn dependencies(&self, name: &Atom) -> Option<&HashSet<Atom>>
+	{
+		match self.values.get(name)
+		{
+			Some(UnparsedPropertyValue::SpecifiedValue(value)) if value.hasReferences() => Some(&value.references),
+			_ => None,
+		}
+	}
+	
+	/// The cycles of custom properties that depend on each other, such as `--a: var(--b); --b: var(--a)`; each is sorted by name, and the cycles are sorted too.
+	/// All of the custom properties in a cycle are invalid at computed-value time, even if their `var()` functions have fallbacks.
+	pub fn cycles(&self) -> Vec<Vec<Atom>>
+	{
+		let mut names = self.values.keys().collect::<Vec<_>>();
+		names.sort();
+		
+		let mut tarjan = Tarjan
+		{
+			graph: self,
+			next_index: 0,
+			indices: HashMap::new(),
+			low_links: HashMap::new(),
+			stack: Vec::new(),
+			on_stack: HashSet::new(),
+			cycles: Vec::new(),
+		};
+		for name in names
+		{
+			if !tarjan.indices.contains_key(name)
+			{
+				tarjan.strongly_connect(name);
+			}
+		}
+		
+		let mut cycles = tarjan.cycles;
+		for cycle in cycles.iter_mut()
+		{
+			cycle.sort();
+		}
+		cycles.sort();
+		cycles
+	}
+	
+	/// Resolves the computed values of the custom properties, substituting `var()` functions.
+	/// `inherited` are the computed values of the custom properties of the element's parent (use `ResolvedCustomProperties::default()` for the root element); custom properties are inherited unless declared, and `inherit` and `unset` use them too.
+	pub fn resolve(&self, inherited: &ResolvedCustomProperties) -> ResolvedCustomProperties
+	{
+		let mut resolved = HashMap::with_capacity(self.values.len());
+		for cycle in self.cycles()
+		{
+			for name in cycle
+			{
+				resolved.insert(name, None);
+			}
+		}
+		
+		for name in self.values.keys()
+		{
+			self.resolve_one(name, inherited, &mut resolved);
+		}
+		
+		let mut computed = inherited.clone();
+		for (name, value) in resolved
+		{
+			match value
+			{
+				None =>
+				{
+					computed.0.remove(&name);
+				}
+				
+				Some(value) =>
+				{
+					computed.0.insert(name, value);
+				}
+			}
+		}
+		computed
+	}
+	
+	/// Resolves `name` into `resolved` (`None` is the guaranteed-invalid value), first resolving the custom properties it depends on.
+	/// Custom properties in cycles must already be in `resolved`, so this always terminates.
+	fn resolve_one(&self, name: &Atom, inherited: &ResolvedCustomProperties, resolved: &mut HashMap<Atom, Option<TokenSequence>>)
+	{
+		if resolved.contains_key(name)
+		{
+			return
+		}
+		
+		let value = match self.values.get(name)
+		{
+			None => return,
+			
+			Some(UnparsedPropertyValue::CssWideKeyword(CssWideKeyword::initial)) => None,
+			
+			Some(UnparsedPropertyValue::CssWideKeyword(_)) => inherited.get(name).cloned(),
+			
+			Some(UnparsedPropertyValue::SpecifiedValue(value)) =>
+			{
+				for reference in value.references.iter()
+				{
+					self.resolve_one(reference, inherited, resolved);
+				}
+				
+				let lookup = |reference: &Atom| match resolved.get(reference)
+				{
+					Some(value) => value.as_ref(),
+					None => inherited.get(reference),
+				};
+				TokenSequence::substitute(&value.originalCss, &lookup, false)
+			}
+			
+			// Custom properties are never parsed into typed values, but one could be constructed; it has no `var()` functions to substitute.
+			Some(UnparsedPropertyValue::Typed(value)) => TokenSequence::substitute(&value.to_css_string(), &|_| None, false),
+		};
+		
+		resolved.insert(name.clone(), value);
+	}
+}
+
+/// Tarjan's algorithm for strongly connected components, with each component that has a cycle being recorded.
+struct Tarjan<'a>
+{
+	graph: &'a CustomPropertyGraph,
+	next_index: usize,
+	indices: HashMap<&'a Atom, usize>,
+	low_links: HashMap<&'a Atom, usize>,
+	stack: Vec<&'a Atom>,
+	on_stack: HashSet<&'a Atom>,
+	cycles: Vec<Vec<Atom>>,
+}
+
+impl<'a> Tarjan<'a>
+{
+	fn strongly_connect(&mut self, name: &'a Atom)
+	{
+		let index = self.next_index;
+		self.next_index += 1;
+		self.indices.insert(name, index);
+		self.low_links.insert(name, index);
+		self.stack.push(name);
+		self.on_stack.insert(name);
+		
+		let graph = self.graph;
+		let mut referencesItself = false;
+		if let Some(references) = graph.dependencies(name)
+		{
+			// Only declared custom properties can be part of a cycle; the order is sorted so that cycles are found in a stable order.
+			let mut references = references.iter().filter_map(|reference| graph.values.get_key_value(reference).map(|(reference, _)| reference)).collect::<Vec<_>>();
+			references.sort();
+			
+			for reference in references
+			{
+				if reference == name
+				{
+					referencesItself = true;
+				}
+				
+				if !self.indices.contains_key(reference)
+				{
+					self.strongly_connect(reference);
+					let lowLink = min(self.low_links[name], self.low_links[reference]);
+					self.low_links.insert(name, lowLink);
+				}
+				else if self.on_stack.contains(reference)
+				{
+					let lowLink = min(self.low_links[name], self.indices[reference]);
+					self.low_links.insert(name, lowLink);
+				}
+			}
+		}
+		
+		if self.low_links[name] == index
+		{
+			let mut component = Vec::new();
+			loop
+			{
+				let member = self.stack.pop().unwrap();
+				self.on_stack.remove(member);
+				component.push(member.clone());
+				if member == name
+				{
+					break
+				}
+			}
+			
+			if component.len() > 1 || referencesItself
+			{
+				self.cycles.push(component);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod custom_property_graph_tests
+{
+	use super::*;
+	
+	#[test]
+	fn typed_custom_property_values_are_resolved_to_their_css()
+	{
+		let mut declaration = PropertyDeclaration::<Importance>::parse("--x: 1").unwrap();
+		declaration.value = UnparsedPropertyValue::Typed(TypedPropertyValue::FlexGrow(CssUnsignedNumber::One));
+		
+		let resolved = CustomPropertyGraph::new(&[declaration]).resolve(&ResolvedCustomProperties::default());
+		assert_eq!(resolved.get(&Atom::from("--x")).map(TokenSequence::as_css), Some("1"));
+	}
+}
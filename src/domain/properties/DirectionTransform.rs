@@ -0,0 +1,285 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A change to the horizontal direction of property declarations; see `Stylesheet::transform_direction()`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DirectionTransform
+{
+	/// Produces a right-to-left stylesheet from a left-to-right one.
+	///
+	/// `left` and `right` are swapped in property names, such as `margin-left` and `border-top-left-radius`, and in the values of `float`, `clear` and `text-align`.
+	/// The right and left values of four-value shorthands, such as `margin: 1px 2px 3px 4px`, are swapped, as are the corners of `border-radius`.
+	/// Background positions, including those of `background-position-x`, are mirrored, so `left 10px top` becomes `right 10px top` and `25%` becomes `75%`.
+	/// Values that use `var()` are left alone, although the names of their properties are still swapped.
+	FlipToRightToLeft,
+	
+	/// Replaces physical properties with their logical equivalents for horizontal writing modes, so that one stylesheet serves both directions; for example, `margin-left` becomes `margin-inline-start`, `left` becomes `inset-inline-start`, `border-top-right-radius` becomes `border-start-end-radius` and `float: right` becomes `float: inline-end`.
+	/// Four-value shorthands and background positions are left alone, as they have no logical equivalents.
+	PhysicalToLogical,
+}
+
+impl DirectionTransform
+{
+	/// Applies this transform to a property declaration; custom properties are left alone.
+	pub fn apply<I: HasImportance>(self, property_declaration: &mut PropertyDeclaration<I>)
+	{
+		use self::DirectionTransform::*;
+		
+		if property_declaration.hasACustomPropertyName()
+		{
+			return
+		}
+		
+		let vendor_prefix = &property_declaration.vendor_prefix;
+		let name = &property_declaration.name;
+		let value = &mut property_declaration.value;
+		let renamed = match self
+		{
+			FlipToRightToLeft =>
+			{
+				match &**name
+				{
+					"float" | "clear" | "text-align" => Self::replace_keyword(vendor_prefix, name, value, &[("left", "right"), ("right", "left")]),
+					
+					"margin" | "padding" | "border-width" | "border-style" | "border-color" | "inset" | "scroll-margin" | "scroll-padding" => Self::mirror_box_sides(vendor_prefix, name, value),
+					
+					"border-radius" => Self::mirror_corners(vendor_prefix, name, value),
+					
+					"background-position-x" => Self::mirror_horizontal_positions(vendor_prefix, name, value),
+					
+					_ => if let UnparsedPropertyValue::Typed(ref mut typedValue) = *value
+					{
+						typedValue.background_positions_mut(&mut |position| position.mirror_horizontally())
+					},
+				}
+				
+				Self::swap_left_and_right(name)
+			}
+			
+			PhysicalToLogical =>
+			{
+				match &**name
+				{
+					"float" | "clear" => Self::replace_keyword(vendor_prefix, name, value, &[("left", "inline-start"), ("right", "inline-end")]),
+					
+					"text-align" => Self::replace_keyword(vendor_prefix, name, value, &[("left", "start"), ("right", "end")]),
+					
+					_ => (),
+				}
+				
+				Self::logical_name(name)
+			}
+		};
+		
+		if let Some(renamed) = renamed
+		{
+			property_declaration.name = renamed;
+		}
+	}
+	
+	/// `margin-left` becomes `margin-right`, `border-top-right-radius` becomes `border-top-left-radius`, and so on.
+	fn swap_left_and_right(name: &Atom) -> Option<Atom>
+	{
+		if !name.split('-').any(|segment| segment == "left" || segment == "right")
+		{
+			return None
+		}
+		
+		let swapped = name.split('-').map(|segment| match segment
+		{
+			"left" => "right",
+			"right" => "left",
+			_ => segment,
+		}).collect::<Vec<_>>();
+		Some(Atom::from(swapped.join("-")))
+	}
+	
+	/// The logical equivalent of a physical property with a left or right side; top and bottom are only changed for the corners of `border-radius`.
+	fn logical_name(name: &Atom) -> Option<Atom>
+	{
+		#[inline(always)]
+		fn inline(side: &str) -> Option<&'static str>
+		{
+			match side
+			{
+				"left" => Some("start"),
+				"right" => Some("end"),
+				_ => None,
+			}
+		}
+		
+		let segments = name.split('-').collect::<Vec<_>>();
+		let logical = match segments[..]
+		{
+			[side] => inline(side).map(|edge| format!("inset-inline-{}", edge)),
+			
+			["margin", side] | ["padding", side] | ["border", side] => inline(side).map(|edge| format!("{}-inline-{}", segments[0], edge)),
+			
+			["scroll", area, side] if area == "margin" || area == "padding" => inline(side).map(|edge| format!("scroll-{}-inline-{}", area, edge)),
+			
+			["border", side, property] if property == "width" || property == "style" || property == "color" => inline(side).map(|edge| format!("border-inline-{}-{}", edge, property)),
+			
+			["border", vertical, side, "radius"] =>
+			{
+				let block = match vertical
+				{
+					"top" => "start",
+					"bottom" => "end",
+					_ => return None,
+				};
+				inline(side).map(|edge| format!("border-{}-{}-radius", block, edge))
+			}
+			
+			_ => None,
+		};
+		logical.map(Atom::from)
+	}
+	
+	/// Replaces a value that is just one of the keywords in `replacements`.
+	fn replace_keyword(vendor_prefix: &Option<VendorPrefix>, name: &Atom, value: &mut UnparsedPropertyValue, replacements: &[(&str, &str)])
+	{
+		let replacement = match *value
+		{
+			UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) =>
+			{
+				let keyword = specifiedValue.originalCss.trim();
+				replacements.iter().find(|&&(from, _)| keyword.eq_ignore_ascii_case(from)).map(|&(_, to)| to.to_owned())
+			}
+			
+			_ => None,
+		};
+		
+		if let Some(replacement) = replacement
+		{
+			Self::replace(vendor_prefix, name, value, replacement)
+		}
+	}
+	
+	/// Swaps the right and left values of a four-value shorthand, such as `margin: 1px 2px 3px 4px`; a shorthand with fewer values is the same both ways.
+	fn mirror_box_sides(vendor_prefix: &Option<VendorPrefix>, name: &Atom, value: &mut UnparsedPropertyValue)
+	{
+		if let Some(mut components) = Self::components(value)
+		{
+			if components.len() == 4
+			{
+				components.swap(1, 3);
+				Self::replace(vendor_prefix, name, value, components.join(" "))
+			}
+		}
+	}
+	
+	/// Swaps the left and right corners of `border-radius`, for both the horizontal and (after any `/`) the vertical radii.
+	fn mirror_corners(vendor_prefix: &Option<VendorPrefix>, name: &Atom, value: &mut UnparsedPropertyValue)
+	{
+		if let Some(components) = Self::components(value)
+		{
+			let mirrored = components.split(|component| component == "/").map(|radii| match radii.len()
+			{
+				2 => vec![&radii[1], &radii[0]],
+				3 => vec![&radii[1], &radii[0], &radii[1], &radii[2]],
+				4 => vec![&radii[1], &radii[0], &radii[3], &radii[2]],
+				_ => radii.iter().collect(),
+			}.iter().map(|radius| radius.as_str()).collect::<Vec<_>>().join(" ")).collect::<Vec<_>>();
+			Self::replace(vendor_prefix, name, value, mirrored.join(" / "))
+		}
+	}
+	
+	/// Mirrors each of the comma-separated positions of `background-position-x`, so `left 10px` becomes `right 10px` and `25%` becomes `75%`.
+	fn mirror_horizontal_positions(vendor_prefix: &Option<VendorPrefix>, name: &Atom, value: &mut UnparsedPropertyValue)
+	{
+		if let Some(components) = Self::components(value)
+		{
+			let context = ParserContext::new(CssRuleType::Style);
+			let mut mirrored = String::new();
+			for (index, horizontal) in components.split(|component| component == ",").enumerate()
+			{
+				// A vertical position is added so that the horizontal one can be parsed as a `Position`.
+				let css = format!("{} top", horizontal.join(" "));
+				let mut position = match CssError::parse_entirely(&css, |input| Position::parse(&context, input))
+				{
+					Ok(position) => position,
+					Err(_) => return,
+				};
+				position.mirror_horizontally();
+				
+				if index != 0
+				{
+					mirrored.push(',');
+				}
+				position.horizontal.to_css_as_longhand("left", "right", &mut mirrored).unwrap();
+			}
+			Self::replace(vendor_prefix, name, value, mirrored)
+		}
+	}
+	
+	/// The components of a value that does not use `var()`, separated by whitespace, such as `1px` and `calc(2px + 1em)` in `1px calc(2px + 1em)`; comments are dropped.
+	fn components(value: &UnparsedPropertyValue) -> Option<Vec<String>>
+	{
+		match *value
+		{
+			UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) if !specifiedValue.hasReferences() => CssError::parse_entirely(&specifiedValue.originalCss, |input|
+			{
+				let mut components = Vec::new();
+				loop
+				{
+					input.skip_whitespace();
+					let start = input.position();
+					let isBlock = match input.next()
+					{
+						Err(_) => break,
+						Ok(&Token::Function(_)) | Ok(&Token::ParenthesisBlock) | Ok(&Token::SquareBracketBlock) | Ok(&Token::CurlyBracketBlock) => true,
+						Ok(_) => false,
+					};
+					if isBlock
+					{
+						input.parse_nested_block(|input|
+						{
+							while input.next().is_ok()
+							{
+							}
+							Ok::<(), ParseError<CustomParseError>>(())
+						})?;
+					}
+					components.push(input.slice_from(start).to_owned());
+				}
+				Ok(components)
+			}).ok(),
+			
+			_ => None,
+		}
+	}
+	
+	/// Replaces `value` with `css` re-parsed, as long as it is valid.
+	#[inline(always)]
+	fn replace(vendor_prefix: &Option<VendorPrefix>, name: &Atom, value: &mut UnparsedPropertyValue, css: String)
+	{
+		if let Ok(replacement) = UnparsedPropertyValue::parse(vendor_prefix, name, &css)
+		{
+			*value = replacement;
+		}
+	}
+}
+
+#[cfg(test)]
+mod direction_transform_tests
+{
+	use super::*;
+	
+	#[test]
+	fn background_position_x_is_mirrored()
+	{
+		let flip = |css: &str|
+		{
+			let mut propertyDeclaration = PropertyDeclaration::<Importance>::parse(css).unwrap();
+			DirectionTransform::FlipToRightToLeft.apply(&mut propertyDeclaration);
+			propertyDeclaration.to_css_string()
+		};
+		
+		assert_eq!(flip("background-position-x:left"), "background-position-x:100%;");
+		assert_eq!(flip("background-position-x:10%"), "background-position-x:90%;");
+		assert_eq!(flip("background-position-x:10px"), "background-position-x:right 10px;");
+		assert_eq!(flip("background-position-x:right 10px, center"), "background-position-x:10px,50%;");
+		assert_eq!(flip("background-position-x:var(--x)"), "background-position-x:var(--x);");
+	}
+}
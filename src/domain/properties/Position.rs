@@ -0,0 +1,228 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `<position>`, such as the value of `background-position`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Position
+{
+	pub horizontal: PositionComponent,
+	
+	pub vertical: PositionComponent,
+}
+
+impl ToCss for Position
+{
+	/// Writes the shortest form; for example, `50% 0` is written as `top`, and `10px 50%` as `10px`.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.horizontal.isFromFarEdge() || self.vertical.isFromFarEdge()
+		{
+			self.horizontal.to_css_with_keyword("left", "right", dest)?;
+			dest.write_char(' ')?;
+			self.vertical.to_css_with_keyword("top", "bottom", dest)
+		}
+		else if self.vertical.isPercentage(0.5)
+		{
+			self.horizontal.to_css_as_offset(dest)
+		}
+		else if self.horizontal.isPercentage(0.5) && self.vertical.isPercentage(0.0)
+		{
+			dest.write_str("top")
+		}
+		else if self.horizontal.isPercentage(0.5) && self.vertical.isPercentage(1.0)
+		{
+			dest.write_str("bottom")
+		}
+		else
+		{
+			self.horizontal.to_css_as_offset(dest)?;
+			dest.write_char(' ')?;
+			self.vertical.to_css_as_offset(dest)
+		}
+	}
+}
+
+impl Default for Position
+{
+	/// `0 0`, which is the initial value of `background-position`.
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			horizontal: PositionComponent::percentage(0.0),
+			vertical: PositionComponent::percentage(0.0),
+		}
+	}
+}
+
+impl Parse for Position
+{
+	/// Parses the one, two, three and four value forms.
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::PositionKeyword::*;
+		
+		let mut keywords: Vec<Option<PositionKeyword>> = Vec::with_capacity(4);
+		let mut lengths: Vec<Option<PositionLength>> = Vec::with_capacity(4);
+		while keywords.len() < 4
+		{
+			if let Ok(keyword) = input.r#try(|input| PositionKeyword::parse(input))
+			{
+				keywords.push(Some(keyword));
+				lengths.push(None);
+			}
+			else if let Ok(length) = input.r#try(|input| LengthOrPercentageUnit::parse_one_outside_calc_function(context, input))
+			{
+				keywords.push(None);
+				lengths.push(Some(length));
+			}
+			else
+			{
+				break;
+			}
+		}
+		
+		let invalid = || Err(input.new_custom_error(CustomParseError::InvalidPosition));
+		
+		let mut lengths = lengths.drain(..);
+		match keywords.len()
+		{
+			1 => Ok
+			(
+				match keywords[0]
+				{
+					None => Self::new(PositionComponent::Offset(lengths.next().unwrap().unwrap()), center.component(None)),
+					Some(keyword) if keyword.isVertical() => Self::new(center.component(None), keyword.component(None)),
+					Some(keyword) => Self::new(keyword.component(None), center.component(None)),
+				}
+			),
+			
+			2 =>
+			{
+				let first = (keywords[0], lengths.next().unwrap());
+				let second = (keywords[1], lengths.next().unwrap());
+				
+				// `top left` is the same as `left top`, but `top 10px` and `10px left` are invalid.
+				let (horizontal, vertical) = match (first.0, second.0)
+				{
+					(Some(firstKeyword), Some(secondKeyword)) if firstKeyword.isVertical() || secondKeyword.isHorizontal() => (second, first),
+					_ => (first, second),
+				};
+				
+				let horizontal = match horizontal
+				{
+					(Some(keyword), _) if !keyword.isVertical() => keyword.component(None),
+					(None, Some(length)) => PositionComponent::Offset(length),
+					_ => return invalid(),
+				};
+				
+				let vertical = match vertical
+				{
+					(Some(keyword), _) if !keyword.isHorizontal() => keyword.component(None),
+					(None, Some(length)) => PositionComponent::Offset(length),
+					_ => return invalid(),
+				};
+				
+				Ok(Self::new(horizontal, vertical))
+			}
+			
+			3 | 4 =>
+			{
+				// Each keyword may be followed by an offset, except `center`.
+				let mut pairs: Vec<(PositionKeyword, Option<PositionLength>)> = Vec::with_capacity(2);
+				for (keyword, length) in keywords.iter().zip(lengths)
+				{
+					match (*keyword, length)
+					{
+						(Some(keyword), None) => pairs.push((keyword, None)),
+						
+						(None, Some(length)) => match pairs.last_mut()
+						{
+							Some(pair) if pair.0 != center && pair.1.is_none() => pair.1 = Some(length),
+							_ => return invalid(),
+						},
+						
+						_ => unreachable!("Either a keyword or a length is always parsed"),
+					}
+				}
+				
+				if pairs.len() != 2
+				{
+					return invalid();
+				}
+				
+				let second = pairs.pop().unwrap();
+				let first = pairs.pop().unwrap();
+				let (horizontal, vertical) = if first.0.isVertical() || second.0.isHorizontal()
+				{
+					(second, first)
+				}
+				else
+				{
+					(first, second)
+				};
+				
+				if horizontal.0.isVertical() || vertical.0.isHorizontal()
+				{
+					return invalid();
+				}
+				
+				Ok(Self::new(horizontal.0.component(horizontal.1), vertical.0.component(vertical.1)))
+			}
+			
+			_ => invalid(),
+		}
+	}
+}
+
+impl SimplifyCalcFunctions for Position
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.horizontal.simplify_calc_functions();
+		self.vertical.simplify_calc_functions()
+	}
+}
+
+impl Position
+{
+	#[inline(always)]
+	pub fn new(horizontal: PositionComponent, vertical: PositionComponent) -> Self
+	{
+		Self
+		{
+			horizontal,
+			vertical,
+		}
+	}
+	
+	/// `50% 50%`, which is the initial position of a radial or conic gradient.
+	#[inline(always)]
+	pub fn center() -> Self
+	{
+		Self::new(PositionComponent::percentage(0.5), PositionComponent::percentage(0.5))
+	}
+	
+	#[inline(always)]
+	pub fn isCenter(&self) -> bool
+	{
+		self.horizontal.isPercentage(0.5) && self.vertical.isPercentage(0.5)
+	}
+	
+	/// Mirrors this position horizontally, as for right-to-left text; for example, `left 10px top` becomes `right 10px top`, and `25%` becomes `75%`.
+	#[inline(always)]
+	pub fn mirror_horizontally(&mut self)
+	{
+		self.horizontal.mirror()
+	}
+	
+	/// Is this `0 0` (ie `left top`)?
+	#[inline(always)]
+	pub fn isInitial(&self) -> bool
+	{
+		self.horizontal.isPercentage(0.0) && self.vertical.isPercentage(0.0)
+	}
+}
@@ -0,0 +1,147 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A length or percentage in a position.
+pub type PositionLength = CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>;
+
+/// One axis of a position.
+/// Keywords are resolved to percentages (eg `right` is `100%`), as these are never longer.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum PositionComponent
+{
+	/// An offset from the left or top edge.
+	Offset(PositionLength),
+	
+	/// An offset from the right or bottom edge, eg `right 10px`; it can not be resolved without `calc()`.
+	FromFarEdge(PositionLength),
+}
+
+impl SimplifyCalcFunctions for PositionComponent
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		match *self
+		{
+			PositionComponent::Offset(ref mut offset) | PositionComponent::FromFarEdge(ref mut offset) => offset.simplify_calc_functions(),
+		}
+	}
+}
+
+impl PositionComponent
+{
+	#[inline(always)]
+	pub(crate) fn percentage(fraction: f32) -> Self
+	{
+		PositionComponent::Offset(CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(PercentageUnit(CssSignedNumber::new(fraction).unwrap()))))
+	}
+	
+	/// `right 0` is the same as `100%`.
+	#[inline(always)]
+	fn fromFarEdge(offset: PositionLength) -> Self
+	{
+		if Self::isZero(&offset)
+		{
+			Self::percentage(1.0)
+		}
+		else
+		{
+			PositionComponent::FromFarEdge(offset)
+		}
+	}
+	
+	/// Is this an offset (from the left or top edge) of exactly `fraction`, eg 0.5 for `50%`?
+	#[inline(always)]
+	pub fn isPercentage(&self, fraction: f32) -> bool
+	{
+		match *self
+		{
+			PositionComponent::Offset(CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(percentage))) => percentage.to_f32() == fraction,
+			PositionComponent::Offset(ref offset) => fraction == 0.0 && Self::isZero(offset),
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn isZero(offset: &PositionLength) -> bool
+	{
+		match *offset
+		{
+			CalculablePropertyValue::Constant(ref constant) => constant.is_zero(),
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn isFromFarEdge(&self) -> bool
+	{
+		matches!(*self, PositionComponent::FromFarEdge(_))
+	}
+	
+	/// An offset from the near edge becomes the same offset from the far edge, and vice versa; a percentage is instead measured from the other edge.
+	#[inline(always)]
+	fn mirror(&mut self)
+	{
+		use self::PositionComponent::*;
+		
+		let mirrored = match *self
+		{
+			Offset(CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(percentage))) => Self::percentage(1.0 - percentage.to_f32()),
+			Offset(ref offset) => Self::fromFarEdge(offset.clone()),
+			FromFarEdge(ref offset) => Offset(offset.clone()),
+		};
+		*self = mirrored;
+	}
+	
+	/// Writes this as the value of a longhand such as `background-position-x`, where only an offset from the far edge needs a keyword.
+	pub(crate) fn to_css_as_longhand<W: fmt::Write>(&self, nearEdge: &str, farEdge: &str, dest: &mut W) -> fmt::Result
+	{
+		if self.isFromFarEdge()
+		{
+			self.to_css_with_keyword(nearEdge, farEdge, dest)
+		}
+		else
+		{
+			self.to_css_as_offset(dest)
+		}
+	}
+	
+	/// Writes the offset alone, which is only valid if it is not from the far edge.
+	fn to_css_as_offset<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			PositionComponent::Offset(ref offset) | PositionComponent::FromFarEdge(ref offset) => serialize_length_or_percentage(offset, dest),
+		}
+	}
+	
+	/// Writes the edge keyword and the offset from it; used when the other axis is from its far edge.
+	fn to_css_with_keyword<W: fmt::Write>(&self, nearEdge: &str, farEdge: &str, dest: &mut W) -> fmt::Result
+	{
+		use self::PositionComponent::*;
+		
+		match *self
+		{
+			FromFarEdge(ref offset) =>
+			{
+				dest.write_str(farEdge)?;
+				dest.write_char(' ')?;
+				serialize_length_or_percentage(offset, dest)
+			}
+			
+			Offset(_) if self.isPercentage(0.0) => dest.write_str(nearEdge),
+			
+			Offset(_) if self.isPercentage(0.5) => dest.write_str("center"),
+			
+			Offset(_) if self.isPercentage(1.0) => dest.write_str(farEdge),
+			
+			Offset(ref offset) =>
+			{
+				dest.write_str(nearEdge)?;
+				dest.write_char(' ')?;
+				serialize_length_or_percentage(offset, dest)
+			}
+		}
+	}
+}
@@ -0,0 +1,44 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	PositionKeyword:
+	"left" => left,
+	"right" => right,
+	"top" => top,
+	"bottom" => bottom,
+	"center" => center,
+}
+
+impl PositionKeyword
+{
+	#[inline(always)]
+	fn isHorizontal(&self) -> bool
+	{
+		matches!(*self, PositionKeyword::left | PositionKeyword::right)
+	}
+	
+	#[inline(always)]
+	fn isVertical(&self) -> bool
+	{
+		matches!(*self, PositionKeyword::top | PositionKeyword::bottom)
+	}
+	
+	/// Resolves this keyword and an optional offset from it.
+	#[inline(always)]
+	fn component(self, offset: Option<PositionLength>) -> PositionComponent
+	{
+		use self::PositionKeyword::*;
+		
+		match (self, offset)
+		{
+			(left, Some(offset)) | (top, Some(offset)) => PositionComponent::Offset(offset),
+			(right, Some(offset)) | (bottom, Some(offset)) => PositionComponent::fromFarEdge(offset),
+			(left, None) | (top, None) => PositionComponent::percentage(0.0),
+			(right, None) | (bottom, None) => PositionComponent::percentage(1.0),
+			(center, _) => PositionComponent::percentage(0.5),
+		}
+	}
+}
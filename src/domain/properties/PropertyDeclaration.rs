@@ -2,13 +2,61 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// Equality, ordering and hashing ignore `source_range`, so the same declaration parsed from different places is equal.
+#[derive(Debug, Clone)]
 pub struct PropertyDeclaration<I: HasImportance>
 {
 	pub vendor_prefix: Option<VendorPrefix>,
 	pub name: Atom,
 	pub value: UnparsedPropertyValue,
 	pub importance: I,
+	
+	/// Where this declaration came from in the original CSS, if recorded when parsing (see `ParseOptions::source_ranges`).
+	/// The range includes any terminating semicolon.
+	pub source_range: Option<SourceRange>,
+}
+
+impl<I: HasImportance> PartialEq for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.vendor_prefix == other.vendor_prefix && self.name == other.name && self.value == other.value && self.importance == other.importance
+	}
+}
+
+impl<I: HasImportance> Eq for PropertyDeclaration<I>
+{
+}
+
+impl<I: HasImportance> PartialOrd for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl<I: HasImportance> Ord for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.vendor_prefix.cmp(&other.vendor_prefix).then_with(|| self.name.cmp(&other.name)).then_with(|| self.value.cmp(&other.value)).then_with(|| self.importance.cmp(&other.importance))
+	}
+}
+
+impl<I: HasImportance> Hash for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.vendor_prefix.hash(state);
+		self.name.hash(state);
+		self.value.hash(state);
+		self.importance.hash(state);
+	}
 }
 
 impl<I: HasImportance> ToCss for PropertyDeclaration<I>
@@ -31,6 +79,56 @@ impl<I: HasImportance> HasVendorPrefix for PropertyDeclaration<I>
 
 impl<I: HasImportance> PropertyDeclaration<I>
 {
+	/// Parses a single property declaration, such as `margin: 0 auto !important`.
+	pub fn parse(css: &str) -> Result<Self, CssError>
+	{
+		CssError::parse_entirely(css, |input|
+		{
+			let name = input.expect_ident()?.clone();
+			input.expect_colon()?;
+			
+			let context = ParserContext::new(CssRuleType::Style);
+			let mut propertyDeclarationParser = PropertyDeclarationParser
+			{
+				context: &context,
+				marker: PhantomData,
+			};
+			propertyDeclarationParser.parse_value(name, input)
+		})
+	}
+	
+	/// If this declaration is of a shorthand property with a typed value (such as `font`), the declarations of its longhand properties, with the same importance.
+	/// A CSS-wide keyword is given to every longhand.
+	/// Returns `None` for longhand properties, for shorthand values that can not be expanded (such as a system font) and for values that are not typed (such as those using `var()`).
+	pub fn longhands(&self) -> Option<Vec<Self>>
+	{
+		if self.vendor_prefix.is_some()
+		{
+			return None
+		}
+		
+		let longhands = match self.value
+		{
+			UnparsedPropertyValue::CssWideKeyword(cssWideKeyword) => TypedPropertyValue::longhand_names(&self.name)?.iter().map(|&name| (name, UnparsedPropertyValue::CssWideKeyword(cssWideKeyword))).collect::<Vec<_>>(),
+			
+			UnparsedPropertyValue::SpecifiedValue(_) => return None,
+			
+			UnparsedPropertyValue::Typed(ref typedPropertyValue) => typedPropertyValue.longhands()?.into_iter().map(|longhand| (longhand.name(), UnparsedPropertyValue::Typed(longhand))).collect(),
+		};
+		
+		Some
+		(
+			longhands.into_iter().map(|(name, value)| PropertyDeclaration
+			{
+				vendor_prefix: None,
+				name: Atom::from(name),
+				value,
+				importance: self.importance,
+				source_range: None,
+			}).collect()
+		)
+	}
+	
 	/// https://drafts.csswg.org/css-variables/#typedef-custom-property-name
 	#[inline(always)]
 	pub fn hasACustomPropertyName(&self) -> bool
@@ -45,7 +143,7 @@ impl<I: HasImportance> PropertyDeclaration<I>
 	}
 	
 	#[inline(always)]
-	fn to_css_without_trailing_semicolon<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	pub(crate) fn to_css_without_trailing_semicolon<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
 		if let Some(ref vendorPrefix) = self.vendor_prefix
 		{
@@ -57,3 +155,60 @@ impl<I: HasImportance> PropertyDeclaration<I>
 		self.importance.to_css(dest)
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use ::std::collections::hash_map::DefaultHasher;
+	
+	fn hash(propertyDeclaration: &PropertyDeclaration<Importance>) -> u64
+	{
+		let mut hasher = DefaultHasher::new();
+		propertyDeclaration.hash(&mut hasher);
+		hasher.finish()
+	}
+	
+	#[test]
+	fn source_ranges_are_ignored_when_comparing_and_hashing()
+	{
+		let withoutSourceRange = PropertyDeclaration::<Importance>::parse("color: red").unwrap();
+		let offset = |byte_offset| SourceOffset { line: 0, column: byte_offset as u32 + 1, byte_offset };
+		let mut withSourceRange = withoutSourceRange.clone();
+		withSourceRange.source_range = Some(SourceRange { start: offset(10), end: offset(20) });
+		
+		assert_eq!(withoutSourceRange, withSourceRange);
+		assert_eq!(withoutSourceRange.cmp(&withSourceRange), Ordering::Equal);
+		assert_eq!(hash(&withoutSourceRange), hash(&withSourceRange));
+		
+		assert_ne!(withoutSourceRange, PropertyDeclaration::<Importance>::parse("color: red !important").unwrap());
+	}
+	
+	#[test]
+	fn typed_values_are_never_longer_than_their_input()
+	{
+		for css in &["transition:opacity .3s", "transition-duration:.3s", "animation:a .3s ease-in .1s", "line-height:.5", "transform:scale(.5) rotate(-.5turn)", "gap:.5em", "background-position:-.5em 50.5%", "font-size:.5em", "flex:1 1 .5px", "grid-template-columns:.5fr 1fr"]
+		{
+			let propertyDeclaration = PropertyDeclaration::<Importance>::parse(css).unwrap();
+			match propertyDeclaration.value
+			{
+				UnparsedPropertyValue::Typed(_) => (),
+				_ => panic!("{} is not typed", css),
+			}
+			
+			let serialized = propertyDeclaration.to_css_string();
+			let serialized = serialized.trim_end_matches(';');
+			assert!(serialized.len() <= css.len(), "{} was serialized as {}", css, serialized);
+		}
+	}
+	
+	#[test]
+	fn invalid_property_declarations_in_a_style_attribute_are_skipped()
+	{
+		let (propertyDeclarations, errors) = PropertyDeclarations::<Importance>::parse("color:red; 1px:bad; margin:0 !important; width:1px !unimportant");
+		
+		assert_eq!(propertyDeclarations.to_css_string(), "color:red;margin:0 !important");
+		assert_eq!(errors.iter().map(|error| error.snippet().lines().next().unwrap()).collect::<Vec<_>>(), vec![" 1 | color:red; 1px:bad; margin:0 !important; width:1px !unimportant"; 2]);
+		assert_eq!(errors[0].location().column, 12);
+	}
+}
@@ -68,8 +68,171 @@ impl<I: HasImportance> PropertyDeclarations<I>
 		self.0.is_empty()
 	}
 	
+	/// Calls `visitor` with the value of each property declaration that was parsed into a typed value.
+	#[inline(always)]
+	pub fn typed_values_mut<Visitor: FnMut(&mut TypedPropertyValue)>(&mut self, visitor: &mut Visitor)
+	{
+		for property_declaration in self.0.iter_mut()
+		{
+			if let UnparsedPropertyValue::Typed(ref mut typed_value) = property_declaration.value
+			{
+				visitor(typed_value)
+			}
+		}
+	}
+	
+	/// Calls `visitor` with the vendor prefix, name and value of each property declaration.
+	#[inline(always)]
+	pub fn values_mut<Visitor: FnMut(&Option<VendorPrefix>, &Atom, &mut UnparsedPropertyValue)>(&mut self, visitor: &mut Visitor)
+	{
+		for property_declaration in self.0.iter_mut()
+		{
+			visitor(&property_declaration.vendor_prefix, &property_declaration.name, &mut property_declaration.value)
+		}
+	}
+	
+	/// Calls `visitor` with the value, and source range, of each property declaration that was parsed into a typed value.
+	#[inline(always)]
+	pub fn typed_values<Visitor: FnMut(&TypedPropertyValue, Option<SourceRange>)>(&self, visitor: &mut Visitor)
+	{
+		for property_declaration in self.0.iter()
+		{
+			if let UnparsedPropertyValue::Typed(ref typed_value) = property_declaration.value
+			{
+				visitor(typed_value, property_declaration.source_range)
+			}
+		}
+	}
+	
+	/// Applies `transform` to each property declaration, except those that `control_comments` ignore.
+	pub(crate) fn transform_direction(&mut self, transform: DirectionTransform, control_comments: Option<&RtlControlComments>)
+	{
+		for property_declaration in self.0.iter_mut()
+		{
+			if !control_comments.is_some_and(|control_comments| control_comments.ignores_property_declaration(property_declaration.source_range))
+			{
+				transform.apply(property_declaration)
+			}
+		}
+	}
+	
+	/// Replaces each run of consecutive declarations that sets every longhand of a shorthand property exactly once (in any order, with the same importance) with a single declaration of the shorthand; for example, `flex-grow: 1; flex-shrink: 1; flex-basis: 0%` becomes `flex: 1`.
+	/// The longhands must either all have typed values or all have the same CSS-wide keyword.
+	/// Only the shorthands supported by `TypedPropertyValue::from_longhands()` are collapsed.
+	pub fn collapse_longhands(&mut self)
+	{
+		const Shorthands: &[&str] = &["flex", "flex-flow", "gap", "place-content", "place-items", "place-self"];
+		
+		let mut index = 0;
+		while index < self.0.len()
+		{
+			for &shorthand in Shorthands.iter()
+			{
+				if let Some((collapsed, length)) = self.collapse_longhands_at(index, shorthand)
+				{
+					self.0.splice(index .. index + length, Some(collapsed));
+					break;
+				}
+			}
+			index += 1;
+		}
+	}
+	
+	fn collapse_longhands_at(&self, index: usize, shorthand: &'static str) -> Option<(PropertyDeclaration<I>, usize)>
+	{
+		let longhandNames = TypedPropertyValue::longhand_names(shorthand)?;
+		let run = self.0.get(index .. index + longhandNames.len())?;
+		let importance = run[0].importance;
+		
+		// As there are as many declarations in the run as longhands, finding each longhand means none are repeated.
+		let mut longhands = Vec::with_capacity(longhandNames.len());
+		for longhandName in longhandNames.iter()
+		{
+			let longhand = run.iter().find(|declaration| declaration.vendor_prefix.is_none() && declaration.hasAsciiNameIgnoringCase(longhandName) && declaration.importance == importance)?;
+			longhands.push(&longhand.value);
+		}
+		
+		let value = match *longhands[0]
+		{
+			UnparsedPropertyValue::CssWideKeyword(cssWideKeyword) if longhands.iter().all(|&value| *value == UnparsedPropertyValue::CssWideKeyword(cssWideKeyword)) => UnparsedPropertyValue::CssWideKeyword(cssWideKeyword),
+			
+			_ =>
+			{
+				let mut typedValues = Vec::with_capacity(longhands.len());
+				for value in longhands
+				{
+					match *value
+					{
+						UnparsedPropertyValue::Typed(ref typedValue) => typedValues.push(typedValue.clone()),
+						_ => return None,
+					}
+				}
+				UnparsedPropertyValue::Typed(TypedPropertyValue::from_longhands(shorthand, &typedValues)?)
+			}
+		};
+		
+		Some
+		(
+			(
+				PropertyDeclaration
+				{
+					vendor_prefix: None,
+					name: Atom::from(shorthand),
+					value,
+					importance,
+					source_range: None,
+				},
+				longhandNames.len(),
+			)
+		)
+	}
+	
+	/// Adds the likely mistakes in these property declarations to `lints`; `keyframes_names` are the names of the stylesheet's `@keyframes` rules.
+	pub(crate) fn lint(&self, keyframes_names: &HashSet<Atom>, lints: &mut Vec<Lint>)
+	{
+		for property_declaration in self.0.iter()
+		{
+			let source_range = property_declaration.source_range;
+			let mut report = |kind| lints.push(Lint { kind, source_range });
+			
+			match property_declaration.value
+			{
+				UnparsedPropertyValue::Typed(ref typed_value) => typed_value.lint(keyframes_names, &mut report),
+				
+				UnparsedPropertyValue::SpecifiedValue(ref specified_value) if property_declaration.vendor_prefix.is_none() => TypedPropertyValue::lint_specified_value(&property_declaration.name, specified_value, &mut report),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	/// Parses a list of property declarations, such as the value of a HTML `style` attribute (eg `color: red; margin: 0 !important`).
+	/// As in browsers, property declarations that can not be parsed are skipped; an error is returned for each of them.
+	pub fn parse(css: &str) -> (Self, Vec<CssError>)
+	{
+		let diagnostics = Rc::new(RefCell::new(Vec::new()));
+		let context = ParserContext
+		{
+			diagnostics: Some(diagnostics.clone()),
+			..ParserContext::new(CssRuleType::Style)
+		};
+		
+		let result = CssError::parse_entirely(css, |input| Self::parse_property_declaration_list(&context, input));
+		
+		let mut errors = diagnostics.borrow_mut().drain(..).map(|diagnostic| CssError::from_diagnostic(diagnostic, css)).collect::<Vec<_>>();
+		match result
+		{
+			Ok(propertyDeclarations) => (propertyDeclarations, errors),
+			Err(error) =>
+			{
+				errors.push(error);
+				(PropertyDeclarations(Vec::new()), errors)
+			}
+		}
+	}
+	
 	// Parse a list of property declarations and return a property declaration block.
-	pub(crate) fn parse_property_declaration_list<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<PropertyDeclarations<I>, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_property_declaration_list<'i: 't, 't>(context: &ParserContext<'i>, input: &mut Parser<'i, 't>) -> Result<PropertyDeclarations<I>, ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut propertyDeclarations = Vec::new();
 		let mut parsedPropertyDeclarations = DeclarationListParser::new(input, PropertyDeclarationParser
@@ -78,10 +241,26 @@ impl<I: HasImportance> PropertyDeclarations<I>
 			marker: PhantomData,
 		});
 		
-		while let Some(propertyDeclaration) = parsedPropertyDeclarations.next()
+		let contents_start = parsedPropertyDeclarations.input.position();
+		let mut trailing_trivia_start = contents_start;
+		loop
 		{
-			propertyDeclarations.push(propertyDeclaration.map_err(|(error, _)| error)?);
+			let trivia_start = parsedPropertyDeclarations.input.position();
+			let start = context.source_range_start(parsedPropertyDeclarations.input, |token| *token == Token::Semicolon);
+			let propertyDeclaration = match parsedPropertyDeclarations.next()
+			{
+				None => break,
+				Some(propertyDeclaration) => propertyDeclaration,
+			};
+			if let Some(mut declaration) = context.recover(propertyDeclaration)?
+			{
+				declaration.source_range = ParserContext::source_range(start.clone(), parsedPropertyDeclarations.input);
+				context.record_original_css_node(trivia_start, &start, parsedPropertyDeclarations.input);
+				propertyDeclarations.push(declaration);
+			}
+			trailing_trivia_start = parsedPropertyDeclarations.input.position();
 		}
+		context.record_original_css_block(contents_start, trailing_trivia_start);
 		
 		Ok(PropertyDeclarations(propertyDeclarations))
 	}
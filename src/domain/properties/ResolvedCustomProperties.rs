@@ -0,0 +1,54 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The computed values of an element's custom properties, after `var()` substitution; see `CustomPropertyGraph::resolve()`.
+///
+/// Custom properties that are not set, or whose values are invalid at computed-value time (such as those in a dependency cycle), are absent: https://drafts.csswg.org/css-variables/#guaranteed-invalid
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedCustomProperties(HashMap<Atom, TokenSequence>);
+
+impl ResolvedCustomProperties
+{
+	/// The computed value of the custom property `name` (including the leading `--`), if it has one.
+	#[inline(always)]
+	pub fn get(&self, name: &Atom) -> Option<&TokenSequence>
+	{
+		self.0.get(name)
+	}
+	
+	/// The custom properties with computed values, in no particular order.
+	#[inline(always)]
+	pub fn iter(&self) -> ::std::collections::hash_map::Iter<'_, Atom, TokenSequence>
+	{
+		self.0.iter()
+	}
+	
+	/// The number of custom properties with computed values.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.0.len()
+	}
+	
+	/// Whether no custom property has a computed value.
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// Substitutes the `var()` functions in `value` (the value of any property) with these custom properties, or with the `var()` functions' fallbacks, giving the CSS to parse as the property's value.
+	/// Returns `None` if a `var()` function references a custom property without a value and has no fallback; the property is then invalid at computed-value time, so behaves as `unset`.
+	pub fn substitute(&self, value: &SpecifiedValue) -> Option<TokenSequence>
+	{
+		TokenSequence::substitute(&value.originalCss, &|name| self.0.get(name), false)
+	}
+	
+	/// Substitutes only the `var()` functions in `value` that reference these custom properties, keeping the others (including their fallbacks, in which `var()` functions are substituted in turn).
+	/// Used to inline custom properties whose values are known wherever they are used.
+	pub fn substitute_known(&self, value: &SpecifiedValue) -> Option<TokenSequence>
+	{
+		TokenSequence::substitute(&value.originalCss, &|name| self.0.get(name), true)
+	}
+}
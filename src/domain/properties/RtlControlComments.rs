@@ -0,0 +1,159 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The RTLCSS-style control comments in the original CSS, which stop `DirectionTransform` changing rules and property declarations.
+///
+/// * `/* rtl:ignore */` ignores the following rule or property declaration; it can also be inside a property declaration, eg after its value;
+/// * `/* rtl:begin:ignore */` and `/* rtl:end:ignore */` ignore the rules and property declarations between them.
+///
+/// Important comments, such as `/*! rtl:ignore */`, are control comments too.
+///
+/// Control comments are found whenever a stylesheet is parsed (see `Stylesheet::rtl_control_comments`); to know which rules and property declarations they apply to, where each was in the original CSS is recorded too.
+#[derive(Debug, Clone)]
+pub struct RtlControlComments
+{
+	original_css: OriginalCss,
+	
+	/// The byte offsets of the `rtl:ignore` comments.
+	ignore: BTreeSet<usize>,
+	
+	/// The byte offsets of the `rtl:begin:ignore` (true) and `rtl:end:ignore` (false) comments.
+	ignoring: BTreeMap<usize, bool>,
+}
+
+impl RtlControlComments
+{
+	/// Finds the control comments in `css`; returns `None` if there are none.
+	/// `record_original_css()` must be called once `css` has been parsed.
+	pub(crate) fn find(css: &str) -> Option<Self>
+	{
+		let mut controlComments = Self
+		{
+			original_css: OriginalCss::default(),
+			ignore: BTreeSet::new(),
+			ignoring: BTreeMap::new(),
+		};
+		
+		let bytes = css.as_bytes();
+		let mut index = 0;
+		while index < bytes.len()
+		{
+			match bytes[index]
+			{
+				// Escapes and strings may contain `/*`.
+				b'\\' => index += 2,
+				
+				quote @ b'"' | quote @ b'\'' =>
+				{
+					index += 1;
+					while index < bytes.len() && bytes[index] != quote && bytes[index] != b'\n'
+					{
+						index += if bytes[index] == b'\\' { 2 } else { 1 };
+					}
+					index += 1;
+				}
+				
+				b'/' if bytes.get(index + 1) == Some(&b'*') =>
+				{
+					let textStart = index + 2;
+					let textEnd = css[textStart .. ].find("*/").map_or(css.len(), |length| textStart + length);
+					match css[textStart .. textEnd].trim().trim_start_matches('!').trim()
+					{
+						"rtl:ignore" =>
+						{
+							controlComments.ignore.insert(index);
+						}
+						
+						"rtl:begin:ignore" =>
+						{
+							controlComments.ignoring.insert(index, true);
+						}
+						
+						"rtl:end:ignore" =>
+						{
+							controlComments.ignoring.insert(index, false);
+						}
+						
+						_ => (),
+					}
+					index = textEnd + 2;
+				}
+				
+				_ => index += 1,
+			}
+		}
+		
+		if controlComments.ignore.is_empty() && controlComments.ignoring.is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(controlComments)
+		}
+	}
+	
+	/// Records where the rules and property declarations were in the CSS the control comments were found in.
+	#[inline(always)]
+	pub(crate) fn record_original_css(&mut self, original_css: OriginalCss)
+	{
+		self.original_css = original_css;
+	}
+	
+	/// Whether a rule is ignored; if so, so are the rules and property declarations inside it.
+	#[inline(always)]
+	pub(crate) fn ignores_rule(&self, source_range: Option<SourceRange>) -> bool
+	{
+		self.ignores(source_range, false)
+	}
+	
+	/// Whether a property declaration is ignored.
+	#[inline(always)]
+	pub(crate) fn ignores_property_declaration(&self, source_range: Option<SourceRange>) -> bool
+	{
+		self.ignores(source_range, true)
+	}
+	
+	fn ignores(&self, source_range: Option<SourceRange>, includingInside: bool) -> bool
+	{
+		let (start, node) = match self.original_css.node(source_range)
+		{
+			None => return false,
+			Some(node) => node,
+		};
+		
+		let end = if includingInside { node.end } else { start };
+		if self.ignore.range(node.trivia_start .. end).next().is_some()
+		{
+			return true
+		}
+		
+		match self.ignoring.range(.. start).next_back()
+		{
+			Some((_, &isBegin)) => isBegin,
+			None => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod rtl_control_comments_tests
+{
+	use super::*;
+	
+	#[test]
+	fn rtl_control_comments_are_honoured_without_lossless_parsing()
+	{
+		let flip = |css: &str|
+		{
+			let mut stylesheet = Stylesheet::parse(css).unwrap();
+			stylesheet.transform_direction(DirectionTransform::FlipToRightToLeft);
+			stylesheet.to_css_string(false)
+		};
+		
+		assert_eq!(flip("a{margin-left:1px;/* rtl:ignore */padding-left:2px}"), "a{margin-right:1px;padding-left:2px}");
+		assert_eq!(flip("/* rtl:ignore */a{margin-left:1px}b{margin-left:1px}"), "a{margin-left:1px}b{margin-right:1px}");
+		assert_eq!(flip("/*rtl:begin:ignore*/a{margin-left:1px}/*rtl:end:ignore*/b{margin-left:1px}"), "a{margin-left:1px}b{margin-right:1px}");
+	}
+}
@@ -5,15 +5,57 @@
 /// A specified value for a property is just a set of tokens.
 ///
 /// The original CSS is preserved for serialization, as are variable references to other property names.
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// Specified values are compared, ordered and hashed by their original CSS; the references are derived from it.
+#[derive(Debug, Clone)]
 pub struct SpecifiedValue
 {
 	pub originalCss: String,
 	
 	//first_token_type: TokenSerializationType,
 	//last_token_type: TokenSerializationType,
-	// References to property names in var() functions.
-	//pub references: HashSet<Atom>,
+	
+	/// References to custom property names (such as `--main-colour`) in `var()` functions, including those in the fallbacks of `var()` functions.
+	pub references: HashSet<Atom>,
+}
+
+impl PartialEq for SpecifiedValue
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.originalCss == other.originalCss
+	}
+}
+
+impl Eq for SpecifiedValue
+{
+}
+
+impl PartialOrd for SpecifiedValue
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for SpecifiedValue
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.originalCss.cmp(&other.originalCss)
+	}
+}
+
+impl Hash for SpecifiedValue
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.originalCss.hash(state)
+	}
 }
 
 impl ToCss for SpecifiedValue
@@ -26,8 +68,89 @@ impl ToCss for SpecifiedValue
 
 impl SpecifiedValue
 {
-	/// Parse a custom property SpecifiedValue.
-	pub(crate) fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	/// Whether this value uses `var()`, and so can only be checked once the variables are substituted.
+	#[inline(always)]
+	pub fn hasReferences(&self) -> bool
+	{
+		!self.references.is_empty()
+	}
+	
+	/// This value's CSS with each `calc()` function of lengths and percentages simplified (see `CalcExpression::simplify()`), or `None` if none can be.
+	/// Only non-negative lengths are simplified, as the type of this value is not known; eg `calc(-5px)` is valid for a `width`, but `-5px` is not.
+	pub(crate) fn simplify_calc_functions(&self) -> Option<String>
+	{
+		if self.hasReferences()
+		{
+			return None
+		}
+	
+		let mut simplifications = Vec::new();
+		let mut parserInput = ParserInput::new(&self.originalCss);
+		let mut input = Parser::new(&mut parserInput);
+		Self::find_calc_functions_to_simplify(&ParserContext::new(CssRuleType::Style), &mut input, &mut simplifications);
+		if simplifications.is_empty()
+		{
+			return None
+		}
+	
+		let mut css = String::with_capacity(self.originalCss.len());
+		let mut from = 0;
+		for (start, end, simplified) in simplifications
+		{
+			css.push_str(&self.originalCss[from .. start]);
+			css.push_str(&simplified);
+			from = end;
+		}
+		css.push_str(&self.originalCss[from .. ]);
+		Some(css)
+	}
+	
+	fn find_calc_functions_to_simplify<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, simplifications: &mut Vec<(usize, usize, String)>)
+	{
+		use ::cssparser::Token::*;
+	
+		loop
+		{
+			let start = input.position();
+			let state = input.state();
+			let (isCalcFunction, isBlock) = match input.next_including_whitespace_and_comments()
+			{
+				Err(_) => return,
+				Ok(Function(name)) => (name.eq_ignore_ascii_case("calc"), true),
+				Ok(ParenthesisBlock) | Ok(SquareBracketBlock) | Ok(CurlyBracketBlock) => (false, true),
+				Ok(_) => (false, false),
+			};
+	
+			if isCalcFunction
+			{
+				input.reset(&state);
+				if let Ok(mut value) = input.r#try(|input| LengthOrPercentageUnit::<CssUnsignedNumber>::parse_one_outside_calc_function(context, input))
+				{
+					value.simplify_calc_functions();
+					let simplified = value.to_css_string();
+					if simplified != input.slice_from(start)
+					{
+						simplifications.push((start.byte_index(), input.position().byte_index(), simplified));
+					}
+					continue
+				}
+				input.reset(&state);
+				let _ = input.next_including_whitespace_and_comments();
+			}
+	
+			if isBlock
+			{
+				let _: Result<(), ParseError<()>> = input.parse_nested_block(|input|
+				{
+					Self::find_calc_functions_to_simplify(context, input, simplifications);
+					Ok(())
+				});
+			}
+		}
+	}
+	
+	/// Parse a SpecifiedValue, recording the names of the custom properties it references in `var()` functions.
+	pub(crate) fn parse_with_references<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut references = Some(HashSet::new());
 		let (_first, css, _last) = Self::parse_self_contained_declaration_value(input, &mut references)?;
@@ -38,7 +161,7 @@ impl SpecifiedValue
 				originalCss: css.into_owned(),
 				//first_token_type: first,
 				//last_token_type: last,
-				//references: references.unwrap(),
+				references: references.unwrap(),
 			}
 		)
 	}
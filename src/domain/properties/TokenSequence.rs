@@ -0,0 +1,198 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A sequence of tokens after `var()` substitution, such as the computed value of a custom property.
+///
+/// The types of the first and last tokens are kept so that, when sequences are concatenated, an empty comment (`/**/`) can be inserted where two tokens would otherwise be re-parsed as one (eg `1` followed by `px`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenSequence
+{
+	css: String,
+	first_token_type: TokenSerializationType,
+	last_token_type: TokenSerializationType,
+}
+
+impl ToCss for TokenSequence
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str(&self.css)
+	}
+}
+
+impl TokenSequence
+{
+	/// The tokens as CSS.
+	#[inline(always)]
+	pub fn as_css(&self) -> &str
+	{
+		&self.css
+	}
+	
+	/// Substitutes the `var()` functions in `css` using `lookup`, which returns the value of a custom property or `None` if it has no (valid) value.
+	/// If `keepUnknown`, a `var()` of a custom property without a value is kept (with any `var()` functions in its fallback substituted); otherwise, it is replaced by its substituted fallback.
+	/// Returns `None` if a `var()` that is not kept has neither a value nor a fallback, as the property using it is then invalid at computed-value time.
+	/// Leading and trailing whitespace is removed.
+	pub(crate) fn substitute<'a, Lookup: Fn(&Atom) -> Option<&'a TokenSequence>>(css: &str, lookup: &Lookup, keepUnknown: bool) -> Option<Self>
+	{
+		let css = css.trim_matches(|character| matches!(character, ' ' | '\t' | '\n' | '\r' | '\x0C'));
+		
+		let mut parserInput = ParserInput::new(css);
+		let mut input = Parser::new(&mut parserInput);
+		
+		let start = input.state();
+		let first_token_type = match input.next_including_whitespace_and_comments()
+		{
+			Ok(token) => token.serialization_type(),
+			Err(_) => TokenSerializationType::nothing(),
+		};
+		input.reset(&start);
+		
+		let mut position = (input.position(), first_token_type);
+		let mut substituted = Self::empty();
+		let last_token_type = Self::substitute_block(&mut input, &mut position, &mut substituted, lookup, keepUnknown).ok()?;
+		substituted.push_from(position, &input, last_token_type);
+		Some(substituted)
+	}
+	
+	#[inline(always)]
+	fn empty() -> Self
+	{
+		Self
+		{
+			css: String::new(),
+			first_token_type: TokenSerializationType::nothing(),
+			last_token_type: TokenSerializationType::nothing(),
+		}
+	}
+	
+	/// Copies the tokens from `position` up to the current position of `input`, substituting any `var()` functions.
+	/// `position` is the start of the tokens not yet pushed to `substituted`, and the type of the first of them.
+	fn substitute_block<'i, 't, 'a, Lookup: Fn(&Atom) -> Option<&'a TokenSequence>>(input: &mut Parser<'i, 't>, position: &mut (SourcePosition, TokenSerializationType), substituted: &mut Self, lookup: &Lookup, keepUnknown: bool) -> Result<TokenSerializationType, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut last_token_type = TokenSerializationType::nothing();
+		let mut set_position_at_next_iteration = false;
+		loop
+		{
+			let before_this_token = input.position();
+			let next = input.next_including_whitespace_and_comments().cloned();
+			if set_position_at_next_iteration
+			{
+				let token_type = match next
+				{
+					Ok(ref token) => token.serialization_type(),
+					Err(_) => TokenSerializationType::nothing(),
+				};
+				*position = (before_this_token, token_type);
+				set_position_at_next_iteration = false;
+			}
+			
+			let token = match next
+			{
+				Ok(token) => token,
+				Err(_) => break,
+			};
+			
+			match token
+			{
+				Token::Function(ref name) if name.eq_ignore_ascii_case("var") =>
+				{
+					let mut kept = false;
+					input.parse_nested_block(|input|
+					{
+						let name = Atom::from(input.expect_ident_cloned()?);
+						let value = lookup(&name);
+						
+						if value.is_none() && keepUnknown
+						{
+							// The `var()` function, up to the end of its fallback, is copied with the tokens before it.
+							kept = true;
+							return Self::substitute_block(input, position, substituted, lookup, keepUnknown).map(|_| ())
+						}
+						
+						substituted.push(input.slice(position.0 .. before_this_token), position.1, last_token_type);
+						match value
+						{
+							Some(value) =>
+							{
+								substituted.push(&value.css, value.first_token_type, value.last_token_type);
+								last_token_type = value.last_token_type;
+								
+								// Skip the fallback, which would otherwise be an error as not all of the nested block was consumed.
+								while input.next().is_ok()
+								{
+								}
+							}
+							
+							None =>
+							{
+								input.expect_comma()?;
+								input.skip_whitespace();
+								let after_comma = input.state();
+								let first_token_type = match input.next_including_whitespace_and_comments()
+								{
+									Ok(token) => token.serialization_type(),
+									Err(_) => TokenSerializationType::nothing(),
+								};
+								input.reset(&after_comma);
+								
+								let mut position = (after_comma.position(), first_token_type);
+								last_token_type = Self::substitute_block(input, &mut position, substituted, lookup, keepUnknown)?;
+								substituted.push_from(position, input, last_token_type);
+							}
+						}
+						Ok(())
+					})?;
+					
+					if kept
+					{
+						last_token_type = Token::CloseParenthesis.serialization_type();
+					}
+					else
+					{
+						set_position_at_next_iteration = true
+					}
+				}
+				
+				Token::Function(_) | Token::ParenthesisBlock | Token::CurlyBracketBlock | Token::SquareBracketBlock =>
+				{
+					input.parse_nested_block(|input| Self::substitute_block(input, position, substituted, lookup, keepUnknown))?;
+					
+					// The same type as for CloseCurlyBracket and CloseSquareBracket.
+					last_token_type = Token::CloseParenthesis.serialization_type();
+				}
+				
+				_ => last_token_type = token.serialization_type(),
+			}
+		}
+		
+		Ok(last_token_type)
+	}
+	
+	#[inline(always)]
+	fn push_from<'i, 't>(&mut self, position: (SourcePosition, TokenSerializationType), input: &Parser<'i, 't>, last_token_type: TokenSerializationType)
+	{
+		self.push(input.slice_from(position.0), position.1, last_token_type)
+	}
+	
+	fn push(&mut self, css: &str, first_token_type: TokenSerializationType, last_token_type: TokenSerializationType)
+	{
+		// This happens between two `var()` functions, eg `var(--a)var(--b)`, and for empty values; the token types are then meaningless.
+		if css.is_empty()
+		{
+			return
+		}
+		
+		self.first_token_type.set_if_nothing(first_token_type);
+		
+		// If nothing has been pushed, `last_token_type` is nothing, and so no separator is needed.
+		if self.last_token_type.needs_separator_when_before(first_token_type)
+		{
+			self.css.push_str("/**/")
+		}
+		self.css.push_str(css);
+		self.last_token_type = last_token_type
+	}
+}
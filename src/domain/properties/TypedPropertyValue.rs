@@ -0,0 +1,687 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of a property that is parsed into a typed representation, rather than kept as a `SpecifiedValue`.
+/// Values are serialized in their shortest form.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TypedPropertyValue
+{
+	AlignContent(ContentAlignment),
+	AlignItems(ItemAlignment),
+	AlignSelf(ItemAlignment),
+	Animation(Animation),
+	AnimationDelay(CommaSeparatedList<Delay>),
+	AnimationDirection(CommaSeparatedList<AnimationDirection>),
+	AnimationDuration(CommaSeparatedList<Duration>),
+	AnimationFillMode(CommaSeparatedList<AnimationFillMode>),
+	AnimationIterationCount(CommaSeparatedList<AnimationIterationCount>),
+	AnimationName(CommaSeparatedList<AnimationName>),
+	AnimationPlayState(CommaSeparatedList<AnimationPlayState>),
+	AnimationTimingFunction(CommaSeparatedList<EasingFunction>),
+	Background(Background),
+	BackgroundAttachment(CommaSeparatedList<Attachment>),
+	BackgroundClip(CommaSeparatedList<VisualBox>),
+	BackgroundColor(Colour),
+	BackgroundImage(CommaSeparatedList<LayerImage>),
+	BackgroundOrigin(CommaSeparatedList<VisualBox>),
+	BackgroundPosition(CommaSeparatedList<Position>),
+	BackgroundRepeat(CommaSeparatedList<LayerRepeat>),
+	BackgroundSize(CommaSeparatedList<LayerSize>),
+	ColumnGap(Gap),
+	Display(DisplayValue),
+	Flex(Flex),
+	FlexBasis(FlexBasis),
+	FlexDirection(FlexDirection),
+	FlexFlow(FlexFlow),
+	FlexGrow(CssUnsignedNumber),
+	FlexShrink(CssUnsignedNumber),
+	FlexWrap(FlexWrap),
+	Font(Font),
+	FontFamily(FontFamilyList),
+	FontFeatureSettings(FontFeatureSettings),
+	FontSize(FontSize),
+	FontStretch(FontStretch),
+	FontStyle(FontStyle),
+	FontVariantAlternates(FontVariantAlternates),
+	FontVariantCaps(FontVariantCaps),
+	FontVariantEastAsian(FontVariantEastAsian),
+	FontVariantLigatures(FontVariantLigatures),
+	FontVariantNumeric(FontVariantNumeric),
+	FontVariantPosition(FontVariantPosition),
+	FontWeight(FontWeightValue),
+	Gap(Gaps),
+	Grid(Grid),
+	GridArea(GridArea),
+	GridAutoColumns(TrackSizeList),
+	GridAutoFlow(GridAutoFlow),
+	GridAutoRows(TrackSizeList),
+	GridColumn(GridPlacement),
+	GridColumnEnd(GridLine),
+	GridColumnStart(GridLine),
+	GridRow(GridPlacement),
+	GridRowEnd(GridLine),
+	GridRowStart(GridLine),
+	GridTemplate(GridTemplate),
+	GridTemplateAreas(GridTemplateAreas),
+	GridTemplateColumns(GridTemplateTracks),
+	GridTemplateRows(GridTemplateTracks),
+	JustifyContent(ContentAlignment),
+	JustifyItems(ItemAlignment),
+	JustifySelf(ItemAlignment),
+	LineHeight(LineHeight),
+	Mask(Mask),
+	MaskClip(CommaSeparatedList<VisualBox>),
+	MaskComposite(CommaSeparatedList<CompositingOperator>),
+	MaskImage(CommaSeparatedList<LayerImage>),
+	MaskMode(CommaSeparatedList<MaskingMode>),
+	MaskOrigin(CommaSeparatedList<VisualBox>),
+	MaskPosition(CommaSeparatedList<Position>),
+	MaskRepeat(CommaSeparatedList<LayerRepeat>),
+	MaskSize(CommaSeparatedList<LayerSize>),
+	Order(i32),
+	PlaceContent(PlaceContent),
+	PlaceItems(PlaceItems),
+	PlaceSelf(PlaceSelf),
+	Rotate(Rotate),
+	RowGap(Gap),
+	Scale(Scale),
+	Transform(TransformList),
+	Transition(Transition),
+	TransitionBehavior(CommaSeparatedList<TransitionBehavior>),
+	TransitionDelay(CommaSeparatedList<Delay>),
+	TransitionDuration(CommaSeparatedList<Duration>),
+	TransitionProperty(CommaSeparatedList<TransitionProperty>),
+	TransitionTimingFunction(CommaSeparatedList<EasingFunction>),
+	Translate(Translate),
+}
+
+impl ToCss for TypedPropertyValue
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TypedPropertyValue::*;
+		
+		match *self
+		{
+			AlignContent(ref value) => value.to_css(dest),
+			AlignItems(ref value) => value.to_css(dest),
+			AlignSelf(ref value) => value.to_css(dest),
+			Animation(ref value) => value.to_css(dest),
+			AnimationDelay(ref value) => value.to_css(dest),
+			AnimationDirection(ref value) => value.to_css(dest),
+			AnimationDuration(ref value) => value.to_css(dest),
+			AnimationFillMode(ref value) => value.to_css(dest),
+			AnimationIterationCount(ref value) => value.to_css(dest),
+			AnimationName(ref value) => value.to_css(dest),
+			AnimationPlayState(ref value) => value.to_css(dest),
+			AnimationTimingFunction(ref value) => value.to_css(dest),
+			Background(ref value) => value.to_css(dest),
+			BackgroundAttachment(ref value) => value.to_css(dest),
+			BackgroundClip(ref value) => value.to_css(dest),
+			BackgroundColor(ref value) => value.to_css(dest),
+			BackgroundImage(ref value) => value.to_css(dest),
+			BackgroundOrigin(ref value) => value.to_css(dest),
+			BackgroundPosition(ref value) => value.to_css(dest),
+			BackgroundRepeat(ref value) => value.to_css(dest),
+			BackgroundSize(ref value) => value.to_css(dest),
+			ColumnGap(ref value) => value.to_css(dest),
+			Display(ref value) => value.to_css(dest),
+			Flex(ref value) => value.to_css(dest),
+			FlexBasis(ref value) => value.to_css(dest),
+			FlexDirection(ref value) => value.to_css(dest),
+			FlexFlow(ref value) => value.to_css(dest),
+			FlexGrow(ref value) => value.to_css(dest),
+			FlexShrink(ref value) => value.to_css(dest),
+			FlexWrap(ref value) => value.to_css(dest),
+			Font(ref value) => value.to_css(dest),
+			FontFamily(ref value) => value.to_css(dest),
+			FontFeatureSettings(ref value) => value.to_css(dest),
+			FontSize(ref value) => value.to_css(dest),
+			FontStretch(ref value) => value.to_css(dest),
+			FontStyle(ref value) => value.to_css(dest),
+			FontVariantAlternates(ref value) => value.to_css(dest),
+			FontVariantCaps(ref value) => value.to_css(dest),
+			FontVariantEastAsian(ref value) => value.to_css(dest),
+			FontVariantLigatures(ref value) => value.to_css(dest),
+			FontVariantNumeric(ref value) => value.to_css(dest),
+			FontVariantPosition(ref value) => value.to_css(dest),
+			FontWeight(ref value) => value.to_css(dest),
+			Gap(ref value) => value.to_css(dest),
+			Grid(ref value) => value.to_css(dest),
+			GridArea(ref value) => value.to_css(dest),
+			GridAutoColumns(ref value) => value.to_css(dest),
+			GridAutoFlow(ref value) => value.to_css(dest),
+			GridAutoRows(ref value) => value.to_css(dest),
+			GridColumn(ref value) => value.to_css(dest),
+			GridColumnEnd(ref value) => value.to_css(dest),
+			GridColumnStart(ref value) => value.to_css(dest),
+			GridRow(ref value) => value.to_css(dest),
+			GridRowEnd(ref value) => value.to_css(dest),
+			GridRowStart(ref value) => value.to_css(dest),
+			GridTemplate(ref value) => value.to_css(dest),
+			GridTemplateAreas(ref value) => value.to_css(dest),
+			GridTemplateColumns(ref value) => value.to_css(dest),
+			GridTemplateRows(ref value) => value.to_css(dest),
+			JustifyContent(ref value) => value.to_css(dest),
+			JustifyItems(ref value) => value.to_css(dest),
+			JustifySelf(ref value) => value.to_css(dest),
+			LineHeight(ref value) => value.to_css(dest),
+			Mask(ref value) => value.to_css(dest),
+			MaskClip(ref value) => value.to_css(dest),
+			MaskComposite(ref value) => value.to_css(dest),
+			MaskImage(ref value) => value.to_css(dest),
+			MaskMode(ref value) => value.to_css(dest),
+			MaskOrigin(ref value) => value.to_css(dest),
+			MaskPosition(ref value) => value.to_css(dest),
+			MaskRepeat(ref value) => value.to_css(dest),
+			MaskSize(ref value) => value.to_css(dest),
+			Order(ref value) => value.to_css(dest),
+			PlaceContent(ref value) => value.to_css(dest),
+			PlaceItems(ref value) => value.to_css(dest),
+			PlaceSelf(ref value) => value.to_css(dest),
+			Rotate(ref value) => value.to_css(dest),
+			RowGap(ref value) => value.to_css(dest),
+			Scale(ref value) => value.to_css(dest),
+			Transform(ref value) => value.to_css(dest),
+			Transition(ref value) => value.to_css(dest),
+			TransitionBehavior(ref value) => value.to_css(dest),
+			TransitionDelay(ref value) => value.to_css(dest),
+			TransitionDuration(ref value) => value.to_css(dest),
+			TransitionProperty(ref value) => value.to_css(dest),
+			TransitionTimingFunction(ref value) => value.to_css(dest),
+			Translate(ref value) => value.to_css(dest),
+		}
+	}
+}
+
+impl SimplifyCalcFunctions for TypedPropertyValue
+{
+	/// The `calc()` functions in gradients and the `font` shorthand are left alone.
+	fn simplify_calc_functions(&mut self)
+	{
+		use self::TypedPropertyValue::*;
+		
+		match *self
+		{
+			Animation(ref mut value) => value.simplify_calc_functions(),
+			
+			AnimationDelay(ref mut value) | TransitionDelay(ref mut value) => value.simplify_calc_functions(),
+			
+			AnimationDuration(ref mut value) | TransitionDuration(ref mut value) => value.simplify_calc_functions(),
+			
+			Background(ref mut value) => value.simplify_calc_functions(),
+			
+			BackgroundPosition(ref mut value) | MaskPosition(ref mut value) => value.simplify_calc_functions(),
+			
+			BackgroundSize(ref mut value) | MaskSize(ref mut value) => value.simplify_calc_functions(),
+			
+			ColumnGap(ref mut value) | RowGap(ref mut value) => value.simplify_calc_functions(),
+			
+			Flex(ref mut value) => value.simplify_calc_functions(),
+			
+			FlexBasis(ref mut value) => value.simplify_calc_functions(),
+			
+			FontSize(ref mut value) => value.simplify_calc_functions(),
+			
+			Gap(ref mut value) => value.simplify_calc_functions(),
+			
+			Grid(ref mut value) => value.simplify_calc_functions(),
+			
+			GridAutoColumns(ref mut value) | GridAutoRows(ref mut value) => value.simplify_calc_functions(),
+			
+			GridTemplate(ref mut value) => value.simplify_calc_functions(),
+			
+			GridTemplateColumns(ref mut value) | GridTemplateRows(ref mut value) => value.simplify_calc_functions(),
+			
+			LineHeight(ref mut value) => value.simplify_calc_functions(),
+			
+			Mask(ref mut value) => value.simplify_calc_functions(),
+			
+			Rotate(ref mut value) => value.simplify_calc_functions(),
+			
+			Transform(ref mut value) => value.simplify_calc_functions(),
+			
+			Transition(ref mut value) => value.simplify_calc_functions(),
+			
+			Translate(ref mut value) => value.simplify_calc_functions(),
+			
+			_ => (),
+		}
+	}
+}
+
+impl TypedPropertyValue
+{
+	/// Parses the value of the (unprefixed, lower case) property `name`, which must be all of `input`.
+	/// Returns `Ok(None)`, without consuming any input, if the property does not have a typed value.
+	pub(crate) fn parse<'i, 't>(name: &str, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Option<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TypedPropertyValue::*;
+		
+		macro_rules! parse
+		{
+			($variant: ident, $type: ty) =>
+			{
+				<$type as Parse>::parse(context, input).map($variant)?
+			}
+		}
+		
+		macro_rules! times
+		{
+			($variant: ident) =>
+			{
+				$variant(CommaSeparatedList(input.parse_comma_separated(|input| TimeUnit::parse_one_outside_calc_function(context, input))?))
+			}
+		}
+		
+		let value = match name
+		{
+			"align-content" => AlignContent(ContentAlignment::parse_longhand(AlignmentProperty::align_content, input)?),
+			"align-items" => AlignItems(ItemAlignment::parse_longhand(AlignmentProperty::align_items, input)?),
+			"align-self" => AlignSelf(ItemAlignment::parse_longhand(AlignmentProperty::align_self, input)?),
+			"animation" => parse!(Animation, self::Animation),
+			"animation-delay" => times!(AnimationDelay),
+			"animation-direction" => parse!(AnimationDirection, CommaSeparatedList<self::AnimationDirection>),
+			"animation-duration" => times!(AnimationDuration),
+			"animation-fill-mode" => parse!(AnimationFillMode, CommaSeparatedList<self::AnimationFillMode>),
+			"animation-iteration-count" => parse!(AnimationIterationCount, CommaSeparatedList<self::AnimationIterationCount>),
+			"animation-name" => parse!(AnimationName, CommaSeparatedList<self::AnimationName>),
+			"animation-play-state" => parse!(AnimationPlayState, CommaSeparatedList<self::AnimationPlayState>),
+			"animation-timing-function" => parse!(AnimationTimingFunction, CommaSeparatedList<EasingFunction>),
+			"background" => parse!(Background, self::Background),
+			"background-attachment" => parse!(BackgroundAttachment, CommaSeparatedList<Attachment>),
+			"background-clip" => BackgroundClip(LayerKind::Background.parse_clips(input)?),
+			"background-color" => parse!(BackgroundColor, Colour),
+			"background-image" => parse!(BackgroundImage, CommaSeparatedList<LayerImage>),
+			"background-origin" => BackgroundOrigin(LayerKind::Background.parse_origins(input)?),
+			"background-position" => parse!(BackgroundPosition, CommaSeparatedList<Position>),
+			"background-repeat" => parse!(BackgroundRepeat, CommaSeparatedList<LayerRepeat>),
+			"background-size" => parse!(BackgroundSize, CommaSeparatedList<LayerSize>),
+			"column-gap" => parse!(ColumnGap, self::Gap),
+			"display" => parse!(Display, DisplayValue),
+			"flex" => parse!(Flex, self::Flex),
+			"flex-basis" => parse!(FlexBasis, self::FlexBasis),
+			"flex-direction" => parse!(FlexDirection, self::FlexDirection),
+			"flex-flow" => parse!(FlexFlow, self::FlexFlow),
+			"flex-grow" => FlexGrow(self::Flex::parse_factor(input)?),
+			"flex-shrink" => FlexShrink(self::Flex::parse_factor(input)?),
+			"flex-wrap" => parse!(FlexWrap, self::FlexWrap),
+			"font" => parse!(Font, self::Font),
+			"font-family" => parse!(FontFamily, FontFamilyList),
+			"font-feature-settings" => parse!(FontFeatureSettings, self::FontFeatureSettings),
+			"font-size" => parse!(FontSize, self::FontSize),
+			"font-stretch" => parse!(FontStretch, self::FontStretch),
+			"font-style" => parse!(FontStyle, self::FontStyle),
+			"font-variant-alternates" => parse!(FontVariantAlternates, self::FontVariantAlternates),
+			"font-variant-caps" => parse!(FontVariantCaps, self::FontVariantCaps),
+			"font-variant-east-asian" => parse!(FontVariantEastAsian, self::FontVariantEastAsian),
+			"font-variant-ligatures" => parse!(FontVariantLigatures, self::FontVariantLigatures),
+			"font-variant-numeric" => parse!(FontVariantNumeric, self::FontVariantNumeric),
+			"font-variant-position" => parse!(FontVariantPosition, self::FontVariantPosition),
+			"font-weight" => parse!(FontWeight, FontWeightValue),
+			"gap" => parse!(Gap, Gaps),
+			"grid" => parse!(Grid, self::Grid),
+			"grid-area" => parse!(GridArea, self::GridArea),
+			"grid-auto-columns" => parse!(GridAutoColumns, TrackSizeList),
+			"grid-auto-flow" => parse!(GridAutoFlow, self::GridAutoFlow),
+			"grid-auto-rows" => parse!(GridAutoRows, TrackSizeList),
+			"grid-column" => parse!(GridColumn, GridPlacement),
+			"grid-column-end" => parse!(GridColumnEnd, GridLine),
+			"grid-column-start" => parse!(GridColumnStart, GridLine),
+			"grid-row" => parse!(GridRow, GridPlacement),
+			"grid-row-end" => parse!(GridRowEnd, GridLine),
+			"grid-row-start" => parse!(GridRowStart, GridLine),
+			"grid-template" => parse!(GridTemplate, self::GridTemplate),
+			"grid-template-areas" => parse!(GridTemplateAreas, self::GridTemplateAreas),
+			"grid-template-columns" => parse!(GridTemplateColumns, GridTemplateTracks),
+			"grid-template-rows" => parse!(GridTemplateRows, GridTemplateTracks),
+			"justify-content" => JustifyContent(ContentAlignment::parse_longhand(AlignmentProperty::justify_content, input)?),
+			"justify-items" => JustifyItems(ItemAlignment::parse_longhand(AlignmentProperty::justify_items, input)?),
+			"justify-self" => JustifySelf(ItemAlignment::parse_longhand(AlignmentProperty::justify_self, input)?),
+			"line-height" => parse!(LineHeight, self::LineHeight),
+			"mask" => parse!(Mask, self::Mask),
+			"mask-clip" => MaskClip(LayerKind::Mask.parse_clips(input)?),
+			"mask-composite" => parse!(MaskComposite, CommaSeparatedList<CompositingOperator>),
+			"mask-image" => parse!(MaskImage, CommaSeparatedList<LayerImage>),
+			"mask-mode" => parse!(MaskMode, CommaSeparatedList<MaskingMode>),
+			"mask-origin" => MaskOrigin(LayerKind::Mask.parse_origins(input)?),
+			"mask-position" => parse!(MaskPosition, CommaSeparatedList<Position>),
+			"mask-repeat" => parse!(MaskRepeat, CommaSeparatedList<LayerRepeat>),
+			"mask-size" => parse!(MaskSize, CommaSeparatedList<LayerSize>),
+			"order" => Order(input.expect_integer()?),
+			"place-content" => parse!(PlaceContent, self::PlaceContent),
+			"place-items" => parse!(PlaceItems, self::PlaceItems),
+			"place-self" => parse!(PlaceSelf, self::PlaceSelf),
+			"rotate" => parse!(Rotate, self::Rotate),
+			"row-gap" => parse!(RowGap, self::Gap),
+			"scale" => parse!(Scale, self::Scale),
+			"transform" => parse!(Transform, TransformList),
+			"transition" => parse!(Transition, self::Transition),
+			"transition-behavior" => parse!(TransitionBehavior, CommaSeparatedList<self::TransitionBehavior>),
+			"transition-delay" => times!(TransitionDelay),
+			"transition-duration" => times!(TransitionDuration),
+			"transition-property" =>
+			{
+				let properties = <CommaSeparatedList<self::TransitionProperty> as Parse>::parse(context, input)?;
+				self::TransitionProperty::validate(&properties, input)?;
+				TransitionProperty(properties)
+			}
+			"transition-timing-function" => parse!(TransitionTimingFunction, CommaSeparatedList<EasingFunction>),
+			"translate" => parse!(Translate, self::Translate),
+			
+			_ => return Ok(None),
+		};
+		
+		input.expect_exhausted()?;
+		
+		Ok(Some(value))
+	}
+	
+	/// The name of the property this is a value of.
+	pub fn name(&self) -> &'static str
+	{
+		use self::TypedPropertyValue::*;
+		
+		match *self
+		{
+			AlignContent(_) => "align-content",
+			AlignItems(_) => "align-items",
+			AlignSelf(_) => "align-self",
+			Animation(_) => "animation",
+			AnimationDelay(_) => "animation-delay",
+			AnimationDirection(_) => "animation-direction",
+			AnimationDuration(_) => "animation-duration",
+			AnimationFillMode(_) => "animation-fill-mode",
+			AnimationIterationCount(_) => "animation-iteration-count",
+			AnimationName(_) => "animation-name",
+			AnimationPlayState(_) => "animation-play-state",
+			AnimationTimingFunction(_) => "animation-timing-function",
+			Background(_) => "background",
+			BackgroundAttachment(_) => "background-attachment",
+			BackgroundClip(_) => "background-clip",
+			BackgroundColor(_) => "background-color",
+			BackgroundImage(_) => "background-image",
+			BackgroundOrigin(_) => "background-origin",
+			BackgroundPosition(_) => "background-position",
+			BackgroundRepeat(_) => "background-repeat",
+			BackgroundSize(_) => "background-size",
+			ColumnGap(_) => "column-gap",
+			Display(_) => "display",
+			Flex(_) => "flex",
+			FlexBasis(_) => "flex-basis",
+			FlexDirection(_) => "flex-direction",
+			FlexFlow(_) => "flex-flow",
+			FlexGrow(_) => "flex-grow",
+			FlexShrink(_) => "flex-shrink",
+			FlexWrap(_) => "flex-wrap",
+			Font(_) => "font",
+			FontFamily(_) => "font-family",
+			FontFeatureSettings(_) => "font-feature-settings",
+			FontSize(_) => "font-size",
+			FontStretch(_) => "font-stretch",
+			FontStyle(_) => "font-style",
+			FontVariantAlternates(_) => "font-variant-alternates",
+			FontVariantCaps(_) => "font-variant-caps",
+			FontVariantEastAsian(_) => "font-variant-east-asian",
+			FontVariantLigatures(_) => "font-variant-ligatures",
+			FontVariantNumeric(_) => "font-variant-numeric",
+			FontVariantPosition(_) => "font-variant-position",
+			FontWeight(_) => "font-weight",
+			Gap(_) => "gap",
+			Grid(_) => "grid",
+			GridArea(_) => "grid-area",
+			GridAutoColumns(_) => "grid-auto-columns",
+			GridAutoFlow(_) => "grid-auto-flow",
+			GridAutoRows(_) => "grid-auto-rows",
+			GridColumn(_) => "grid-column",
+			GridColumnEnd(_) => "grid-column-end",
+			GridColumnStart(_) => "grid-column-start",
+			GridRow(_) => "grid-row",
+			GridRowEnd(_) => "grid-row-end",
+			GridRowStart(_) => "grid-row-start",
+			GridTemplate(_) => "grid-template",
+			GridTemplateAreas(_) => "grid-template-areas",
+			GridTemplateColumns(_) => "grid-template-columns",
+			GridTemplateRows(_) => "grid-template-rows",
+			JustifyContent(_) => "justify-content",
+			JustifyItems(_) => "justify-items",
+			JustifySelf(_) => "justify-self",
+			LineHeight(_) => "line-height",
+			Mask(_) => "mask",
+			MaskClip(_) => "mask-clip",
+			MaskComposite(_) => "mask-composite",
+			MaskImage(_) => "mask-image",
+			MaskMode(_) => "mask-mode",
+			MaskOrigin(_) => "mask-origin",
+			MaskPosition(_) => "mask-position",
+			MaskRepeat(_) => "mask-repeat",
+			MaskSize(_) => "mask-size",
+			Order(_) => "order",
+			PlaceContent(_) => "place-content",
+			PlaceItems(_) => "place-items",
+			PlaceSelf(_) => "place-self",
+			Rotate(_) => "rotate",
+			RowGap(_) => "row-gap",
+			Scale(_) => "scale",
+			Transform(_) => "transform",
+			Transition(_) => "transition",
+			TransitionBehavior(_) => "transition-behavior",
+			TransitionDelay(_) => "transition-delay",
+			TransitionDuration(_) => "transition-duration",
+			TransitionProperty(_) => "transition-property",
+			TransitionTimingFunction(_) => "transition-timing-function",
+			Translate(_) => "translate",
+		}
+	}
+	
+	/// If this is the value of a shorthand property, its expansion into longhand property values.
+	/// Returns `None` for longhand properties, and for shorthand values that can not be expanded (such as a system font).
+	pub fn longhands(&self) -> Option<Vec<TypedPropertyValue>>
+	{
+		use self::TypedPropertyValue::*;
+		
+		match *self
+		{
+			Animation(ref value) => Some(value.longhands()),
+			Background(ref value) => Some(value.longhands()),
+			Flex(ref value) => Some(value.longhands()),
+			FlexFlow(ref value) => Some(value.longhands()),
+			Font(ref value) => value.longhands(),
+			Gap(ref value) => Some(value.longhands()),
+			Grid(ref value) => Some(value.longhands()),
+			GridArea(ref value) => Some(value.longhands()),
+			GridColumn(ref value) => Some(value.longhands(false)),
+			GridRow(ref value) => Some(value.longhands(true)),
+			GridTemplate(ref value) => Some(value.longhands()),
+			Mask(ref value) => Some(value.longhands()),
+			PlaceContent(ref value) => Some(value.longhands()),
+			PlaceItems(ref value) => Some(value.longhands()),
+			PlaceSelf(ref value) => Some(value.longhands()),
+			Transition(ref value) => Some(value.longhands()),
+			_ => None,
+		}
+	}
+	
+	/// The names of the longhand properties of the (unprefixed, lower case) shorthand property `name`, or `None` if it is not a shorthand with a typed value.
+	pub fn longhand_names(name: &str) -> Option<&'static [&'static str]>
+	{
+		match name
+		{
+			"animation" => Some(Animation::LonghandNames),
+			"background" => Some(Background::LonghandNames),
+			"flex" => Some(Flex::LonghandNames),
+			"flex-flow" => Some(FlexFlow::LonghandNames),
+			"font" => Some(Font::LonghandNames),
+			"gap" => Some(Gaps::LonghandNames),
+			"grid" => Some(Grid::LonghandNames),
+			"grid-area" => Some(GridArea::LonghandNames),
+			"grid-column" => Some(GridPlacement::ColumnLonghandNames),
+			"grid-row" => Some(GridPlacement::RowLonghandNames),
+			"grid-template" => Some(GridTemplate::LonghandNames),
+			"mask" => Some(Mask::LonghandNames),
+			"place-content" => Some(PlaceContent::LonghandNames),
+			"place-items" => Some(PlaceItems::LonghandNames),
+			"place-self" => Some(PlaceSelf::LonghandNames),
+			"transition" => Some(Transition::LonghandNames),
+			_ => None,
+		}
+	}
+	
+	/// The inverse of `longhands()`: collapses the values of all of the longhand properties of the (unprefixed, lower case) shorthand property `name`, in the order of `longhand_names(name)`, into a value of the shorthand.
+	/// Returns `None` if collapsing the shorthand is not supported, or if `longhands` are not values of its longhands in that order.
+	/// Currently supports `flex`, `flex-flow`, `gap`, `place-content`, `place-items` and `place-self`.
+	pub fn from_longhands(name: &str, longhands: &[TypedPropertyValue]) -> Option<Self>
+	{
+		use self::TypedPropertyValue::*;
+		
+		let value = match (name, longhands)
+		{
+			("flex", &[FlexGrow(grow), FlexShrink(shrink), FlexBasis(ref basis)]) => Flex(self::Flex { grow, shrink, basis: basis.clone() }),
+			
+			("flex-flow", &[FlexDirection(direction), FlexWrap(flexWrap)]) => FlexFlow(self::FlexFlow { direction, wrap: flexWrap }),
+			
+			("gap", &[RowGap(ref row), ColumnGap(ref column)]) => Gap(Gaps { row: row.clone(), column: column.clone() }),
+			
+			("place-content", &[AlignContent(align), JustifyContent(justify)]) => PlaceContent(self::PlaceContent { align, justify }),
+			
+			("place-items", &[AlignItems(align), JustifyItems(justify)]) => PlaceItems(self::PlaceItems { align, justify }),
+			
+			("place-self", &[AlignSelf(align), JustifySelf(justify)]) => PlaceSelf(self::PlaceSelf { align, justify }),
+			
+			_ => return None,
+		};
+		
+		Some(value)
+	}
+	
+	/// Calls `report` with each likely mistake in this value; `keyframes_names` are the names of the stylesheet's `@keyframes` rules.
+	pub(crate) fn lint<Report: FnMut(LintKind)>(&self, keyframes_names: &HashSet<Atom>, report: &mut Report)
+	{
+		use self::TypedPropertyValue::*;
+		
+		let mut animationName = |name: &self::AnimationName| if let Some(name) = name.keyframes_name()
+		{
+			if !keyframes_names.contains(name)
+			{
+				report(LintKind::AnimationNameWithoutKeyframes(name.clone()))
+			}
+		};
+		
+		match *self
+		{
+			Animation(ref value) => for animation in value.animations.iter()
+			{
+				animationName(&animation.name)
+			},
+			
+			AnimationName(ref value) => for name in value.iter()
+			{
+				animationName(name)
+			},
+			
+			_ => (),
+		}
+	}
+	
+	/// Calls `report` if `value`, the untyped value of the (unprefixed, lower case) property `name`, is an invalid box alignment value, such as `justify-items: left baseline`, or has invalid grid template areas, such as `grid-template-areas: "a b" "b a"`.
+	/// Invalid values are kept as a `SpecifiedValue` when parsing, so would otherwise go unnoticed; values using `var()` are not checked, as they can only be checked once the variables are substituted.
+	pub(crate) fn lint_specified_value<Report: FnMut(LintKind)>(name: &Atom, value: &SpecifiedValue, report: &mut Report)
+	{
+		let isBoxAlignment = match &**name
+		{
+			"place-content" | "place-items" | "place-self" => true,
+			_ => AlignmentProperty::from_ident(&CowRcStr::from(&**name)).is_ok(),
+		};
+		let isGridTemplate = matches!(&**name, "grid-template-areas" | "grid-template" | "grid");
+		if !isBoxAlignment && !isGridTemplate
+		{
+			return
+		}
+		
+		if value.hasReferences()
+		{
+			return
+		}
+		
+		let context = ParserContext::new(CssRuleType::Style);
+		let mut parserInput = ParserInput::new(&value.originalCss);
+		let mut input = Parser::new(&mut parserInput);
+		let error = match input.parse_entirely(|input| Self::parse(name, &context, input))
+		{
+			Ok(_) => return,
+			Err(error) => CustomParseError::from(error.kind),
+		};
+		
+		let property = name.clone();
+		let value = value.originalCss.trim().to_owned();
+		if isBoxAlignment
+		{
+			report(LintKind::InvalidBoxAlignment { property, value, reason: error.to_string() })
+		}
+		else
+		{
+			// Other values of `grid-template` and `grid` that are not understood are not necessarily mistakes.
+			match error
+			{
+				CustomParseError::InvalidGridTemplateAreasString(_) | CustomParseError::GridTemplateAreasRowsMustHaveTheSameNumberOfColumns | CustomParseError::GridTemplateAreaMustBeRectangular(_) => report(LintKind::InvalidGridTemplateAreas { property, value, reason: error.to_string() }),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	/// Calls `visitor` with each url in this value, eg so it can be rebased.
+	#[inline(always)]
+	pub fn urls_mut<Visitor: FnMut(&mut SpecifiedUrl)>(&mut self, visitor: &mut Visitor)
+	{
+		self.images_mut(&mut |image| image.urls_mut(visitor))
+	}
+	
+	/// Calls `visitor` with each position of a background layer in this value.
+	pub fn background_positions_mut<Visitor: FnMut(&mut Position)>(&mut self, visitor: &mut Visitor)
+	{
+		use self::TypedPropertyValue::*;
+		
+		match *self
+		{
+			Background(ref mut value) => for layer in value.layers.iter_mut()
+			{
+				visitor(&mut layer.position)
+			},
+			
+			BackgroundPosition(ref mut value) => for position in value.0.iter_mut()
+			{
+				visitor(position)
+			},
+			
+			_ => (),
+		}
+	}
+	
+	/// Calls `visitor` with each image in this value, such as those of a `background`'s layers.
+	pub fn images_mut<Visitor: FnMut(&mut Image)>(&mut self, visitor: &mut Visitor)
+	{
+		use self::TypedPropertyValue::*;
+		
+		match *self
+		{
+			Background(ref mut value) => for layer in value.layers.iter_mut()
+			{
+				layer.image.images_mut(visitor)
+			},
+			
+			Mask(ref mut value) => for layer in value.layers.iter_mut()
+			{
+				layer.image.images_mut(visitor)
+			},
+			
+			BackgroundImage(ref mut value) | MaskImage(ref mut value) => for image in value.0.iter_mut()
+			{
+				image.images_mut(visitor)
+			},
+			
+			_ => (),
+		}
+	}
+}
@@ -7,6 +7,9 @@ pub enum UnparsedPropertyValue
 {
 	CssWideKeyword(CssWideKeyword),
 	SpecifiedValue(SpecifiedValue),
+	
+	/// Only some properties have typed values; values that use `var()`, or that the typed parser does not understand, are kept as a `SpecifiedValue`.
+	Typed(TypedPropertyValue),
 }
 
 impl ToCss for UnparsedPropertyValue
@@ -20,6 +23,25 @@ impl ToCss for UnparsedPropertyValue
 		{
 			CssWideKeyword(cssWideKeyWord) => cssWideKeyWord.to_css(dest),
 			SpecifiedValue(ref specifiedValue) => specifiedValue.to_css(dest),
+			Typed(ref typedPropertyValue) => typedPropertyValue.to_css(dest),
 		}
 	}
 }
+
+impl UnparsedPropertyValue
+{
+	/// Parses `css` as the value (without any importance) of the property `name` (unprefixed, and lower case unless a custom property), as when parsing a stylesheet.
+	pub(crate) fn parse(vendor_prefix: &Option<VendorPrefix>, name: &Atom, css: &str) -> Result<Self, CssError>
+	{
+		CssError::parse_entirely(css, |input|
+		{
+			let context = ParserContext::new(CssRuleType::Style);
+			let propertyDeclarationParser: PropertyDeclarationParser<Importance> = PropertyDeclarationParser
+			{
+				context: &context,
+				marker: PhantomData,
+			};
+			propertyDeclarationParser.parse_unparsed_value(vendor_prefix, name, input)
+		})
+	}
+}
@@ -0,0 +1,92 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	AlignmentProperty:
+	"align-content" => align_content,
+	"justify-content" => justify_content,
+	"align-items" => align_items,
+	"justify-items" => justify_items,
+	"align-self" => align_self,
+	"justify-self" => justify_self,
+}
+
+impl AlignmentProperty
+{
+	/// Whether this property aligns in the inline axis, and so accepts `left` and `right`.
+	#[inline(always)]
+	pub fn isJustify(&self) -> bool
+	{
+		use self::AlignmentProperty::*;
+		
+		match *self
+		{
+			justify_content | justify_items | justify_self => true,
+			align_content | align_items | align_self => false,
+		}
+	}
+	
+	/// Whether this property accepts a baseline position; `justify-content` does not.
+	#[inline(always)]
+	pub fn allowsBaseline(&self) -> bool
+	{
+		*self != AlignmentProperty::justify_content
+	}
+	
+	/// Whether this property accepts `auto`; only `align-self` and `justify-self` do.
+	#[inline(always)]
+	pub fn allowsAuto(&self) -> bool
+	{
+		matches!(*self, AlignmentProperty::align_self | AlignmentProperty::justify_self)
+	}
+	
+	/// Whether this property accepts `legacy`; only `justify-items` does.
+	#[inline(always)]
+	pub fn allowsLegacy(&self) -> bool
+	{
+		*self == AlignmentProperty::justify_items
+	}
+	
+	#[inline(always)]
+	pub(crate) fn keywordIsNotAllowed<'i, 't>(&self, input: &Parser<'i, 't>, keyword: CowRcStr<'i>) -> ParseError<'i, CustomParseError<'i>>
+	{
+		input.new_custom_error(CustomParseError::AlignmentKeywordIsNotAllowed(self.to_str(), keyword))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn validateLeftOrRight<'i, 't>(&self, isLeftOrRight: bool, keyword: &'static str, input: &Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		if isLeftOrRight && !self.isJustify()
+		{
+			Err(self.keywordIsNotAllowed(input, CowRcStr::from(keyword)))
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+	
+	/// A baseline position is a complete value on its own; gives a clearer error than an unexpected token for mistakes such as `left baseline`.
+	pub(crate) fn validateNoBaselineCombination<'i, 't>(&self, isBaseline: bool, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		let isCombined = if isBaseline
+		{
+			!input.is_exhausted()
+		}
+		else
+		{
+			BaselinePosition::isNext(input)
+		};
+		
+		if isCombined
+		{
+			Err(input.new_custom_error(CustomParseError::BaselinePositionCanNotBeCombinedWithOtherKeywords(self.to_str())))
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+}
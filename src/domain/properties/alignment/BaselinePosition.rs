@@ -0,0 +1,62 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `<baseline-position>`: `baseline` (which is the same as `first baseline`) or `last baseline`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BaselinePosition
+{
+	first,
+	
+	last,
+}
+
+impl ToCss for BaselinePosition
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			BaselinePosition::first => dest.write_str("baseline"),
+			BaselinePosition::last => dest.write_str("last baseline"),
+		}
+	}
+}
+
+impl BaselinePosition
+{
+	/// Parses `baseline`, `first baseline` or `last baseline`.
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::BaselinePosition::*;
+		
+		let ident = input.expect_ident()?.clone();
+		let position = match_ignore_ascii_case!
+		{
+			&*ident,
+			
+			"baseline" => return Ok(first),
+			
+			"first" => first,
+			
+			"last" => last,
+			
+			_ => return Err(input.new_unexpected_token_error(Token::Ident(ident.clone()))),
+		};
+		input.expect_ident_matching("baseline")?;
+		Ok(position)
+	}
+	
+	/// Whether the next token starts a baseline position; nothing is consumed.
+	pub(crate) fn isNext<'i, 't>(input: &mut Parser<'i, 't>) -> bool
+	{
+		let state = input.state();
+		let isNext = match input.next()
+		{
+			Ok(Token::Ident(ident)) => ident.eq_ignore_ascii_case("baseline") || ident.eq_ignore_ascii_case("first") || ident.eq_ignore_ascii_case("last"),
+			_ => false,
+		};
+		input.reset(&state);
+		isNext
+	}
+}
@@ -0,0 +1,105 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `align-content` or `justify-content` properties: https://drafts.csswg.org/css-align/#propdef-align-content
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ContentAlignment
+{
+	normal,
+	
+	/// Only valid for `align-content`.
+	Baseline(BaselinePosition),
+	
+	Distribution(ContentDistribution),
+	
+	/// `left` and `right` are only valid for `justify-content`.
+	Position(Option<OverflowPosition>, ContentPosition),
+}
+
+impl Default for ContentAlignment
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		ContentAlignment::normal
+	}
+}
+
+impl ToCss for ContentAlignment
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::ContentAlignment::*;
+		
+		match *self
+		{
+			normal => dest.write_str("normal"),
+			Baseline(baseline) => baseline.to_css(dest),
+			Distribution(distribution) => distribution.to_css(dest),
+			Position(overflow, position) =>
+			{
+				if let Some(overflow) = overflow
+				{
+					overflow.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				position.to_css(dest)
+			}
+		}
+	}
+}
+
+impl ContentAlignment
+{
+	/// Parses the whole value of the `property` longhand (which must be `align-content` or `justify-content`).
+	pub(crate) fn parse_longhand<'i, 't>(property: AlignmentProperty, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let value = Self::parse_for(property, input)?;
+		property.validateNoBaselineCombination(value.isBaseline(), input)?;
+		Ok(value)
+	}
+	
+	/// Parses a value for `property` (which must be `align-content` or `justify-content`).
+	pub(crate) fn parse_for<'i, 't>(property: AlignmentProperty, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::ContentAlignment::*;
+		
+		if let Ok(baseline) = input.r#try(|input| BaselinePosition::parse(input))
+		{
+			return if property.allowsBaseline()
+			{
+				Ok(Baseline(baseline))
+			}
+			else
+			{
+				Err(property.keywordIsNotAllowed(input, CowRcStr::from("baseline")))
+			}
+		}
+		
+		let overflow = input.r#try(|input| OverflowPosition::parse(input)).ok();
+		if overflow.is_none()
+		{
+			if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+			{
+				return Ok(normal)
+			}
+			
+			if let Ok(distribution) = input.r#try(|input| ContentDistribution::parse(input))
+			{
+				return Ok(Distribution(distribution))
+			}
+		}
+		
+		let position = ContentPosition::parse(input)?;
+		property.validateLeftOrRight(position.isLeftOrRight(), position.to_str(), input)?;
+		Ok(Position(overflow, position))
+	}
+	
+	/// Whether this is a baseline position.
+	#[inline(always)]
+	pub fn isBaseline(&self) -> bool
+	{
+		matches!(*self, ContentAlignment::Baseline(_))
+	}
+}
@@ -0,0 +1,25 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	ContentPosition:
+	"center" => center,
+	"start" => start,
+	"end" => end,
+	"flex-start" => flex_start,
+	"flex-end" => flex_end,
+	"left" => left,
+	"right" => right,
+}
+
+impl ContentPosition
+{
+	/// `left` and `right` are only valid in the inline axis, ie for `justify-content`.
+	#[inline(always)]
+	pub fn isLeftOrRight(&self) -> bool
+	{
+		matches!(*self, ContentPosition::left | ContentPosition::right)
+	}
+}
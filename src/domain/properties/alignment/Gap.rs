@@ -0,0 +1,58 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `row-gap` or `column-gap` properties.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Gap
+{
+	normal,
+	
+	LengthOrPercentage(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl Default for Gap
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Gap::normal
+	}
+}
+
+impl ToCss for Gap
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			Gap::normal => dest.write_str("normal"),
+			Gap::LengthOrPercentage(ref length) => serialize_length_or_percentage(length, dest),
+		}
+	}
+}
+
+impl Parse for Gap
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Gap::normal)
+		}
+		
+		LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(Gap::LengthOrPercentage)
+	}
+}
+
+impl SimplifyCalcFunctions for Gap
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let Gap::LengthOrPercentage(ref mut length) = *self
+		{
+			length.simplify_calc_functions()
+		}
+	}
+}
@@ -0,0 +1,77 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `gap` shorthand property, such as `1em 2em`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Gaps
+{
+	pub row: Gap,
+	
+	pub column: Gap,
+}
+
+impl ToCss for Gaps
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.row.to_css(dest)?;
+		if self.column != self.row
+		{
+			dest.write_char(' ')?;
+			self.column.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl Parse for Gaps
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let row = Gap::parse(context, input)?;
+		let column = if input.is_exhausted()
+		{
+			row.clone()
+		}
+		else
+		{
+			Gap::parse(context, input)?
+		};
+		
+		Ok
+		(
+			Self
+			{
+				row,
+				column,
+			}
+		)
+	}
+}
+
+impl SimplifyCalcFunctions for Gaps
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.row.simplify_calc_functions();
+		self.column.simplify_calc_functions()
+	}
+}
+
+impl Gaps
+{
+	/// The longhand properties set by this shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"row-gap",
+		"column-gap",
+	];
+	
+	/// Expands into longhand property values; an omitted column gap copies the row gap.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		vec![TypedPropertyValue::RowGap(self.row.clone()), TypedPropertyValue::ColumnGap(self.column.clone())]
+	}
+}
@@ -0,0 +1,169 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `align-items`, `justify-items`, `align-self` or `justify-self` properties: https://drafts.csswg.org/css-align/#propdef-justify-self
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ItemAlignment
+{
+	/// Only valid for `align-self` and `justify-self`.
+	auto,
+	
+	normal,
+	
+	stretch,
+	
+	Baseline(BaselinePosition),
+	
+	/// `left` and `right` are only valid for `justify-items` and `justify-self`.
+	Position(Option<OverflowPosition>, SelfPosition),
+	
+	/// Only valid for `justify-items`; the position, if any, is `left`, `right` or `center`.
+	legacy(Option<SelfPosition>),
+}
+
+impl ToCss for ItemAlignment
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::ItemAlignment::*;
+		
+		match *self
+		{
+			auto => dest.write_str("auto"),
+			normal => dest.write_str("normal"),
+			stretch => dest.write_str("stretch"),
+			Baseline(baseline) => baseline.to_css(dest),
+			Position(overflow, position) =>
+			{
+				if let Some(overflow) = overflow
+				{
+					overflow.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				position.to_css(dest)
+			}
+			legacy(None) => dest.write_str("legacy"),
+			legacy(Some(position)) =>
+			{
+				dest.write_str("legacy ")?;
+				position.to_css(dest)
+			}
+		}
+	}
+}
+
+impl ItemAlignment
+{
+	/// The initial value of `property` (which must be `align-items`, `justify-items`, `align-self` or `justify-self`).
+	#[inline(always)]
+	pub fn initial(property: AlignmentProperty) -> Self
+	{
+		if property.allowsAuto()
+		{
+			ItemAlignment::auto
+		}
+		else if property.allowsLegacy()
+		{
+			ItemAlignment::legacy(None)
+		}
+		else
+		{
+			ItemAlignment::normal
+		}
+	}
+	
+	/// Parses the whole value of the `property` longhand (which must be `align-items`, `justify-items`, `align-self` or `justify-self`).
+	pub(crate) fn parse_longhand<'i, 't>(property: AlignmentProperty, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let value = Self::parse_for(property, input)?;
+		property.validateNoBaselineCombination(value.isBaseline(), input)?;
+		Ok(value)
+	}
+	
+	/// Parses a value for `property` (which must be `align-items`, `justify-items`, `align-self` or `justify-self`).
+	pub(crate) fn parse_for<'i, 't>(property: AlignmentProperty, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::ItemAlignment::*;
+		
+		if let Ok(baseline) = input.r#try(|input| BaselinePosition::parse(input))
+		{
+			return Ok(Baseline(baseline))
+		}
+		
+		let ident = input.expect_ident()?.clone();
+		let position = match_ignore_ascii_case!
+		{
+			&*ident,
+			
+			"auto" => return if property.allowsAuto()
+			{
+				Ok(auto)
+			}
+			else
+			{
+				Err(property.keywordIsNotAllowed(input, ident.clone()))
+			},
+			
+			"normal" => return Ok(normal),
+			
+			"stretch" => return Ok(stretch),
+			
+			"legacy" =>
+			{
+				if !property.allowsLegacy()
+				{
+					return Err(property.keywordIsNotAllowed(input, ident.clone()))
+				}
+				let position = input.r#try(|input| Self::parse_legacy_position(input)).ok();
+				return Ok(legacy(position))
+			},
+			
+			"safe" | "unsafe" =>
+			{
+				let overflow = OverflowPosition::from_ident(&ident).unwrap();
+				let position = SelfPosition::parse(input)?;
+				property.validateLeftOrRight(position.isLeftOrRight(), position.to_str(), input)?;
+				return Ok(Position(Some(overflow), position))
+			},
+			
+			_ => SelfPosition::from_ident(&ident).map_err(|ident| input.new_unexpected_token_error(Token::Ident(ident)))?,
+		};
+		
+		if position.isLegacy() && input.r#try(|input| input.expect_ident_matching("legacy")).is_ok()
+		{
+			return if property.allowsLegacy()
+			{
+				Ok(legacy(Some(position)))
+			}
+			else
+			{
+				Err(property.keywordIsNotAllowed(input, CowRcStr::from("legacy")))
+			}
+		}
+		
+		property.validateLeftOrRight(position.isLeftOrRight(), position.to_str(), input)?;
+		Ok(Position(None, position))
+	}
+	
+	/// Whether this is a baseline position.
+	#[inline(always)]
+	pub fn isBaseline(&self) -> bool
+	{
+		matches!(*self, ItemAlignment::Baseline(_))
+	}
+	
+	#[inline(always)]
+	fn parse_legacy_position<'i, 't>(input: &mut Parser<'i, 't>) -> Result<SelfPosition, ParseError<'i, CustomParseError<'i>>>
+	{
+		let position = SelfPosition::parse(input)?;
+		if position.isLegacy()
+		{
+			Ok(position)
+		}
+		else
+		{
+			Err(input.new_custom_error(CustomParseError::AlignmentKeywordIsNotAllowed(AlignmentProperty::justify_items.to_str(), CowRcStr::from(position.to_str()))))
+		}
+	}
+}
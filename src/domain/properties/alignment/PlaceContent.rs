@@ -0,0 +1,78 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `place-content` shorthand property, such as `center space-between`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PlaceContent
+{
+	pub align: ContentAlignment,
+	
+	pub justify: ContentAlignment,
+}
+
+impl ToCss for PlaceContent
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.align.to_css(dest)?;
+		if self.justify != Self::omitted(&self.align)
+		{
+			dest.write_char(' ')?;
+			self.justify.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl Parse for PlaceContent
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let align = ContentAlignment::parse_for(AlignmentProperty::align_content, input)?;
+		let justify = if input.is_exhausted()
+		{
+			Self::omitted(&align)
+		}
+		else
+		{
+			ContentAlignment::parse_for(AlignmentProperty::justify_content, input)?
+		};
+		
+		Ok
+		(
+			Self
+			{
+				align,
+				justify,
+			}
+		)
+	}
+}
+
+impl PlaceContent
+{
+	/// The longhand properties set by this shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"align-content",
+		"justify-content",
+	];
+	
+	/// Expands into longhand property values.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		vec![TypedPropertyValue::AlignContent(self.align), TypedPropertyValue::JustifyContent(self.justify)]
+	}
+	
+	/// An omitted `justify-content` copies `align-content`, except that a baseline position (which `justify-content` does not accept) becomes `start`.
+	#[inline(always)]
+	pub fn omitted(align: &ContentAlignment) -> ContentAlignment
+	{
+		match *align
+		{
+			ContentAlignment::Baseline(_) => ContentAlignment::Position(None, ContentPosition::start),
+			other => other,
+		}
+	}
+}
@@ -0,0 +1,67 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `place-items` shorthand property, such as `center legacy left`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PlaceItems
+{
+	pub align: ItemAlignment,
+	
+	pub justify: ItemAlignment,
+}
+
+impl ToCss for PlaceItems
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.align.to_css(dest)?;
+		if self.justify != self.align
+		{
+			dest.write_char(' ')?;
+			self.justify.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl Parse for PlaceItems
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let align = ItemAlignment::parse_for(AlignmentProperty::align_items, input)?;
+		let justify = if input.is_exhausted()
+		{
+			align
+		}
+		else
+		{
+			ItemAlignment::parse_for(AlignmentProperty::justify_items, input)?
+		};
+		
+		Ok
+		(
+			Self
+			{
+				align,
+				justify,
+			}
+		)
+	}
+}
+
+impl PlaceItems
+{
+	/// The longhand properties set by this shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"align-items",
+		"justify-items",
+	];
+	
+	/// Expands into longhand property values; an omitted `justify-items` copies `align-items`.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		vec![TypedPropertyValue::AlignItems(self.align), TypedPropertyValue::JustifyItems(self.justify)]
+	}
+}
@@ -0,0 +1,34 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	SelfPosition:
+	"center" => center,
+	"start" => start,
+	"end" => end,
+	"self-start" => self_start,
+	"self-end" => self_end,
+	"flex-start" => flex_start,
+	"flex-end" => flex_end,
+	"left" => left,
+	"right" => right,
+}
+
+impl SelfPosition
+{
+	/// `left` and `right` are only valid in the inline axis, ie for `justify-self` and `justify-items`.
+	#[inline(always)]
+	pub fn isLeftOrRight(&self) -> bool
+	{
+		matches!(*self, SelfPosition::left | SelfPosition::right)
+	}
+	
+	/// Whether this can be combined with `legacy` in `justify-items`.
+	#[inline(always)]
+	pub fn isLegacy(&self) -> bool
+	{
+		matches!(*self, SelfPosition::left | SelfPosition::right | SelfPosition::center)
+	}
+}
@@ -0,0 +1,20 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("AlignmentProperty.rs");
+include!("BaselinePosition.rs");
+include!("ContentAlignment.rs");
+include!("ContentDistribution.rs");
+include!("ContentPosition.rs");
+include!("Gap.rs");
+include!("Gaps.rs");
+include!("ItemAlignment.rs");
+include!("OverflowPosition.rs");
+include!("PlaceContent.rs");
+include!("PlaceItems.rs");
+include!("PlaceSelf.rs");
+include!("SelfPosition.rs");
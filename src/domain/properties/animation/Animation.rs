@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `animation` shorthand property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Animation
+{
+	/// There is always at least one animation.
+	pub animations: Vec<SingleAnimation>,
+}
+
+impl ToCss for Animation
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut separator = "";
+		for animation in self.animations.iter()
+		{
+			dest.write_str(separator)?;
+			animation.to_css(dest)?;
+			separator = ",";
+		}
+		Ok(())
+	}
+}
+
+impl Parse for Animation
+{
+	#[inline(always)]
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Ok
+		(
+			Self
+			{
+				animations: input.parse_comma_separated(|input| SingleAnimation::parse(context, input))?,
+			}
+		)
+	}
+}
+
+impl SimplifyCalcFunctions for Animation
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for animation in self.animations.iter_mut()
+		{
+			animation.simplify_calc_functions()
+		}
+	}
+}
+
+impl Animation
+{
+	/// The longhand properties set (or reset) by the `animation` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"animation-name",
+		"animation-duration",
+		"animation-timing-function",
+		"animation-delay",
+		"animation-iteration-count",
+		"animation-direction",
+		"animation-fill-mode",
+		"animation-play-state",
+	];
+	
+	/// Expands into longhand property values, each with one item per animation.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		macro_rules! each
+		{
+			($part: ident) =>
+			{
+				CommaSeparatedList(self.animations.iter().map(|animation| animation.$part.clone()).collect())
+			}
+		}
+		
+		vec!
+		[
+			TypedPropertyValue::AnimationName(each!(name)),
+			TypedPropertyValue::AnimationDuration(each!(duration)),
+			TypedPropertyValue::AnimationTimingFunction(each!(timing_function)),
+			TypedPropertyValue::AnimationDelay(each!(delay)),
+			TypedPropertyValue::AnimationIterationCount(each!(iteration_count)),
+			TypedPropertyValue::AnimationDirection(each!(direction)),
+			TypedPropertyValue::AnimationFillMode(each!(fill_mode)),
+			TypedPropertyValue::AnimationPlayState(each!(play_state)),
+		]
+	}
+}
@@ -0,0 +1,51 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `animation-iteration-count` property.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AnimationIterationCount
+{
+	infinite,
+	
+	Count(CssUnsignedNumber),
+}
+
+impl Default for AnimationIterationCount
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		AnimationIterationCount::Count(CssUnsignedNumber::One)
+	}
+}
+
+impl ToCss for AnimationIterationCount
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::AnimationIterationCount::*;
+		
+		match *self
+		{
+			infinite => dest.write_str("infinite"),
+			Count(count) => count.to_css(dest),
+		}
+	}
+}
+
+impl Parse for AnimationIterationCount
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::AnimationIterationCount::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("infinite")).is_ok()
+		{
+			return Ok(infinite)
+		}
+		
+		let value = input.expect_number()?;
+		CssUnsignedNumber::parseNumber(value, None).map(Count).map_err(|error| input.new_custom_error(error))
+	}
+}
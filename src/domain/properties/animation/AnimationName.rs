@@ -0,0 +1,67 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `animation-name` property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AnimationName
+{
+	none,
+	
+	/// The name of a `@keyframes` rule; if there is no such rule, no animation runs.
+	Named(KeyframesName),
+}
+
+impl Default for AnimationName
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		AnimationName::none
+	}
+}
+
+impl ToCss for AnimationName
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::AnimationName::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			Named(ref name) => name.to_css(dest),
+		}
+	}
+}
+
+impl Parse for AnimationName
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::AnimationName::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none)
+		}
+		
+		KeyframesName::parse(input).map(Named)
+	}
+}
+
+impl AnimationName
+{
+	/// The name of the `@keyframes` rule, if any.
+	#[inline(always)]
+	pub fn keyframes_name(&self) -> Option<&Atom>
+	{
+		use self::AnimationName::*;
+		
+		match *self
+		{
+			none => None,
+			Named(ref name) => Some(name.as_atom()),
+		}
+	}
+}
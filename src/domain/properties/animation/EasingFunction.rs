@@ -0,0 +1,415 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An easing function, as used by `transition-timing-function` and `animation-timing-function`: https://drafts.csswg.org/css-easing-2/#easing-functions
+/// Keywords are parsed as the functions they are equivalent to, and serialized as keywords where possible.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum EasingFunction
+{
+	/// There are always at least two stops, or none for the keyword `linear`.
+	linear(Vec<LinearStop>),
+	
+	/// `x1`, `y1`, `x2` and `y2`; `x1` and `x2` are between zero and one inclusive.
+	cubic_bezier(CssSignedNumber, CssSignedNumber, CssSignedNumber, CssSignedNumber),
+	
+	/// There is always at least one step, or two for `jump-none`.
+	steps(CssUnsignedInteger, StepPosition),
+}
+
+impl Default for EasingFunction
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		EasingFunction::ease()
+	}
+}
+
+impl ToCss for EasingFunction
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::EasingFunction::*;
+		
+		match *self
+		{
+			linear(ref stops) =>
+			{
+				if stops.is_empty()
+				{
+					return dest.write_str("linear")
+				}
+				
+				dest.write_str("linear(")?;
+				let mut separator = "";
+				for stop in stops.iter()
+				{
+					dest.write_str(separator)?;
+					stop.to_css(dest)?;
+					separator = ",";
+				}
+				dest.write_char(')')
+			}
+			
+			cubic_bezier(x1, y1, x2, y2) =>
+			{
+				let points = [*x1, *y1, *x2, *y2];
+				for &(keyword, keywordPoints) in Self::CubicBezierKeywords.iter()
+				{
+					if points == keywordPoints
+					{
+						return dest.write_str(keyword)
+					}
+				}
+				
+				dest.write_str("cubic-bezier(")?;
+				x1.to_css(dest)?;
+				dest.write_char(',')?;
+				y1.to_css(dest)?;
+				dest.write_char(',')?;
+				x2.to_css(dest)?;
+				dest.write_char(',')?;
+				y2.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
+			steps(count, position) =>
+			{
+				if count == CssUnsignedInteger::One
+				{
+					match position
+					{
+						StepPosition::start => return dest.write_str("step-start"),
+						StepPosition::end => return dest.write_str("step-end"),
+						_ => (),
+					}
+				}
+				
+				dest.write_str("steps(")?;
+				count.to_css(dest)?;
+				if position != StepPosition::end
+				{
+					dest.write_char(',')?;
+					position.to_css(dest)?;
+				}
+				dest.write_char(')')
+			}
+		}
+	}
+}
+
+impl Parse for EasingFunction
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::EasingFunction::*;
+		
+		let name = match *input.next()?
+		{
+			Token::Ident(ref ident) =>
+			{
+				let ident = ident.clone();
+				return match_ignore_ascii_case!
+				{
+					&*ident,
+					
+					"linear" => Ok(linear(Vec::new())),
+					
+					"ease" => Ok(Self::ease()),
+					
+					"ease-in" => Ok(Self::cubicBezier(0.42, 0.0, 1.0, 1.0)),
+					
+					"ease-out" => Ok(Self::cubicBezier(0.0, 0.0, 0.58, 1.0)),
+					
+					"ease-in-out" => Ok(Self::cubicBezier(0.42, 0.0, 0.58, 1.0)),
+					
+					"step-start" => Ok(steps(CssUnsignedInteger::One, StepPosition::start)),
+					
+					"step-end" => Ok(steps(CssUnsignedInteger::One, StepPosition::end)),
+					
+					_ => Err(input.new_unexpected_token_error(Token::Ident(ident.clone()))),
+				}
+			}
+			
+			Token::Function(ref name) => name.clone(),
+			
+			ref unexpectedToken =>
+			{
+				let unexpectedToken = unexpectedToken.clone();
+				return Err(input.new_unexpected_token_error(unexpectedToken))
+			}
+		};
+		
+		input.parse_nested_block(|input|
+		{
+			match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"linear" =>
+				{
+					let stops = input.parse_comma_separated(|input| LinearStop::parse(context, input))?;
+					if stops.len() < 2
+					{
+						return Err(input.new_custom_error(CustomParseError::LinearEasingFunctionMustHaveAtLeastTwoStops))
+					}
+					Ok(linear(stops))
+				},
+				
+				"cubic-bezier" =>
+				{
+					#[inline(always)]
+					fn number<'i, 't>(input: &mut Parser<'i, 't>, isX: bool) -> Result<CssSignedNumber, ParseError<'i, CustomParseError<'i>>>
+					{
+						let value = input.expect_number()?;
+						if isX && !(0.0 ..= 1.0).contains(&value)
+						{
+							return Err(input.new_custom_error(CustomParseError::CubicBezierXMustBeBetweenZeroAndOne(value)))
+						}
+						CssSignedNumber::parseNumber(value, None).map_err(|error| input.new_custom_error(error))
+					}
+					
+					let x1 = number(input, true)?;
+					input.expect_comma()?;
+					let y1 = number(input, false)?;
+					input.expect_comma()?;
+					let x2 = number(input, true)?;
+					input.expect_comma()?;
+					let y2 = number(input, false)?;
+					Ok(cubic_bezier(x1, y1, x2, y2))
+				},
+				
+				"steps" =>
+				{
+					let value = input.expect_integer()?;
+					let count = CssUnsignedInteger::parseNumber(value as f32, Some(value)).map_err(|error| input.new_custom_error(error))?;
+					
+					let position = if input.r#try(|input| input.expect_comma()).is_ok()
+					{
+						StepPosition::parse(input)?
+					}
+					else
+					{
+						StepPosition::end
+					};
+					
+					let minimum = if position == StepPosition::jump_none
+					{
+						2
+					}
+					else
+					{
+						1
+					};
+					if value < minimum
+					{
+						return Err(input.new_custom_error(CustomParseError::NotEnoughSteps(value)))
+					}
+					
+					Ok(steps(count, position))
+				},
+				
+				_ => Err(input.new_custom_error(CustomParseError::UnexpectedEasingFunction(name.clone()))),
+			}
+		})
+	}
+}
+
+impl EasingFunction
+{
+	const CubicBezierKeywords: [(&str, [f32; 4]); 4] =
+	[
+		("ease", [0.25, 0.1, 0.25, 1.0]),
+		("ease-in", [0.42, 0.0, 1.0, 1.0]),
+		("ease-out", [0.0, 0.0, 0.58, 1.0]),
+		("ease-in-out", [0.42, 0.0, 0.58, 1.0]),
+	];
+	
+	/// The keyword `ease`, the initial value of the `transition-timing-function` and `animation-timing-function` properties.
+	#[inline(always)]
+	pub fn ease() -> Self
+	{
+		Self::cubicBezier(0.25, 0.1, 0.25, 1.0)
+	}
+	
+	/// Whether this is the keyword `ease`.
+	#[inline(always)]
+	pub fn isEase(&self) -> bool
+	{
+		*self == Self::ease()
+	}
+	
+	#[inline(always)]
+	fn cubicBezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self
+	{
+		EasingFunction::cubic_bezier(CssSignedNumber::_construct(x1), CssSignedNumber::_construct(y1), CssSignedNumber::_construct(x2), CssSignedNumber::_construct(y2))
+	}
+	
+	/// The output progress at the input progress `t`, which is usually, but not always, between zero and one.
+	/// As there is no 'before flag', `steps()` with `start` or `jump-both` behaves as if after the start of an animation for negative `t`.
+	pub fn evaluate(&self, t: f64) -> f64
+	{
+		use self::EasingFunction::*;
+		
+		match *self
+		{
+			linear(ref stops) => if stops.is_empty()
+			{
+				t
+			}
+			else
+			{
+				Self::evaluateLinear(&LinearStop::controlPoints(stops), t)
+			},
+			
+			cubic_bezier(x1, y1, x2, y2) => Self::evaluateCubicBezier(*x1 as f64, *y1 as f64, *x2 as f64, *y2 as f64, t),
+			
+			steps(count, position) =>
+			{
+				let count = count.to_f32() as f64;
+				
+				// https://drafts.csswg.org/css-easing-2/#step-easing-algo
+				let mut currentStep = (t * count).floor();
+				if position == StepPosition::start || position == StepPosition::jump_both
+				{
+					currentStep += 1.0;
+				}
+				if t >= 0.0 && currentStep < 0.0
+				{
+					currentStep = 0.0;
+				}
+				let jumps = position.jumps(count);
+				if t <= 1.0 && currentStep > jumps
+				{
+					currentStep = jumps;
+				}
+				currentStep / jumps
+			}
+		}
+	}
+	
+	/// https://drafts.csswg.org/css-easing-2/#linear-easing-function-output
+	fn evaluateLinear(points: &[(f64, f64)], t: f64) -> f64
+	{
+		// The final point with an input at or before `t`, or the first point; if the final point, the one before it, so that there is always a following point to interpolate (or extrapolate) towards.
+		let mut previousIndex = points.iter().rposition(|&(_, input)| input <= t).unwrap_or(0);
+		if previousIndex == points.len() - 1
+		{
+			previousIndex -= 1;
+		}
+		
+		let (previousOutput, previousInput) = points[previousIndex];
+		let (nextOutput, nextInput) = points[previousIndex + 1];
+		
+		if previousInput == nextInput
+		{
+			return nextOutput
+		}
+		
+		let progress = (t - previousInput) / (nextInput - previousInput);
+		previousOutput + progress * (nextOutput - previousOutput)
+	}
+	
+	/// Outside of zero to one, extrapolates using the tangent at the nearest end point.
+	fn evaluateCubicBezier(x1: f64, y1: f64, x2: f64, y2: f64, t: f64) -> f64
+	{
+		#[inline(always)]
+		fn bezier(p1: f64, p2: f64, s: f64) -> f64
+		{
+			let inverse = 1.0 - s;
+			3.0 * inverse * inverse * s * p1 + 3.0 * inverse * s * s * p2 + s * s * s
+		}
+		
+		#[inline(always)]
+		fn derivative(p1: f64, p2: f64, s: f64) -> f64
+		{
+			let inverse = 1.0 - s;
+			3.0 * inverse * inverse * p1 + 6.0 * inverse * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+		}
+		
+		if t < 0.0
+		{
+			let gradient = if x1 > 0.0
+			{
+				y1 / x1
+			}
+			else if y1 == 0.0 && x2 > 0.0
+			{
+				y2 / x2
+			}
+			else
+			{
+				0.0
+			};
+			return gradient * t
+		}
+		
+		if t > 1.0
+		{
+			let gradient = if x2 < 1.0
+			{
+				(y2 - 1.0) / (x2 - 1.0)
+			}
+			else if y2 == 1.0 && x1 < 1.0
+			{
+				(y1 - 1.0) / (x1 - 1.0)
+			}
+			else
+			{
+				0.0
+			};
+			return 1.0 + gradient * (t - 1.0)
+		}
+		
+		const Epsilon: f64 = 1e-7;
+		
+		// Find the parameter `s` for which the curve's x is `t`; Newton's method is fast, but can fail where the curve is flat, so fall back to bisection.
+		let mut s = t;
+		for _ in 0 .. 8
+		{
+			let error = bezier(x1, x2, s) - t;
+			if error.abs() < Epsilon
+			{
+				return bezier(y1, y2, s)
+			}
+			let gradient = derivative(x1, x2, s);
+			if gradient.abs() < Epsilon
+			{
+				break
+			}
+			s -= error / gradient;
+			if !(0.0 ..= 1.0).contains(&s)
+			{
+				break
+			}
+		}
+		
+		let mut lower = 0.0;
+		let mut upper = 1.0;
+		s = t;
+		while lower < upper
+		{
+			let x = bezier(x1, x2, s);
+			if (x - t).abs() < Epsilon
+			{
+				break
+			}
+			if x < t
+			{
+				lower = s;
+			}
+			else
+			{
+				upper = s;
+			}
+			let next = (lower + upper) / 2.0;
+			if next == s
+			{
+				break
+			}
+			s = next;
+		}
+		bezier(y1, y2, s)
+	}
+}
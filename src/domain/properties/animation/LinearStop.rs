@@ -0,0 +1,131 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One stop of a `linear()` easing function: an output progress value, and up to two input progress values (percentages).
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LinearStop
+{
+	pub output: CssSignedNumber,
+	
+	/// Empty if the input should be interpolated from that of the neighbouring stops; two values are the same as two stops with the same output.
+	pub input: Vec<PercentageUnit<CssSignedNumber>>,
+}
+
+impl ToCss for LinearStop
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.output.to_css(dest)?;
+		for input in self.input.iter()
+		{
+			dest.write_char(' ')?;
+			input.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl Parse for LinearStop
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		#[inline(always)]
+		fn percentages<'i, 't>(input: &mut Parser<'i, 't>, positions: &mut Vec<PercentageUnit<CssSignedNumber>>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+		{
+			while positions.len() < 2
+			{
+				match input.r#try(|input| input.expect_percentage())
+				{
+					Ok(value) => positions.push(PercentageUnit::parse_percentage(value).map_err(|error| input.new_custom_error(error))?),
+					Err(_) => break,
+				}
+			}
+			Ok(())
+		}
+		
+		// The input progress values may come before or after the output progress value.
+		let mut positions = Vec::with_capacity(2);
+		percentages(input, &mut positions)?;
+		
+		let value = input.expect_number()?;
+		let output = CssSignedNumber::parseNumber(value, None).map_err(|error| input.new_custom_error(error))?;
+		
+		if positions.is_empty()
+		{
+			percentages(input, &mut positions)?;
+		}
+		
+		Ok
+		(
+			Self
+			{
+				output,
+				input: positions,
+			}
+		)
+	}
+}
+
+impl LinearStop
+{
+	/// The control points, each an output and an input progress value: https://drafts.csswg.org/css-easing-2/#linear-easing-function-parsing
+	/// Missing inputs are interpolated, and each input is at least that of any earlier point.
+	pub(crate) fn controlPoints(stops: &[Self]) -> Vec<(f64, f64)>
+	{
+		let mut points: Vec<(f64, Option<f64>)> = Vec::with_capacity(stops.len() + 2);
+		for stop in stops.iter()
+		{
+			let output = *stop.output as f64;
+			if stop.input.is_empty()
+			{
+				points.push((output, None));
+			}
+			for input in stop.input.iter()
+			{
+				points.push((output, Some(*input.0 as f64)));
+			}
+		}
+		
+		let finalIndex = points.len() - 1;
+		if points[0].1.is_none()
+		{
+			points[0].1 = Some(0.0);
+		}
+		if points[finalIndex].1.is_none()
+		{
+			points[finalIndex].1 = Some(1.0);
+		}
+		
+		let mut largest = f64::NEG_INFINITY;
+		for point in points.iter_mut()
+		{
+			if let Some(ref mut input) = point.1
+			{
+				if *input < largest
+				{
+					*input = largest;
+				}
+				largest = *input;
+			}
+		}
+		
+		// Runs of missing inputs are spread evenly between the known inputs either side.
+		let mut previousKnownIndex = 0;
+		for index in 1 .. points.len()
+		{
+			if let Some(input) = points[index].1
+			{
+				let previousInput = points[previousKnownIndex].1.unwrap();
+				let gap = (index - previousKnownIndex) as f64;
+				for (offset, point) in points[(previousKnownIndex + 1) .. index].iter_mut().enumerate()
+				{
+					point.1 = Some(previousInput + (input - previousInput) * ((offset + 1) as f64) / gap);
+				}
+				previousKnownIndex = index;
+			}
+		}
+		
+		points.into_iter().map(|(output, input)| (output, input.unwrap())).collect()
+	}
+}
@@ -0,0 +1,200 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A duration, as used by `transition-duration` and `animation-duration`.
+pub type Duration = CalculablePropertyValue<TimeUnit<CssUnsignedNumber>>;
+
+/// A delay, as used by `transition-delay` and `animation-delay`; negative delays start part way through.
+pub type Delay = CalculablePropertyValue<TimeUnit<CssSignedNumber>>;
+
+#[inline(always)]
+fn isZeroTime<Number: CssNumber>(time: &CalculablePropertyValue<TimeUnit<Number>>) -> bool
+{
+	match *time
+	{
+		CalculablePropertyValue::Constant(ref time) => time.is_zero(),
+		_ => false,
+	}
+}
+
+/// One comma-separated item of an `animation` shorthand.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SingleAnimation
+{
+	pub name: AnimationName,
+	
+	pub duration: Duration,
+	
+	pub timing_function: EasingFunction,
+	
+	pub delay: Delay,
+	
+	pub iteration_count: AnimationIterationCount,
+	
+	pub direction: AnimationDirection,
+	
+	pub fill_mode: AnimationFillMode,
+	
+	pub play_state: AnimationPlayState,
+}
+
+impl Default for SingleAnimation
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			name: AnimationName::default(),
+			duration: Duration::default(),
+			timing_function: EasingFunction::default(),
+			delay: Delay::default(),
+			iteration_count: AnimationIterationCount::default(),
+			direction: AnimationDirection::default(),
+			fill_mode: AnimationFillMode::default(),
+			play_state: AnimationPlayState::default(),
+		}
+	}
+}
+
+impl ToCss for SingleAnimation
+{
+	/// Writes the shortest form, omitting parts that have their initial values; the duration is always written if the delay is, as the first time is the duration.
+	/// A name that could be mistaken for a keyword of another part is written as a string.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut separator = "";
+		
+		macro_rules! part
+		{
+			($condition: expr, $value: expr) =>
+			{
+				if $condition
+				{
+					dest.write_str(separator)?;
+					$value.to_css(dest)?;
+					separator = " ";
+				}
+			}
+		}
+		
+		let hasDelay = !isZeroTime(&self.delay);
+		
+		part!(hasDelay || !isZeroTime(&self.duration), self.duration);
+		part!(!self.timing_function.isEase(), self.timing_function);
+		part!(hasDelay, self.delay);
+		part!(self.iteration_count != AnimationIterationCount::default(), self.iteration_count);
+		part!(self.direction != AnimationDirection::normal, self.direction);
+		part!(self.fill_mode != AnimationFillMode::none, self.fill_mode);
+		part!(self.play_state != AnimationPlayState::running, self.play_state);
+		
+		match self.name
+		{
+			AnimationName::none => if separator.is_empty()
+			{
+				dest.write_str("none")?;
+			},
+			
+			AnimationName::Named(ref name) if Self::isKeywordOfAnotherPart(name.as_atom()) =>
+			{
+				dest.write_str(separator)?;
+				serialize_string(name.as_atom(), dest)?;
+			}
+			
+			AnimationName::Named(ref name) =>
+			{
+				dest.write_str(separator)?;
+				name.to_css(dest)?;
+			}
+		}
+		
+		Ok(())
+	}
+}
+
+impl Parse for SingleAnimation
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut animation = Self::default();
+		
+		let mut name = false;
+		let mut duration = false;
+		let mut timingFunction = false;
+		let mut delay = false;
+		let mut iterationCount = false;
+		let mut direction = false;
+		let mut fillMode = false;
+		let mut playState = false;
+		
+		macro_rules! part
+		{
+			($seen: ident, $field: ident, $parser: expr) =>
+			{
+				if !$seen
+				{
+					if let Ok(value) = input.r#try($parser)
+					{
+						animation.$field = value;
+						$seen = true;
+						continue;
+					}
+				}
+			}
+		}
+		
+		// Each part may occur at most once, in any order; the first time is the duration and the second the delay.
+		// Keywords are taken to be those of other parts in preference to being the name, so the name is tried last.
+		loop
+		{
+			if duration
+			{
+				part!(delay, delay, |input| TimeUnit::parse_one_outside_calc_function(context, input));
+			}
+			part!(duration, duration, |input| TimeUnit::parse_one_outside_calc_function(context, input));
+			part!(timingFunction, timing_function, |input| EasingFunction::parse(context, input));
+			part!(iterationCount, iteration_count, |input| AnimationIterationCount::parse(context, input));
+			part!(direction, direction, |input| AnimationDirection::parse(input));
+			part!(fillMode, fill_mode, |input| AnimationFillMode::parse(input));
+			part!(playState, play_state, |input| AnimationPlayState::parse(input));
+			part!(name, name, |input| AnimationName::parse(context, input));
+			break;
+		}
+		
+		if !(name || duration || timingFunction || delay || iterationCount || direction || fillMode || playState)
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		Ok(animation)
+	}
+}
+
+impl SimplifyCalcFunctions for SingleAnimation
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.duration.simplify_calc_functions();
+		self.delay.simplify_calc_functions()
+	}
+}
+
+impl SingleAnimation
+{
+	/// Whether `name` would be parsed as a keyword of another part if written as an identifier.
+	fn isKeywordOfAnotherPart(name: &str) -> bool
+	{
+		let name: CowRcStr = name.into();
+		
+		match_ignore_ascii_case!
+		{
+			&*name,
+			
+			"linear" | "ease" | "ease-in" | "ease-out" | "ease-in-out" | "step-start" | "step-end" | "infinite" => true,
+			
+			_ => AnimationDirection::from_ident(&name).is_ok() || AnimationFillMode::from_ident(&name).is_ok() || AnimationPlayState::from_ident(&name).is_ok(),
+		}
+	}
+}
@@ -0,0 +1,157 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One comma-separated item of a `transition` shorthand.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SingleTransition
+{
+	pub property: TransitionProperty,
+	
+	pub duration: Duration,
+	
+	pub timing_function: EasingFunction,
+	
+	pub delay: Delay,
+	
+	pub behavior: TransitionBehavior,
+}
+
+impl Default for SingleTransition
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			property: TransitionProperty::default(),
+			duration: Duration::default(),
+			timing_function: EasingFunction::default(),
+			delay: Delay::default(),
+			behavior: TransitionBehavior::default(),
+		}
+	}
+}
+
+impl ToCss for SingleTransition
+{
+	/// Writes the shortest form, omitting parts that have their initial values; the duration is always written if the delay is, as the first time is the duration.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut separator = "";
+		
+		macro_rules! part
+		{
+			($condition: expr, $value: expr) =>
+			{
+				if $condition
+				{
+					dest.write_str(separator)?;
+					$value.to_css(dest)?;
+					separator = " ";
+				}
+			}
+		}
+		
+		let hasDelay = !isZeroTime(&self.delay);
+		
+		part!(self.property != TransitionProperty::all, self.property);
+		part!(hasDelay || !isZeroTime(&self.duration), self.duration);
+		part!(!self.timing_function.isEase(), self.timing_function);
+		part!(hasDelay, self.delay);
+		part!(self.behavior != TransitionBehavior::normal, self.behavior);
+		
+		if separator.is_empty()
+		{
+			dest.write_str("0s")?;
+		}
+		
+		Ok(())
+	}
+}
+
+impl Parse for SingleTransition
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut transition = Self::default();
+		
+		let mut property = false;
+		let mut duration = false;
+		let mut timingFunction = false;
+		let mut delay = false;
+		let mut behavior = false;
+		
+		// Each part may occur at most once, in any order; the first time is the duration and the second the delay.
+		loop
+		{
+			if !duration
+			{
+				if let Ok(value) = input.r#try(|input| TimeUnit::parse_one_outside_calc_function(context, input))
+				{
+					transition.duration = value;
+					duration = true;
+					continue;
+				}
+			}
+			else if !delay
+			{
+				if let Ok(value) = input.r#try(|input| TimeUnit::parse_one_outside_calc_function(context, input))
+				{
+					transition.delay = value;
+					delay = true;
+					continue;
+				}
+			}
+			
+			if !timingFunction
+			{
+				if let Ok(value) = input.r#try(|input| EasingFunction::parse(context, input))
+				{
+					transition.timing_function = value;
+					timingFunction = true;
+					continue;
+				}
+			}
+			
+			if !behavior
+			{
+				if let Ok(value) = input.r#try(|input| TransitionBehavior::parse(input))
+				{
+					transition.behavior = value;
+					behavior = true;
+					continue;
+				}
+			}
+			
+			if !property
+			{
+				if let Ok(value) = input.r#try(|input| TransitionProperty::parse(context, input))
+				{
+					transition.property = value;
+					property = true;
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if !(property || duration || timingFunction || delay || behavior)
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		Ok(transition)
+	}
+}
+
+impl SimplifyCalcFunctions for SingleTransition
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.duration.simplify_calc_functions();
+		self.delay.simplify_calc_functions()
+	}
+}
@@ -0,0 +1,46 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	StepPosition:
+	values
+	{
+		"start" => start,
+		"end" => end,
+		"jump-none" => jump_none,
+		"jump-both" => jump_both,
+	}
+	aliases
+	{
+		"jump-start" => start,
+		"jump-end" => end,
+	}
+}
+
+impl Default for StepPosition
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		StepPosition::end
+	}
+}
+
+impl StepPosition
+{
+	/// The number of jumps, the intervals of output progress, for `steps` steps.
+	#[inline(always)]
+	fn jumps(self, steps: f64) -> f64
+	{
+		use self::StepPosition::*;
+		
+		match self
+		{
+			start | end => steps,
+			jump_none => steps - 1.0,
+			jump_both => steps + 1.0,
+		}
+	}
+}
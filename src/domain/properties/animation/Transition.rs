@@ -0,0 +1,92 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `transition` shorthand property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Transition
+{
+	/// There is always at least one transition; a transition with a `transition-property` of `none` is the only transition.
+	pub transitions: Vec<SingleTransition>,
+}
+
+impl ToCss for Transition
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut separator = "";
+		for transition in self.transitions.iter()
+		{
+			dest.write_str(separator)?;
+			transition.to_css(dest)?;
+			separator = ",";
+		}
+		Ok(())
+	}
+}
+
+impl Parse for Transition
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let transitions = input.parse_comma_separated(|input| SingleTransition::parse(context, input))?;
+		
+		let properties: Vec<TransitionProperty> = transitions.iter().map(|transition| transition.property.clone()).collect();
+		TransitionProperty::validate(&properties, input)?;
+		
+		Ok
+		(
+			Self
+			{
+				transitions,
+			}
+		)
+	}
+}
+
+impl SimplifyCalcFunctions for Transition
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for transition in self.transitions.iter_mut()
+		{
+			transition.simplify_calc_functions()
+		}
+	}
+}
+
+impl Transition
+{
+	/// The longhand properties set (or reset) by the `transition` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"transition-property",
+		"transition-duration",
+		"transition-timing-function",
+		"transition-delay",
+		"transition-behavior",
+	];
+	
+	/// Expands into longhand property values, each with one item per transition.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		macro_rules! each
+		{
+			($part: ident) =>
+			{
+				CommaSeparatedList(self.transitions.iter().map(|transition| transition.$part.clone()).collect())
+			}
+		}
+		
+		vec!
+		[
+			TypedPropertyValue::TransitionProperty(each!(property)),
+			TypedPropertyValue::TransitionDuration(each!(duration)),
+			TypedPropertyValue::TransitionTimingFunction(each!(timing_function)),
+			TypedPropertyValue::TransitionDelay(each!(delay)),
+			TypedPropertyValue::TransitionBehavior(each!(behavior)),
+		]
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One item of the `transition-property` property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TransitionProperty
+{
+	all,
+	
+	/// Only valid on its own.
+	none,
+	
+	/// Property names are in lower case, except for those of custom properties (eg `--main-colour`), which are case-sensitive; unknown properties are allowed.
+	Property(Atom),
+}
+
+impl Default for TransitionProperty
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		TransitionProperty::all
+	}
+}
+
+impl ToCss for TransitionProperty
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TransitionProperty::*;
+		
+		match *self
+		{
+			all => dest.write_str("all"),
+			none => dest.write_str("none"),
+			Property(ref name) => name.to_css(dest),
+		}
+	}
+}
+
+impl Parse for TransitionProperty
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TransitionProperty::*;
+		
+		let ident = input.expect_ident()?.clone();
+		
+		match_ignore_ascii_case!
+		{
+			&*ident,
+			
+			"all" => return Ok(all),
+			
+			"none" => return Ok(none),
+			
+			_ => (),
+		}
+		
+		let CustomIdent(name) = CustomIdent::from_ident(&ident, &[]).map_err(|error| input.new_custom_error(error))?;
+		if name.starts_with("--")
+		{
+			Ok(Property(name))
+		}
+		else
+		{
+			Ok(Property(Atom::from(name.to_ascii_lowercase())))
+		}
+	}
+}
+
+impl TransitionProperty
+{
+	/// Checks that `none` is only used on its own.
+	pub(crate) fn validate<'i, 't>(properties: &[Self], input: &Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		if properties.len() > 1 && properties.contains(&TransitionProperty::none)
+		{
+			Err(input.new_custom_error(CustomParseError::TransitionPropertyNoneMustBeOnItsOwn))
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+}
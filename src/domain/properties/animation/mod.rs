@@ -0,0 +1,21 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("Animation.rs");
+include!("AnimationDirection.rs");
+include!("AnimationFillMode.rs");
+include!("AnimationIterationCount.rs");
+include!("AnimationName.rs");
+include!("AnimationPlayState.rs");
+include!("EasingFunction.rs");
+include!("LinearStop.rs");
+include!("SingleAnimation.rs");
+include!("SingleTransition.rs");
+include!("StepPosition.rs");
+include!("Transition.rs");
+include!("TransitionBehavior.rs");
+include!("TransitionProperty.rs");
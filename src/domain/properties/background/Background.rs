@@ -0,0 +1,105 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `background` shorthand property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Background
+{
+	/// There is always at least one layer.
+	pub layers: Vec<Layer>,
+	
+	/// Painted beneath all the layers; given with the final layer.
+	pub colour: Colour,
+}
+
+impl ToCss for Background
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let finalLayerIndex = self.layers.len() - 1;
+		for (index, layer) in self.layers.iter().enumerate()
+		{
+			if index == finalLayerIndex
+			{
+				layer.to_css(LayerKind::Background, Some(&self.colour), dest)?;
+			}
+			else
+			{
+				layer.to_css(LayerKind::Background, None, dest)?;
+				dest.write_char(',')?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl Parse for Background
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let layers = input.parse_comma_separated(|input| Layer::parse(LayerKind::Background, context, input))?;
+		
+		let finalLayerIndex = layers.len() - 1;
+		if layers[.. finalLayerIndex].iter().any(|(_, colour)| colour.is_some())
+		{
+			return Err(input.new_custom_error(CustomParseError::BackgroundColorMustBeInTheFinalLayer))
+		}
+		
+		let colour = layers[finalLayerIndex].1.unwrap_or_default();
+		Ok
+		(
+			Self
+			{
+				layers: layers.into_iter().map(|(layer, _)| layer).collect(),
+				colour,
+			}
+		)
+	}
+}
+
+impl SimplifyCalcFunctions for Background
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for layer in self.layers.iter_mut()
+		{
+			layer.simplify_calc_functions()
+		}
+	}
+}
+
+impl Background
+{
+	/// The longhand properties set (or reset) by the `background` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"background-image",
+		"background-position",
+		"background-size",
+		"background-repeat",
+		"background-attachment",
+		"background-origin",
+		"background-clip",
+		"background-color",
+	];
+	
+	/// Expands into longhand property values, each with one item per layer.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		let layers = &self.layers;
+		
+		vec!
+		[
+			TypedPropertyValue::BackgroundImage(Layer::each(layers, |layer| layer.image.clone())),
+			TypedPropertyValue::BackgroundPosition(Layer::each(layers, |layer| layer.position.clone())),
+			TypedPropertyValue::BackgroundSize(Layer::each(layers, |layer| layer.size.clone())),
+			TypedPropertyValue::BackgroundRepeat(Layer::each(layers, |layer| layer.repeat)),
+			TypedPropertyValue::BackgroundAttachment(Layer::each(layers, |layer| layer.attachment)),
+			TypedPropertyValue::BackgroundOrigin(Layer::each(layers, |layer| layer.origin)),
+			TypedPropertyValue::BackgroundClip(Layer::each(layers, |layer| layer.clip)),
+			TypedPropertyValue::BackgroundColor(self.colour),
+		]
+	}
+}
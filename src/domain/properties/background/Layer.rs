@@ -0,0 +1,271 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One comma-separated layer of a `background` or `mask` shorthand.
+/// Parts that do not apply to the layer's `LayerKind` (eg `attachment` for a mask) keep their initial values.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Layer
+{
+	pub image: LayerImage,
+	
+	pub position: Position,
+	
+	pub size: LayerSize,
+	
+	pub repeat: LayerRepeat,
+	
+	pub origin: VisualBox,
+	
+	pub clip: VisualBox,
+	
+	/// Background layers only.
+	pub attachment: Attachment,
+	
+	/// Mask layers only.
+	pub composite: CompositingOperator,
+	
+	/// Mask layers only.
+	pub mode: MaskingMode,
+}
+
+impl SimplifyCalcFunctions for Layer
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.position.simplify_calc_functions();
+		self.size.simplify_calc_functions()
+	}
+}
+
+impl Layer
+{
+	/// A layer with every part at its initial value.
+	#[inline(always)]
+	pub fn initial(kind: LayerKind) -> Self
+	{
+		Self
+		{
+			image: LayerImage::none,
+			position: Position::default(),
+			size: LayerSize::default(),
+			repeat: LayerRepeat::default(),
+			origin: kind.initialOrigin(),
+			clip: kind.initialClip(),
+			attachment: Attachment::scroll,
+			composite: CompositingOperator::add,
+			mode: MaskingMode::match_source,
+		}
+	}
+	
+	/// Collects one part of each layer into the value of a longhand property.
+	#[inline(always)]
+	pub(crate) fn each<T, Part: Fn(&Layer) -> T>(layers: &[Layer], part: Part) -> CommaSeparatedList<T>
+	{
+		CommaSeparatedList(layers.iter().map(part).collect())
+	}
+	
+	/// Writes the shortest form, omitting parts that have their initial values; `colour` is only ever present for the final layer of a background.
+	pub(crate) fn to_css<W: fmt::Write>(&self, kind: LayerKind, colour: Option<&Colour>, dest: &mut W) -> fmt::Result
+	{
+		let mut separator = "";
+		
+		macro_rules! part
+		{
+			($condition: expr, $value: expr) =>
+			{
+				if $condition
+				{
+					dest.write_str(separator)?;
+					$value.to_css(dest)?;
+					separator = " ";
+				}
+			}
+		}
+		
+		part!(self.image != LayerImage::none, self.image);
+		
+		if !self.position.isInitial() || !self.size.isInitial()
+		{
+			dest.write_str(separator)?;
+			self.position.to_css(dest)?;
+			if !self.size.isInitial()
+			{
+				dest.write_char('/')?;
+				self.size.to_css(dest)?;
+			}
+			separator = " ";
+		}
+		
+		part!(!self.repeat.isInitial(), self.repeat);
+		part!(self.attachment != Attachment::scroll, self.attachment);
+		
+		// A single box sets both the origin and the clip, unless it is only valid as a clip.
+		if self.origin == self.clip
+		{
+			part!(self.origin != kind.initialOrigin() || self.clip != kind.initialClip(), self.origin);
+		}
+		else if self.origin == kind.initialOrigin() && !kind.isValidOrigin(self.clip)
+		{
+			part!(true, self.clip);
+		}
+		else if self.origin != kind.initialOrigin() || self.clip != kind.initialClip()
+		{
+			part!(true, self.origin);
+			part!(true, self.clip);
+		}
+		
+		part!(self.composite != CompositingOperator::add, self.composite);
+		part!(self.mode != MaskingMode::match_source, self.mode);
+		
+		if let Some(colour) = colour
+		{
+			part!(!colour.isTransparent(), colour);
+		}
+		
+		if separator.is_empty()
+		{
+			dest.write_str("none")?;
+		}
+		
+		Ok(())
+	}
+	
+	/// Parses a layer, and, for a background, a colour, which is only valid in the final layer.
+	pub(crate) fn parse<'i, 't>(kind: LayerKind, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<(Self, Option<Colour>), ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut layer = Self::initial(kind);
+		let mut colour = None;
+		
+		let mut image = false;
+		let mut positionAndSize = false;
+		let mut repeat = false;
+		let mut attachment = false;
+		let mut boxes: Vec<VisualBox> = Vec::with_capacity(2);
+		let mut composite = false;
+		let mut mode = false;
+		
+		// Each part may occur at most once, in any order.
+		loop
+		{
+			if !image
+			{
+				if let Ok(value) = input.r#try(|input| LayerImage::parse(context, input))
+				{
+					layer.image = value;
+					image = true;
+					continue;
+				}
+			}
+			
+			if !positionAndSize
+			{
+				if let Ok(value) = input.r#try(|input| Position::parse(context, input))
+				{
+					layer.position = value;
+					if input.r#try(|input| input.expect_delim('/')).is_ok()
+					{
+						layer.size = LayerSize::parse(context, input)?;
+					}
+					positionAndSize = true;
+					continue;
+				}
+			}
+			
+			if !repeat
+			{
+				if let Ok(value) = input.r#try(|input| LayerRepeat::parse(context, input))
+				{
+					layer.repeat = value;
+					repeat = true;
+					continue;
+				}
+			}
+			
+			if !attachment && kind == LayerKind::Background
+			{
+				if let Ok(value) = input.r#try(|input| Attachment::parse(input))
+				{
+					layer.attachment = value;
+					attachment = true;
+					continue;
+				}
+			}
+			
+			if boxes.len() < 2
+			{
+				if let Ok(value) = input.r#try(|input| kind.parse_box(input, |visualBox| kind.isValidClip(visualBox)))
+				{
+					boxes.push(value);
+					continue;
+				}
+			}
+			
+			if !composite && kind == LayerKind::Mask
+			{
+				if let Ok(value) = input.r#try(|input| CompositingOperator::parse(input))
+				{
+					layer.composite = value;
+					composite = true;
+					continue;
+				}
+			}
+			
+			if !mode && kind == LayerKind::Mask
+			{
+				if let Ok(value) = input.r#try(|input| MaskingMode::parse(input))
+				{
+					layer.mode = value;
+					mode = true;
+					continue;
+				}
+			}
+			
+			if colour.is_none() && kind == LayerKind::Background
+			{
+				if let Ok(value) = input.r#try(|input| Colour::parse(context, input))
+				{
+					colour = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if !(image || positionAndSize || repeat || attachment || composite || mode || colour.is_some() || !boxes.is_empty())
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		// One box sets both the origin and the clip, unless it is only valid as a clip (eg `text`); with two, the first is the origin.
+		match boxes.len()
+		{
+			0 => (),
+			
+			1 =>
+			{
+				let visualBox = boxes[0];
+				if kind.isValidOrigin(visualBox)
+				{
+					layer.origin = visualBox;
+				}
+				layer.clip = visualBox;
+			}
+			
+			_ =>
+			{
+				let origin = boxes[0];
+				if !kind.isValidOrigin(origin)
+				{
+					return Err(input.new_custom_error(CustomParseError::VisualBoxIsNotAllowedHere(origin.to_str())))
+				}
+				layer.origin = origin;
+				layer.clip = boxes[1];
+			}
+		}
+		
+		Ok((layer, colour))
+	}
+}
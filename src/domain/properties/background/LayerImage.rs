@@ -0,0 +1,61 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of `background-image` or `mask-image` for one layer.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LayerImage
+{
+	none,
+	
+	Image(Image),
+}
+
+impl ToCss for LayerImage
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			LayerImage::none => dest.write_str("none"),
+			LayerImage::Image(ref image) => image.to_css(dest),
+		}
+	}
+}
+
+impl Default for LayerImage
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		LayerImage::none
+	}
+}
+
+impl Parse for LayerImage
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			Ok(LayerImage::none)
+		}
+		else
+		{
+			Image::parse(context, input).map(LayerImage::Image)
+		}
+	}
+}
+
+impl LayerImage
+{
+	/// Calls `visitor` with the image, if this is not `none`.
+	#[inline(always)]
+	pub fn images_mut<Visitor: FnMut(&mut Image)>(&mut self, visitor: &mut Visitor)
+	{
+		if let LayerImage::Image(ref mut image) = *self
+		{
+			visitor(image)
+		}
+	}
+}
@@ -0,0 +1,76 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Whether a `Layer` is a layer of `background` or of `mask`; the two differ in which parts they have and in their initial values.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LayerKind
+{
+	Background,
+	Mask,
+}
+
+impl LayerKind
+{
+	/// The initial value of `background-origin` or `mask-origin`.
+	#[inline(always)]
+	pub fn initialOrigin(&self) -> VisualBox
+	{
+		match *self
+		{
+			LayerKind::Background => VisualBox::padding_box,
+			LayerKind::Mask => VisualBox::border_box,
+		}
+	}
+	
+	/// The initial value of `background-clip` or `mask-clip`.
+	#[inline(always)]
+	pub fn initialClip(&self) -> VisualBox
+	{
+		VisualBox::border_box
+	}
+	
+	#[inline(always)]
+	pub fn isValidOrigin(&self, visualBox: VisualBox) -> bool
+	{
+		use self::VisualBox::*;
+		
+		matches!((*self, visualBox), (_, border_box) | (_, padding_box) | (_, content_box) | (LayerKind::Mask, margin_box) | (LayerKind::Mask, fill_box) | (LayerKind::Mask, stroke_box) | (LayerKind::Mask, view_box))
+	}
+	
+	#[inline(always)]
+	pub fn isValidClip(&self, visualBox: VisualBox) -> bool
+	{
+		match (*self, visualBox)
+		{
+			(LayerKind::Background, VisualBox::text) | (LayerKind::Mask, VisualBox::no_clip) => true,
+			_ => self.isValidOrigin(visualBox),
+		}
+	}
+	
+	/// Parses the value of `background-origin` or `mask-origin`.
+	pub(crate) fn parse_origins<'i, 't>(&self, input: &mut Parser<'i, 't>) -> Result<CommaSeparatedList<VisualBox>, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| self.parse_box(input, |visualBox| self.isValidOrigin(visualBox))).map(CommaSeparatedList)
+	}
+	
+	/// Parses the value of `background-clip` or `mask-clip`.
+	pub(crate) fn parse_clips<'i, 't>(&self, input: &mut Parser<'i, 't>) -> Result<CommaSeparatedList<VisualBox>, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| self.parse_box(input, |visualBox| self.isValidClip(visualBox))).map(CommaSeparatedList)
+	}
+	
+	#[inline(always)]
+	fn parse_box<'i, 't, IsValid: Fn(VisualBox) -> bool>(&self, input: &mut Parser<'i, 't>, isValid: IsValid) -> Result<VisualBox, ParseError<'i, CustomParseError<'i>>>
+	{
+		let visualBox = VisualBox::parse(input)?;
+		if isValid(visualBox)
+		{
+			Ok(visualBox)
+		}
+		else
+		{
+			Err(input.new_custom_error(CustomParseError::VisualBoxIsNotAllowedHere(visualBox.to_str())))
+		}
+	}
+}
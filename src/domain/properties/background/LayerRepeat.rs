@@ -0,0 +1,78 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of `background-repeat` or `mask-repeat` for one layer.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LayerRepeat
+{
+	pub horizontal: RepeatStyle,
+	
+	pub vertical: RepeatStyle,
+}
+
+impl ToCss for LayerRepeat
+{
+	/// Writes the shortest form, using `repeat-x` and `repeat-y` where possible.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::RepeatStyle::*;
+		
+		match (self.horizontal, self.vertical)
+		{
+			(repeat, no_repeat) => dest.write_str("repeat-x"),
+			(no_repeat, repeat) => dest.write_str("repeat-y"),
+			(horizontal, vertical) if horizontal == vertical => horizontal.to_css(dest),
+			(horizontal, vertical) =>
+			{
+				horizontal.to_css(dest)?;
+				dest.write_char(' ')?;
+				vertical.to_css(dest)
+			}
+		}
+	}
+}
+
+impl Default for LayerRepeat
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			horizontal: RepeatStyle::repeat,
+			vertical: RepeatStyle::repeat,
+		}
+	}
+}
+
+impl Parse for LayerRepeat
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::RepeatStyle::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("repeat-x")).is_ok()
+		{
+			return Ok(Self { horizontal: repeat, vertical: no_repeat })
+		}
+		
+		if input.r#try(|input| input.expect_ident_matching("repeat-y")).is_ok()
+		{
+			return Ok(Self { horizontal: no_repeat, vertical: repeat })
+		}
+		
+		let horizontal = RepeatStyle::parse(input)?;
+		let vertical = input.r#try(|input| RepeatStyle::parse(input)).unwrap_or(horizontal);
+		Ok(Self { horizontal, vertical })
+	}
+}
+
+impl LayerRepeat
+{
+	#[inline(always)]
+	pub fn isInitial(&self) -> bool
+	{
+		*self == Self::default()
+	}
+}
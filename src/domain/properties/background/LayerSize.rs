@@ -0,0 +1,128 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A width or height in a `LayerSize`.
+pub type LayerSizeLength = CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>;
+
+/// A value of `background-size` or `mask-size` for one layer.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LayerSize
+{
+	cover,
+	
+	contain,
+	
+	/// `None` is `auto`.
+	Explicit
+	{
+		width: Option<LayerSizeLength>,
+		height: Option<LayerSizeLength>,
+	},
+}
+
+impl ToCss for LayerSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::LayerSize::*;
+		
+		#[inline(always)]
+		fn write<W: fmt::Write>(length: &Option<LayerSizeLength>, dest: &mut W) -> fmt::Result
+		{
+			match *length
+			{
+				None => dest.write_str("auto"),
+				Some(ref length) => serialize_length_or_percentage(length, dest),
+			}
+		}
+		
+		match *self
+		{
+			cover => dest.write_str("cover"),
+			
+			contain => dest.write_str("contain"),
+			
+			// A missing height is `auto`.
+			Explicit { ref width, ref height } =>
+			{
+				write(width, dest)?;
+				if height.is_some()
+				{
+					dest.write_char(' ')?;
+					write(height, dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Default for LayerSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		LayerSize::Explicit
+		{
+			width: None,
+			height: None,
+		}
+	}
+}
+
+impl Parse for LayerSize
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::LayerSize::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("cover")).is_ok()
+		{
+			return Ok(cover)
+		}
+		
+		if input.r#try(|input| input.expect_ident_matching("contain")).is_ok()
+		{
+			return Ok(contain)
+		}
+		
+		fn parseLengthOrAuto<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Option<LayerSizeLength>, ParseError<'i, CustomParseError<'i>>>
+		{
+			if input.r#try(|input| input.expect_ident_matching("auto")).is_ok()
+			{
+				Ok(None)
+			}
+			else
+			{
+				LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(Some)
+			}
+		}
+		
+		let width = parseLengthOrAuto(context, input)?;
+		let height = input.r#try(|input| parseLengthOrAuto(context, input)).unwrap_or(None);
+		Ok(Explicit { width, height })
+	}
+}
+
+impl SimplifyCalcFunctions for LayerSize
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let LayerSize::Explicit { ref mut width, ref mut height } = *self
+		{
+			width.simplify_calc_functions();
+			height.simplify_calc_functions()
+		}
+	}
+}
+
+impl LayerSize
+{
+	#[inline(always)]
+	pub fn isInitial(&self) -> bool
+	{
+		*self == Self::default()
+	}
+}
@@ -0,0 +1,91 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `mask` shorthand property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Mask
+{
+	/// There is always at least one layer.
+	pub layers: Vec<Layer>,
+}
+
+impl ToCss for Mask
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut iterator = self.layers.iter();
+		if let Some(first) = iterator.next()
+		{
+			first.to_css(LayerKind::Mask, None, dest)?;
+			for layer in iterator
+			{
+				dest.write_char(',')?;
+				layer.to_css(LayerKind::Mask, None, dest)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl Parse for Mask
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let layers = input.parse_comma_separated(|input| Layer::parse(LayerKind::Mask, context, input))?;
+		
+		Ok
+		(
+			Self
+			{
+				layers: layers.into_iter().map(|(layer, _)| layer).collect(),
+			}
+		)
+	}
+}
+
+impl SimplifyCalcFunctions for Mask
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for layer in self.layers.iter_mut()
+		{
+			layer.simplify_calc_functions()
+		}
+	}
+}
+
+impl Mask
+{
+	/// The longhand properties set (or reset) by the `mask` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"mask-image",
+		"mask-position",
+		"mask-size",
+		"mask-repeat",
+		"mask-origin",
+		"mask-clip",
+		"mask-composite",
+		"mask-mode",
+	];
+	
+	/// Expands into longhand property values, each with one item per layer.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		let layers = &self.layers;
+		
+		vec!
+		[
+			TypedPropertyValue::MaskImage(Layer::each(layers, |layer| layer.image.clone())),
+			TypedPropertyValue::MaskPosition(Layer::each(layers, |layer| layer.position.clone())),
+			TypedPropertyValue::MaskSize(Layer::each(layers, |layer| layer.size.clone())),
+			TypedPropertyValue::MaskRepeat(Layer::each(layers, |layer| layer.repeat)),
+			TypedPropertyValue::MaskOrigin(Layer::each(layers, |layer| layer.origin)),
+			TypedPropertyValue::MaskClip(Layer::each(layers, |layer| layer.clip)),
+			TypedPropertyValue::MaskComposite(Layer::each(layers, |layer| layer.composite)),
+			TypedPropertyValue::MaskMode(Layer::each(layers, |layer| layer.mode)),
+		]
+	}
+}
@@ -0,0 +1,19 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("Attachment.rs");
+include!("Background.rs");
+include!("CompositingOperator.rs");
+include!("Layer.rs");
+include!("LayerImage.rs");
+include!("LayerKind.rs");
+include!("LayerRepeat.rs");
+include!("LayerSize.rs");
+include!("Mask.rs");
+include!("MaskingMode.rs");
+include!("RepeatStyle.rs");
+include!("VisualBox.rs");
@@ -0,0 +1,20 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	DisplayInternal:
+	"table-row-group" => table_row_group,
+	"table-header-group" => table_header_group,
+	"table-footer-group" => table_footer_group,
+	"table-row" => table_row,
+	"table-cell" => table_cell,
+	"table-column-group" => table_column_group,
+	"table-column" => table_column,
+	"table-caption" => table_caption,
+	"ruby-base" => ruby_base,
+	"ruby-text" => ruby_text,
+	"ruby-base-container" => ruby_base_container,
+	"ruby-text-container" => ruby_text_container,
+}
@@ -0,0 +1,221 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `display` property, in either the multi-keyword syntax (eg `inline flex`) or the legacy single keyword syntax (eg `inline-flex`): https://drafts.csswg.org/css-display/#the-display-properties
+/// Values are serialized using the shortest equivalent keywords.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum DisplayValue
+{
+	/// An outer and an inner display type, and whether a marker box is generated (`list-item`).
+	/// `list-item` can only be combined with the inner display types `flow` and `flow-root`.
+	Pair
+	{
+		outside: DisplayOutside,
+		
+		inside: DisplayInside,
+		
+		list_item: bool,
+	},
+	
+	Internal(DisplayInternal),
+	
+	BoxGeneration(DisplayBox),
+}
+
+impl Default for DisplayValue
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		DisplayValue::Pair
+		{
+			outside: DisplayOutside::inline,
+			inside: DisplayInside::flow,
+			list_item: false,
+		}
+	}
+}
+
+impl ToCss for DisplayValue
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::DisplayInside::*;
+		use self::DisplayOutside::*;
+		
+		match *self
+		{
+			DisplayValue::Internal(internal) => internal.to_css(dest),
+			
+			DisplayValue::BoxGeneration(boxGeneration) => boxGeneration.to_css(dest),
+			
+			DisplayValue::Pair { outside, inside, list_item: true } =>
+			{
+				if outside != block
+				{
+					outside.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				if inside != flow
+				{
+					inside.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				dest.write_str("list-item")
+			}
+			
+			DisplayValue::Pair { outside, inside, list_item: false } => match (outside, inside)
+			{
+				(block, flow) => dest.write_str("block"),
+				(inline, flow) => dest.write_str("inline"),
+				(run_in, flow) => dest.write_str("run-in"),
+				(block, flow_root) => dest.write_str("flow-root"),
+				(inline, flow_root) => dest.write_str("inline-block"),
+				(block, table) => dest.write_str("table"),
+				(inline, table) => dest.write_str("inline-table"),
+				(block, flex) => dest.write_str("flex"),
+				(inline, flex) => dest.write_str("inline-flex"),
+				(block, grid) => dest.write_str("grid"),
+				(inline, grid) => dest.write_str("inline-grid"),
+				(inline, ruby) => dest.write_str("ruby"),
+				(outside, inside) =>
+				{
+					outside.to_css(dest)?;
+					dest.write_char(' ')?;
+					inside.to_css(dest)
+				}
+			},
+		}
+	}
+}
+
+impl Parse for DisplayValue
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::DisplayInside::*;
+		use self::DisplayOutside::*;
+		
+		if let Ok(boxGeneration) = input.r#try(|input| DisplayBox::parse(input))
+		{
+			return Ok(DisplayValue::BoxGeneration(boxGeneration))
+		}
+		
+		if let Ok(internal) = input.r#try(|input| DisplayInternal::parse(input))
+		{
+			return Ok(DisplayValue::Internal(internal))
+		}
+		
+		if let Ok(inside) = input.r#try(|input| Self::parse_legacy_inline(input))
+		{
+			return Ok(DisplayValue::Pair { outside: inline, inside, list_item: false })
+		}
+		
+		let mut outside = None;
+		let mut inside = None;
+		let mut list_item = false;
+		
+		loop
+		{
+			if outside.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| DisplayOutside::parse(input))
+				{
+					outside = Some(value);
+					continue;
+				}
+			}
+			
+			if inside.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| DisplayInside::parse(input))
+				{
+					inside = Some(value);
+					continue;
+				}
+			}
+			
+			if !list_item && input.r#try(|input| input.expect_ident_matching("list-item")).is_ok()
+			{
+				list_item = true;
+				continue;
+			}
+			
+			break;
+		}
+		
+		if outside.is_none() && inside.is_none() && !list_item
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		let inside = inside.unwrap_or(flow);
+		if list_item && inside != flow && inside != flow_root
+		{
+			return Err(input.new_custom_error(CustomParseError::DisplayListItemMustHaveAFlowInnerDisplayType))
+		}
+		
+		// `ruby` on its own is an inline-level box; every other inner display type on its own is block-level.
+		let outside = outside.unwrap_or(if inside == ruby { inline } else { block });
+		
+		Ok(DisplayValue::Pair { outside, inside, list_item })
+	}
+}
+
+impl DisplayValue
+{
+	/// Whether this is `display: none`.
+	#[inline(always)]
+	pub fn isNone(&self) -> bool
+	{
+		*self == DisplayValue::BoxGeneration(DisplayBox::none)
+	}
+	
+	/// Whether this box is a flex container, ie `flex` or `inline-flex`.
+	#[inline(always)]
+	pub fn isFlexContainer(&self) -> bool
+	{
+		self.inside() == Some(DisplayInside::flex)
+	}
+	
+	/// Whether this box is a grid container, ie `grid` or `inline-grid`.
+	#[inline(always)]
+	pub fn isGridContainer(&self) -> bool
+	{
+		self.inside() == Some(DisplayInside::grid)
+	}
+	
+	/// The inner display type, if this is not an internal or box generation keyword.
+	#[inline(always)]
+	pub fn inside(&self) -> Option<DisplayInside>
+	{
+		match *self
+		{
+			DisplayValue::Pair { inside, .. } => Some(inside),
+			_ => None,
+		}
+	}
+	
+	/// Parses `inline-block`, `inline-table`, `inline-flex` or `inline-grid`, returning the inner display type.
+	fn parse_legacy_inline<'i, 't>(input: &mut Parser<'i, 't>) -> Result<DisplayInside, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::DisplayInside::*;
+		
+		let ident = input.expect_ident()?.clone();
+		match_ignore_ascii_case!
+		{
+			&*ident,
+			
+			"inline-block" => Ok(flow_root),
+			
+			"inline-table" => Ok(table),
+			
+			"inline-flex" => Ok(flex),
+			
+			"inline-grid" => Ok(grid),
+			
+			_ => Err(input.new_unexpected_token_error(Token::Ident(ident.clone()))),
+		}
+	}
+}
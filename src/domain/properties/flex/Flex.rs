@@ -0,0 +1,169 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `flex` shorthand property, such as `1`, `none` or `2 1 10em`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Flex
+{
+	pub grow: CssUnsignedNumber,
+	
+	pub shrink: CssUnsignedNumber,
+	
+	pub basis: FlexBasis,
+}
+
+impl Default for Flex
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			grow: CssUnsignedNumber::Zero,
+			shrink: CssUnsignedNumber::One,
+			basis: FlexBasis::auto,
+		}
+	}
+}
+
+impl ToCss for Flex
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if *self == Self::none()
+		{
+			return dest.write_str("none")
+		}
+		
+		let shrinkIsOmitted = self.shrink == CssUnsignedNumber::One;
+		
+		if self.basis == FlexBasis::omitted()
+		{
+			self.grow.to_css(dest)?;
+			if !shrinkIsOmitted
+			{
+				dest.write_char(' ')?;
+				self.shrink.to_css(dest)?;
+			}
+			return Ok(())
+		}
+		
+		// A zero length basis is serialized as `0`, which is only a basis if it follows both flex factors.
+		let basisIsZero = self.basis.isZero();
+		
+		if self.grow == CssUnsignedNumber::One && shrinkIsOmitted && !basisIsZero
+		{
+			return self.basis.to_css(dest)
+		}
+		
+		self.grow.to_css(dest)?;
+		dest.write_char(' ')?;
+		if !shrinkIsOmitted || basisIsZero
+		{
+			self.shrink.to_css(dest)?;
+			dest.write_char(' ')?;
+		}
+		self.basis.to_css(dest)
+	}
+}
+
+impl Parse for Flex
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(Self::none())
+		}
+		
+		let mut factors = None;
+		let mut basis = None;
+		
+		loop
+		{
+			if factors.is_none()
+			{
+				if let Ok(grow) = input.r#try(|input| Self::parse_factor(input))
+				{
+					let shrink = input.r#try(|input| Self::parse_factor(input)).ok();
+					factors = Some((grow, shrink));
+					continue;
+				}
+			}
+			
+			if basis.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| FlexBasis::parse(context, input))
+				{
+					basis = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if factors.is_none() && basis.is_none()
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		let (grow, shrink) = factors.unwrap_or((CssUnsignedNumber::One, None));
+		
+		Ok
+		(
+			Self
+			{
+				grow,
+				shrink: shrink.unwrap_or(CssUnsignedNumber::One),
+				basis: basis.unwrap_or_else(FlexBasis::omitted),
+			}
+		)
+	}
+}
+
+impl SimplifyCalcFunctions for Flex
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.basis.simplify_calc_functions()
+	}
+}
+
+impl Flex
+{
+	/// The longhand properties set by this shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"flex-grow",
+		"flex-shrink",
+		"flex-basis",
+	];
+	
+	/// `flex: none`, ie `0 0 auto`.
+	#[inline(always)]
+	pub fn none() -> Self
+	{
+		Self
+		{
+			grow: CssUnsignedNumber::Zero,
+			shrink: CssUnsignedNumber::Zero,
+			basis: FlexBasis::auto,
+		}
+	}
+	
+	/// Expands into longhand property values.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		vec![TypedPropertyValue::FlexGrow(self.grow), TypedPropertyValue::FlexShrink(self.shrink), TypedPropertyValue::FlexBasis(self.basis.clone())]
+	}
+	
+	/// Parses a value of `flex-grow` or `flex-shrink`.
+	pub(crate) fn parse_factor<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssUnsignedNumber, ParseError<'i, CustomParseError<'i>>>
+	{
+		let value = input.expect_number()?;
+		CssUnsignedNumber::parseNumber(value, None).map_err(|error| input.new_custom_error(error))
+	}
+}
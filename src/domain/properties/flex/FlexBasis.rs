@@ -0,0 +1,128 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `flex-basis` property, or the basis of the `flex` shorthand property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FlexBasis
+{
+	auto,
+	
+	content,
+	
+	min_content,
+	
+	max_content,
+	
+	fit_content,
+	
+	LengthOrPercentage(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl Default for FlexBasis
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FlexBasis::auto
+	}
+}
+
+impl ToCss for FlexBasis
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FlexBasis::*;
+		
+		match *self
+		{
+			auto => dest.write_str("auto"),
+			content => dest.write_str("content"),
+			min_content => dest.write_str("min-content"),
+			max_content => dest.write_str("max-content"),
+			fit_content => dest.write_str("fit-content"),
+			LengthOrPercentage(ref length) => serialize_length_or_percentage(length, dest),
+		}
+	}
+}
+
+impl Parse for FlexBasis
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FlexBasis::*;
+		
+		if let Ok(ident) = input.r#try(|input| input.expect_ident().cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"auto" => Ok(auto),
+				
+				"content" => Ok(content),
+				
+				"min-content" => Ok(min_content),
+				
+				"max-content" => Ok(max_content),
+				
+				"fit-content" => Ok(fit_content),
+				
+				_ => Err(input.new_unexpected_token_error(Token::Ident(ident.clone()))),
+			}
+		}
+		
+		LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(LengthOrPercentage)
+	}
+}
+
+impl SimplifyCalcFunctions for FlexBasis
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let FlexBasis::LengthOrPercentage(ref mut length) = *self
+		{
+			length.simplify_calc_functions()
+		}
+	}
+}
+
+impl FlexBasis
+{
+	/// The basis used by the `flex` shorthand when it is omitted, `0%`.
+	#[inline(always)]
+	pub fn omitted() -> Self
+	{
+		FlexBasis::LengthOrPercentage(CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(PercentageUnit::ZeroPercent)))
+	}
+	
+	/// Whether this is a zero length, which is serialized as an unitless `0` that could be mistaken for a flex factor.
+	#[inline(always)]
+	pub fn isZero(&self) -> bool
+	{
+		match *self
+		{
+			FlexBasis::LengthOrPercentage(CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsLength(ref length))) => length.is_zero(),
+			_ => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn zero_percentage_flex_bases_keep_their_unit()
+	{
+		assert_eq!(Stylesheet::parse("a{flex-basis:0%}").unwrap().to_css_string(false), "a{flex-basis:0%}");
+		assert_eq!(Stylesheet::parse("a{flex:2 0%}").unwrap().to_css_string(false), "a{flex:2}");
+		assert_eq!(Stylesheet::parse("a{flex:2 0px}").unwrap().to_css_string(false), "a{flex:2 1 0}");
+		
+		let longhands = PropertyDeclaration::<Importance>::parse("flex: 1").unwrap().longhands().unwrap();
+		let longhands = longhands.iter().map(|longhand| longhand.to_css_string()).collect::<Vec<_>>();
+		assert_eq!(longhands, vec!["flex-grow:1;", "flex-shrink:1;", "flex-basis:0%;"]);
+	}
+}
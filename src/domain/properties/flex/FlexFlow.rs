@@ -0,0 +1,95 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `flex-flow` shorthand property, such as `column wrap`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FlexFlow
+{
+	pub direction: FlexDirection,
+	
+	pub wrap: FlexWrap,
+}
+
+impl ToCss for FlexFlow
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.wrap == FlexWrap::default()
+		{
+			self.direction.to_css(dest)
+		}
+		else if self.direction == FlexDirection::default()
+		{
+			self.wrap.to_css(dest)
+		}
+		else
+		{
+			self.direction.to_css(dest)?;
+			dest.write_char(' ')?;
+			self.wrap.to_css(dest)
+		}
+	}
+}
+
+impl Parse for FlexFlow
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut direction = None;
+		let mut wrap = None;
+		
+		loop
+		{
+			if direction.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| FlexDirection::parse(input))
+				{
+					direction = Some(value);
+					continue;
+				}
+			}
+			
+			if wrap.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| FlexWrap::parse(input))
+				{
+					wrap = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if direction.is_none() && wrap.is_none()
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		Ok
+		(
+			Self
+			{
+				direction: direction.unwrap_or_default(),
+				wrap: wrap.unwrap_or_default(),
+			}
+		)
+	}
+}
+
+impl FlexFlow
+{
+	/// The longhand properties set by this shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"flex-direction",
+		"flex-wrap",
+	];
+	
+	/// Expands into longhand property values.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		vec![TypedPropertyValue::FlexDirection(self.direction), TypedPropertyValue::FlexWrap(self.wrap)]
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font` shorthand property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Font
+{
+	/// The font used by the user's operating system for, eg, captions; it can not be expanded into longhands.
+	System(SystemFont),
+	
+	Specified(SpecifiedFont),
+}
+
+impl ToCss for Font
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Font::*;
+		
+		match *self
+		{
+			System(ref systemFont) => systemFont.to_css(dest),
+			Specified(ref specifiedFont) => specifiedFont.to_css(dest),
+		}
+	}
+}
+
+impl Parse for Font
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Font::*;
+		
+		if let Ok(systemFont) = input.r#try(|input| SystemFont::parse(input))
+		{
+			Ok(System(systemFont))
+		}
+		else
+		{
+			SpecifiedFont::parse(context, input).map(Specified)
+		}
+	}
+}
+
+impl Font
+{
+	/// The longhand properties set (or reset) by the `font` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"font-style",
+		"font-variant-caps",
+		"font-weight",
+		"font-stretch",
+		"font-size",
+		"line-height",
+		"font-family",
+		"font-variant-ligatures",
+		"font-variant-numeric",
+		"font-variant-east-asian",
+		"font-variant-position",
+		"font-variant-alternates",
+		"font-feature-settings",
+	];
+	
+	/// Expands into longhand property values; `None` for a system font.
+	pub fn longhands(&self) -> Option<Vec<TypedPropertyValue>>
+	{
+		match *self
+		{
+			Font::System(_) => None,
+			
+			Font::Specified(ref specifiedFont) => Some
+			(
+				vec!
+				[
+					TypedPropertyValue::FontStyle(specifiedFont.style),
+					TypedPropertyValue::FontVariantCaps(specifiedFont.variant_caps),
+					TypedPropertyValue::FontWeight(specifiedFont.weight),
+					TypedPropertyValue::FontStretch(specifiedFont.stretch),
+					TypedPropertyValue::FontSize(specifiedFont.size.clone()),
+					TypedPropertyValue::LineHeight(specifiedFont.line_height.clone()),
+					TypedPropertyValue::FontFamily(specifiedFont.family.clone()),
+					TypedPropertyValue::FontVariantLigatures(Default::default()),
+					TypedPropertyValue::FontVariantNumeric(Default::default()),
+					TypedPropertyValue::FontVariantEastAsian(Default::default()),
+					TypedPropertyValue::FontVariantPosition(FontVariantPosition::normal),
+					TypedPropertyValue::FontVariantAlternates(Default::default()),
+					TypedPropertyValue::FontFeatureSettings(FontFeatureSettings::normal()),
+				]
+			),
+		}
+	}
+}
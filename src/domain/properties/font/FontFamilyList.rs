@@ -0,0 +1,85 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font-family` property; a comma-separated list of family names and generic families, in order of preference.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontFamilyList(pub Vec<FontFamily>);
+
+impl ToCss for FontFamilyList
+{
+	/// Quoted family names are written without quotes if that is shorter and does not change their meaning.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut isFirst = true;
+		for fontFamily in self.0.iter()
+		{
+			if isFirst
+			{
+				isFirst = false;
+			}
+			else
+			{
+				dest.write_char(',')?;
+			}
+			
+			match *fontFamily
+			{
+				FontFamily::FamilyName(FamilyName { ref name, syntax: FamilyNameSyntax::Quoted }) if Self::canBeWrittenAsIdentifiers(name) => dest.write_str(name)?,
+				
+				_ => fontFamily.to_css(dest)?,
+			}
+		}
+		Ok(())
+	}
+}
+
+impl Parse for FontFamilyList
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| FontFamily::parse(input)).map(FontFamilyList)
+	}
+}
+
+impl FontFamilyList
+{
+	/// Generic family names and keywords that a family name can not be written as without quotes.
+	const ReservedIdentifiers: &'static [&'static str] = &
+	[
+		"serif",
+		"sans-serif",
+		"cursive",
+		"fantasy",
+		"monospace",
+		"system-ui",
+		"ui-serif",
+		"ui-sans-serif",
+		"ui-monospace",
+		"ui-rounded",
+		"emoji",
+		"math",
+		"fangsong",
+		"inherit",
+		"initial",
+		"unset",
+		"revert",
+		"revert-layer",
+		"default",
+	];
+	
+	fn canBeWrittenAsIdentifiers(name: &str) -> bool
+	{
+		let mut serialization = String::with_capacity(name.len());
+		name.split(' ').all(|identifier|
+		{
+			if identifier.is_empty() || Self::ReservedIdentifiers.iter().any(|reserved| identifier.eq_ignore_ascii_case(reserved))
+			{
+				return false;
+			}
+			
+			serialization.clear();
+			serialize_identifier(identifier, &mut serialization).is_ok() && serialization == identifier
+		})
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font-size` property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FontSize
+{
+	Absolute(AbsoluteFontSize),
+	larger,
+	smaller,
+	LengthOrPercentage(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl ToCss for FontSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FontSize::*;
+		
+		match *self
+		{
+			Absolute(ref absoluteFontSize) => absoluteFontSize.to_css(dest),
+			larger => dest.write_str("larger"),
+			smaller => dest.write_str("smaller"),
+			LengthOrPercentage(ref lengthOrPercentage) => serialize_length_or_percentage(lengthOrPercentage, dest),
+		}
+	}
+}
+
+impl Default for FontSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FontSize::Absolute(AbsoluteFontSize::medium)
+	}
+}
+
+impl Parse for FontSize
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FontSize::*;
+		
+		if let Ok(absoluteFontSize) = input.r#try(|input| AbsoluteFontSize::parse(input))
+		{
+			Ok(Absolute(absoluteFontSize))
+		}
+		else if input.r#try(|input| input.expect_ident_matching("larger")).is_ok()
+		{
+			Ok(larger)
+		}
+		else if input.r#try(|input| input.expect_ident_matching("smaller")).is_ok()
+		{
+			Ok(smaller)
+		}
+		else
+		{
+			LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(LengthOrPercentage)
+		}
+	}
+}
+
+impl SimplifyCalcFunctions for FontSize
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let FontSize::LengthOrPercentage(ref mut length) = *self
+		{
+			length.simplify_calc_functions()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn zero_font_sizes_are_serialized_without_a_unit()
+	{
+		assert_eq!(Stylesheet::parse("a{font-size:0}").unwrap().to_css_string(false), "a{font-size:0}");
+		assert_eq!(Stylesheet::parse("a{font-size:0px}").unwrap().to_css_string(false), "a{font-size:0}");
+	}
+}
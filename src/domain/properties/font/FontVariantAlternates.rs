@@ -0,0 +1,157 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font-variant-alternates` property; `normal` if nothing is specified.
+/// Functions refer to feature value names defined by `@font-feature-values` rules; those that take a list have an empty list if absent.
+#[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontVariantAlternates
+{
+	pub stylistic: Option<CustomIdent>,
+	pub historical_forms: bool,
+	pub styleset: Vec<CustomIdent>,
+	pub character_variant: Vec<CustomIdent>,
+	pub swash: Option<CustomIdent>,
+	pub ornaments: Option<CustomIdent>,
+	pub annotation: Option<CustomIdent>,
+}
+
+impl ToCss for FontVariantAlternates
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.isNormal()
+		{
+			return dest.write_str("normal")
+		}
+		
+		let functions: [(&str, &[CustomIdent]); 6] =
+		[
+			("stylistic", self.stylistic.as_slice()),
+			("styleset", &self.styleset[..]),
+			("character-variant", &self.character_variant[..]),
+			("swash", self.swash.as_slice()),
+			("ornaments", self.ornaments.as_slice()),
+			("annotation", self.annotation.as_slice()),
+		];
+		
+		let mut separator = "";
+		for (index, &(name, featureValueNames)) in functions.iter().enumerate()
+		{
+			// `historical-forms` comes after `stylistic()` in the grammar.
+			if index == 1 && self.historical_forms
+			{
+				dest.write_str(separator)?;
+				dest.write_str("historical-forms")?;
+				separator = " ";
+			}
+			
+			if featureValueNames.is_empty()
+			{
+				continue;
+			}
+			
+			dest.write_str(separator)?;
+			dest.write_str(name)?;
+			dest.write_char('(')?;
+			let mut isFirst = true;
+			for featureValueName in featureValueNames.iter()
+			{
+				if isFirst
+				{
+					isFirst = false;
+				}
+				else
+				{
+					dest.write_char(',')?;
+				}
+				featureValueName.to_css(dest)?;
+			}
+			dest.write_char(')')?;
+			separator = " ";
+		}
+		
+		Ok(())
+	}
+}
+
+impl Parse for FontVariantAlternates
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default())
+		}
+		
+		fn parse_feature_value_name<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CustomIdent, ParseError<'i, CustomParseError<'i>>>
+		{
+			let ident = input.expect_ident()?.clone();
+			CustomIdent::from_ident(&ident, &[]).map_err(|error| input.new_custom_error(error))
+		}
+		
+		let mut value = Self::default();
+		let mut isEmpty = true;
+		loop
+		{
+			if input.r#try(|input| input.expect_ident_matching("historical-forms")).is_ok()
+			{
+				if replace(&mut value.historical_forms, true)
+				{
+					return Err(input.new_unexpected_token_error(Token::Ident("historical-forms".into())))
+				}
+				isEmpty = false;
+				continue;
+			}
+			
+			let name = match input.r#try(|input| input.expect_function().cloned())
+			{
+				Err(_) => break,
+				Ok(name) => name,
+			};
+			
+			let isDuplicate = match_ignore_ascii_case!
+			{
+				&name,
+				
+				"stylistic" => input.parse_nested_block(parse_feature_value_name).map(|featureValueName| value.stylistic.replace(featureValueName).is_some())?,
+				
+				"styleset" => input.parse_nested_block(|input| input.parse_comma_separated(parse_feature_value_name)).map(|featureValueNames| !replace(&mut value.styleset, featureValueNames).is_empty())?,
+				
+				"character-variant" => input.parse_nested_block(|input| input.parse_comma_separated(parse_feature_value_name)).map(|featureValueNames| !replace(&mut value.character_variant, featureValueNames).is_empty())?,
+				
+				"swash" => input.parse_nested_block(parse_feature_value_name).map(|featureValueName| value.swash.replace(featureValueName).is_some())?,
+				
+				"ornaments" => input.parse_nested_block(parse_feature_value_name).map(|featureValueName| value.ornaments.replace(featureValueName).is_some())?,
+				
+				"annotation" => input.parse_nested_block(parse_feature_value_name).map(|featureValueName| value.annotation.replace(featureValueName).is_some())?,
+				
+				_ => return Err(input.new_unexpected_token_error(Token::Function(name))),
+			};
+			
+			if isDuplicate
+			{
+				return Err(input.new_unexpected_token_error(Token::Function(name)))
+			}
+			isEmpty = false;
+		}
+		
+		if isEmpty
+		{
+			Err(input.new_error_for_next_token())
+		}
+		else
+		{
+			Ok(value)
+		}
+	}
+}
+
+impl FontVariantAlternates
+{
+	#[inline(always)]
+	pub fn isNormal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font-variant-east-asian` property; `normal` if nothing is specified.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontVariantEastAsian
+{
+	pub variant: Option<EastAsianVariantValues>,
+	pub width: Option<EastAsianWidthValues>,
+	pub ruby: bool,
+}
+
+impl ToCss for FontVariantEastAsian
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.isNormal()
+		{
+			return dest.write_str("normal")
+		}
+		
+		let keywords =
+		[
+			self.variant.map(|variant| variant.to_str()),
+			self.width.map(|width| width.to_str()),
+			if self.ruby { Some("ruby") } else { None },
+		];
+		
+		let mut separator = "";
+		for keyword in keywords.iter().filter_map(|keyword| *keyword)
+		{
+			dest.write_str(separator)?;
+			dest.write_str(keyword)?;
+			separator = " ";
+		}
+		Ok(())
+	}
+}
+
+impl Parse for FontVariantEastAsian
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default())
+		}
+		
+		let mut value = Self::default();
+		while let Ok(ident) = input.r#try(|input| input.expect_ident_cloned())
+		{
+			let isDuplicate = if let Ok(variant) = EastAsianVariantValues::from_ident(&ident)
+			{
+				value.variant.replace(variant).is_some()
+			}
+			else if let Ok(width) = EastAsianWidthValues::from_ident(&ident)
+			{
+				value.width.replace(width).is_some()
+			}
+			else if ident.eq_ignore_ascii_case("ruby")
+			{
+				replace(&mut value.ruby, true)
+			}
+			else
+			{
+				true
+			};
+			
+			if isDuplicate
+			{
+				return Err(input.new_unexpected_token_error(Token::Ident(ident)))
+			}
+		}
+		
+		if value.isNormal()
+		{
+			Err(input.new_error_for_next_token())
+		}
+		else
+		{
+			Ok(value)
+		}
+	}
+}
+
+impl FontVariantEastAsian
+{
+	#[inline(always)]
+	pub fn isNormal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+}
@@ -0,0 +1,122 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font-variant-ligatures` property.
+/// For each kind of ligature, `Some(true)` enables it (eg `common-ligatures`), `Some(false)` disables it (eg `no-common-ligatures`) and `None` leaves it as normal.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FontVariantLigatures
+{
+	normal,
+	none,
+	Values
+	{
+		common: Option<bool>,
+		discretionary: Option<bool>,
+		historical: Option<bool>,
+		contextual: Option<bool>,
+	},
+}
+
+impl ToCss for FontVariantLigatures
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FontVariantLigatures::*;
+		
+		match *self
+		{
+			normal => dest.write_str("normal"),
+			
+			none => dest.write_str("none"),
+			
+			Values { common, discretionary, historical, contextual } =>
+			{
+				let mut separator = "";
+				for &(value, enabled, disabled) in &
+				[
+					(common, "common-ligatures", "no-common-ligatures"),
+					(discretionary, "discretionary-ligatures", "no-discretionary-ligatures"),
+					(historical, "historical-ligatures", "no-historical-ligatures"),
+					(contextual, "contextual", "no-contextual"),
+				]
+				{
+					if let Some(isEnabled) = value
+					{
+						dest.write_str(separator)?;
+						dest.write_str(if isEnabled { enabled } else { disabled })?;
+						separator = " ";
+					}
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Default for FontVariantLigatures
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FontVariantLigatures::normal
+	}
+}
+
+impl Parse for FontVariantLigatures
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FontVariantLigatures::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(normal)
+		}
+		
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none)
+		}
+		
+		let mut common = None;
+		let mut discretionary = None;
+		let mut historical = None;
+		let mut contextual = None;
+		let mut isEmpty = true;
+		while let Ok(ident) = input.r#try(|input| input.expect_ident_cloned())
+		{
+			let (value, isEnabled) = match_ignore_ascii_case!
+			{
+				&ident,
+				
+				"common-ligatures" => (&mut common, true),
+				"no-common-ligatures" => (&mut common, false),
+				"discretionary-ligatures" => (&mut discretionary, true),
+				"no-discretionary-ligatures" => (&mut discretionary, false),
+				"historical-ligatures" => (&mut historical, true),
+				"no-historical-ligatures" => (&mut historical, false),
+				"contextual" => (&mut contextual, true),
+				"no-contextual" => (&mut contextual, false),
+				
+				_ => return Err(input.new_unexpected_token_error(Token::Ident(ident))),
+			};
+			
+			if value.is_some()
+			{
+				return Err(input.new_unexpected_token_error(Token::Ident(ident)))
+			}
+			*value = Some(isEnabled);
+			isEmpty = false;
+		}
+		
+		if isEmpty
+		{
+			Err(input.new_error_for_next_token())
+		}
+		else
+		{
+			Ok(Values { common, discretionary, historical, contextual })
+		}
+	}
+}
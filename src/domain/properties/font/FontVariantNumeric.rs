@@ -0,0 +1,106 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font-variant-numeric` property; `normal` if nothing is specified.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontVariantNumeric
+{
+	pub figure: Option<NumericFigureValues>,
+	pub spacing: Option<NumericSpacingValues>,
+	pub fraction: Option<NumericFractionValues>,
+	pub ordinal: bool,
+	pub slashed_zero: bool,
+}
+
+impl ToCss for FontVariantNumeric
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.isNormal()
+		{
+			return dest.write_str("normal")
+		}
+		
+		let keywords =
+		[
+			self.figure.map(|figure| figure.to_str()),
+			self.spacing.map(|spacing| spacing.to_str()),
+			self.fraction.map(|fraction| fraction.to_str()),
+			if self.ordinal { Some("ordinal") } else { None },
+			if self.slashed_zero { Some("slashed-zero") } else { None },
+		];
+		
+		let mut separator = "";
+		for keyword in keywords.iter().filter_map(|keyword| *keyword)
+		{
+			dest.write_str(separator)?;
+			dest.write_str(keyword)?;
+			separator = " ";
+		}
+		Ok(())
+	}
+}
+
+impl Parse for FontVariantNumeric
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default())
+		}
+		
+		let mut value = Self::default();
+		while let Ok(ident) = input.r#try(|input| input.expect_ident_cloned())
+		{
+			let isDuplicate = if let Ok(figure) = NumericFigureValues::from_ident(&ident)
+			{
+				value.figure.replace(figure).is_some()
+			}
+			else if let Ok(spacing) = NumericSpacingValues::from_ident(&ident)
+			{
+				value.spacing.replace(spacing).is_some()
+			}
+			else if let Ok(fraction) = NumericFractionValues::from_ident(&ident)
+			{
+				value.fraction.replace(fraction).is_some()
+			}
+			else if ident.eq_ignore_ascii_case("ordinal")
+			{
+				replace(&mut value.ordinal, true)
+			}
+			else if ident.eq_ignore_ascii_case("slashed-zero")
+			{
+				replace(&mut value.slashed_zero, true)
+			}
+			else
+			{
+				true
+			};
+			
+			if isDuplicate
+			{
+				return Err(input.new_unexpected_token_error(Token::Ident(ident)))
+			}
+		}
+		
+		if value.isNormal()
+		{
+			Err(input.new_error_for_next_token())
+		}
+		else
+		{
+			Ok(value)
+		}
+	}
+}
+
+impl FontVariantNumeric
+{
+	#[inline(always)]
+	pub fn isNormal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+}
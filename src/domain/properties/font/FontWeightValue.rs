@@ -0,0 +1,67 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font-weight` property.
+/// Unlike the `font-weight` descriptor of a `@font-face` rule, it can be relative to the inherited weight.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FontWeightValue
+{
+	Absolute(FontWeight),
+	bolder,
+	lighter,
+}
+
+impl ToCss for FontWeightValue
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FontWeightValue::*;
+		
+		match *self
+		{
+			Absolute(ref fontWeight) => fontWeight.to_css(dest),
+			bolder => dest.write_str("bolder"),
+			lighter => dest.write_str("lighter"),
+		}
+	}
+}
+
+impl Default for FontWeightValue
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FontWeightValue::Absolute(FontWeight::normal)
+	}
+}
+
+impl Parse for FontWeightValue
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FontWeightValue::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("bolder")).is_ok()
+		{
+			Ok(bolder)
+		}
+		else if input.r#try(|input| input.expect_ident_matching("lighter")).is_ok()
+		{
+			Ok(lighter)
+		}
+		else
+		{
+			<FontWeight as Parse>::parse(context, input).map(Absolute)
+		}
+	}
+}
+
+impl FontWeightValue
+{
+	#[inline(always)]
+	pub fn isNormal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+}
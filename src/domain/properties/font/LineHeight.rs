@@ -0,0 +1,103 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `line-height` property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LineHeight
+{
+	normal,
+	
+	/// A multiple of the font size; unlike a percentage, it is inherited as a multiple.
+	Number(CssUnsignedNumber),
+	
+	LengthOrPercentage(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl ToCss for LineHeight
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::LineHeight::*;
+		
+		match *self
+		{
+			normal => dest.write_str("normal"),
+			Number(ref number) => number.to_css(dest),
+			LengthOrPercentage(ref lengthOrPercentage) => lengthOrPercentage.to_css(dest),
+		}
+	}
+}
+
+impl Default for LineHeight
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		LineHeight::normal
+	}
+}
+
+impl Parse for LineHeight
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::LineHeight::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(normal)
+		}
+		
+		if let Ok(value) = input.r#try(|input| input.expect_number())
+		{
+			return CssUnsignedNumber::new(value).map(Number).map_err(|cssNumberConversionError| input.new_custom_error(CustomParseError::CouldNotParseCssUnsignedNumber(cssNumberConversionError, value)))
+		}
+		
+		if let Ok(lengthOrPercentage) = input.r#try(|input| LengthOrPercentageUnit::parse_one_outside_calc_function(context, input))
+		{
+			return Ok(LengthOrPercentage(lengthOrPercentage))
+		}
+		
+		// A `calc()` function that is a number, such as `calc(1 + 0.5)`, is not a length; it is kept as one until simplified.
+		input.expect_function_matching("calc")?;
+		let expression = CalcExpression::parse(context, input)?;
+		match expression.is_number()
+		{
+			Ok(Some(true)) => Ok(LengthOrPercentage(CalculablePropertyValue::Calc(CalcFunction(Arc::new(expression))))),
+			_ => Err(input.new_custom_error(CustomParseError::CalcFunctionResultMustBeANumber)),
+		}
+	}
+}
+
+impl SimplifyCalcFunctions for LineHeight
+{
+	/// A `calc()` function that simplifies to a number, such as `calc(1 + 0.5)`, becomes that number.
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		let number = match *self
+		{
+			LineHeight::LengthOrPercentage(ref mut length) =>
+			{
+				length.simplify_calc_functions();
+				length.calc_number()
+			}
+			_ => return,
+		};
+		
+		if let Some(number) = number
+		{
+			*self = LineHeight::Number(number)
+		}
+	}
+}
+
+impl LineHeight
+{
+	#[inline(always)]
+	pub fn isNormal(&self) -> bool
+	{
+		*self == LineHeight::normal
+	}
+}
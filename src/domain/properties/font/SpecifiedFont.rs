@@ -0,0 +1,148 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `font` shorthand property that is not a system font.
+/// Longhands not given in the CSS have their initial values.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SpecifiedFont
+{
+	pub style: FontStyle,
+	
+	/// Only `normal` or `small-caps` can be specified in the shorthand.
+	pub variant_caps: FontVariantCaps,
+	
+	pub weight: FontWeightValue,
+	
+	pub stretch: FontStretch,
+	
+	pub size: FontSize,
+	
+	pub line_height: LineHeight,
+	
+	pub family: FontFamilyList,
+}
+
+impl ToCss for SpecifiedFont
+{
+	/// Writes the shortest form, omitting values that are `normal`.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.style != FontStyle::normal
+		{
+			self.style.to_css(dest)?;
+			dest.write_char(' ')?;
+		}
+		
+		if self.variant_caps != FontVariantCaps::normal
+		{
+			self.variant_caps.to_css(dest)?;
+			dest.write_char(' ')?;
+		}
+		
+		if !self.weight.isNormal()
+		{
+			self.weight.to_css(dest)?;
+			dest.write_char(' ')?;
+		}
+		
+		if self.stretch != FontStretch::normal
+		{
+			self.stretch.to_css(dest)?;
+			dest.write_char(' ')?;
+		}
+		
+		self.size.to_css(dest)?;
+		
+		if !self.line_height.isNormal()
+		{
+			dest.write_char('/')?;
+			self.line_height.to_css(dest)?;
+		}
+		
+		dest.write_char(' ')?;
+		self.family.to_css(dest)
+	}
+}
+
+impl Parse for SpecifiedFont
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut style = None;
+		let mut variant_caps = None;
+		let mut weight = None;
+		let mut stretch = None;
+		
+		// Up to four of style, variant, weight and stretch, in any order; `normal` could be any of them.
+		for _ in 0 .. 4
+		{
+			if input.r#try(|input| input.expect_ident_matching("normal")).is_ok()
+			{
+				continue;
+			}
+			
+			if style.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| FontStyle::parse(input))
+				{
+					style = Some(value);
+					continue;
+				}
+			}
+			
+			if variant_caps.is_none() && input.r#try(|input| input.expect_ident_matching("small-caps")).is_ok()
+			{
+				variant_caps = Some(FontVariantCaps::small_caps);
+				continue;
+			}
+			
+			if weight.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| FontWeightValue::parse(context, input))
+				{
+					weight = Some(value);
+					continue;
+				}
+			}
+			
+			if stretch.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| FontStretch::parse(input))
+				{
+					stretch = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		let size = FontSize::parse(context, input)?;
+		
+		let line_height = if input.r#try(|input| input.expect_delim('/')).is_ok()
+		{
+			LineHeight::parse(context, input)?
+		}
+		else
+		{
+			LineHeight::normal
+		};
+		
+		let family = FontFamilyList::parse(context, input)?;
+		
+		Ok
+		(
+			Self
+			{
+				style: style.unwrap_or(FontStyle::normal),
+				variant_caps: variant_caps.unwrap_or(FontVariantCaps::normal),
+				weight: weight.unwrap_or_default(),
+				stretch: stretch.unwrap_or(FontStretch::normal),
+				size,
+				line_height,
+				family,
+			}
+		)
+	}
+}
@@ -0,0 +1,27 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use ::std::sync::Arc;
+
+
+include!("AbsoluteFontSize.rs");
+include!("EastAsianVariantValues.rs");
+include!("EastAsianWidthValues.rs");
+include!("Font.rs");
+include!("FontFamilyList.rs");
+include!("FontSize.rs");
+include!("FontVariantAlternates.rs");
+include!("FontVariantCaps.rs");
+include!("FontVariantEastAsian.rs");
+include!("FontVariantLigatures.rs");
+include!("FontVariantNumeric.rs");
+include!("FontVariantPosition.rs");
+include!("FontWeightValue.rs");
+include!("LineHeight.rs");
+include!("NumericFigureValues.rs");
+include!("NumericFractionValues.rs");
+include!("NumericSpacingValues.rs");
+include!("SpecifiedFont.rs");
+include!("SystemFont.rs");
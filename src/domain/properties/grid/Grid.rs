@@ -0,0 +1,188 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid` shorthand property.
+/// Only combinations that the shorthand can express are written: either a `grid-template` with the initial automatic tracks and flow, or automatic tracks and flow in one direction with explicit tracks only in the other.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Grid
+{
+	pub template: GridTemplate,
+	
+	pub auto_rows: TrackSizeList,
+	
+	pub auto_columns: TrackSizeList,
+	
+	pub auto_flow: GridAutoFlow,
+}
+
+impl ToCss for Grid
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		#[inline(always)]
+		fn autoFlow<W: fmt::Write>(dense: bool, sizes: &TrackSizeList, dest: &mut W) -> fmt::Result
+		{
+			dest.write_str("auto-flow")?;
+			if dense
+			{
+				dest.write_str(" dense")?;
+			}
+			if !sizes.isInitial()
+			{
+				dest.write_char(' ')?;
+				sizes.to_css(dest)?;
+			}
+			Ok(())
+		}
+		
+		let template = &self.template;
+		let explicitRowsOnly = template.areas.isNone() && template.columns == GridTemplateTracks::none;
+		let explicitColumnsOnly = template.areas.isNone() && template.rows == GridTemplateTracks::none;
+		
+		match self.auto_flow.direction
+		{
+			GridAutoFlowDirection::row if explicitColumnsOnly && self.auto_columns.isInitial() && (self.auto_flow.dense || !self.auto_rows.isInitial()) =>
+			{
+				autoFlow(self.auto_flow.dense, &self.auto_rows, dest)?;
+				dest.write_char('/')?;
+				template.columns.to_css(dest)
+			}
+			
+			GridAutoFlowDirection::column if explicitRowsOnly && self.auto_rows.isInitial() =>
+			{
+				template.rows.to_css(dest)?;
+				dest.write_char('/')?;
+				autoFlow(self.auto_flow.dense, &self.auto_columns, dest)
+			}
+			
+			_ => template.to_css(dest),
+		}
+	}
+}
+
+impl Parse for Grid
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		/// Parses `auto-flow && dense?`, returning whether `dense` was given.
+		#[inline(always)]
+		fn autoFlow<'i, 't>(input: &mut Parser<'i, 't>) -> Result<bool, ParseError<'i, CustomParseError<'i>>>
+		{
+			let mut autoFlow = false;
+			let mut dense = false;
+			loop
+			{
+				if !autoFlow && input.r#try(|input| input.expect_ident_matching("auto-flow")).is_ok()
+				{
+					autoFlow = true;
+					continue;
+				}
+				
+				if !dense && input.r#try(|input| input.expect_ident_matching("dense")).is_ok()
+				{
+					dense = true;
+					continue;
+				}
+				
+				break;
+			}
+			
+			if autoFlow
+			{
+				Ok(dense)
+			}
+			else
+			{
+				Err(input.new_error_for_next_token())
+			}
+		}
+		
+		let template = input.r#try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+		{
+			let template = GridTemplate::parse(context, input)?;
+			input.expect_exhausted()?;
+			Ok(template)
+		});
+		if let Ok(template) = template
+		{
+			return Ok
+			(
+				Self
+				{
+					template,
+					auto_rows: TrackSizeList::default(),
+					auto_columns: TrackSizeList::default(),
+					auto_flow: GridAutoFlow::default(),
+				}
+			)
+		}
+		
+		let mut grid = Self
+		{
+			template: GridTemplate::default(),
+			auto_rows: TrackSizeList::default(),
+			auto_columns: TrackSizeList::default(),
+			auto_flow: GridAutoFlow::default(),
+		};
+		
+		if let Ok(dense) = input.r#try(|input| autoFlow(input))
+		{
+			if let Ok(sizes) = input.r#try(|input| TrackSizeList::parse(context, input))
+			{
+				grid.auto_rows = sizes;
+			}
+			input.expect_delim('/')?;
+			grid.template.columns = GridTemplateTracks::parse(context, input)?;
+			grid.auto_flow.dense = dense;
+		}
+		else
+		{
+			grid.template.rows = GridTemplateTracks::parse(context, input)?;
+			input.expect_delim('/')?;
+			grid.auto_flow.dense = autoFlow(input)?;
+			if let Ok(sizes) = input.r#try(|input| TrackSizeList::parse(context, input))
+			{
+				grid.auto_columns = sizes;
+			}
+			grid.auto_flow.direction = GridAutoFlowDirection::column;
+		}
+		
+		Ok(grid)
+	}
+}
+
+impl SimplifyCalcFunctions for Grid
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.template.simplify_calc_functions();
+		self.auto_rows.simplify_calc_functions();
+		self.auto_columns.simplify_calc_functions()
+	}
+}
+
+impl Grid
+{
+	/// The longhand properties set (or reset) by the `grid` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"grid-template-rows",
+		"grid-template-columns",
+		"grid-template-areas",
+		"grid-auto-rows",
+		"grid-auto-columns",
+		"grid-auto-flow",
+	];
+	
+	/// Expands into longhand property values.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		let mut longhands = self.template.longhands();
+		longhands.push(TypedPropertyValue::GridAutoRows(self.auto_rows.clone()));
+		longhands.push(TypedPropertyValue::GridAutoColumns(self.auto_columns.clone()));
+		longhands.push(TypedPropertyValue::GridAutoFlow(self.auto_flow));
+		longhands
+	}
+}
@@ -0,0 +1,91 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-area` shorthand property, such as `header` or `1 / 2 / span 3 / 4`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct GridArea
+{
+	pub row_start: GridLine,
+	
+	pub column_start: GridLine,
+	
+	pub row_end: GridLine,
+	
+	pub column_end: GridLine,
+}
+
+impl ToCss for GridArea
+{
+	/// Writes the shortest form, omitting trailing grid lines that are the same as they would be if omitted.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let columnEnd = self.column_end != GridLine::omitted(&self.column_start);
+		let rowEnd = columnEnd || self.row_end != GridLine::omitted(&self.row_start);
+		let columnStart = rowEnd || self.column_start != GridLine::omitted(&self.row_start);
+		
+		self.row_start.to_css(dest)?;
+		for &(write, line) in [(columnStart, &self.column_start), (rowEnd, &self.row_end), (columnEnd, &self.column_end)].iter()
+		{
+			if write
+			{
+				dest.write_char('/')?;
+				line.to_css(dest)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl Parse for GridArea
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut lines = vec![GridLine::parse(context, input)?];
+		while lines.len() < 4 && input.r#try(|input| input.expect_delim('/')).is_ok()
+		{
+			lines.push(GridLine::parse(context, input)?);
+		}
+		
+		let mut lines = lines.into_iter();
+		let row_start = lines.next().unwrap();
+		let column_start = lines.next().unwrap_or_else(|| GridLine::omitted(&row_start));
+		let row_end = lines.next().unwrap_or_else(|| GridLine::omitted(&row_start));
+		let column_end = lines.next().unwrap_or_else(|| GridLine::omitted(&column_start));
+		
+		Ok
+		(
+			Self
+			{
+				row_start,
+				column_start,
+				row_end,
+				column_end,
+			}
+		)
+	}
+}
+
+impl GridArea
+{
+	/// The longhand properties set by the `grid-area` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"grid-row-start",
+		"grid-column-start",
+		"grid-row-end",
+		"grid-column-end",
+	];
+	
+	/// Expands into longhand property values.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		vec!
+		[
+			TypedPropertyValue::GridRowStart(self.row_start.clone()),
+			TypedPropertyValue::GridColumnStart(self.column_start.clone()),
+			TypedPropertyValue::GridRowEnd(self.row_end.clone()),
+			TypedPropertyValue::GridColumnEnd(self.column_end.clone()),
+		]
+	}
+}
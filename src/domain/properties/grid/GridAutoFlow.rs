@@ -0,0 +1,85 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-auto-flow` property.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct GridAutoFlow
+{
+	pub direction: GridAutoFlowDirection,
+	
+	pub dense: bool,
+}
+
+impl Default for GridAutoFlow
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			direction: GridAutoFlowDirection::row,
+			dense: false,
+		}
+	}
+}
+
+impl ToCss for GridAutoFlow
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match (self.direction, self.dense)
+		{
+			(GridAutoFlowDirection::row, true) => dest.write_str("dense"),
+			(direction, false) => direction.to_css(dest),
+			(direction, true) =>
+			{
+				direction.to_css(dest)?;
+				dest.write_str(" dense")
+			}
+		}
+	}
+}
+
+impl Parse for GridAutoFlow
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut direction = None;
+		let mut dense = false;
+		
+		loop
+		{
+			if direction.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| GridAutoFlowDirection::parse(input))
+				{
+					direction = Some(value);
+					continue;
+				}
+			}
+			
+			if !dense && input.r#try(|input| input.expect_ident_matching("dense")).is_ok()
+			{
+				dense = true;
+				continue;
+			}
+			
+			break;
+		}
+		
+		if direction.is_none() && !dense
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		Ok
+		(
+			Self
+			{
+				direction: direction.unwrap_or(GridAutoFlowDirection::row),
+				dense,
+			}
+		)
+	}
+}
@@ -0,0 +1,157 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-row-start`, `grid-row-end`, `grid-column-start` and `grid-column-end` properties: https://drafts.csswg.org/css-grid/#typedef-grid-row-start-grid-line
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum GridLine
+{
+	auto,
+	
+	/// A named area or line.
+	Ident(CustomIdent),
+	
+	/// The nth line, counting from the end if negative (never zero), of those lines with `name` if given.
+	Line(i32, Option<CustomIdent>),
+	
+	/// Spans n lines (at least one), of those lines with `name` if given.
+	Span(u32, Option<CustomIdent>),
+}
+
+impl Default for GridLine
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		GridLine::auto
+	}
+}
+
+impl ToCss for GridLine
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GridLine::*;
+		
+		#[inline(always)]
+		fn name<W: fmt::Write>(name: &Option<CustomIdent>, dest: &mut W) -> fmt::Result
+		{
+			if let Some(ref name) = *name
+			{
+				dest.write_char(' ')?;
+				name.to_css(dest)?;
+			}
+			Ok(())
+		}
+		
+		match *self
+		{
+			auto => dest.write_str("auto"),
+			
+			Ident(ref ident) => ident.to_css(dest),
+			
+			Line(number, ref lineName) =>
+			{
+				number.to_css(dest)?;
+				name(lineName, dest)
+			}
+			
+			// A count of one is the default if there is a name.
+			Span(1, Some(ref lineName)) =>
+			{
+				dest.write_str("span ")?;
+				lineName.to_css(dest)
+			}
+			
+			Span(count, ref lineName) =>
+			{
+				dest.write_str("span ")?;
+				count.to_css(dest)?;
+				name(lineName, dest)
+			}
+		}
+	}
+}
+
+impl Parse for GridLine
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GridLine::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("auto")).is_ok()
+		{
+			return Ok(auto)
+		}
+		
+		let mut span = false;
+		let mut number = None;
+		let mut name = None;
+		
+		// `span`, the number and the name may be in any order.
+		loop
+		{
+			if !span && input.r#try(|input| input.expect_ident_matching("span")).is_ok()
+			{
+				span = true;
+				continue;
+			}
+			
+			if number.is_none()
+			{
+				if let Ok(value) = input.r#try(|input| input.expect_integer())
+				{
+					number = Some(value);
+					continue;
+				}
+			}
+			
+			if name.is_none()
+			{
+				if let Ok(ident) = input.r#try(|input| input.expect_ident().cloned())
+				{
+					name = Some(CustomIdent::from_ident(&ident, &["span", "auto"]).map_err(|error| input.new_custom_error(error))?);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		match (span, number, name)
+		{
+			(false, None, Some(name)) => Ok(Ident(name)),
+			
+			(false, Some(0), _) => Err(input.new_custom_error(CustomParseError::GridLineCanNotBeZero)),
+			
+			(false, Some(number), name) => Ok(Line(number, name)),
+			
+			(true, None, Some(name)) => Ok(Span(1, Some(name))),
+			
+			(true, Some(count), name) => if count < 1
+			{
+				Err(input.new_custom_error(CustomParseError::GridSpanMustBeAtLeastOne(count)))
+			}
+			else
+			{
+				Ok(Span(count as u32, name))
+			},
+			
+			_ => Err(input.new_error_for_next_token()),
+		}
+	}
+}
+
+impl GridLine
+{
+	/// The value of an omitted grid line in a `grid-row`, `grid-column` or `grid-area` shorthand, given the grid line it copies: the same name, or otherwise `auto`.
+	#[inline(always)]
+	pub fn omitted(copied: &Self) -> Self
+	{
+		match *copied
+		{
+			GridLine::Ident(ref name) => GridLine::Ident(name.clone()),
+			_ => GridLine::auto,
+		}
+	}
+}
@@ -0,0 +1,81 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-row` or `grid-column` shorthand properties, such as `1 / span 2`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct GridPlacement
+{
+	pub start: GridLine,
+	
+	pub end: GridLine,
+}
+
+impl ToCss for GridPlacement
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.start.to_css(dest)?;
+		if self.end != GridLine::omitted(&self.start)
+		{
+			dest.write_char('/')?;
+			self.end.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl Parse for GridPlacement
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let start = GridLine::parse(context, input)?;
+		let end = if input.r#try(|input| input.expect_delim('/')).is_ok()
+		{
+			GridLine::parse(context, input)?
+		}
+		else
+		{
+			GridLine::omitted(&start)
+		};
+		
+		Ok
+		(
+			Self
+			{
+				start,
+				end,
+			}
+		)
+	}
+}
+
+impl GridPlacement
+{
+	/// The longhand properties set by the `grid-row` shorthand, in the order of `longhands(true)`.
+	pub const RowLonghandNames: &'static [&'static str] = &
+	[
+		"grid-row-start",
+		"grid-row-end",
+	];
+	
+	/// The longhand properties set by the `grid-column` shorthand, in the order of `longhands(false)`.
+	pub const ColumnLonghandNames: &'static [&'static str] = &
+	[
+		"grid-column-start",
+		"grid-column-end",
+	];
+	
+	/// Expands into longhand property values, of `grid-row` if `isRow`, otherwise of `grid-column`.
+	pub fn longhands(&self, isRow: bool) -> Vec<TypedPropertyValue>
+	{
+		if isRow
+		{
+			vec![TypedPropertyValue::GridRowStart(self.start.clone()), TypedPropertyValue::GridRowEnd(self.end.clone())]
+		}
+		else
+		{
+			vec![TypedPropertyValue::GridColumnStart(self.start.clone()), TypedPropertyValue::GridColumnEnd(self.end.clone())]
+		}
+	}
+}
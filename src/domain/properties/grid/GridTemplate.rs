@@ -0,0 +1,230 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-template` shorthand property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct GridTemplate
+{
+	/// If there are `areas`, there is one track size, without any `repeat()`, for each row of areas, as when parsed.
+	pub rows: GridTemplateTracks,
+	
+	/// If there are `areas`, there is no `repeat()`, as when parsed.
+	pub columns: GridTemplateTracks,
+	
+	pub areas: GridTemplateAreas,
+}
+
+impl Default for GridTemplate
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			rows: GridTemplateTracks::none,
+			columns: GridTemplateTracks::none,
+			areas: GridTemplateAreas::default(),
+		}
+	}
+}
+
+impl ToCss for GridTemplate
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.areas.isNone()
+		{
+			if self.rows == GridTemplateTracks::none && self.columns == GridTemplateTracks::none
+			{
+				return dest.write_str("none")
+			}
+			
+			self.rows.to_css(dest)?;
+			dest.write_char('/')?;
+			return self.columns.to_css(dest)
+		}
+		
+		// Each row of areas is written with its line names and track size (if not `auto`).
+		let rows = match self.rows
+		{
+			GridTemplateTracks::TrackList(ref trackList) => &trackList.0[..],
+			_ => &[],
+		};
+		let mut row = 0;
+		let mut separator = "";
+		for component in rows.iter()
+		{
+			dest.write_str(separator)?;
+			separator = " ";
+			match *component
+			{
+				TrackListComponent::Size(ref size) if row < self.areas.number_of_rows() =>
+				{
+					self.areas.write_row(row, dest)?;
+					row += 1;
+					if *size != TrackSize::default()
+					{
+						dest.write_char(' ')?;
+						size.to_css(dest)?;
+					}
+				}
+				
+				_ => component.to_css(dest)?,
+			}
+		}
+		while row < self.areas.number_of_rows()
+		{
+			dest.write_str(separator)?;
+			separator = " ";
+			self.areas.write_row(row, dest)?;
+			row += 1;
+		}
+		
+		if self.columns != GridTemplateTracks::none
+		{
+			dest.write_char('/')?;
+			self.columns.to_css(dest)?;
+		}
+		
+		Ok(())
+	}
+}
+
+impl Parse for GridTemplate
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let none = input.r#try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+		{
+			input.expect_ident_matching("none")?;
+			input.expect_exhausted()?;
+			Ok(())
+		});
+		if none.is_ok()
+		{
+			return Ok(Self::default())
+		}
+		
+		let tracks = input.r#try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+		{
+			let rows = GridTemplateTracks::parse(context, input)?;
+			input.expect_delim('/')?;
+			let columns = GridTemplateTracks::parse(context, input)?;
+			Ok((rows, columns))
+		});
+		if let Ok((rows, columns)) = tracks
+		{
+			return Ok
+			(
+				Self
+				{
+					rows,
+					columns,
+					areas: GridTemplateAreas::default(),
+				}
+			)
+		}
+		
+		// Each row is a string of areas, optionally with a track size and line names before and after.
+		let mut rows = Vec::new();
+		let mut cells = Vec::new();
+		loop
+		{
+			let row = input.r#try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+			{
+				let namesBefore = input.r#try(|input| LineNames::parse(context, input)).ok();
+				let row = GridTemplateAreas::parse_row(input)?;
+				let size = input.r#try(|input| TrackSize::parse(context, input)).unwrap_or_default();
+				let namesAfter = input.r#try(|input| LineNames::parse(context, input)).ok();
+				Ok((namesBefore, row, size, namesAfter))
+			});
+			
+			let (namesBefore, row, size, namesAfter) = match row
+			{
+				Ok(row) => row,
+				Err(error) => if cells.is_empty()
+				{
+					return Err(error)
+				}
+				else
+				{
+					break
+				},
+			};
+			
+			// The line names after one row and before the next name the same line.
+			if let Some(LineNames(names)) = namesBefore
+			{
+				match rows.last_mut()
+				{
+					Some(&mut TrackListComponent::LineNames(ref mut previousNames)) => previousNames.0.extend(names),
+					_ => if !names.is_empty()
+					{
+						rows.push(TrackListComponent::LineNames(LineNames(names)))
+					},
+				}
+			}
+			cells.push(row);
+			rows.push(TrackListComponent::Size(size));
+			if let Some(names) = namesAfter
+			{
+				if !names.0.is_empty()
+				{
+					rows.push(TrackListComponent::LineNames(names));
+				}
+			}
+		}
+		
+		let columns = if input.r#try(|input| input.expect_delim('/')).is_ok()
+		{
+			GridTemplateTracks::TrackList(TrackList::parse_tracks(context, input, false)?)
+		}
+		else
+		{
+			GridTemplateTracks::none
+		};
+		
+		Ok
+		(
+			Self
+			{
+				rows: GridTemplateTracks::TrackList(TrackList(rows)),
+				columns,
+				areas: GridTemplateAreas::new(cells).map_err(|error| input.new_custom_error(error))?,
+			}
+		)
+	}
+}
+
+impl SimplifyCalcFunctions for GridTemplate
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.rows.simplify_calc_functions();
+		self.columns.simplify_calc_functions()
+	}
+}
+
+impl GridTemplate
+{
+	/// The longhand properties set (or reset) by the `grid-template` shorthand, in the order of `longhands()`.
+	pub const LonghandNames: &'static [&'static str] = &
+	[
+		"grid-template-rows",
+		"grid-template-columns",
+		"grid-template-areas",
+	];
+	
+	/// Expands into longhand property values.
+	pub fn longhands(&self) -> Vec<TypedPropertyValue>
+	{
+		vec!
+		[
+			TypedPropertyValue::GridTemplateRows(self.rows.clone()),
+			TypedPropertyValue::GridTemplateColumns(self.columns.clone()),
+			TypedPropertyValue::GridTemplateAreas(self.areas.clone()),
+		]
+	}
+}
@@ -0,0 +1,260 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-template-areas` property, such as `"header header" "sidebar main"`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct GridTemplateAreas
+{
+	/// The name of the area each cell is in, by row then column; `None` is a null cell (`.`).
+	/// Every row has the same, non-zero, number of columns, and every area is a rectangle.
+	/// There are no rows for `none`.
+	pub cells: Vec<Vec<Option<Atom>>>,
+}
+
+impl Default for GridTemplateAreas
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			cells: Vec::new(),
+		}
+	}
+}
+
+impl ToCss for GridTemplateAreas
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.isNone()
+		{
+			return dest.write_str("none")
+		}
+		
+		let mut separator = "";
+		for row in 0 .. self.cells.len()
+		{
+			dest.write_str(separator)?;
+			self.write_row(row, dest)?;
+			separator = " ";
+		}
+		Ok(())
+	}
+}
+
+impl Parse for GridTemplateAreas
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(Self::default())
+		}
+		
+		let mut rows = vec![Self::parse_row(input)?];
+		while let Ok(row) = input.r#try(|input| Self::parse_row(input))
+		{
+			rows.push(row);
+		}
+		
+		Self::new(rows).map_err(|error| input.new_custom_error(error))
+	}
+}
+
+impl GridTemplateAreas
+{
+	/// Checks that every row has the same number of columns, and every area is a rectangle.
+	pub(crate) fn new<'i>(cells: Vec<Vec<Option<Atom>>>) -> Result<Self, CustomParseError<'i>>
+	{
+		let numberOfColumns = cells[0].len();
+		if cells.iter().any(|row| row.len() != numberOfColumns)
+		{
+			return Err(CustomParseError::GridTemplateAreasRowsMustHaveTheSameNumberOfColumns)
+		}
+		
+		let areas = Self
+		{
+			cells,
+		};
+		
+		for (name, area) in areas.areas()
+		{
+			let numberOfCells = areas.cells.iter().flat_map(|row| row.iter()).filter(|cell| cell.as_ref() == Some(&name)).count();
+			if numberOfCells != (area.row_end - area.row_start) * (area.column_end - area.column_start)
+			{
+				return Err(CustomParseError::GridTemplateAreaMustBeRectangular(name.to_string()))
+			}
+		}
+		
+		Ok(areas)
+	}
+	
+	/// Whether this is `none`.
+	#[inline(always)]
+	pub fn isNone(&self) -> bool
+	{
+		self.cells.is_empty()
+	}
+	
+	/// The number of rows; zero for `none`.
+	#[inline(always)]
+	pub fn number_of_rows(&self) -> usize
+	{
+		self.cells.len()
+	}
+	
+	/// The number of columns; zero for `none`.
+	#[inline(always)]
+	pub fn number_of_columns(&self) -> usize
+	{
+		self.cells.first().map(|row| row.len()).unwrap_or(0)
+	}
+	
+	/// The name of the area the cell at `row` and `column` (counting from zero) is in, or `None` for a null cell or a cell outside of the template.
+	#[inline(always)]
+	pub fn area_at(&self, row: usize, column: usize) -> Option<&Atom>
+	{
+		self.cells.get(row).and_then(|row| row.get(column)).and_then(|cell| cell.as_ref())
+	}
+	
+	/// Where the area called `name` is, if there is one.
+	#[inline(always)]
+	pub fn area(&self, name: &str) -> Option<NamedGridArea>
+	{
+		self.areas().into_iter().find(|(areaName, _)| &areaName[..] == name).map(|(_, area)| area)
+	}
+	
+	/// The named areas, in the order they first occur (by row then column), and where they are.
+	pub fn areas(&self) -> Vec<(Atom, NamedGridArea)>
+	{
+		let mut areas: Vec<(Atom, NamedGridArea)> = Vec::new();
+		for (rowIndex, row) in self.cells.iter().enumerate()
+		{
+			for (columnIndex, cell) in row.iter().enumerate()
+			{
+				if let Some(ref name) = *cell
+				{
+					if let Some((_, area)) = areas.iter_mut().find(|(areaName, _)| areaName == name)
+					{
+						area.row_start = min(area.row_start, rowIndex + 1);
+						area.row_end = max(area.row_end, rowIndex + 2);
+						area.column_start = min(area.column_start, columnIndex + 1);
+						area.column_end = max(area.column_end, columnIndex + 2);
+						continue;
+					}
+					
+					areas.push
+					((
+						name.clone(),
+						NamedGridArea
+						{
+							row_start: rowIndex + 1,
+							row_end: rowIndex + 2,
+							column_start: columnIndex + 1,
+							column_end: columnIndex + 2,
+						}
+					));
+				}
+			}
+		}
+		areas
+	}
+	
+	/// Writes one row as a string.
+	pub(crate) fn write_row<W: fmt::Write>(&self, row: usize, dest: &mut W) -> fmt::Result
+	{
+		let mut string = String::new();
+		for cell in self.cells[row].iter()
+		{
+			if !string.is_empty()
+			{
+				string.push(' ');
+			}
+			match *cell
+			{
+				None => string.push('.'),
+				Some(ref name) => string.push_str(name),
+			}
+		}
+		serialize_string(&string, dest)
+	}
+	
+	/// Parses a string into a row of cells: https://drafts.csswg.org/css-grid/#valdef-grid-template-areas-string
+	pub(crate) fn parse_row<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Vec<Option<Atom>>, ParseError<'i, CustomParseError<'i>>>
+	{
+		#[inline(always)]
+		fn isNameCodePoint(character: char) -> bool
+		{
+			character.is_ascii_alphanumeric() || character == '-' || character == '_' || !character.is_ascii()
+		}
+		
+		let string = input.expect_string()?.clone();
+		
+		let mut cells = Vec::new();
+		let mut characters = string.chars().peekable();
+		while let Some(character) = characters.next()
+		{
+			if character.is_ascii_whitespace()
+			{
+				continue
+			}
+			
+			// A sequence of one or more full stops is a single null cell.
+			if character == '.'
+			{
+				while characters.peek() == Some(&'.')
+				{
+					characters.next();
+				}
+				cells.push(None);
+			}
+			else if isNameCodePoint(character)
+			{
+				let mut name = character.to_string();
+				while let Some(&character) = characters.peek()
+				{
+					if !isNameCodePoint(character)
+					{
+						break
+					}
+					name.push(character);
+					characters.next();
+				}
+				cells.push(Some(Atom::from(name)));
+			}
+			else
+			{
+				return Err(input.new_custom_error(CustomParseError::InvalidGridTemplateAreasString(string.clone())))
+			}
+		}
+		
+		if cells.is_empty()
+		{
+			return Err(input.new_custom_error(CustomParseError::InvalidGridTemplateAreasString(string.clone())))
+		}
+		
+		Ok(cells)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn lint(css: &str) -> Vec<String>
+	{
+		Stylesheet::parse(css).unwrap().lint().iter().map(|lint| lint.to_string()).collect()
+	}
+	
+	#[test]
+	fn invalid_grid_template_areas_are_linted()
+	{
+		assert_eq!(lint("a{grid-template-areas:\"a b\" \"b a\"}"), vec!["'grid-template-areas: \"a b\" \"b a\"' is not valid: the grid template area 'a' is not a rectangle"]);
+		assert_eq!(lint("a{grid-template-areas:\"a b\" \"c\"}"), vec!["'grid-template-areas: \"a b\" \"c\"' is not valid: every row of grid template areas must have the same number of columns"]);
+		assert_eq!(lint("a{grid-template:\"a b\" 1fr \"b a\" 1fr/1fr 1fr}").len(), 1);
+		assert_eq!(lint("a{grid-template-areas:\"a a\" \"b c\";grid-template-areas:var(--areas)}"), Vec::<String>::new());
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-template-columns` or `grid-template-rows` properties.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum GridTemplateTracks
+{
+	none,
+	
+	TrackList(TrackList),
+	
+	/// The names of the subgrid's lines; empty names are kept, as they still name a line (with nothing).
+	subgrid(Vec<LineNames>),
+}
+
+impl Default for GridTemplateTracks
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		GridTemplateTracks::none
+	}
+}
+
+impl ToCss for GridTemplateTracks
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GridTemplateTracks::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			TrackList(ref trackList) => trackList.to_css(dest),
+			
+			subgrid(ref lineNames) =>
+			{
+				dest.write_str("subgrid")?;
+				for names in lineNames.iter()
+				{
+					dest.write_char(' ')?;
+					names.to_css(dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Parse for GridTemplateTracks
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GridTemplateTracks::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none)
+		}
+		
+		if input.r#try(|input| input.expect_ident_matching("subgrid")).is_ok()
+		{
+			let mut lineNames = Vec::new();
+			while let Ok(names) = input.r#try(|input| LineNames::parse(context, input))
+			{
+				lineNames.push(names);
+			}
+			return Ok(subgrid(lineNames))
+		}
+		
+		self::TrackList::parse(context, input).map(TrackList)
+	}
+}
+
+impl SimplifyCalcFunctions for GridTemplateTracks
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let GridTemplateTracks::TrackList(ref mut tracks) = *self
+		{
+			tracks.simplify_calc_functions()
+		}
+	}
+}
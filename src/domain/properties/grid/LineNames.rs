@@ -0,0 +1,41 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A list of names for a grid line, such as `[header-start main-start]`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LineNames(pub Vec<CustomIdent>);
+
+impl ToCss for LineNames
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_char('[')?;
+		let mut separator = "";
+		for name in self.0.iter()
+		{
+			dest.write_str(separator)?;
+			name.to_css(dest)?;
+			separator = " ";
+		}
+		dest.write_char(']')
+	}
+}
+
+impl Parse for LineNames
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.expect_square_bracket_block()?;
+		input.parse_nested_block(|input|
+		{
+			let mut names = Vec::new();
+			while !input.is_exhausted()
+			{
+				let ident = input.expect_ident()?.clone();
+				names.push(CustomIdent::from_ident(&ident, &["span"]).map_err(|error| input.new_custom_error(error))?);
+			}
+			Ok(LineNames(names))
+		})
+	}
+}
@@ -0,0 +1,16 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Where a named grid area is, as grid line numbers; the ends are exclusive, so a single cell in the top left is from lines 1 to 2.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct NamedGridArea
+{
+	pub row_start: usize,
+	
+	pub row_end: usize,
+	
+	pub column_start: usize,
+	
+	pub column_end: usize,
+}
@@ -0,0 +1,69 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The number of repetitions in a `repeat()` track list.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum RepeatCount
+{
+	/// Always at least one.
+	Count(CssUnsignedInteger),
+	
+	auto_fill,
+	
+	auto_fit,
+}
+
+impl ToCss for RepeatCount
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::RepeatCount::*;
+		
+		match *self
+		{
+			Count(count) => count.to_css(dest),
+			auto_fill => dest.write_str("auto-fill"),
+			auto_fit => dest.write_str("auto-fit"),
+		}
+	}
+}
+
+impl Parse for RepeatCount
+{
+	fn parse<'i, 't>(_: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::RepeatCount::*;
+		
+		if let Ok(ident) = input.r#try(|input| input.expect_ident().cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"auto-fill" => Ok(auto_fill),
+				
+				"auto-fit" => Ok(auto_fit),
+				
+				_ => Err(input.new_unexpected_token_error(Token::Ident(ident.clone()))),
+			}
+		}
+		
+		let value = input.expect_integer()?;
+		if value < 1
+		{
+			return Err(input.new_custom_error(CustomParseError::GridRepeatCountMustBeAtLeastOne(value)))
+		}
+		CssUnsignedInteger::parseNumber(value as f32, Some(value)).map(Count).map_err(|error| input.new_custom_error(error))
+	}
+}
+
+impl RepeatCount
+{
+	/// Whether this is `auto-fill` or `auto-fit`.
+	#[inline(always)]
+	pub fn isAutomatic(&self) -> bool
+	{
+		!matches!(*self, RepeatCount::Count(_))
+	}
+}
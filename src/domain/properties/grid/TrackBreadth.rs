@@ -0,0 +1,107 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A fixed track breadth, or the argument of `fit-content()`.
+pub type TrackLength = CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>;
+
+/// The size of a grid track, or one limit of `minmax()`: https://drafts.csswg.org/css-grid/#typedef-track-breadth
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TrackBreadth
+{
+	LengthOrPercentage(TrackLength),
+	
+	Flex(FlexUnit<CssUnsignedNumber>),
+	
+	min_content,
+	
+	max_content,
+	
+	auto,
+}
+
+impl Default for TrackBreadth
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		TrackBreadth::auto
+	}
+}
+
+impl ToCss for TrackBreadth
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TrackBreadth::*;
+		
+		match *self
+		{
+			LengthOrPercentage(ref length) => serialize_length_or_percentage(length, dest),
+			Flex(ref flex) => flex.to_css(dest),
+			min_content => dest.write_str("min-content"),
+			max_content => dest.write_str("max-content"),
+			auto => dest.write_str("auto"),
+		}
+	}
+}
+
+impl Parse for TrackBreadth
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TrackBreadth::*;
+		
+		if let Ok(flex) = input.r#try(|input| FlexUnit::parse(input))
+		{
+			return Ok(Flex(flex))
+		}
+		
+		if let Ok(ident) = input.r#try(|input| input.expect_ident().cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"min-content" => Ok(min_content),
+				
+				"max-content" => Ok(max_content),
+				
+				"auto" => Ok(auto),
+				
+				_ => Err(input.new_unexpected_token_error(Token::Ident(ident.clone()))),
+			}
+		}
+		
+		LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(LengthOrPercentage)
+	}
+}
+
+impl SimplifyCalcFunctions for TrackBreadth
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let TrackBreadth::LengthOrPercentage(ref mut length) = *self
+		{
+			length.simplify_calc_functions()
+		}
+	}
+}
+
+impl TrackBreadth
+{
+	/// Whether this is a flexible (`fr`) breadth.
+	#[inline(always)]
+	pub fn isFlexible(&self) -> bool
+	{
+		matches!(*self, TrackBreadth::Flex(_))
+	}
+	
+	/// Whether this is a length or percentage.
+	#[inline(always)]
+	pub fn isFixed(&self) -> bool
+	{
+		matches!(*self, TrackBreadth::LengthOrPercentage(_))
+	}
+}
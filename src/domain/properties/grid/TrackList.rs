@@ -0,0 +1,150 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A list of grid tracks and the names of the lines between them, such as `[full-start] minmax(1em, 1fr) [main-start] repeat(2, 10em) [main-end]`: https://drafts.csswg.org/css-grid/#track-sizing
+/// There is always at least one track (size or `repeat()`), and at most one `repeat(auto-fill, ...)` or `repeat(auto-fit, ...)`; if there is one, all track sizes are fixed.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TrackList(pub Vec<TrackListComponent>);
+
+impl ToCss for TrackList
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut separator = "";
+		for component in self.0.iter()
+		{
+			dest.write_str(separator)?;
+			component.to_css(dest)?;
+			separator = " ";
+		}
+		Ok(())
+	}
+}
+
+impl Parse for TrackList
+{
+	#[inline(always)]
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_tracks(context, input, true)
+	}
+}
+
+impl SimplifyCalcFunctions for TrackList
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for component in self.0.iter_mut()
+		{
+			component.simplify_calc_functions()
+		}
+	}
+}
+
+impl TrackList
+{
+	/// Whether every track has a fixed size.
+	pub fn isFixed(&self) -> bool
+	{
+		self.0.iter().all(|component| match *component
+		{
+			TrackListComponent::LineNames(_) => true,
+			TrackListComponent::Size(ref size) => size.isFixed(),
+			TrackListComponent::Repeat(ref repeat) => repeat.tracks.isFixed(),
+		})
+	}
+	
+	/// Whether this contains a `repeat()`.
+	#[inline(always)]
+	pub fn hasRepeat(&self) -> bool
+	{
+		self.0.iter().any(|component| matches!(*component, TrackListComponent::Repeat(_)))
+	}
+	
+	/// The track sizes, if there is no `repeat()`.
+	pub fn sizes(&self) -> Option<Vec<&TrackSize>>
+	{
+		let mut sizes = Vec::with_capacity(self.0.len());
+		for component in self.0.iter()
+		{
+			match *component
+			{
+				TrackListComponent::LineNames(_) => (),
+				TrackListComponent::Size(ref size) => sizes.push(size),
+				TrackListComponent::Repeat(_) => return None,
+			}
+		}
+		Some(sizes)
+	}
+	
+	/// Parses a track list; `allowRepeat` is false inside `repeat()` and where only an explicit track list is valid.
+	pub(crate) fn parse_tracks<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, allowRepeat: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut components = Vec::new();
+		let mut hasTrack = false;
+		let mut automaticRepeats = 0;
+		
+		// Line names may come before and after each track, but not twice in a row.
+		let mut afterLineNames = false;
+		loop
+		{
+			if !afterLineNames
+			{
+				if let Ok(names) = input.r#try(|input| LineNames::parse(context, input))
+				{
+					if !names.0.is_empty()
+					{
+						components.push(TrackListComponent::LineNames(names));
+					}
+					afterLineNames = true;
+					continue;
+				}
+			}
+			
+			if allowRepeat
+			{
+				if let Ok(repeat) = input.r#try(|input| TrackRepeat::parse(context, input))
+				{
+					if repeat.count.isAutomatic()
+					{
+						automaticRepeats += 1;
+					}
+					components.push(TrackListComponent::Repeat(repeat));
+					hasTrack = true;
+					afterLineNames = false;
+					continue;
+				}
+			}
+			
+			if let Ok(size) = input.r#try(|input| TrackSize::parse(context, input))
+			{
+				components.push(TrackListComponent::Size(size));
+				hasTrack = true;
+				afterLineNames = false;
+				continue;
+			}
+			
+			break;
+		}
+		
+		if !hasTrack
+		{
+			return Err(input.new_error_for_next_token())
+		}
+		
+		let trackList = TrackList(components);
+		
+		if automaticRepeats > 1
+		{
+			return Err(input.new_custom_error(CustomParseError::GridTrackListCanHaveOnlyOneAutoRepeat))
+		}
+		if automaticRepeats == 1 && !trackList.isFixed()
+		{
+			return Err(input.new_custom_error(CustomParseError::GridAutoRepeatMustOnlyHaveFixedSizes))
+		}
+		
+		Ok(trackList)
+	}
+}
@@ -0,0 +1,47 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One part of a `TrackList`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TrackListComponent
+{
+	/// Never empty, and never next to another `LineNames`.
+	LineNames(LineNames),
+	
+	Size(TrackSize),
+	
+	Repeat(TrackRepeat),
+}
+
+impl ToCss for TrackListComponent
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TrackListComponent::*;
+		
+		match *self
+		{
+			LineNames(ref names) => names.to_css(dest),
+			Size(ref size) => size.to_css(dest),
+			Repeat(ref repeat) => repeat.to_css(dest),
+		}
+	}
+}
+
+impl SimplifyCalcFunctions for TrackListComponent
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		use self::TrackListComponent::*;
+		
+		match *self
+		{
+			LineNames(_) => (),
+			Size(ref mut size) => size.simplify_calc_functions(),
+			Repeat(ref mut repeat) => repeat.simplify_calc_functions(),
+		}
+	}
+}
@@ -0,0 +1,62 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `repeat()` in a track list, such as `repeat(auto-fill, [column-start] minmax(10em, 1fr))`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TrackRepeat
+{
+	pub count: RepeatCount,
+	
+	/// Never contains a `repeat()`.
+	pub tracks: TrackList,
+}
+
+impl ToCss for TrackRepeat
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str("repeat(")?;
+		self.count.to_css(dest)?;
+		dest.write_char(',')?;
+		self.tracks.to_css(dest)?;
+		dest.write_char(')')
+	}
+}
+
+impl Parse for TrackRepeat
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.expect_function_matching("repeat")?;
+		input.parse_nested_block(|input|
+		{
+			let count = RepeatCount::parse(context, input)?;
+			input.expect_comma()?;
+			let tracks = TrackList::parse_tracks(context, input, false)?;
+			
+			if count.isAutomatic() && !tracks.isFixed()
+			{
+				return Err(input.new_custom_error(CustomParseError::GridAutoRepeatMustOnlyHaveFixedSizes))
+			}
+			
+			Ok
+			(
+				Self
+				{
+					count,
+					tracks,
+				}
+			)
+		})
+	}
+}
+
+impl SimplifyCalcFunctions for TrackRepeat
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		self.tracks.simplify_calc_functions()
+	}
+}
@@ -0,0 +1,130 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The size of a grid track: https://drafts.csswg.org/css-grid/#typedef-track-size
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TrackSize
+{
+	Breadth(TrackBreadth),
+	
+	/// The minimum is never flexible.
+	minmax(TrackBreadth, TrackBreadth),
+	
+	fit_content(TrackLength),
+}
+
+impl Default for TrackSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		TrackSize::Breadth(TrackBreadth::auto)
+	}
+}
+
+impl ToCss for TrackSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TrackSize::*;
+		
+		match *self
+		{
+			Breadth(ref breadth) => breadth.to_css(dest),
+			
+			minmax(ref minimum, ref maximum) =>
+			{
+				dest.write_str("minmax(")?;
+				minimum.to_css(dest)?;
+				dest.write_char(',')?;
+				maximum.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
+			fit_content(ref length) =>
+			{
+				dest.write_str("fit-content(")?;
+				serialize_length_or_percentage(length, dest)?;
+				dest.write_char(')')
+			}
+		}
+	}
+}
+
+impl Parse for TrackSize
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TrackSize::*;
+		
+		if let Ok(breadth) = input.r#try(|input| TrackBreadth::parse(context, input))
+		{
+			return Ok(Breadth(breadth))
+		}
+		
+		let name = input.expect_function()?.clone();
+		input.parse_nested_block(|input|
+		{
+			match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"minmax" =>
+				{
+					let minimum = TrackBreadth::parse(context, input)?;
+					if minimum.isFlexible()
+					{
+						return Err(input.new_custom_error(CustomParseError::FlexibleTrackSizeIsNotAllowedHere))
+					}
+					input.expect_comma()?;
+					let maximum = TrackBreadth::parse(context, input)?;
+					Ok(minmax(minimum, maximum))
+				},
+				
+				"fit-content" => LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(fit_content),
+				
+				_ => Err(input.new_custom_error(CustomParseError::UnexpectedGridFunction(name.clone()))),
+			}
+		})
+	}
+}
+
+impl SimplifyCalcFunctions for TrackSize
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		use self::TrackSize::*;
+		
+		match *self
+		{
+			Breadth(ref mut breadth) => breadth.simplify_calc_functions(),
+			
+			minmax(ref mut minimum, ref mut maximum) =>
+			{
+				minimum.simplify_calc_functions();
+				maximum.simplify_calc_functions()
+			}
+			
+			fit_content(ref mut length) => length.simplify_calc_functions(),
+		}
+	}
+}
+
+impl TrackSize
+{
+	/// Whether this is a `<fixed-size>`, which is needed with `repeat(auto-fill, ...)` and `repeat(auto-fit, ...)`: https://drafts.csswg.org/css-grid/#typedef-fixed-size
+	#[inline(always)]
+	pub fn isFixed(&self) -> bool
+	{
+		use self::TrackSize::*;
+		
+		match *self
+		{
+			Breadth(ref breadth) => breadth.isFixed(),
+			minmax(ref minimum, ref maximum) => minimum.isFixed() || maximum.isFixed(),
+			fit_content(_) => false,
+		}
+	}
+}
@@ -0,0 +1,66 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value of the `grid-auto-columns` or `grid-auto-rows` properties; a pattern of track sizes for implicitly created tracks.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TrackSizeList(pub Vec<TrackSize>);
+
+impl Default for TrackSizeList
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		TrackSizeList(vec![TrackSize::default()])
+	}
+}
+
+impl ToCss for TrackSizeList
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut separator = "";
+		for size in self.0.iter()
+		{
+			dest.write_str(separator)?;
+			size.to_css(dest)?;
+			separator = " ";
+		}
+		Ok(())
+	}
+}
+
+impl Parse for TrackSizeList
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut sizes = vec![TrackSize::parse(context, input)?];
+		while let Ok(size) = input.r#try(|input| TrackSize::parse(context, input))
+		{
+			sizes.push(size);
+		}
+		Ok(TrackSizeList(sizes))
+	}
+}
+
+impl SimplifyCalcFunctions for TrackSizeList
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for size in self.0.iter_mut()
+		{
+			size.simplify_calc_functions()
+		}
+	}
+}
+
+impl TrackSizeList
+{
+	/// Whether this is the initial value, `auto`.
+	#[inline(always)]
+	pub fn isInitial(&self) -> bool
+	{
+		*self == Self::default()
+	}
+}
@@ -0,0 +1,25 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("Grid.rs");
+include!("GridArea.rs");
+include!("GridAutoFlow.rs");
+include!("GridAutoFlowDirection.rs");
+include!("GridLine.rs");
+include!("GridPlacement.rs");
+include!("GridTemplate.rs");
+include!("GridTemplateAreas.rs");
+include!("GridTemplateTracks.rs");
+include!("LineNames.rs");
+include!("NamedGridArea.rs");
+include!("RepeatCount.rs");
+include!("TrackBreadth.rs");
+include!("TrackList.rs");
+include!("TrackListComponent.rs");
+include!("TrackRepeat.rs");
+include!("TrackSize.rs");
+include!("TrackSizeList.rs");
@@ -0,0 +1,74 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The position of a colour stop in a conic gradient.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AngleOrPercentage
+{
+	Angle(GradientAngle),
+	
+	Percentage(PercentageUnit<CssSignedNumber>),
+}
+
+impl ToCss for AngleOrPercentage
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			AngleOrPercentage::Angle(ref angle) => angle.to_css(dest),
+			AngleOrPercentage::Percentage(ref percentage) => percentage.to_css(dest),
+		}
+	}
+}
+
+impl GradientStopPosition for AngleOrPercentage
+{
+	#[inline(always)]
+	fn isStart(&self) -> bool
+	{
+		match *self
+		{
+			AngleOrPercentage::Angle(ref angle) => Self::isZeroAngle(angle),
+			AngleOrPercentage::Percentage(ref percentage) => percentage.is_zero(),
+		}
+	}
+	
+	#[inline(always)]
+	fn isEnd(&self) -> bool
+	{
+		match *self
+		{
+			AngleOrPercentage::Angle(CalculablePropertyValue::Constant(ref angle)) => angle.to_degrees().to_f32() == 360.0,
+			AngleOrPercentage::Angle(_) => false,
+			AngleOrPercentage::Percentage(ref percentage) => percentage.to_f32() == 1.0,
+		}
+	}
+	
+	#[inline(always)]
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if let Ok(unit_value) = input.r#try(|input| input.expect_percentage())
+		{
+			PercentageUnit::parse_percentage(unit_value).map(AngleOrPercentage::Percentage).map_err(|error| input.new_custom_error(error))
+		}
+		else
+		{
+			AngleUnit::parse_one_outside_calc_function(context, input).map(AngleOrPercentage::Angle)
+		}
+	}
+}
+
+impl AngleOrPercentage
+{
+	#[inline(always)]
+	pub(crate) fn isZeroAngle(angle: &GradientAngle) -> bool
+	{
+		match *angle
+		{
+			CalculablePropertyValue::Constant(ref angle) => angle.is_zero(),
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `conic-gradient()` or `repeating-conic-gradient()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ConicGradient
+{
+	pub repeating: bool,
+	
+	/// The angle of the start of the gradient; `0deg` points up.
+	pub from: GradientAngle,
+	
+	pub position: Position,
+	
+	pub items: Vec<GradientItem<AngleOrPercentage>>,
+}
+
+impl ToCss for ConicGradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.repeating
+		{
+			dest.write_str("repeating-")?;
+		}
+		dest.write_str("conic-gradient(")?;
+		
+		let hasFrom = !AngleOrPercentage::isZeroAngle(&self.from);
+		let hasPosition = !self.position.isCenter();
+		if hasFrom
+		{
+			dest.write_str("from ")?;
+			self.from.to_css(dest)?;
+		}
+		if hasPosition
+		{
+			if hasFrom
+			{
+				dest.write_char(' ')?;
+			}
+			dest.write_str("at ")?;
+			self.position.to_css(dest)?;
+		}
+		if hasFrom || hasPosition
+		{
+			dest.write_char(',')?;
+		}
+		
+		GradientItem::to_css_list(&self.items, dest)?;
+		dest.write_char(')')
+	}
+}
+
+impl ConicGradient
+{
+	/// Parses the arguments inside the function's parentheses.
+	pub(crate) fn parse_arguments<'i, 't>(repeating: bool, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let from = if input.r#try(|input| input.expect_ident_matching("from")).is_ok()
+		{
+			Some(AngleUnit::parse_one_outside_calc_function(context, input)?)
+		}
+		else
+		{
+			None
+		};
+		
+		let position = if input.r#try(|input| input.expect_ident_matching("at")).is_ok()
+		{
+			Some(Position::parse(context, input)?)
+		}
+		else
+		{
+			None
+		};
+		
+		if from.is_some() || position.is_some()
+		{
+			input.expect_comma()?;
+		}
+		
+		Ok
+		(
+			Self
+			{
+				repeating,
+				from: from.unwrap_or_default(),
+				position: position.unwrap_or_else(Position::center),
+				items: GradientItem::parse_list(context, input)?,
+			}
+		)
+	}
+}
@@ -0,0 +1,132 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// `cross-fade()` or the legacy `-webkit-cross-fade()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum CrossFade
+{
+	/// eg `cross-fade(25% url(a.png), red)`.
+	Standard(CommaSeparatedList<CrossFadeImage>),
+	
+	/// eg `-webkit-cross-fade(url(a.png), url(b.png), 25%)`.
+	Legacy
+	{
+		vendor_prefix: Option<VendorPrefix>,
+		
+		from: Box<Image>,
+		
+		to: Box<Image>,
+		
+		percentage: PercentageUnit<CssUnsignedNumber>,
+	},
+}
+
+impl ToCss for CrossFade
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::CrossFade::*;
+		
+		match *self
+		{
+			Standard(ref images) =>
+			{
+				dest.write_str("cross-fade(")?;
+				images.to_css(dest)?;
+			}
+			
+			Legacy { ref vendor_prefix, ref from, ref to, ref percentage } =>
+			{
+				if let Some(ref vendor_prefix) = *vendor_prefix
+				{
+					vendor_prefix.to_css(dest)?;
+				}
+				dest.write_str("cross-fade(")?;
+				from.to_css(dest)?;
+				dest.write_char(',')?;
+				to.to_css(dest)?;
+				dest.write_char(',')?;
+				percentage.to_css(dest)?;
+			}
+		}
+		dest.write_char(')')
+	}
+}
+
+impl CrossFade
+{
+	/// Parses the arguments inside the function's parentheses.
+	/// Vendor-prefixed functions only support the legacy syntax; unprefixed functions support both.
+	pub(crate) fn parse_arguments<'i, 't>(vendor_prefix: Option<VendorPrefix>, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::CrossFade::*;
+		
+		if vendor_prefix.is_none()
+		{
+			if let Ok(images) = input.r#try(|input| CommaSeparatedList::parse(context, input))
+			{
+				return Ok(Standard(images))
+			}
+		}
+		
+		let from = Image::parse(context, input)?;
+		input.expect_comma()?;
+		let to = Image::parse(context, input)?;
+		input.expect_comma()?;
+		let percentage = match *input.next()?
+		{
+			Token::Number { value, int_value, .. } => CssUnsignedNumber::parseNumber(value, int_value).map(PercentageUnit).map_err(|error| input.new_custom_error(error))?,
+			
+			Token::Percentage { unit_value, .. } => PercentageUnit::parse_percentage(unit_value).map_err(|error| input.new_custom_error(error))?,
+			
+			ref unexpectedToken =>
+			{
+				let unexpectedToken = unexpectedToken.clone();
+				return Err(input.new_unexpected_token_error(unexpectedToken))
+			}
+		};
+		
+		Ok
+		(
+			Legacy
+			{
+				vendor_prefix,
+				from: Box::new(from),
+				to: Box::new(to),
+				percentage,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn parsePercentage<'i, 't>(input: &mut Parser<'i, 't>) -> Result<PercentageUnit<CssUnsignedNumber>, ParseError<'i, CustomParseError<'i>>>
+	{
+		let unit_value = input.expect_percentage()?;
+		PercentageUnit::parse_percentage(unit_value).map_err(|error| input.new_custom_error(error))
+	}
+	
+	/// Calls `visitor` with each image in this cross-fade.
+	#[inline(always)]
+	pub(crate) fn images_mut<Visitor: FnMut(&mut Image)>(&mut self, visitor: &mut Visitor)
+	{
+		use self::CrossFade::*;
+		
+		match *self
+		{
+			Standard(ref mut images) => for image in images.0.iter_mut()
+			{
+				if let CrossFadeSource::Image(ref mut image) = image.source
+				{
+					visitor(image)
+				}
+			},
+			
+			Legacy { ref mut from, ref mut to, .. } =>
+			{
+				visitor(from);
+				visitor(to);
+			}
+		}
+	}
+}
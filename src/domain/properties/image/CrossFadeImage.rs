@@ -0,0 +1,47 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One argument of a standard `cross-fade()`, eg `50% url(a.png)`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct CrossFadeImage
+{
+	pub percentage: Option<PercentageUnit<CssUnsignedNumber>>,
+	
+	pub source: CrossFadeSource,
+}
+
+impl ToCss for CrossFadeImage
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if let Some(ref percentage) = self.percentage
+		{
+			percentage.to_css(dest)?;
+			dest.write_char(' ')?;
+		}
+		self.source.to_css(dest)
+	}
+}
+
+impl Parse for CrossFadeImage
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut percentage = input.r#try(|input| CrossFade::parsePercentage(input)).ok();
+		let source = CrossFadeSource::parse(context, input)?;
+		if percentage.is_none()
+		{
+			percentage = input.r#try(|input| CrossFade::parsePercentage(input)).ok();
+		}
+		
+		Ok
+		(
+			Self
+			{
+				percentage,
+				source,
+			}
+		)
+	}
+}
@@ -0,0 +1,41 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// What a standard `cross-fade()` blends: an image or a solid colour.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum CrossFadeSource
+{
+	Image(Image),
+	
+	Colour(Colour),
+}
+
+impl ToCss for CrossFadeSource
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::CrossFadeSource::*;
+		
+		match *self
+		{
+			Image(ref image) => image.to_css(dest),
+			Colour(ref colour) => colour.to_css(dest),
+		}
+	}
+}
+
+impl Parse for CrossFadeSource
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::CrossFadeSource::*;
+		
+		if let Ok(image) = input.r#try(|input| self::Image::parse(context, input))
+		{
+			return Ok(Image(image))
+		}
+		
+		Ok(Colour(self::Colour::parse(context, input)?))
+	}
+}
@@ -0,0 +1,137 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A radius of a radial gradient's ending shape.
+pub type GradientRadius = CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>;
+
+/// The ending shape of a radial gradient, and its size.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum EndingShape
+{
+	/// A percentage radius is not allowed.
+	Circle(CircleSize),
+	
+	Ellipse(EllipseSize),
+}
+
+impl Default for EndingShape
+{
+	/// `ellipse farthest-corner`.
+	#[inline(always)]
+	fn default() -> Self
+	{
+		EndingShape::Ellipse(EllipseSize::Extent(ShapeExtent::farthest_corner))
+	}
+}
+
+impl ToCss for EndingShape
+{
+	/// Writes the shortest form, which is nothing for the default; a single length implies a circle and two an ellipse.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::EndingShape::*;
+		
+		match *self
+		{
+			Circle(CircleSize::Extent(ShapeExtent::farthest_corner)) => dest.write_str("circle"),
+			
+			Circle(CircleSize::Extent(extent)) =>
+			{
+				dest.write_str("circle ")?;
+				extent.to_css(dest)
+			}
+			
+			Circle(CircleSize::Radius(ref radius)) => serialize_length_or_percentage(radius, dest),
+			
+			Ellipse(EllipseSize::Extent(ShapeExtent::farthest_corner)) => Ok(()),
+			
+			Ellipse(EllipseSize::Extent(extent)) => extent.to_css(dest),
+			
+			Ellipse(EllipseSize::Radii(ref horizontal, ref vertical)) =>
+			{
+				serialize_length_or_percentage(horizontal, dest)?;
+				dest.write_char(' ')?;
+				serialize_length_or_percentage(vertical, dest)
+			}
+		}
+	}
+}
+
+impl Parse for EndingShape
+{
+	/// Parses a shape and a size, in either order, either of which may be omitted (but not both).
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::EndingShape::*;
+		
+		let mut isCircle = None;
+		let mut extent = None;
+		let mut radii: Vec<GradientRadius> = Vec::with_capacity(2);
+		
+		for _ in 0 .. 2
+		{
+			if isCircle.is_none()
+			{
+				if input.r#try(|input| input.expect_ident_matching("circle")).is_ok()
+				{
+					isCircle = Some(true);
+					continue;
+				}
+				
+				if input.r#try(|input| input.expect_ident_matching("ellipse")).is_ok()
+				{
+					isCircle = Some(false);
+					continue;
+				}
+			}
+			
+			if extent.is_none() && radii.is_empty()
+			{
+				if let Ok(value) = input.r#try(|input| ShapeExtent::parse(input))
+				{
+					extent = Some(value);
+					continue;
+				}
+				
+				if let Ok(radius) = input.r#try(|input| LengthOrPercentageUnit::parse_one_outside_calc_function(context, input))
+				{
+					radii.push(radius);
+					if let Ok(radius) = input.r#try(|input| LengthOrPercentageUnit::parse_one_outside_calc_function(context, input))
+					{
+						radii.push(radius);
+					}
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		#[inline(always)]
+		fn isPercentage(radius: &GradientRadius) -> bool
+		{
+			matches!(*radius, CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(_)) | CalculablePropertyValue::Percentage(_))
+		}
+		
+		match (isCircle, extent, radii.len())
+		{
+			(None, None, 0) => Err(input.new_error_for_next_token()),
+			
+			(Some(true), Some(extent), 0) => Ok(Circle(CircleSize::Extent(extent))),
+			(Some(true), None, 0) => Ok(Circle(CircleSize::Extent(ShapeExtent::farthest_corner))),
+			(Some(true), None, 1) | (None, None, 1) if !isPercentage(&radii[0]) => Ok(Circle(CircleSize::Radius(radii.pop().unwrap()))),
+			
+			(Some(false), Some(extent), 0) | (None, Some(extent), 0) => Ok(Ellipse(EllipseSize::Extent(extent))),
+			(Some(false), None, 0) => Ok(Ellipse(EllipseSize::Extent(ShapeExtent::farthest_corner))),
+			(Some(false), None, 2) | (None, None, 2) =>
+			{
+				let vertical = radii.pop().unwrap();
+				let horizontal = radii.pop().unwrap();
+				Ok(Ellipse(EllipseSize::Radii(horizontal, vertical)))
+			}
+			
+			_ => Err(input.new_custom_error(CustomParseError::InvalidGradientShape)),
+		}
+	}
+}
@@ -0,0 +1,83 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A gradient function, such as `linear-gradient()` or the legacy `-webkit-gradient()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Gradient
+{
+	/// `linear-gradient()`, `repeating-linear-gradient()` and their vendor-prefixed forms.
+	Linear(LinearGradient),
+	
+	/// `radial-gradient()`, `repeating-radial-gradient()` and their vendor-prefixed forms.
+	Radial(RadialGradient),
+	
+	/// `conic-gradient()` and `repeating-conic-gradient()`.
+	Conic(ConicGradient),
+	
+	/// `-webkit-gradient()`.
+	LegacyWebKit(LegacyWebKitGradient),
+}
+
+impl ToCss for Gradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Gradient::*;
+		
+		match *self
+		{
+			Linear(ref gradient) => gradient.to_css(dest),
+			Radial(ref gradient) => gradient.to_css(dest),
+			Conic(ref gradient) => gradient.to_css(dest),
+			LegacyWebKit(ref gradient) => gradient.to_css(dest),
+		}
+	}
+}
+
+impl Gradient
+{
+	/// Parses the arguments inside the parentheses of the function `unprefixedName`, or returns `None` if that is not a gradient function.
+	pub(crate) fn parse_arguments<'i, 't>(vendor_prefix: Option<VendorPrefix>, unprefixedName: &str, context: &ParserContext, input: &mut Parser<'i, 't>) -> Option<Result<Self, ParseError<'i, CustomParseError<'i>>>>
+	{
+		use self::Gradient::*;
+		
+		let isUnprefixed = vendor_prefix.is_none();
+		let isWebKit = vendor_prefix == Some(VendorPrefix::webkit);
+		
+		let result = match unprefixedName
+		{
+			"linear-gradient" => LinearGradient::parse_arguments(vendor_prefix, false, context, input).map(Linear),
+			
+			"repeating-linear-gradient" => LinearGradient::parse_arguments(vendor_prefix, true, context, input).map(Linear),
+			
+			"radial-gradient" => RadialGradient::parse_arguments(vendor_prefix, false, context, input).map(Radial),
+			
+			"repeating-radial-gradient" => RadialGradient::parse_arguments(vendor_prefix, true, context, input).map(Radial),
+			
+			"conic-gradient" if isUnprefixed => ConicGradient::parse_arguments(false, context, input).map(Conic),
+			
+			"repeating-conic-gradient" if isUnprefixed => ConicGradient::parse_arguments(true, context, input).map(Conic),
+			
+			"gradient" if isWebKit => LegacyWebKitGradient::parse_arguments(context, input).map(LegacyWebKit),
+			
+			_ => return None,
+		};
+		Some(result)
+	}
+	
+	/// Converts a legacy gradient, such as `-webkit-linear-gradient(top, red, blue)` or `-webkit-gradient(linear, left top, left bottom, from(red), to(blue))`, to its standard equivalent.
+	/// Returns `None` if this gradient is already standard or has no exact standard equivalent.
+	pub fn to_standard(&self) -> Option<Self>
+	{
+		use self::Gradient::*;
+		
+		match *self
+		{
+			Linear(ref gradient) => gradient.to_standard().map(Linear),
+			Radial(ref gradient) => gradient.to_standard().map(Radial),
+			Conic(_) => None,
+			LegacyWebKit(ref gradient) => gradient.to_standard().map(Linear),
+		}
+	}
+}
@@ -0,0 +1,107 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An item in the list of a gradient's colour stops.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum GradientItem<StopPosition: GradientStopPosition>
+{
+	/// A colour stop has no, one or two positions; two are the same as two colour stops of the same colour.
+	ColourStop
+	{
+		colour: Colour,
+		positions: Vec<StopPosition>,
+	},
+	
+	/// A hint, between two colour stops, of where their colours are midway.
+	InterpolationHint(StopPosition),
+}
+
+impl<StopPosition: GradientStopPosition> GradientItem<StopPosition>
+{
+	/// Writes a list of items, comma separated; a first colour stop at the start and a last colour stop at the end do not need their position.
+	pub(crate) fn to_css_list<W: fmt::Write>(items: &[Self], dest: &mut W) -> fmt::Result
+	{
+		use self::GradientItem::*;
+		
+		let lastIndex = items.len() - 1;
+		for (index, item) in items.iter().enumerate()
+		{
+			if index != 0
+			{
+				dest.write_char(',')?;
+			}
+			
+			match *item
+			{
+				ColourStop { ref colour, ref positions } =>
+				{
+					colour.to_css(dest)?;
+					
+					let isImplied = positions.len() == 1 && ((index == 0 && positions[0].isStart()) || (index == lastIndex && positions[0].isEnd()));
+					if !isImplied
+					{
+						for position in positions.iter()
+						{
+							dest.write_char(' ')?;
+							position.write(dest)?;
+						}
+					}
+				}
+				
+				InterpolationHint(ref position) => position.write(dest)?,
+			}
+		}
+		Ok(())
+	}
+	
+	/// Parses a comma-separated list of at least two colour stops, with optional interpolation hints between them.
+	pub(crate) fn parse_list<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Vec<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GradientItem::*;
+		
+		let mut previousWasColourStop = false;
+		let items = input.parse_comma_separated(|input|
+		{
+			let item = if let Ok(colour) = input.r#try(|input| Colour::parse(context, input))
+			{
+				let mut positions = Vec::with_capacity(2);
+				while positions.len() < 2
+				{
+					match input.r#try(|input| StopPosition::parse(context, input))
+					{
+						Ok(position) => positions.push(position),
+						Err(_) => break,
+					}
+				}
+				ColourStop { colour, positions }
+			}
+			else
+			{
+				if !previousWasColourStop
+				{
+					return Err(input.new_custom_error(CustomParseError::GradientInterpolationHintMustBeBetweenColourStops))
+				}
+				InterpolationHint(StopPosition::parse(context, input)?)
+			};
+			previousWasColourStop = match item
+			{
+				ColourStop { .. } => true,
+				InterpolationHint(_) => false,
+			};
+			Ok(item)
+		})?;
+		
+		if !previousWasColourStop
+		{
+			return Err(input.new_custom_error(CustomParseError::GradientInterpolationHintMustBeBetweenColourStops))
+		}
+		
+		if items.len() < 2
+		{
+			return Err(input.new_custom_error(CustomParseError::GradientMustHaveAtLeastTwoColourStops))
+		}
+		
+		Ok(items)
+	}
+}
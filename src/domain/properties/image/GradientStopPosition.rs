@@ -0,0 +1,64 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A length or percentage giving the position of a colour stop in a linear or radial gradient.
+pub type GradientStopLength = CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>;
+
+/// An angle in a gradient, such as the direction of a linear gradient.
+pub type GradientAngle = CalculablePropertyValue<AngleUnit<CssSignedNumber>>;
+
+/// The position of a colour stop or interpolation hint along a gradient.
+pub trait GradientStopPosition: ToCss + Sized
+{
+	/// Is this the start of the gradient, which is the position of a first colour stop if omitted?
+	fn isStart(&self) -> bool;
+	
+	/// Is this the end of the gradient, which is the position of a last colour stop if omitted?
+	fn isEnd(&self) -> bool;
+	
+	#[doc(hidden)]
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>;
+	
+	#[doc(hidden)]
+	#[inline(always)]
+	fn write<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.to_css(dest)
+	}
+}
+
+impl GradientStopPosition for GradientStopLength
+{
+	#[inline(always)]
+	fn isStart(&self) -> bool
+	{
+		match *self
+		{
+			CalculablePropertyValue::Constant(ref constant) => constant.is_zero(),
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn isEnd(&self) -> bool
+	{
+		match *self
+		{
+			CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(percentage)) => percentage.to_f32() == 1.0,
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)
+	}
+	
+	#[inline(always)]
+	fn write<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		serialize_length_or_percentage(self, dest)
+	}
+}
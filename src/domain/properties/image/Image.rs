@@ -0,0 +1,111 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An `<image>`, such as `url(a.png)`, a gradient, `image-set()` or `cross-fade()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Image
+{
+	Url(SpecifiedUrl),
+	
+	Gradient(Gradient),
+	
+	ImageSet(ImageSet),
+	
+	CrossFade(CrossFade),
+}
+
+impl ToCss for Image
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Image::*;
+		
+		match *self
+		{
+			Url(ref url) => url.to_css(dest),
+			Gradient(ref gradient) => gradient.to_css(dest),
+			ImageSet(ref imageSet) => imageSet.to_css(dest),
+			CrossFade(ref crossFade) => crossFade.to_css(dest),
+		}
+	}
+}
+
+impl Parse for Image
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Image::*;
+		
+		if let Ok(url) = input.r#try(|input| SpecifiedUrl::parse(context, input))
+		{
+			return Ok(Url(url))
+		}
+		
+		let name = input.expect_function()?.clone();
+		let (vendor_prefix, unprefixedName) = VendorPrefix::findPrefixIfAnyForAsciiLowerCaseName(name.to_ascii_lowercase());
+		input.parse_nested_block(|input|
+		{
+			if let Some(result) = self::Gradient::parse_arguments(vendor_prefix.clone(), &unprefixedName, context, input)
+			{
+				return result.map(Gradient)
+			}
+			
+			match &unprefixedName[..]
+			{
+				"image-set" => self::ImageSet::parse_arguments(vendor_prefix, context, input).map(ImageSet),
+				
+				"cross-fade" => self::CrossFade::parse_arguments(vendor_prefix, context, input).map(CrossFade),
+				
+				_ => Err(input.new_custom_error(CustomParseError::UnexpectedImageFunction(name.clone()))),
+			}
+		})
+	}
+}
+
+impl Image
+{
+	/// Calls `visitor` with each url in this image, including those nested inside `image-set()` and `cross-fade()`, eg so it can be rebased.
+	pub fn urls_mut<Visitor: FnMut(&mut SpecifiedUrl)>(&mut self, visitor: &mut Visitor)
+	{
+		use self::Image::*;
+		
+		match *self
+		{
+			Url(ref mut url) => visitor(url),
+			
+			Gradient(_) => (),
+			
+			ImageSet(ref mut imageSet) => for option in imageSet.options.0.iter_mut()
+			{
+				option.image.urls_mut(visitor)
+			},
+			
+			CrossFade(ref mut crossFade) => crossFade.images_mut(&mut |image| image.urls_mut(visitor)),
+		}
+	}
+	
+	/// Replaces legacy gradients, including those nested inside `image-set()` and `cross-fade()`, with their standard equivalents where one exists.
+	/// For example, `-webkit-linear-gradient(top, red, blue)` becomes `linear-gradient(red, blue)`.
+	pub fn standardise_legacy_gradients(&mut self)
+	{
+		use self::Image::*;
+		
+		match *self
+		{
+			Url(_) => (),
+			
+			Gradient(ref mut gradient) => if let Some(standard) = gradient.to_standard()
+			{
+				*gradient = standard
+			},
+			
+			ImageSet(ref mut imageSet) => for option in imageSet.options.0.iter_mut()
+			{
+				option.image.standardise_legacy_gradients()
+			},
+			
+			CrossFade(ref mut crossFade) => crossFade.images_mut(&mut |image| image.standardise_legacy_gradients()),
+		}
+	}
+}
@@ -0,0 +1,68 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// `image-set()` or `-webkit-image-set()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ImageSet
+{
+	pub vendor_prefix: Option<VendorPrefix>,
+	
+	pub options: CommaSeparatedList<ImageSetOption>,
+}
+
+impl ToCss for ImageSet
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if let Some(ref vendor_prefix) = self.vendor_prefix
+		{
+			vendor_prefix.to_css(dest)?;
+		}
+		dest.write_str("image-set(")?;
+		let omitDefaultResolution = self.vendor_prefix.is_none();
+		let mut options = self.options.iter();
+		if let Some(first) = options.next()
+		{
+			first.serialize(omitDefaultResolution, dest)?;
+			for option in options
+			{
+				dest.write_char(',')?;
+				option.serialize(omitDefaultResolution, dest)?;
+			}
+		}
+		dest.write_char(')')
+	}
+}
+
+impl ImageSet
+{
+	/// Parses the arguments inside the function's parentheses.
+	#[inline(always)]
+	pub(crate) fn parse_arguments<'i, 't>(vendor_prefix: Option<VendorPrefix>, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Ok
+		(
+			Self
+			{
+				vendor_prefix,
+				options: CommaSeparatedList::parse(context, input)?,
+			}
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn the_default_resolution_is_only_omitted_in_the_unprefixed_image_set()
+	{
+		let roundTrip = |css: &str| PropertyDeclaration::<Importance>::parse(css).unwrap().to_css_string();
+		
+		assert_eq!(roundTrip("background-image:image-set(url(a.png) 1x,url(b.png) 2x)"), "background-image:image-set(url(a.png),url(b.png) 2x);");
+		assert_eq!(roundTrip("background-image:-webkit-image-set(url(a.png) 1x,url(b.png) 2x)"), "background-image:-webkit-image-set(url(a.png) 1x,url(b.png) 2x);");
+	}
+}
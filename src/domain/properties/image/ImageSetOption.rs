@@ -0,0 +1,128 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One option of an `image-set()`, eg `"a.png" 2x type("image/png")`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ImageSetOption
+{
+	/// A string is parsed as if it were a `url()`.
+	pub image: Image,
+	
+	/// `None` is the same as `1x`.
+	pub resolution: Option<ResolutionUnit<CssUnsignedNumber>>,
+	
+	pub mime_type: Option<String>,
+}
+
+impl ToCss for ImageSetOption
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.serialize(false, dest)
+	}
+}
+
+impl Parse for ImageSetOption
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let image = match input.r#try(|input| input.expect_string().map(|url| url.as_ref().to_owned()))
+		{
+			Ok(url) => Image::Url(SpecifiedUrl(url)),
+			Err(_) => Image::parse(context, input)?,
+		};
+		
+		let mut resolution = None;
+		let mut mime_type = None;
+		while !input.is_exhausted() && (resolution.is_none() || mime_type.is_none())
+		{
+			if resolution.is_none()
+			{
+				if let Ok(parsed) = input.r#try(|input| Self::parseResolution(input))
+				{
+					resolution = Some(parsed);
+					continue
+				}
+			}
+			
+			if mime_type.is_none()
+			{
+				if let Ok(parsed) = input.r#try(|input| Self::parseMimeType(input))
+				{
+					mime_type = Some(parsed);
+					continue
+				}
+			}
+			
+			break
+		}
+		
+		Ok
+		(
+			Self
+			{
+				image,
+				resolution,
+				mime_type,
+			}
+		)
+	}
+}
+
+impl ImageSetOption
+{
+	/// `1x` is the default resolution, but it can only be omitted in the unprefixed `image-set()`; `-webkit-image-set()` requires it.
+	pub(crate) fn serialize<W: fmt::Write>(&self, omit_default_resolution: bool, dest: &mut W) -> fmt::Result
+	{
+		self.image.to_css(dest)?;
+		
+		match self.resolution
+		{
+			None => (),
+			Some(ResolutionUnit::dppx(resolution)) => if !omit_default_resolution || resolution != CssUnsignedNumber::One
+			{
+				dest.write_char(' ')?;
+				resolution.to_css(dest)?;
+				dest.write_char('x')?;
+			},
+			Some(ref resolution) =>
+			{
+				dest.write_char(' ')?;
+				resolution.to_css(dest)?;
+			}
+		}
+		
+		if let Some(ref mime_type) = self.mime_type
+		{
+			dest.write_str(" type(")?;
+			serialize_string(mime_type, dest)?;
+			dest.write_char(')')?;
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn parseResolution<'i, 't>(input: &mut Parser<'i, 't>) -> Result<ResolutionUnit<CssUnsignedNumber>, ParseError<'i, CustomParseError<'i>>>
+	{
+		match *input.next()?
+		{
+			Token::Dimension { value, ref unit, .. } => ResolutionUnit::parseDimension(value, unit).map_err(|error| input.new_custom_error(error)),
+			
+			ref unexpectedToken =>
+			{
+				let unexpectedToken = unexpectedToken.clone();
+				Err(input.new_unexpected_token_error(unexpectedToken))
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn parseMimeType<'i, 't>(input: &mut Parser<'i, 't>) -> Result<String, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.expect_function_matching("type")?;
+		input.parse_nested_block(|input| Ok(input.expect_string()?.as_ref().to_owned()))
+	}
+}
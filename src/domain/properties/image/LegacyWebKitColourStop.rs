@@ -0,0 +1,85 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A colour stop of a legacy `-webkit-gradient()`, written as `from()`, `to()` or `color-stop()`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LegacyWebKitColourStop
+{
+	/// A fraction, from `0` (`from()`) to `1` (`to()`).
+	pub position: CssSignedNumber,
+	
+	pub colour: Colour,
+}
+
+impl ToCss for LegacyWebKitColourStop
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let position = self.position.to_f32();
+		if position == 0.0
+		{
+			dest.write_str("from(")?;
+		}
+		else if position == 1.0
+		{
+			dest.write_str("to(")?;
+		}
+		else
+		{
+			dest.write_str("color-stop(")?;
+			self.position.to_css(dest)?;
+			dest.write_char(',')?;
+		}
+		self.colour.to_css(dest)?;
+		dest.write_char(')')
+	}
+}
+
+impl Parse for LegacyWebKitColourStop
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let name = input.expect_function()?.clone();
+		input.parse_nested_block(|input|
+		{
+			let position = match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"from" => CssSignedNumber::Zero,
+				
+				"to" => CssSignedNumber::One,
+				
+				"color-stop" =>
+				{
+					let position = match *input.next()?
+					{
+						Token::Number { value, int_value, .. } => CssSignedNumber::parseNumber(value, int_value).map_err(|error| input.new_custom_error(error))?,
+						
+						Token::Percentage { unit_value, .. } => CssSignedNumber::parseNumber(unit_value, None).map_err(|error| input.new_custom_error(error))?,
+						
+						ref unexpectedToken =>
+						{
+							let unexpectedToken = unexpectedToken.clone();
+							return Err(input.new_unexpected_token_error(unexpectedToken))
+						}
+					};
+					input.expect_comma()?;
+					position
+				},
+				
+				_ => return Err(input.new_unexpected_token_error(Token::Function(name.clone()))),
+			};
+			
+			Ok
+			(
+				Self
+				{
+					position,
+					colour: Colour::parse(context, input)?,
+				}
+			)
+		})
+	}
+}
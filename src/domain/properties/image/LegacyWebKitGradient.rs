@@ -0,0 +1,156 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The legacy `-webkit-gradient()` function, eg `-webkit-gradient(linear, left top, left bottom, from(#fff), to(#000))`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LegacyWebKitGradient
+{
+	pub gradient_type: LegacyWebKitGradientType,
+	
+	pub start: (LegacyWebKitPointComponent, LegacyWebKitPointComponent),
+	
+	/// Radial gradients only.
+	pub start_radius: Option<CssUnsignedNumber>,
+	
+	pub end: (LegacyWebKitPointComponent, LegacyWebKitPointComponent),
+	
+	/// Radial gradients only.
+	pub end_radius: Option<CssUnsignedNumber>,
+	
+	pub stops: Vec<LegacyWebKitColourStop>,
+}
+
+impl ToCss for LegacyWebKitGradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		#[inline(always)]
+		fn writePoint<W: fmt::Write>(point: &(LegacyWebKitPointComponent, LegacyWebKitPointComponent), radius: &Option<CssUnsignedNumber>, dest: &mut W) -> fmt::Result
+		{
+			dest.write_char(',')?;
+			point.0.to_css(dest)?;
+			dest.write_char(' ')?;
+			point.1.to_css(dest)?;
+			if let Some(ref radius) = *radius
+			{
+				dest.write_char(',')?;
+				radius.to_css(dest)?;
+			}
+			Ok(())
+		}
+		
+		dest.write_str("-webkit-gradient(")?;
+		self.gradient_type.to_css(dest)?;
+		writePoint(&self.start, &self.start_radius, dest)?;
+		writePoint(&self.end, &self.end_radius, dest)?;
+		for stop in self.stops.iter()
+		{
+			dest.write_char(',')?;
+			stop.to_css(dest)?;
+		}
+		dest.write_char(')')
+	}
+}
+
+impl LegacyWebKitGradient
+{
+	/// Parses the arguments inside the function's parentheses.
+	pub(crate) fn parse_arguments<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let gradient_type = LegacyWebKitGradientType::parse(input)?;
+		let isRadial = gradient_type == LegacyWebKitGradientType::radial;
+		
+		let parsePointAndRadius = |input: &mut Parser<'i, 't>| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+		{
+			input.expect_comma()?;
+			let point = (LegacyWebKitPointComponent::parse(context, input)?, LegacyWebKitPointComponent::parse(context, input)?);
+			let radius = if isRadial
+			{
+				input.expect_comma()?;
+				let radius = input.expect_number()?;
+				Some(CssUnsignedNumber::parseNumber(radius, None).map_err(|error| input.new_custom_error(error))?)
+			}
+			else
+			{
+				None
+			};
+			Ok((point, radius))
+		};
+		
+		let (start, start_radius) = parsePointAndRadius(input)?;
+		let (end, end_radius) = parsePointAndRadius(input)?;
+		
+		let mut stops = Vec::new();
+		while !input.is_exhausted()
+		{
+			input.expect_comma()?;
+			stops.push(LegacyWebKitColourStop::parse(context, input)?);
+		}
+		
+		Ok
+		(
+			Self
+			{
+				gradient_type,
+				start,
+				start_radius,
+				end,
+				end_radius,
+				stops,
+			}
+		)
+	}
+	
+	/// Converts a linear gradient between the middles of opposite sides, such as `-webkit-gradient(linear, left top, left bottom, from(#fff), to(#000))`, to its standard equivalent, `linear-gradient(#fff, #000)`.
+	/// Returns `None` for other gradients, as the standard forms are not equivalent.
+	pub fn to_standard(&self) -> Option<LinearGradient>
+	{
+		use self::PositionKeyword::*;
+		
+		if self.gradient_type != LegacyWebKitGradientType::linear || self.stops.len() < 2
+		{
+			return None
+		}
+		
+		let start = (self.start.0.fraction()?, self.start.1.fraction()?);
+		let end = (self.end.0.fraction()?, self.end.1.fraction()?);
+		
+		let (horizontal, vertical) = match (start, end)
+		{
+			((x1, 0.0), (x2, 1.0)) if x1 == x2 => (None, Some(bottom)),
+			((x1, 1.0), (x2, 0.0)) if x1 == x2 => (None, Some(top)),
+			((0.0, y1), (1.0, y2)) if y1 == y2 => (Some(right), None),
+			((1.0, y1), (0.0, y2)) if y1 == y2 => (Some(left), None),
+			_ => return None,
+		};
+		
+		let mut stops = self.stops.clone();
+		stops.sort_by_key(|stop| stop.position);
+		
+		let mut items = Vec::with_capacity(stops.len());
+		for stop in stops.drain(..)
+		{
+			let percentage = PercentageUnit(stop.position);
+			items.push
+			(
+				GradientItem::ColourStop
+				{
+					colour: stop.colour,
+					positions: vec![CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(percentage))],
+				}
+			);
+		}
+		
+		Some
+		(
+			LinearGradient
+			{
+				vendor_prefix: None,
+				repeating: false,
+				direction: LineDirection::Sides { horizontal, vertical },
+				items,
+			}
+		)
+	}
+}
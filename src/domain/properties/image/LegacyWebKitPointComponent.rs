@@ -0,0 +1,76 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One axis of a `LegacyWebKitPoint`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LegacyWebKitPointComponent
+{
+	Keyword(PositionKeyword),
+	
+	/// In pixels.
+	Number(CssSignedNumber),
+	
+	Percentage(PercentageUnit<CssSignedNumber>),
+}
+
+impl ToCss for LegacyWebKitPointComponent
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::LegacyWebKitPointComponent::*;
+		
+		match *self
+		{
+			Keyword(ref keyword) => keyword.to_css(dest),
+			Number(ref number) => number.to_css(dest),
+			Percentage(ref percentage) => percentage.to_css(dest),
+		}
+	}
+}
+
+impl Parse for LegacyWebKitPointComponent
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::LegacyWebKitPointComponent::*;
+		
+		if let Ok(keyword) = input.r#try(|input| PositionKeyword::parse(input))
+		{
+			return Ok(Keyword(keyword))
+		}
+		
+		match *input.next()?
+		{
+			Token::Number { value, int_value, .. } => CssSignedNumber::parseNumber(value, int_value).map(Number).map_err(|error| input.new_custom_error(error)),
+			
+			Token::Percentage { unit_value, .. } => PercentageUnit::parse_percentage(unit_value).map(Percentage).map_err(|error| input.new_custom_error(error)),
+			
+			ref unexpectedToken =>
+			{
+				let unexpectedToken = unexpectedToken.clone();
+				Err(input.new_unexpected_token_error(unexpectedToken))
+			}
+		}
+	}
+}
+
+impl LegacyWebKitPointComponent
+{
+	/// The position as a fraction of the box's width or height, or `None` for pixels.
+	#[inline(always)]
+	pub fn fraction(&self) -> Option<f32>
+	{
+		use self::LegacyWebKitPointComponent::*;
+		use self::PositionKeyword::*;
+		
+		match *self
+		{
+			Keyword(left) | Keyword(top) => Some(0.0),
+			Keyword(center) => Some(0.5),
+			Keyword(right) | Keyword(bottom) => Some(1.0),
+			Number(_) => None,
+			Percentage(ref percentage) => Some(percentage.to_f32()),
+		}
+	}
+}
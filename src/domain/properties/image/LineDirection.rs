@@ -0,0 +1,167 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The direction of a linear gradient.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LineDirection
+{
+	/// For standard gradients, `0deg` points up and angles increase clockwise.
+	/// For legacy, vendor-prefixed gradients, `0deg` points right and angles increase anti-clockwise.
+	Angle(GradientAngle),
+	
+	/// For standard gradients, the side or corner the gradient goes towards, eg `to left top`.
+	/// For legacy, vendor-prefixed gradients, the side or corner it starts from, eg `left top`.
+	/// At least one of `horizontal` (`left` or `right`) and `vertical` (`top` or `bottom`) is present.
+	Sides
+	{
+		horizontal: Option<PositionKeyword>,
+		vertical: Option<PositionKeyword>,
+	},
+}
+
+impl LineDirection
+{
+	/// `to bottom` for standard gradients, and `top` for legacy gradients.
+	#[inline(always)]
+	pub fn initial(isLegacy: bool) -> Self
+	{
+		LineDirection::Sides
+		{
+			horizontal: None,
+			vertical: Some(if isLegacy { PositionKeyword::top } else { PositionKeyword::bottom }),
+		}
+	}
+	
+	/// Writes the shortest form, followed by a comma; nothing is written for the initial direction.
+	pub(crate) fn to_css<W: fmt::Write>(&self, isLegacy: bool, dest: &mut W) -> fmt::Result
+	{
+		use self::LineDirection::*;
+		use self::PositionKeyword::*;
+		
+		if self.isInitial(isLegacy)
+		{
+			return Ok(())
+		}
+		
+		match *self
+		{
+			Angle(ref angle) => angle.to_css(dest)?,
+			
+			// Sides are shorter as angles.
+			Sides { horizontal: None, vertical: Some(top) } if !isLegacy => dest.write_str("0deg")?,
+			Sides { horizontal: Some(right), vertical: None } if !isLegacy => dest.write_str("90deg")?,
+			Sides { horizontal: Some(left), vertical: None } if !isLegacy => dest.write_str("270deg")?,
+			
+			Sides { ref horizontal, ref vertical } =>
+			{
+				if !isLegacy
+				{
+					dest.write_str("to ")?;
+				}
+				if let Some(ref horizontal) = *horizontal
+				{
+					horizontal.to_css(dest)?;
+					if vertical.is_some()
+					{
+						dest.write_char(' ')?;
+					}
+				}
+				if let Some(ref vertical) = *vertical
+				{
+					vertical.to_css(dest)?;
+				}
+			}
+		}
+		
+		dest.write_char(',')
+	}
+	
+	/// Parses an angle, or sides (preceded by `to` unless `isLegacy`).
+	pub(crate) fn parse<'i, 't>(isLegacy: bool, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::LineDirection::*;
+		
+		if let Ok(angle) = input.r#try(|input| AngleUnit::parse_one_outside_calc_function(context, input))
+		{
+			return Ok(Angle(angle))
+		}
+		
+		if !isLegacy
+		{
+			input.expect_ident_matching("to")?;
+		}
+		
+		let mut horizontal = None;
+		let mut vertical = None;
+		for _ in 0 .. 2
+		{
+			match input.r#try(|input| PositionKeyword::parse(input))
+			{
+				Ok(keyword) if keyword.isHorizontal() && horizontal.is_none() => horizontal = Some(keyword),
+				Ok(keyword) if keyword.isVertical() && vertical.is_none() => vertical = Some(keyword),
+				Ok(keyword) => return Err(input.new_unexpected_token_error(Token::Ident(keyword.to_str().into()))),
+				Err(error) => if horizontal.is_none() && vertical.is_none()
+				{
+					return Err(error)
+				}
+				else
+				{
+					break
+				},
+			}
+		}
+		
+		Ok(Sides { horizontal, vertical })
+	}
+	
+	/// Converts a legacy direction to a standard one; returns `None` for an angle that is not constant.
+	pub fn legacy_to_standard(&self) -> Option<Self>
+	{
+		use self::LineDirection::*;
+		use self::PositionKeyword::*;
+		
+		#[inline(always)]
+		fn opposite(keyword: &Option<PositionKeyword>) -> Option<PositionKeyword>
+		{
+			keyword.map(|keyword| match keyword
+			{
+				left => right,
+				right => left,
+				top => bottom,
+				bottom => top,
+				center => center,
+			})
+		}
+		
+		match *self
+		{
+			Angle(CalculablePropertyValue::Constant(ref angle)) =>
+			{
+				let degrees = (90.0 - angle.to_degrees().to_f32()).rem_euclid(360.0);
+				Some(Angle(CalculablePropertyValue::Constant(AngleUnit::deg(CssSignedNumber::new(degrees).ok()?))))
+			}
+			
+			Angle(_) => None,
+			
+			Sides { ref horizontal, ref vertical } => Some
+			(
+				Sides
+				{
+					horizontal: opposite(horizontal),
+					vertical: opposite(vertical),
+				}
+			),
+		}
+	}
+	
+	#[inline(always)]
+	fn isInitial(&self, isLegacy: bool) -> bool
+	{
+		match *self
+		{
+			LineDirection::Angle(CalculablePropertyValue::Constant(ref angle)) => angle.to_degrees().to_f32().rem_euclid(360.0) == if isLegacy { 270.0 } else { 180.0 },
+			_ => *self == Self::initial(isLegacy),
+		}
+	}
+}
@@ -0,0 +1,84 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `linear-gradient()` or `repeating-linear-gradient()`, or a legacy, vendor-prefixed form of either.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LinearGradient
+{
+	/// Legacy prefixed gradients, such as `-webkit-linear-gradient()`, interpret `direction` differently.
+	pub vendor_prefix: Option<VendorPrefix>,
+	
+	pub repeating: bool,
+	
+	pub direction: LineDirection,
+	
+	pub items: Vec<GradientItem<GradientStopLength>>,
+}
+
+impl ToCss for LinearGradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if let Some(ref vendor_prefix) = self.vendor_prefix
+		{
+			vendor_prefix.to_css(dest)?;
+		}
+		if self.repeating
+		{
+			dest.write_str("repeating-")?;
+		}
+		dest.write_str("linear-gradient(")?;
+		self.direction.to_css(self.vendor_prefix.is_some(), dest)?;
+		GradientItem::to_css_list(&self.items, dest)?;
+		dest.write_char(')')
+	}
+}
+
+impl LinearGradient
+{
+	/// Parses the arguments inside the function's parentheses.
+	pub(crate) fn parse_arguments<'i, 't>(vendor_prefix: Option<VendorPrefix>, repeating: bool, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let isLegacy = vendor_prefix.is_some();
+		
+		let direction = match input.r#try(|input| LineDirection::parse(isLegacy, context, input))
+		{
+			Ok(direction) =>
+			{
+				input.expect_comma()?;
+				direction
+			}
+			Err(_) => LineDirection::initial(isLegacy),
+		};
+		
+		Ok
+		(
+			Self
+			{
+				vendor_prefix,
+				repeating,
+				direction,
+				items: GradientItem::parse_list(context, input)?,
+			}
+		)
+	}
+	
+	/// Converts a legacy, vendor-prefixed gradient, such as `-webkit-linear-gradient(top, red, blue)`, to its standard equivalent, `linear-gradient(to bottom, red, blue)`.
+	/// Returns `None` if this gradient is not prefixed, or if its direction is an angle that is not constant.
+	pub fn to_standard(&self) -> Option<Self>
+	{
+		self.vendor_prefix.as_ref()?;
+		
+		Some
+		(
+			Self
+			{
+				vendor_prefix: None,
+				repeating: self.repeating,
+				direction: self.direction.legacy_to_standard()?,
+				items: self.items.clone(),
+			}
+		)
+	}
+}
@@ -0,0 +1,142 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `radial-gradient()` or `repeating-radial-gradient()`, or a legacy, vendor-prefixed form of either.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct RadialGradient
+{
+	/// Legacy prefixed gradients, such as `-webkit-radial-gradient()`, give the position first and separate it, and the shape, with commas.
+	pub vendor_prefix: Option<VendorPrefix>,
+	
+	pub repeating: bool,
+	
+	pub shape: EndingShape,
+	
+	pub position: Position,
+	
+	pub items: Vec<GradientItem<GradientStopLength>>,
+}
+
+impl ToCss for RadialGradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if let Some(ref vendor_prefix) = self.vendor_prefix
+		{
+			vendor_prefix.to_css(dest)?;
+		}
+		if self.repeating
+		{
+			dest.write_str("repeating-")?;
+		}
+		dest.write_str("radial-gradient(")?;
+		
+		let hasShape = self.shape != EndingShape::default();
+		let hasPosition = !self.position.isCenter();
+		if self.vendor_prefix.is_some()
+		{
+			if hasPosition
+			{
+				self.position.to_css(dest)?;
+				dest.write_char(',')?;
+			}
+			if hasShape
+			{
+				self.shape.to_css(dest)?;
+				dest.write_char(',')?;
+			}
+		}
+		else if hasShape || hasPosition
+		{
+			self.shape.to_css(dest)?;
+			if hasPosition
+			{
+				if hasShape
+				{
+					dest.write_char(' ')?;
+				}
+				dest.write_str("at ")?;
+				self.position.to_css(dest)?;
+			}
+			dest.write_char(',')?;
+		}
+		
+		GradientItem::to_css_list(&self.items, dest)?;
+		dest.write_char(')')
+	}
+}
+
+impl RadialGradient
+{
+	/// Parses the arguments inside the function's parentheses.
+	pub(crate) fn parse_arguments<'i, 't>(vendor_prefix: Option<VendorPrefix>, repeating: bool, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let (shape, position) = if vendor_prefix.is_some()
+		{
+			let position = input.r#try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+			{
+				let position = Position::parse(context, input)?;
+				input.expect_comma()?;
+				Ok(position)
+			});
+			
+			let shape = input.r#try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+			{
+				let shape = EndingShape::parse(context, input)?;
+				input.expect_comma()?;
+				Ok(shape)
+			});
+			
+			(shape.ok(), position.ok())
+		}
+		else
+		{
+			let shape = input.r#try(|input| EndingShape::parse(context, input)).ok();
+			
+			let position = if input.r#try(|input| input.expect_ident_matching("at")).is_ok()
+			{
+				Some(Position::parse(context, input)?)
+			}
+			else
+			{
+				None
+			};
+			
+			if shape.is_some() || position.is_some()
+			{
+				input.expect_comma()?;
+			}
+			
+			(shape, position)
+		};
+		
+		Ok
+		(
+			Self
+			{
+				vendor_prefix,
+				repeating,
+				shape: shape.unwrap_or_default(),
+				position: position.unwrap_or_else(Position::center),
+				items: GradientItem::parse_list(context, input)?,
+			}
+		)
+	}
+	
+	/// Converts a legacy, vendor-prefixed gradient, such as `-webkit-radial-gradient(top, circle cover, red, blue)`, to its standard equivalent, `radial-gradient(circle at top, red, blue)`.
+	/// Returns `None` if this gradient is not prefixed.
+	pub fn to_standard(&self) -> Option<Self>
+	{
+		self.vendor_prefix.as_ref()?;
+		
+		Some
+		(
+			Self
+			{
+				vendor_prefix: None,
+				.. self.clone()
+			}
+		)
+	}
+}
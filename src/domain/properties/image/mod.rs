@@ -0,0 +1,29 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("AngleOrPercentage.rs");
+include!("CircleSize.rs");
+include!("ConicGradient.rs");
+include!("CrossFade.rs");
+include!("CrossFadeImage.rs");
+include!("CrossFadeSource.rs");
+include!("EllipseSize.rs");
+include!("EndingShape.rs");
+include!("Gradient.rs");
+include!("GradientItem.rs");
+include!("GradientStopPosition.rs");
+include!("Image.rs");
+include!("ImageSet.rs");
+include!("ImageSetOption.rs");
+include!("LegacyWebKitColourStop.rs");
+include!("LegacyWebKitGradient.rs");
+include!("LegacyWebKitGradientType.rs");
+include!("LegacyWebKitPointComponent.rs");
+include!("LinearGradient.rs");
+include!("LineDirection.rs");
+include!("RadialGradient.rs");
+include!("ShapeExtent.rs");
@@ -5,18 +5,52 @@
 use super::*;
 use super::parsers::PropertyDeclarationParser;
 use self::Importance::*;
+use self::alignment::*;
+use self::animation::*;
+use self::background::*;
+use self::display::*;
+use self::flex::*;
+use self::font::*;
+use self::grid::*;
+use self::image::*;
+use self::transform::*;
 use ::std::borrow::Cow;
+use ::std::collections::BTreeMap;
+use ::std::collections::BTreeSet;
+use ::std::collections::HashMap;
 use ::std::collections::HashSet;
 use ::std::fmt::Debug;
 use ::std::marker::PhantomData;
+use ::std::mem::replace;
 use ::std::iter::Iterator;
 
 
+pub mod alignment;
+pub mod animation;
+pub mod background;
+pub mod display;
+pub mod flex;
+pub mod font;
+pub mod grid;
+pub mod image;
+pub mod transform;
+
+
+include!("CommaSeparatedList.rs");
 include!("CssWideKeyword.rs");
+include!("CustomPropertyGraph.rs");
+include!("DirectionTransform.rs");
 include!("DoesNotHaveImportance.rs");
 include!("Importance.rs");
 include!("HasImportance.rs");
+include!("Position.rs");
+include!("PositionComponent.rs");
+include!("PositionKeyword.rs");
 include!("PropertyDeclaration.rs");
 include!("PropertyDeclarations.rs");
+include!("ResolvedCustomProperties.rs");
+include!("RtlControlComments.rs");
 include!("SpecifiedValue.rs");
+include!("TokenSequence.rs");
+include!("TypedPropertyValue.rs");
 include!("UnparsedPropertyValue.rs");
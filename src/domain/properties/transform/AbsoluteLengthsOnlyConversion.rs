@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Used to convert transform functions that have been checked with `TransformFunction::isAbsolute()` to matrices.
+/// As such functions contain no relative lengths, percentages, `calc()`, `attr()` or `var()`, none of these conversions are actually made.
+struct AbsoluteLengthsOnlyConversion;
+
+impl FontRelativeLengthConversion<CssSignedNumber> for AbsoluteLengthsOnlyConversion
+{
+	#[inline(always)]
+	fn em(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+	
+	#[inline(always)]
+	fn ex(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+	
+	#[inline(always)]
+	fn ch(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+	
+	#[inline(always)]
+	fn rem(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+}
+
+impl ViewportPercentageLengthConversion<CssSignedNumber> for AbsoluteLengthsOnlyConversion
+{
+	#[inline(always)]
+	fn vw(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+	
+	#[inline(always)]
+	fn vh(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+	
+	#[inline(always)]
+	fn vmin(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+	
+	#[inline(always)]
+	fn vmax(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+}
+
+impl ReferenceBoxConversion<CssSignedNumber> for AbsoluteLengthsOnlyConversion
+{
+	#[inline(always)]
+	fn reference_box_width(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+	
+	#[inline(always)]
+	fn reference_box_height(&self) -> CssSignedNumber
+	{
+		CssSignedNumber::Zero
+	}
+}
+
+impl<U: Unit> AttributeConversion<U> for AbsoluteLengthsOnlyConversion
+{
+	#[inline(always)]
+	fn attributeValue(&self, _attribute_lower_case_name: &str) -> (Option<&str>, U)
+	{
+		(None, U::default())
+	}
+}
+
+impl CssVariableConversion for AbsoluteLengthsOnlyConversion
+{
+	#[inline(always)]
+	fn cssVariableValue(&self, _css_variable_lower_case_name_without_leading_double_dash: &str) -> Option<&str>
+	{
+		None
+	}
+}
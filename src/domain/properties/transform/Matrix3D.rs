@@ -0,0 +1,152 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A 4×4 transformation matrix, with its values in the (column-major) order of the arguments of `matrix3d()`.
+/// Translations are in pixels.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Matrix3D(pub [f64; 16]);
+
+impl Default for Matrix3D
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::Identity
+	}
+}
+
+impl Matrix3D
+{
+	pub const Identity: Self = Matrix3D
+	([
+		1.0, 0.0, 0.0, 0.0,
+		0.0, 1.0, 0.0, 0.0,
+		0.0, 0.0, 1.0, 0.0,
+		0.0, 0.0, 0.0, 1.0,
+	]);
+	
+	/// The equivalent of `matrix(a, b, c, d, e, f)`.
+	#[inline(always)]
+	pub fn from_2d(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self
+	{
+		Matrix3D
+		([
+			a, b, 0.0, 0.0,
+			c, d, 0.0, 0.0,
+			0.0, 0.0, 1.0, 0.0,
+			e, f, 0.0, 1.0,
+		])
+	}
+	
+	#[inline(always)]
+	pub fn translation(x: f64, y: f64, z: f64) -> Self
+	{
+		let mut matrix = Self::Identity;
+		matrix.0[12] = x;
+		matrix.0[13] = y;
+		matrix.0[14] = z;
+		matrix
+	}
+	
+	#[inline(always)]
+	pub fn scaling(x: f64, y: f64, z: f64) -> Self
+	{
+		let mut matrix = Self::Identity;
+		matrix.0[0] = x;
+		matrix.0[5] = y;
+		matrix.0[10] = z;
+		matrix
+	}
+	
+	/// A clockwise rotation about the vector `(x, y, z)`, as for `rotate3d()`.
+	/// Returns the identity matrix if the vector has no length.
+	pub fn rotation(x: f64, y: f64, z: f64, radians: f64) -> Self
+	{
+		let length = (x * x + y * y + z * z).sqrt();
+		if length == 0.0
+		{
+			return Self::Identity
+		}
+		let (x, y, z) = (x / length, y / length, z / length);
+		
+		// See https://drafts.csswg.org/css-transforms-2/#Rotate3dDefined
+		let halfAngle = radians / 2.0;
+		let sc = halfAngle.sin() * halfAngle.cos();
+		let sq = halfAngle.sin() * halfAngle.sin();
+		
+		Matrix3D
+		([
+			1.0 - 2.0 * (y * y + z * z) * sq, 2.0 * (x * y * sq + z * sc), 2.0 * (x * z * sq - y * sc), 0.0,
+			2.0 * (x * y * sq - z * sc), 1.0 - 2.0 * (x * x + z * z) * sq, 2.0 * (y * z * sq + x * sc), 0.0,
+			2.0 * (x * z * sq + y * sc), 2.0 * (y * z * sq - x * sc), 1.0 - 2.0 * (x * x + y * y) * sq, 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// As for `skew()`.
+	#[inline(always)]
+	pub fn skewing(xRadians: f64, yRadians: f64) -> Self
+	{
+		Self::from_2d(1.0, yRadians.tan(), xRadians.tan(), 1.0, 0.0, 0.0)
+	}
+	
+	/// As for `perspective()`; distances of less than one pixel are treated as one pixel.
+	#[inline(always)]
+	pub fn perspective(distance: f64) -> Self
+	{
+		let mut matrix = Self::Identity;
+		matrix.0[11] = -1.0 / distance.max(1.0);
+		matrix
+	}
+	
+	/// `self × other`, ie the transform `other` followed by `self`, as for the transform functions `self other`.
+	pub fn multiply(&self, other: &Self) -> Self
+	{
+		let mut product = [0.0; 16];
+		for column in 0 .. 4
+		{
+			for row in 0 .. 4
+			{
+				product[column * 4 + row] = (0 .. 4).map(|index| self.0[index * 4 + row] * other.0[column * 4 + index]).sum();
+			}
+		}
+		Matrix3D(product)
+	}
+	
+	/// Whether this matrix can be written as `matrix()`.
+	#[inline(always)]
+	pub fn is2D(&self) -> bool
+	{
+		const Indices: [(usize, f64); 10] = [(2, 0.0), (3, 0.0), (6, 0.0), (7, 0.0), (8, 0.0), (9, 0.0), (10, 1.0), (11, 0.0), (14, 0.0), (15, 1.0)];
+		
+		Indices.iter().all(|&(index, value)| self.0[index] == value)
+	}
+	
+	/// This matrix as `matrix()`, if it is 2D, or `matrix3d()`, with its values rounded to six decimal places.
+	/// Returns `None` if a value is not finite.
+	pub fn to_transform_function(&self) -> Option<TransformFunction>
+	{
+		#[inline(always)]
+		fn number(value: f64) -> Option<CssSignedNumber>
+		{
+			// Adding zero turns `-0` into `0`.
+			CssSignedNumber::new(((value * 1_000_000.0).round() / 1_000_000.0 + 0.0) as f32).ok()
+		}
+		
+		if self.is2D()
+		{
+			let m = &self.0;
+			Some(TransformFunction::matrix([number(m[0])?, number(m[1])?, number(m[4])?, number(m[5])?, number(m[12])?, number(m[13])?]))
+		}
+		else
+		{
+			let mut values = [CssSignedNumber::Zero; 16];
+			for (index, value) in self.0.iter().enumerate()
+			{
+				values[index] = number(*value)?;
+			}
+			Some(TransformFunction::matrix3d(values))
+		}
+	}
+}
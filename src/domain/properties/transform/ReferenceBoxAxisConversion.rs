@@ -0,0 +1,127 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Resolves the lengths and angles of transform functions, with percentages resolved against either the width or the height of the reference box.
+struct ReferenceBoxAxisConversion<'a, Conversion: 'a + TransformConversion>
+{
+	conversion: &'a Conversion,
+	one_hundred_percent: CssSignedNumber,
+}
+
+impl<'a, Conversion: 'a + TransformConversion> FontRelativeLengthConversion<CssSignedNumber> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn em(&self) -> CssSignedNumber
+	{
+		self.conversion.em()
+	}
+	
+	#[inline(always)]
+	fn ex(&self) -> CssSignedNumber
+	{
+		self.conversion.ex()
+	}
+	
+	#[inline(always)]
+	fn ch(&self) -> CssSignedNumber
+	{
+		self.conversion.ch()
+	}
+	
+	#[inline(always)]
+	fn rem(&self) -> CssSignedNumber
+	{
+		self.conversion.rem()
+	}
+}
+
+impl<'a, Conversion: 'a + TransformConversion> ViewportPercentageLengthConversion<CssSignedNumber> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn vw(&self) -> CssSignedNumber
+	{
+		self.conversion.vw()
+	}
+	
+	#[inline(always)]
+	fn vh(&self) -> CssSignedNumber
+	{
+		self.conversion.vh()
+	}
+	
+	#[inline(always)]
+	fn vmin(&self) -> CssSignedNumber
+	{
+		self.conversion.vmin()
+	}
+	
+	#[inline(always)]
+	fn vmax(&self) -> CssSignedNumber
+	{
+		self.conversion.vmax()
+	}
+}
+
+impl<'a, Conversion: 'a + TransformConversion> PercentageConversion<CssSignedNumber> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn one_hundred_percent_in_absolute_units(&self) -> CssSignedNumber
+	{
+		self.one_hundred_percent
+	}
+}
+
+impl<'a, U, Conversion: 'a + TransformConversion + AttributeConversion<U>> AttributeConversion<U> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn attributeValue(&self, attribute_lower_case_name: &str) -> (Option<&str>, U)
+	{
+		self.conversion.attributeValue(attribute_lower_case_name)
+	}
+}
+
+impl<'a, Conversion: 'a + TransformConversion> CssVariableConversion for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn cssVariableValue(&self, css_variable_lower_case_name_without_leading_double_dash: &str) -> Option<&str>
+	{
+		self.conversion.cssVariableValue(css_variable_lower_case_name_without_leading_double_dash)
+	}
+}
+
+impl<'a, Conversion: 'a + TransformConversion> ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn horizontal(conversion: &'a Conversion) -> Self
+	{
+		Self
+		{
+			conversion,
+			one_hundred_percent: conversion.reference_box_width(),
+		}
+	}
+	
+	#[inline(always)]
+	fn vertical(conversion: &'a Conversion) -> Self
+	{
+		Self
+		{
+			conversion,
+			one_hundred_percent: conversion.reference_box_height(),
+		}
+	}
+	
+	/// In pixels.
+	#[inline(always)]
+	fn evaluate<U: Unit<Number=CssSignedNumber>>(&self, value: &CalculablePropertyValue<U>) -> Option<f64> where Conversion: AttributeConversion<U>
+	{
+		value.evaluate(self).map(|value| value.to_f32() as f64)
+	}
+	
+	#[inline(always)]
+	fn radians(&self, angle: &TransformAngle) -> Option<f64>
+	{
+		self.evaluate(angle).map(|degrees| degrees.to_radians())
+	}
+}
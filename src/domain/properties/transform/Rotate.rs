@@ -0,0 +1,147 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `rotate` property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Rotate
+{
+	none,
+	
+	/// The `x`, `y` and `z` of the axis to rotate about, and the angle; the `z` axis is the default.
+	Explicit(CssSignedNumber, CssSignedNumber, CssSignedNumber, TransformAngle),
+}
+
+impl ToCss for Rotate
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Rotate::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			Explicit(x, y, z, ref angle) =>
+			{
+				let zero = CssSignedNumber::Zero;
+				let one = CssSignedNumber::One;
+				
+				if (x, y, z) == (one, zero, zero)
+				{
+					dest.write_str("x ")?;
+				}
+				else if (x, y, z) == (zero, one, zero)
+				{
+					dest.write_str("y ")?;
+				}
+				else if (x, y, z) != (zero, zero, one)
+				{
+					x.to_css(dest)?;
+					dest.write_char(' ')?;
+					y.to_css(dest)?;
+					dest.write_char(' ')?;
+					z.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				angle.to_css(dest)
+			}
+		}
+	}
+}
+
+impl Parse for Rotate
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Rotate::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none)
+		}
+		
+		let angle = input.r#try(|input| AngleUnit::parse_one_outside_calc_function(context, input)).ok();
+		
+		let zero = CssSignedNumber::Zero;
+		let one = CssSignedNumber::One;
+		let axis = input.r#try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+		{
+			if let Ok(ident) = input.r#try(|input| input.expect_ident().cloned())
+			{
+				return match_ignore_ascii_case!
+				{
+					&*ident,
+					
+					"x" => Ok((one, zero, zero)),
+					
+					"y" => Ok((zero, one, zero)),
+					
+					"z" => Ok((zero, zero, one)),
+					
+					_ => Err(input.new_unexpected_token_error(Token::Ident(ident.clone()))),
+				}
+			}
+			
+			let mut number = || -> Result<_, ParseError<'i, CustomParseError<'i>>>
+			{
+				let value = input.expect_number()?;
+				CssSignedNumber::parseNumber(value, None).map_err(|error| input.new_custom_error(error))
+			};
+			Ok((number()?, number()?, number()?))
+		}).unwrap_or((zero, zero, one));
+		
+		let angle = match angle
+		{
+			Some(angle) => angle,
+			None => AngleUnit::parse_one_outside_calc_function(context, input)?,
+		};
+		
+		Ok(Explicit(axis.0, axis.1, axis.2, angle))
+	}
+}
+
+impl SimplifyCalcFunctions for Rotate
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let Rotate::Explicit(_, _, _, ref mut angle) = *self
+		{
+			angle.simplify_calc_functions()
+		}
+	}
+}
+
+impl Rotate
+{
+	/// The equivalent transform function, or `None` for `none`.
+	pub fn to_transform_function(&self) -> Option<TransformFunction>
+	{
+		use self::Rotate::*;
+		
+		match *self
+		{
+			none => None,
+			Explicit(x, y, z, ref angle) => Some(if (x, y, z) == (CssSignedNumber::Zero, CssSignedNumber::Zero, CssSignedNumber::One)
+			{
+				TransformFunction::rotate(angle.clone())
+			}
+			else
+			{
+				TransformFunction::rotate3d(x, y, z, angle.clone())
+			}),
+		}
+	}
+	
+	/// The equivalent matrix, with angles resolved using `conversion`.
+	#[inline(always)]
+	pub fn to_matrix3d<Conversion: TransformConversion>(&self, conversion: &Conversion) -> Option<Matrix3D>
+	{
+		match self.to_transform_function()
+		{
+			None => Some(Matrix3D::Identity),
+			Some(function) => function.to_matrix3d(conversion),
+		}
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `scale` property; percentages are parsed as numbers.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Scale
+{
+	none,
+	
+	/// `x`, `y` and `z`; an omitted `y` is the same as `x`, and an omitted `z` is one.
+	Explicit(CssSignedNumber, CssSignedNumber, CssSignedNumber),
+}
+
+impl ToCss for Scale
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Scale::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			Explicit(x, y, z) =>
+			{
+				x.to_css(dest)?;
+				let hasZ = z != CssSignedNumber::One;
+				if hasZ || y != x
+				{
+					dest.write_char(' ')?;
+					y.to_css(dest)?;
+				}
+				if hasZ
+				{
+					dest.write_char(' ')?;
+					z.to_css(dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Parse for Scale
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Scale::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none)
+		}
+		
+		let x = TransformFunction::numberOrPercentage(input)?;
+		let y = input.r#try(|input| TransformFunction::numberOrPercentage(input)).unwrap_or(x);
+		let z = input.r#try(|input| TransformFunction::numberOrPercentage(input)).unwrap_or(CssSignedNumber::One);
+		Ok(Explicit(x, y, z))
+	}
+}
+
+impl Scale
+{
+	/// The equivalent transform function, or `None` for `none`.
+	pub fn to_transform_function(&self) -> Option<TransformFunction>
+	{
+		use self::Scale::*;
+		
+		match *self
+		{
+			none => None,
+			Explicit(x, y, z) => Some(if z == CssSignedNumber::One
+			{
+				TransformFunction::scale(x, y)
+			}
+			else
+			{
+				TransformFunction::scale3d(x, y, z)
+			}),
+		}
+	}
+	
+	/// The equivalent matrix.
+	#[inline(always)]
+	pub fn to_matrix3d(&self) -> Matrix3D
+	{
+		match *self
+		{
+			Scale::none => Matrix3D::Identity,
+			Scale::Explicit(x, y, z) => Matrix3D::scaling(x.to_f32() as f64, y.to_f32() as f64, z.to_f32() as f64),
+		}
+	}
+}
@@ -0,0 +1,608 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+pub type TransformLength = CalculablePropertyValue<LengthUnit<CssSignedNumber>>;
+
+pub type TransformLengthOrPercentage = CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>;
+
+pub type TransformAngle = CalculablePropertyValue<AngleUnit<CssSignedNumber>>;
+
+/// A transform function, such as `translate()` or `rotate3d()`: https://drafts.csswg.org/css-transforms-2/#transform-functions
+/// Percentages in `scale()` and its relations are parsed as numbers.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TransformFunction
+{
+	matrix([CssSignedNumber; 6]),
+	
+	matrix3d([CssSignedNumber; 16]),
+	
+	/// The second value defaults to zero.
+	translate(TransformLengthOrPercentage, TransformLengthOrPercentage),
+	
+	translate3d(TransformLengthOrPercentage, TransformLengthOrPercentage, TransformLength),
+	
+	translateX(TransformLengthOrPercentage),
+	
+	translateY(TransformLengthOrPercentage),
+	
+	translateZ(TransformLength),
+	
+	/// The second value defaults to the first.
+	scale(CssSignedNumber, CssSignedNumber),
+	
+	scale3d(CssSignedNumber, CssSignedNumber, CssSignedNumber),
+	
+	scaleX(CssSignedNumber),
+	
+	scaleY(CssSignedNumber),
+	
+	scaleZ(CssSignedNumber),
+	
+	rotate(TransformAngle),
+	
+	rotate3d(CssSignedNumber, CssSignedNumber, CssSignedNumber, TransformAngle),
+	
+	rotateX(TransformAngle),
+	
+	rotateY(TransformAngle),
+	
+	rotateZ(TransformAngle),
+	
+	/// The second value defaults to zero.
+	skew(TransformAngle, TransformAngle),
+	
+	skewX(TransformAngle),
+	
+	skewY(TransformAngle),
+	
+	/// `None` is `perspective(none)`.
+	perspective(Option<TransformLength>),
+}
+
+impl ToCss for TransformFunction
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TransformFunction::*;
+		
+		#[inline(always)]
+		fn writeNumbers<W: fmt::Write>(numbers: &[CssSignedNumber], dest: &mut W) -> fmt::Result
+		{
+			let mut iterator = numbers.iter();
+			if let Some(first) = iterator.next()
+			{
+				first.to_css(dest)?;
+				for number in iterator
+				{
+					dest.write_char(',')?;
+					number.to_css(dest)?;
+				}
+			}
+			Ok(())
+		}
+		
+		match *self
+		{
+			matrix(ref values) =>
+			{
+				dest.write_str("matrix(")?;
+				writeNumbers(&values[..], dest)?;
+			}
+			
+			matrix3d(ref values) =>
+			{
+				dest.write_str("matrix3d(")?;
+				writeNumbers(&values[..], dest)?;
+			}
+			
+			translate(ref x, ref y) =>
+			{
+				dest.write_str("translate(")?;
+				serialize_length_or_percentage(x, dest)?;
+				if !Self::isZero(y)
+				{
+					dest.write_char(',')?;
+					serialize_length_or_percentage(y, dest)?;
+				}
+			}
+			
+			translate3d(ref x, ref y, ref z) =>
+			{
+				dest.write_str("translate3d(")?;
+				serialize_length_or_percentage(x, dest)?;
+				dest.write_char(',')?;
+				serialize_length_or_percentage(y, dest)?;
+				dest.write_char(',')?;
+				Self::writeWithUnitlessZero(z, dest)?;
+			}
+			
+			translateX(ref x) =>
+			{
+				dest.write_str("translateX(")?;
+				serialize_length_or_percentage(x, dest)?;
+			}
+			
+			translateY(ref y) =>
+			{
+				dest.write_str("translateY(")?;
+				serialize_length_or_percentage(y, dest)?;
+			}
+			
+			translateZ(ref z) =>
+			{
+				dest.write_str("translateZ(")?;
+				Self::writeWithUnitlessZero(z, dest)?;
+			}
+			
+			scale(x, y) =>
+			{
+				dest.write_str("scale(")?;
+				x.to_css(dest)?;
+				if y != x
+				{
+					dest.write_char(',')?;
+					y.to_css(dest)?;
+				}
+			}
+			
+			scale3d(x, y, z) =>
+			{
+				dest.write_str("scale3d(")?;
+				writeNumbers(&[x, y, z], dest)?;
+			}
+			
+			scaleX(x) =>
+			{
+				dest.write_str("scaleX(")?;
+				x.to_css(dest)?;
+			}
+			
+			scaleY(y) =>
+			{
+				dest.write_str("scaleY(")?;
+				y.to_css(dest)?;
+			}
+			
+			scaleZ(z) =>
+			{
+				dest.write_str("scaleZ(")?;
+				z.to_css(dest)?;
+			}
+			
+			rotate(ref angle) =>
+			{
+				dest.write_str("rotate(")?;
+				Self::writeWithUnitlessZero(angle, dest)?;
+			}
+			
+			rotate3d(x, y, z, ref angle) =>
+			{
+				dest.write_str("rotate3d(")?;
+				writeNumbers(&[x, y, z], dest)?;
+				dest.write_char(',')?;
+				Self::writeWithUnitlessZero(angle, dest)?;
+			}
+			
+			rotateX(ref angle) =>
+			{
+				dest.write_str("rotateX(")?;
+				Self::writeWithUnitlessZero(angle, dest)?;
+			}
+			
+			rotateY(ref angle) =>
+			{
+				dest.write_str("rotateY(")?;
+				Self::writeWithUnitlessZero(angle, dest)?;
+			}
+			
+			rotateZ(ref angle) =>
+			{
+				dest.write_str("rotateZ(")?;
+				Self::writeWithUnitlessZero(angle, dest)?;
+			}
+			
+			skew(ref x, ref y) =>
+			{
+				dest.write_str("skew(")?;
+				Self::writeWithUnitlessZero(x, dest)?;
+				if !Self::isZero(y)
+				{
+					dest.write_char(',')?;
+					Self::writeWithUnitlessZero(y, dest)?;
+				}
+			}
+			
+			skewX(ref angle) =>
+			{
+				dest.write_str("skewX(")?;
+				Self::writeWithUnitlessZero(angle, dest)?;
+			}
+			
+			skewY(ref angle) =>
+			{
+				dest.write_str("skewY(")?;
+				Self::writeWithUnitlessZero(angle, dest)?;
+			}
+			
+			perspective(ref distance) =>
+			{
+				dest.write_str("perspective(")?;
+				match *distance
+				{
+					None => dest.write_str("none")?,
+					Some(ref distance) => Self::writeWithUnitlessZero(distance, dest)?,
+				}
+			}
+		}
+		
+		dest.write_char(')')
+	}
+}
+
+impl Parse for TransformFunction
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TransformFunction::*;
+		
+		let name = input.expect_function()?.clone();
+		input.parse_nested_block(|input|
+		{
+			#[inline(always)]
+			fn lengthOrPercentage<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<TransformLengthOrPercentage, ParseError<'i, CustomParseError<'i>>>
+			{
+				LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)
+			}
+			
+			#[inline(always)]
+			fn length<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<TransformLength, ParseError<'i, CustomParseError<'i>>>
+			{
+				LengthUnit::parse_one_outside_calc_function(context, input)
+			}
+			
+			#[inline(always)]
+			fn angle<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<TransformAngle, ParseError<'i, CustomParseError<'i>>>
+			{
+				AngleUnit::parse_one_outside_calc_function(context, input)
+			}
+			
+			#[inline(always)]
+			fn number<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssSignedNumber, ParseError<'i, CustomParseError<'i>>>
+			{
+				let value = input.expect_number()?;
+				CssSignedNumber::parseNumber(value, None).map_err(|error| input.new_custom_error(error))
+			}
+			
+			#[inline(always)]
+			fn comma<'i, 't, T, ValueParser: for<'a> FnOnce(&mut Parser<'i, 'a>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(input: &mut Parser<'i, 't>, parser: ValueParser) -> Result<T, ParseError<'i, CustomParseError<'i>>>
+			{
+				input.expect_comma()?;
+				parser(input)
+			}
+			
+			#[inline(always)]
+			fn optional<'i, 't, T, ValueParser: for<'a> FnOnce(&mut Parser<'i, 'a>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(input: &mut Parser<'i, 't>, parser: ValueParser) -> Result<Option<T>, ParseError<'i, CustomParseError<'i>>>
+			{
+				if input.is_exhausted()
+				{
+					Ok(None)
+				}
+				else
+				{
+					comma(input, parser).map(Some)
+				}
+			}
+			
+			let function = match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"matrix" =>
+				{
+					let mut values = [CssSignedNumber::Zero; 6];
+					for (index, value) in values.iter_mut().enumerate()
+					{
+						if index != 0
+						{
+							input.expect_comma()?;
+						}
+						*value = number(input)?;
+					}
+					matrix(values)
+				},
+				
+				"matrix3d" =>
+				{
+					let mut values = [CssSignedNumber::Zero; 16];
+					for (index, value) in values.iter_mut().enumerate()
+					{
+						if index != 0
+						{
+							input.expect_comma()?;
+						}
+						*value = number(input)?;
+					}
+					matrix3d(values)
+				},
+				
+				"translate" =>
+				{
+					let x = lengthOrPercentage(context, input)?;
+					let y = optional(input, |input| lengthOrPercentage(context, input))?.unwrap_or_default();
+					translate(x, y)
+				},
+				
+				"translate3d" => translate3d(lengthOrPercentage(context, input)?, comma(input, |input| lengthOrPercentage(context, input))?, comma(input, |input| length(context, input))?),
+				
+				"translatex" => translateX(lengthOrPercentage(context, input)?),
+				
+				"translatey" => translateY(lengthOrPercentage(context, input)?),
+				
+				"translatez" => translateZ(length(context, input)?),
+				
+				"scale" =>
+				{
+					let x = Self::numberOrPercentage(input)?;
+					let y = optional(input, |input| Self::numberOrPercentage(input))?.unwrap_or(x);
+					scale(x, y)
+				},
+				
+				"scale3d" => scale3d(Self::numberOrPercentage(input)?, comma(input, |input| Self::numberOrPercentage(input))?, comma(input, |input| Self::numberOrPercentage(input))?),
+				
+				"scalex" => scaleX(Self::numberOrPercentage(input)?),
+				
+				"scaley" => scaleY(Self::numberOrPercentage(input)?),
+				
+				"scalez" => scaleZ(Self::numberOrPercentage(input)?),
+				
+				"rotate" => rotate(angle(context, input)?),
+				
+				"rotate3d" => rotate3d(number(input)?, comma(input, number)?, comma(input, number)?, comma(input, |input| angle(context, input))?),
+				
+				"rotatex" => rotateX(angle(context, input)?),
+				
+				"rotatey" => rotateY(angle(context, input)?),
+				
+				"rotatez" => rotateZ(angle(context, input)?),
+				
+				"skew" =>
+				{
+					let x = angle(context, input)?;
+					let y = optional(input, |input| angle(context, input))?.unwrap_or_default();
+					skew(x, y)
+				},
+				
+				"skewx" => skewX(angle(context, input)?),
+				
+				"skewy" => skewY(angle(context, input)?),
+				
+				"perspective" => if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+				{
+					perspective(None)
+				}
+				else
+				{
+					perspective(Some(length(context, input)?))
+				},
+				
+				_ => return Err(input.new_custom_error(CustomParseError::UnexpectedTransformFunction(name.clone()))),
+			};
+			
+			input.expect_exhausted()?;
+			Ok(function)
+		})
+	}
+}
+
+impl SimplifyCalcFunctions for TransformFunction
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		use self::TransformFunction::*;
+		
+		match *self
+		{
+			translate(ref mut x, ref mut y) =>
+			{
+				x.simplify_calc_functions();
+				y.simplify_calc_functions()
+			}
+			
+			translate3d(ref mut x, ref mut y, ref mut z) =>
+			{
+				x.simplify_calc_functions();
+				y.simplify_calc_functions();
+				z.simplify_calc_functions()
+			}
+			
+			translateX(ref mut length) | translateY(ref mut length) => length.simplify_calc_functions(),
+			
+			translateZ(ref mut length) => length.simplify_calc_functions(),
+			
+			rotate(ref mut angle) | rotate3d(_, _, _, ref mut angle) | rotateX(ref mut angle) | rotateY(ref mut angle) | rotateZ(ref mut angle) | skewX(ref mut angle) | skewY(ref mut angle) => angle.simplify_calc_functions(),
+			
+			skew(ref mut x, ref mut y) =>
+			{
+				x.simplify_calc_functions();
+				y.simplify_calc_functions()
+			}
+			
+			perspective(ref mut length) => length.simplify_calc_functions(),
+			
+			matrix(_) | matrix3d(_) | scale(..) | scale3d(..) | scaleX(_) | scaleY(_) | scaleZ(_) => (),
+		}
+	}
+}
+
+impl TransformFunction
+{
+	/// The matrix equivalent to this function, with lengths and percentages resolved using `conversion`.
+	/// Returns `None` if a value can not be evaluated, such as a `var()` that is not defined.
+	pub fn to_matrix3d<Conversion: TransformConversion>(&self, conversion: &Conversion) -> Option<Matrix3D>
+	{
+		use self::TransformFunction::*;
+		
+		let horizontal = ReferenceBoxAxisConversion::horizontal(conversion);
+		let vertical = ReferenceBoxAxisConversion::vertical(conversion);
+		
+		let result = match *self
+		{
+			matrix(ref values) =>
+			{
+				let m: Vec<f64> = values.iter().map(|value| value.to_f32() as f64).collect();
+				Matrix3D::from_2d(m[0], m[1], m[2], m[3], m[4], m[5])
+			}
+			
+			matrix3d(ref values) =>
+			{
+				let mut m = [0.0; 16];
+				for (index, value) in values.iter().enumerate()
+				{
+					m[index] = value.to_f32() as f64;
+				}
+				Matrix3D(m)
+			}
+			
+			translate(ref x, ref y) => Matrix3D::translation(horizontal.evaluate(x)?, vertical.evaluate(y)?, 0.0),
+			
+			translate3d(ref x, ref y, ref z) => Matrix3D::translation(horizontal.evaluate(x)?, vertical.evaluate(y)?, horizontal.evaluate(z)?),
+			
+			translateX(ref x) => Matrix3D::translation(horizontal.evaluate(x)?, 0.0, 0.0),
+			
+			translateY(ref y) => Matrix3D::translation(0.0, vertical.evaluate(y)?, 0.0),
+			
+			translateZ(ref z) => Matrix3D::translation(0.0, 0.0, horizontal.evaluate(z)?),
+			
+			scale(x, y) => Matrix3D::scaling(x.to_f32() as f64, y.to_f32() as f64, 1.0),
+			
+			scale3d(x, y, z) => Matrix3D::scaling(x.to_f32() as f64, y.to_f32() as f64, z.to_f32() as f64),
+			
+			scaleX(x) => Matrix3D::scaling(x.to_f32() as f64, 1.0, 1.0),
+			
+			scaleY(y) => Matrix3D::scaling(1.0, y.to_f32() as f64, 1.0),
+			
+			scaleZ(z) => Matrix3D::scaling(1.0, 1.0, z.to_f32() as f64),
+			
+			rotate(ref angle) | rotateZ(ref angle) => Matrix3D::rotation(0.0, 0.0, 1.0, horizontal.radians(angle)?),
+			
+			rotate3d(x, y, z, ref angle) => Matrix3D::rotation(x.to_f32() as f64, y.to_f32() as f64, z.to_f32() as f64, horizontal.radians(angle)?),
+			
+			rotateX(ref angle) => Matrix3D::rotation(1.0, 0.0, 0.0, horizontal.radians(angle)?),
+			
+			rotateY(ref angle) => Matrix3D::rotation(0.0, 1.0, 0.0, horizontal.radians(angle)?),
+			
+			skew(ref x, ref y) => Matrix3D::skewing(horizontal.radians(x)?, horizontal.radians(y)?),
+			
+			skewX(ref angle) => Matrix3D::skewing(horizontal.radians(angle)?, 0.0),
+			
+			skewY(ref angle) => Matrix3D::skewing(0.0, horizontal.radians(angle)?),
+			
+			perspective(None) => Matrix3D::Identity,
+			
+			perspective(Some(ref distance)) => Matrix3D::perspective(horizontal.evaluate(distance)?),
+		};
+		
+		Some(result)
+	}
+	
+	/// Whether this function's lengths are all absolute (such as `px`) or zero, and its angles are all constant, so that it can be converted to a matrix without knowing anything about the element it applies to.
+	pub fn isAbsolute(&self) -> bool
+	{
+		use self::TransformFunction::*;
+		
+		#[inline(always)]
+		fn lengthOrPercentage(value: &TransformLengthOrPercentage) -> bool
+		{
+			use self::CalculablePropertyValue::*;
+			use self::LengthOrPercentageUnit::*;
+			
+			match *value
+			{
+				Constant(IsLength(LengthUnit::Absolute(_))) => true,
+				Constant(ref constant) => constant.is_zero(),
+				Percentage(ref percentage) => percentage.is_zero(),
+				_ => false,
+			}
+		}
+		
+		#[inline(always)]
+		fn length(value: &TransformLength) -> bool
+		{
+			use self::CalculablePropertyValue::*;
+			
+			match *value
+			{
+				Constant(LengthUnit::Absolute(_)) => true,
+				Constant(ref constant) => constant.is_zero(),
+				_ => false,
+			}
+		}
+		
+		#[inline(always)]
+		fn angle(value: &TransformAngle) -> bool
+		{
+			matches!(*value, CalculablePropertyValue::Constant(_))
+		}
+		
+		match *self
+		{
+			matrix(_) | matrix3d(_) | scale(_, _) | scale3d(_, _, _) | scaleX(_) | scaleY(_) | scaleZ(_) | perspective(None) => true,
+			
+			translate(ref x, ref y) => lengthOrPercentage(x) && lengthOrPercentage(y),
+			
+			translate3d(ref x, ref y, ref z) => lengthOrPercentage(x) && lengthOrPercentage(y) && length(z),
+			
+			translateX(ref value) | translateY(ref value) => lengthOrPercentage(value),
+			
+			translateZ(ref value) | perspective(Some(ref value)) => length(value),
+			
+			rotate(ref value) | rotate3d(_, _, _, ref value) | rotateX(ref value) | rotateY(ref value) | rotateZ(ref value) | skewX(ref value) | skewY(ref value) => angle(value),
+			
+			skew(ref x, ref y) => angle(x) && angle(y),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn numberOrPercentage<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssSignedNumber, ParseError<'i, CustomParseError<'i>>>
+	{
+		match *input.next()?
+		{
+			Token::Number { value, int_value, .. } => CssSignedNumber::parseNumber(value, int_value).map_err(|error| input.new_custom_error(error)),
+			
+			Token::Percentage { unit_value, .. } => CssSignedNumber::parseNumber(unit_value, None).map_err(|error| input.new_custom_error(error)),
+			
+			ref unexpectedToken =>
+			{
+				let unexpectedToken = unexpectedToken.clone();
+				Err(input.new_unexpected_token_error(unexpectedToken))
+			}
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn isZero<U: Unit>(value: &CalculablePropertyValue<U>) -> bool
+	{
+		match *value
+		{
+			CalculablePropertyValue::Constant(ref constant) => constant.is_zero(),
+			CalculablePropertyValue::Percentage(ref percentage) => percentage.is_zero(),
+			_ => false,
+		}
+	}
+	
+	/// Zero lengths and angles do not need a unit inside transform functions.
+	#[inline(always)]
+	pub(crate) fn writeWithUnitlessZero<U: Unit, W: fmt::Write>(value: &CalculablePropertyValue<U>, dest: &mut W) -> fmt::Result
+	{
+		if Self::isZero(value)
+		{
+			dest.write_char('0')
+		}
+		else
+		{
+			value.to_css(dest)
+		}
+	}
+}
@@ -0,0 +1,107 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of `transform`: `none`, or a list of transform functions applied in order.
+#[derive(Default, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TransformList(pub Vec<TransformFunction>);
+
+impl ToCss for TransformList
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.0.is_empty()
+		{
+			return dest.write_str("none")
+		}
+		
+		// Functions do not need to be separated by whitespace.
+		for function in self.0.iter()
+		{
+			function.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl Parse for TransformList
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(TransformList(Vec::new()))
+		}
+		
+		let mut functions = vec![TransformFunction::parse(context, input)?];
+		while !input.is_exhausted()
+		{
+			functions.push(TransformFunction::parse(context, input)?);
+		}
+		Ok(TransformList(functions))
+	}
+}
+
+impl SimplifyCalcFunctions for TransformList
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		for function in self.0.iter_mut()
+		{
+			function.simplify_calc_functions()
+		}
+	}
+}
+
+impl TransformList
+{
+	/// `none`.
+	#[inline(always)]
+	pub fn isNone(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// The single matrix equivalent to all of the functions in this list, with lengths and percentages resolved using `conversion`.
+	/// Returns `None` if a value can not be evaluated, such as a `var()` that is not defined.
+	pub fn to_matrix3d<Conversion: TransformConversion>(&self, conversion: &Conversion) -> Option<Matrix3D>
+	{
+		let mut matrix = Matrix3D::Identity;
+		for function in self.0.iter()
+		{
+			matrix = matrix.multiply(&function.to_matrix3d(conversion)?);
+		}
+		Some(matrix)
+	}
+	
+	/// Whether all of the functions in this list can be converted to a matrix without knowing anything about the element they apply to.
+	#[inline(always)]
+	pub fn isAbsolute(&self) -> bool
+	{
+		self.0.iter().all(TransformFunction::isAbsolute)
+	}
+	
+	/// Replaces the functions in this list with an equivalent `matrix()` (or `matrix3d()`) if that is shorter when serialized.
+	/// Lists with relative lengths, percentages or `calc()` are left alone, as are lists that are `none`.
+	pub fn shorten_to_matrix(&mut self)
+	{
+		if self.isNone() || !self.isAbsolute()
+		{
+			return
+		}
+		
+		let matrix = match self.to_matrix3d(&AbsoluteLengthsOnlyConversion).and_then(|matrix| matrix.to_transform_function())
+		{
+			None => return,
+			Some(matrix) => matrix,
+		};
+		
+		let original = self.to_css_string();
+		let replacement = matrix.to_css_string();
+		if replacement.len() < original.len()
+		{
+			self.0 = vec![matrix];
+		}
+	}
+}
@@ -0,0 +1,112 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `translate` property.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Translate
+{
+	none,
+	
+	/// `x`, `y` and `z`; omitted values are zero.
+	Explicit(TransformLengthOrPercentage, TransformLengthOrPercentage, TransformLength),
+}
+
+impl ToCss for Translate
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Translate::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			Explicit(ref x, ref y, ref z) =>
+			{
+				serialize_length_or_percentage(x, dest)?;
+				let hasZ = !TransformFunction::isZero(z);
+				if hasZ || !TransformFunction::isZero(y)
+				{
+					dest.write_char(' ')?;
+					serialize_length_or_percentage(y, dest)?;
+				}
+				if hasZ
+				{
+					dest.write_char(' ')?;
+					z.to_css(dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Parse for Translate
+{
+	fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Translate::*;
+		
+		if input.r#try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none)
+		}
+		
+		let x = LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+		let y = match input.r#try(|input| LengthOrPercentageUnit::parse_one_outside_calc_function(context, input))
+		{
+			Err(_) => return Ok(Explicit(x, Default::default(), Default::default())),
+			Ok(y) => y,
+		};
+		let z = input.r#try(|input| LengthUnit::parse_one_outside_calc_function(context, input)).unwrap_or_default();
+		Ok(Explicit(x, y, z))
+	}
+}
+
+impl SimplifyCalcFunctions for Translate
+{
+	#[inline(always)]
+	fn simplify_calc_functions(&mut self)
+	{
+		if let Translate::Explicit(ref mut x, ref mut y, ref mut z) = *self
+		{
+			x.simplify_calc_functions();
+			y.simplify_calc_functions();
+			z.simplify_calc_functions()
+		}
+	}
+}
+
+impl Translate
+{
+	/// The equivalent transform function, or `None` for `none`.
+	pub fn to_transform_function(&self) -> Option<TransformFunction>
+	{
+		use self::Translate::*;
+		
+		match *self
+		{
+			none => None,
+			Explicit(ref x, ref y, ref z) => Some(if TransformFunction::isZero(z)
+			{
+				TransformFunction::translate(x.clone(), y.clone())
+			}
+			else
+			{
+				TransformFunction::translate3d(x.clone(), y.clone(), z.clone())
+			}),
+		}
+	}
+	
+	/// The equivalent matrix, with lengths and percentages resolved using `conversion`.
+	#[inline(always)]
+	pub fn to_matrix3d<Conversion: TransformConversion>(&self, conversion: &Conversion) -> Option<Matrix3D>
+	{
+		match self.to_transform_function()
+		{
+			None => Some(Matrix3D::Identity),
+			Some(function) => function.to_matrix3d(conversion),
+		}
+	}
+}
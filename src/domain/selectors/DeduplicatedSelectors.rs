@@ -12,7 +12,7 @@ impl ToCss for DeduplicatedSelectors
 	{
 		let mut iter = self.0.iter();
 		dest.write_str(&iter.next().unwrap().to_css_string())?;
-		for selector in iter.next()
+		for selector in iter
 		{
 			dest.write_char(',')?;
 			dest.write_str(&selector.to_css_string())?;
@@ -20,3 +20,16 @@ impl ToCss for DeduplicatedSelectors
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn every_selector_in_a_list_is_serialized()
+	{
+		let stylesheet = Stylesheet::parse("a,b,c.d,e{color:red}").unwrap();
+		assert_eq!(stylesheet.to_css_string(false), "a,b,c.d,e{color:red}");
+	}
+}
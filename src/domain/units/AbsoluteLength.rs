@@ -290,6 +290,22 @@ impl<Number: CssNumber> CssNumberNewType<Number> for AbsoluteLength<Number>
 
 impl<Number: CssNumber> AbsoluteLength<Number>
 {
+	/// This length, in the same unit, but of `number`.
+	#[inline(always)]
+	pub(crate) fn with_number(self, number: Number) -> Self
+	{
+		match self
+		{
+			px(_) => px(number),
+			in_(_) => in_(number),
+			cm(_) => cm(number),
+			mm(_) => mm(number),
+			q(_) => q(number),
+			pt(_) => pt(number),
+			pc(_) => pc(number),
+		}
+	}
+	
 	/// Convert this into a pixel value.
 	#[inline]
 	pub fn to_px(&self) -> Number
@@ -0,0 +1,62 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A flexible length, a fraction of the leftover space in a grid container: https://drafts.csswg.org/css-grid/#fr-unit
+/// Flexible lengths are not lengths, and can not be used in `calc()`, so this is not a `Unit`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FlexUnit<Number: CssNumber>(pub Number);
+
+impl<Number: CssNumber> ToCss for FlexUnit<Number>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		serialize_dimension(self.0, "fr", dest)
+	}
+}
+
+impl<Number: CssNumber> Default for FlexUnit<Number>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FlexUnit(Number::default())
+	}
+}
+
+impl<Number: CssNumber> CssNumberNewType<Number> for FlexUnit<Number>
+{
+	#[inline(always)]
+	fn to_f32(&self) -> f32
+	{
+		self.0.to_f32()
+	}
+	
+	#[inline(always)]
+	fn as_CssNumber(&self) -> &Number
+	{
+		&self.0
+	}
+}
+
+impl<Number: CssNumber> FlexUnit<Number>
+{
+	/// Parses a dimension with the unit `fr`.
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		match *input.next()?
+		{
+			Token::Dimension { value, ref unit, .. } if unit.eq_ignore_ascii_case("fr") =>
+			{
+				let number = Number::new(value).map_err(|cssNumberConversionError| CouldNotParseCssUnsignedNumber(cssNumberConversionError, value));
+				number.map(FlexUnit).map_err(|error| input.new_custom_error(error))
+			}
+			
+			ref unexpectedToken =>
+			{
+				let unexpectedToken = unexpectedToken.clone();
+				Err(input.new_unexpected_token_error(unexpectedToken))
+			}
+		}
+	}
+}
@@ -242,6 +242,19 @@ impl<Number: CssNumber> CssNumberNewType<Number> for FontRelativeLength<Number>
 
 impl<Number: CssNumber> FontRelativeLength<Number>
 {
+	/// This length, in the same unit, but of `number`.
+	#[inline(always)]
+	pub(crate) fn with_number(self, number: Number) -> Self
+	{
+		match self
+		{
+			em(_) => em(number),
+			ex(_) => ex(number),
+			ch(_) => ch(number),
+			rem(_) => rem(number),
+		}
+	}
+	
 	/// Convert this into a pixel value.
 	#[inline(always)]
 	fn to_px<V>(&self, fontRelativeLengthConversion: &V) -> Number
@@ -273,6 +273,18 @@ impl<NumberX: CssNumber> Unit for LengthOrPercentageUnit<NumberX>
 		functionParser.parse_one_inside_calc_function(context, input)
 	}
 	
+	#[inline(always)]
+	fn to_canonical_dimension(self) -> Self
+	{
+		use self::LengthOrPercentageUnit::*;
+		
+		match self
+		{
+			IsLength(length) => IsLength(length.to_canonical_dimension()),
+			unchanged @ IsPercentage(_) => unchanged,
+		}
+	}
+	
 	#[inline(always)]
 	fn to_canonical_dimension_value<Conversion: FontRelativeLengthConversion<Self::Number> + ViewportPercentageLengthConversion<Self::Number> + PercentageConversion<Self::Number>>(&self, conversion: &Conversion) -> Self::Number
 	{
@@ -285,6 +297,24 @@ impl<NumberX: CssNumber> Unit for LengthOrPercentageUnit<NumberX>
 		}
 	}
 	
+	#[inline(always)]
+	fn is_percentage(&self) -> bool
+	{
+		matches!(*self, LengthOrPercentageUnit::IsPercentage(_))
+	}
+	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		use self::LengthOrPercentageUnit::*;
+		
+		match self
+		{
+			IsLength(length) => IsLength(length.with_number(number)),
+			IsPercentage(_) => IsPercentage(PercentageUnit(number)),
+		}
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, is_not_in_page_rule: bool) -> Option<Self>
 	{
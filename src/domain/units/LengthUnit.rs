@@ -301,6 +301,17 @@ impl<NumberX: CssNumber> Unit for LengthUnit<NumberX>
 		self.to_px(conversion)
 	}
 	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		match self
+		{
+			Absolute(length) => Absolute(length.with_number(number)),
+			FontRelative(length) => FontRelative(length.with_number(number)),
+			ViewportPercentage(length) => ViewportPercentage(length.with_number(number)),
+		}
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, is_not_in_page_rule: bool) -> Option<Self>
 	{
@@ -281,6 +281,40 @@ impl<NumberX: CssNumber> Unit for NumberOrPercentageUnit<NumberX>
 		}
 	}
 	
+	#[inline(always)]
+	fn is_valid_calc_function_result(_is_number: bool) -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn is_number(&self) -> bool
+	{
+		match *self
+		{
+			NumberOrPercentageUnit::IsNumber(_) => true,
+			NumberOrPercentageUnit::IsPercentage(_) => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn is_percentage(&self) -> bool
+	{
+		matches!(*self, NumberOrPercentageUnit::IsPercentage(_))
+	}
+	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		use self::NumberOrPercentageUnit::*;
+		
+		match self
+		{
+			IsNumber(_) => IsNumber(number),
+			IsPercentage(_) => IsPercentage(PercentageUnit(number)),
+		}
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
 	{
@@ -212,6 +212,18 @@ impl<NumberX: CssNumber> Unit for PercentageUnit<NumberX>
 		self.to_absolute_value(conversion)
 	}
 	
+	#[inline(always)]
+	fn is_percentage(&self) -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		PercentageUnit(number)
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
 	{
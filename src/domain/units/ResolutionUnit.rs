@@ -289,8 +289,8 @@ impl<NumberX: CssNumber> Unit for ResolutionUnit<NumberX>
 	{
 		match self
 		{
-			dpi(value) => dpi(value / NumberX::_construct(96.0)),
-			dpcm(value) => dppx(value / NumberX::_construct(96.0 * 2.54)),
+			dpi(value) => dppx(value / NumberX::_construct(96.0)),
+			dpcm(value) => dppx(value / NumberX::_construct(96.0 / 2.54)),
 			canonical @ _ => canonical,
 		}
 	}
@@ -301,11 +301,22 @@ impl<NumberX: CssNumber> Unit for ResolutionUnit<NumberX>
 		match *self
 		{
 			dpi(value) => value / NumberX::_construct(96.0),
-			dpcm(value) => value / NumberX::_construct(96.0 * 2.54),
+			dpcm(value) => value / NumberX::_construct(96.0 / 2.54),
 			dppx(value) => value,
 		}
 	}
 	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		match self
+		{
+			dpi(_) => dpi(number),
+			dppx(_) => dppx(number),
+			dpcm(_) => dpcm(number),
+		}
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
 	{
@@ -352,7 +363,7 @@ impl<NumberX: CssNumber> Unit for ResolutionUnit<NumberX>
 impl<Number: CssNumber> ResolutionUnit<Number>
 {
 	#[inline(always)]
-	fn parseDimension<'i>(value: f32, unit: &CowRcStr<'i>) -> Result<Self, CustomParseError<'i>>
+	pub(crate) fn parseDimension<'i>(value: f32, unit: &CowRcStr<'i>) -> Result<Self, CustomParseError<'i>>
 	{
 		let cssNumber = <ResolutionUnit<Number> as Unit>::Number::new(value).map_err(|cssNumberConversionError| CouldNotParseCssSignedNumber(cssNumberConversionError, value))?;
 		
@@ -364,7 +375,9 @@ impl<Number: CssNumber> ResolutionUnit<Number>
 			
 			"dppx" => Ok(dppx(cssNumber)),
 			
-			"dpcm" => Ok(dppx(cssNumber)),
+			"x" => Ok(dppx(cssNumber)),
+			
+			"dpcm" => Ok(dpcm(cssNumber)),
 			
 			_ => Err(CouldNotParseDimension(value, unit.clone())),
 		}
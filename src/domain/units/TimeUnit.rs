@@ -274,7 +274,7 @@ impl<NumberX: CssNumber> Unit for TimeUnit<NumberX>
 		match self
 		{
 			s(seconds) => s(seconds),
-			ms(milliseconds) => ms(milliseconds / NumberX::_construct(1000_f32)),
+			ms(milliseconds) => s(milliseconds / NumberX::_construct(1000_f32)),
 		}
 	}
 	
@@ -288,6 +288,16 @@ impl<NumberX: CssNumber> Unit for TimeUnit<NumberX>
 		}
 	}
 	
+	#[inline(always)]
+	fn with_number(self, number: Self::Number) -> Self
+	{
+		match self
+		{
+			s(_) => s(number),
+			ms(_) => ms(number),
+		}
+	}
+	
 	#[inline(always)]
 	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
 	{
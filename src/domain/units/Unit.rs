@@ -2,7 +2,7 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-pub trait Unit: Sized + ToCss + Default + CssNumberNewType<<Self as Unit>::Number>
+pub trait Unit: Sized + Copy + Eq + ToCss + Default + CssNumberNewType<<Self as Unit>::Number>
 {
 	type Number: CssNumber;
 	
@@ -18,6 +18,30 @@ pub trait Unit: Sized + ToCss + Default + CssNumberNewType<<Self as Unit>::Numbe
 		self
 	}
 	
+	/// Whether this is a number without a unit, such as `2` in `calc(2 * 1px)`; used to simplify `calc()` expressions.
+	#[inline(always)]
+	fn is_number(&self) -> bool
+	{
+		!Self::HasDimension
+	}
+	
+	/// Whether this is a percentage, such as `50%`; used to simplify `calc()` expressions.
+	#[inline(always)]
+	fn is_percentage(&self) -> bool
+	{
+		false
+	}
+	
+	/// Whether the result of a `calc()` of this unit can be a number (such as `calc(2 * 3)`), if `is_number`, or a dimension or percentage (such as `calc(2 * 3px)`).
+	#[inline(always)]
+	fn is_valid_calc_function_result(is_number: bool) -> bool
+	{
+		is_number != Self::HasDimension
+	}
+	
+	/// This value, in the same unit, but of `number`, such as `2px` for `1px` and `2`; used to simplify `calc()` expressions.
+	fn with_number(self, number: Self::Number) -> Self;
+	
 	fn to_canonical_dimension_value<Conversion: FontRelativeLengthConversion<Self::Number> + ViewportPercentageLengthConversion<Self::Number> + PercentageConversion<Self::Number>>(&self, conversion: &Conversion) -> Self::Number;
 
 	fn from_raw_css_for_var_expression_evaluation(value: &str, is_not_in_page_rule: bool) -> Option<Self>;
@@ -29,3 +53,23 @@ pub trait Unit: Sized + ToCss + Default + CssNumberNewType<<Self as Unit>::Numbe
 		Ok(Right(CalcExpression::Number(constant)))
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn number(value: f32) -> CssSignedNumber
+	{
+		CssSignedNumber::new(value).unwrap()
+	}
+	
+	#[test]
+	fn to_canonical_dimension_converts_to_the_canonical_unit()
+	{
+		assert_eq!(TimeUnit::ms(number(500.0)).to_canonical_dimension(), TimeUnit::s(number(0.5)));
+		assert_eq!(ResolutionUnit::dpi(number(192.0)).to_canonical_dimension(), ResolutionUnit::dppx(number(2.0)));
+		assert_eq!(ResolutionUnit::dpcm(number(96.0)).to_canonical_dimension(), ResolutionUnit::dppx(number(2.54)));
+		assert_eq!(LengthOrPercentageUnit::IsLength(LengthUnit::Absolute(AbsoluteLength::in_(number(1.0)))).to_canonical_dimension(), LengthOrPercentageUnit::IsLength(LengthUnit::Absolute(AbsoluteLength::px(number(96.0)))));
+	}
+}
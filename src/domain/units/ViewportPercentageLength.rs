@@ -246,6 +246,19 @@ impl<Number: CssNumber> CssNumberNewType<Number> for ViewportPercentageLength<Nu
 
 impl<Number: CssNumber> ViewportPercentageLength<Number>
 {
+	/// This length, in the same unit, but of `number`.
+	#[inline(always)]
+	pub(crate) fn with_number(self, number: Number) -> Self
+	{
+		match self
+		{
+			vw(_) => vw(number),
+			vh(_) => vh(number),
+			vmin(_) => vmin(number),
+			vmax(_) => vmax(number),
+		}
+	}
+	
 	/// Convert this into a pixel value.
 	#[inline(always)]
 	fn to_px<V>(&self, viewportPercentageLengthConversion: &V) -> Number
@@ -88,6 +88,22 @@ impl<U: Unit> PercentageConversion<U::Number> for SimplisticExampleOfConversion<
 	}
 }
 
+/// In this simplistic example, the reference box is a square whose sides are `one_hundred_percent_in_absolute_units`.
+impl<U: Unit> ReferenceBoxConversion<U::Number> for SimplisticExampleOfConversion<U>
+{
+	#[inline(always)]
+	fn reference_box_width(&self) -> U::Number
+	{
+		self.one_hundred_percent_in_absolute_units
+	}
+	
+	#[inline(always)]
+	fn reference_box_height(&self) -> U::Number
+	{
+		self.one_hundred_percent_in_absolute_units
+	}
+}
+
 impl<U: Unit> CssVariableConversion for SimplisticExampleOfConversion<U>
 {
 	#[inline(always)]
@@ -101,17 +117,17 @@ impl<U: Unit> CssVariableConversion for SimplisticExampleOfConversion<U>
 	}
 }
 
-impl<U: Unit> AttributeConversion<U> for SimplisticExampleOfConversion<U>
+impl<U: Unit, AttributeUnit: Unit> AttributeConversion<AttributeUnit> for SimplisticExampleOfConversion<U>
 {
 	/// Returns the (value of the attribute, property default)
 	/// In this simplistic example, since we don't know th property name, we assume all property defaults are the specification default: https://drafts.csswg.org/css-values-3/#typedef-type-or-unit
 	#[inline(always)]
-	fn attributeValue(&self, attribute_lower_case_name: &str) -> (Option<&str>, U)
+	fn attributeValue(&self, attribute_lower_case_name: &str) -> (Option<&str>, AttributeUnit)
 	{
 		match self.attributesWithLowerCaseNames.get(attribute_lower_case_name)
 		{
-			Some(value) => (Some(&value[..]), U::default()),
-			None => (None, U::default()),
+			Some(value) => (Some(&value[..]), AttributeUnit::default()),
+			None => (None, AttributeUnit::default()),
 		}
 	}
 }
@@ -0,0 +1,13 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Everything needed to evaluate the lengths, percentages and angles of a transform, such as by `TransformList::to_matrix3d()`.
+/// Implemented for any type that implements all of the traits it extends.
+pub trait TransformConversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + ReferenceBoxConversion<CssSignedNumber> + AttributeConversion<LengthUnit<CssSignedNumber>> + AttributeConversion<LengthOrPercentageUnit<CssSignedNumber>> + AttributeConversion<AngleUnit<CssSignedNumber>> + CssVariableConversion
+{
+}
+
+impl<Conversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + ReferenceBoxConversion<CssSignedNumber> + AttributeConversion<LengthUnit<CssSignedNumber>> + AttributeConversion<LengthOrPercentageUnit<CssSignedNumber>> + AttributeConversion<AngleUnit<CssSignedNumber>> + CssVariableConversion> TransformConversion for Conversion
+{
+}
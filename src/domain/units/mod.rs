@@ -4,6 +4,7 @@
 
 use super::*;
 use self::AbsoluteLength::*;
+use self::AngleUnit::*;
 use self::LengthUnit::*;
 use self::either::Left;
 use self::either::Right;
@@ -34,7 +35,9 @@ pub mod conversions;
 
 
 include!("AbsoluteLength.rs");
+include!("AngleUnit.rs");
 include!("AppUnitsPer.rs");
+include!("FlexUnit.rs");
 include!("FontRelativeLength.rs");
 include!("LengthOrPercentageUnit.rs");
 include!("LengthUnit.rs");
@@ -25,7 +25,7 @@
 //! extern crate css;
 //! use ::css::Stylesheet;
 //!
-//! let some_css = "margin-left: 10pt; top: 20px;".to_owned();
+//! let some_css = "p { margin-left:10pt; top:20px; }".to_owned();
 //! let stylesheet = Stylesheet::parse(&some_css).expect("CSS was invalid");
 //!
 //! // Alternatively, load from a file using Stylesheet::from_file_path("/path/to/stylesheet.css").unwrap();
@@ -37,13 +37,32 @@
 //!
 //! stylesheet.to_css(&mut destination, include_source_urls).expect("Failed to write to destination");
 //!
-//! assert_eq!(&destination, "margin-left:10pt;top:20px");
+//! assert_eq!(&destination, "p{margin-left:10pt;top:20px}");
 //!
 //! // To serialize to a Vec<u8> of bytes instead
-//! let mut bytes = stylesheet.to_bytes();
+//! let mut bytes = stylesheet.to_bytes(include_source_urls);
 //!
 //! // To serialize to a file instead
-//! stylesheet.to_file_path("/path/to/to/stylesheet.css").unwrap();
+//! stylesheet.to_file_path(::std::env::temp_dir().join("stylesheet.css"), include_source_urls).unwrap();
+//! ```
+//!
+//!
+//! ### To parse a `style` attribute, media query list or supports condition
+//!
+//! ```
+//! extern crate css;
+//! use ::css::domain::atRules::media::MediaList;
+//! use ::css::domain::atRules::supports::SupportsCondition;
+//! use ::css::domain::properties::Importance;
+//! use ::css::domain::properties::PropertyDeclarations;
+//!
+//! // As in browsers, invalid property declarations are skipped; each one is returned as an error.
+//! let (property_declarations, errors) = PropertyDeclarations::<Importance>::parse("color: red; margin: 0 !important");
+//! assert!(errors.is_empty());
+//!
+//! let media_list = MediaList::parse("screen and (min-width: 100px), print").unwrap();
+//!
+//! let supports_condition = SupportsCondition::parse("(display: grid) and (not (display: inline-grid))").unwrap();
 //! ```
 //!
 //!
@@ -51,9 +70,9 @@
 //!
 //! ```
 //! extern crate css;
-//! use ::css::parse_selector;
+//! use ::css::parse_css_selector;
 //!
-//! let selector = parse_selector("P.myclass").unwrap();
+//! let selector = parse_css_selector("P.myclass").unwrap();
 //! ```
 //!
 //!
@@ -77,25 +96,53 @@ extern crate quick_error;
 pub extern crate smallvec;
 
 use self::domain::atRules::counterStyle::System;
+use self::domain::atRules::fontFace::Source;
+use self::domain::atRules::import::ImportAtRule;
+use self::domain::atRules::import::ImportLayer;
+use self::domain::atRules::layer::LayerBlockAtRule;
+use self::domain::atRules::media::MediaAtRule;
 use self::domain::atRules::namespace::Namespaces;
+use self::domain::atRules::supports::SupportsAtRule;
+use self::domain::properties::DirectionTransform;
+use self::domain::properties::HasImportance;
+use self::domain::properties::PropertyDeclarations;
+use self::domain::properties::RtlControlComments;
+use self::domain::properties::UnparsedPropertyValue;
 use self::domain::selectors::*;
 use self::domain::*;
+use self::lossless::*;
 use self::parsers::*;
 use self::serializers::*;
+use self::sourceMaps::*;
 use ::cssparser::*;
 use crate::quick_error::ResultExt;
 use crate::selectors::parser::SelectorParseError;
+use ::std::cell::RefCell;
+use ::std::cmp::max;
+use ::std::cmp::min;
 use ::std::fmt;
 use ::std::fs::File;
 use ::std::io::Read;
+use ::std::num::NonZeroUsize;
+use ::std::ops::Range;
+use ::std::panic::resume_unwind;
 use ::std::mem::uninitialized;
 use ::std::path::Path;
 use ::std::path::PathBuf;
+use ::std::rc::Rc;
+use ::std::thread::available_parallelism;
+use ::std::thread::scope;
 
 /// Contains definitions of objects used in Stylesheet.
 pub mod domain;
+/// Lossless serialization, writing out unmodified rules and property declarations exactly as they were parsed.
+pub mod lossless;
 pub(crate) mod parsers;
+/// Pretty-printing (formatting) of stylesheets.
+pub mod prettyPrinting;
 pub(crate) mod serializers;
+/// Source maps (version 3), for mapping serialized CSS back to the original.
+pub mod sourceMaps;
 
 // This module is forked from the servo repository 'https://github.com/servo/servo' component selectors crate (components/selectors) at revision 4f984a6428a0f497e311a0800efa55166c15aac6
 // To be removed once selectors 0.19.0 lands in crates.io
@@ -115,7 +162,18 @@ extern crate stable_deref_trait;
 pub mod servo_arc;
 
 include!("BlockingIoOnlyStdFmtWriteToStdIoWriteAdaptor.rs");
+include!("BundleError.rs");
+include!("CssError.rs");
 include!("CustomParseError.rs");
+include!("Diagnostic.rs");
+include!("FileSystemStylesheetLoader.rs");
+include!("Lint.rs");
+include!("LintKind.rs");
+include!("MinifyOptions.rs");
 include!("parse_css_selector.rs");
+include!("ParseOptions.rs");
+include!("relative_urls.rs");
 include!("Stylesheet.rs");
+include!("StylesheetBundler.rs");
 include!("StylesheetError.rs");
+include!("StylesheetLoader.rs");
@@ -0,0 +1,174 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Serializes rules and property declarations, writing those that have not been modified since parsing exactly as they were in the original CSS, including the whitespace and comments before them.
+/// Modified (and new) rules and property declarations are serialized as for `ToCss`.
+pub(crate) struct LosslessWriter<'a, W: 'a + fmt::Write>
+{
+	destination: &'a mut W,
+	original_css: &'a OriginalCss,
+}
+
+impl<'a, W: 'a + fmt::Write> LosslessWriter<'a, W>
+{
+	#[inline(always)]
+	pub(crate) fn new(destination: &'a mut W, original_css: &'a OriginalCss) -> Self
+	{
+		Self
+		{
+			destination,
+			original_css,
+		}
+	}
+	
+	/// Writes the rules of a stylesheet, followed by any whitespace and comments after the last original rule.
+	#[inline(always)]
+	pub(crate) fn write_stylesheet(&mut self, rules: &CssRules) -> fmt::Result
+	{
+		let original_css = self.original_css;
+		
+		let trailing_trivia = original_css.stylesheet_trailing_trivia_start().map(|trailing_trivia_start| (trailing_trivia_start, original_css.css().len()));
+		self.write_rules(rules, trailing_trivia)
+	}
+	
+	fn write_rules(&mut self, rules: &CssRules, trailing_trivia: Option<(usize, usize)>) -> fmt::Result
+	{
+		for rule in rules.0.iter()
+		{
+			self.write_rule(rule)?;
+		}
+		
+		self.write_trailing_trivia(trailing_trivia)
+	}
+	
+	fn write_rule(&mut self, rule: &CssRule) -> fmt::Result
+	{
+		use self::CssRule::*;
+		
+		let original_css = self.original_css;
+		
+		let (start, node) = match original_css.node(rule.source_range())
+		{
+			None => return rule.to_css(self.destination),
+			Some(node) => node,
+		};
+		
+		self.destination.write_str(original_css.text(node.trivia_start, start))?;
+		
+		let block = if OriginalCss::has_block_of_rules_or_property_declarations(rule)
+		{
+			original_css.block(start, &node)
+		}
+		else
+		{
+			None
+		};
+		
+		let (contents_start, trailing_trivia_start) = match block
+		{
+			Some(block) => block,
+			
+			None => return if OriginalCss::rule_fingerprint(rule) == node.fingerprint
+			{
+				self.destination.write_str(original_css.text(start, node.end))
+			}
+			else
+			{
+				rule.to_css(self.destination)
+			},
+		};
+		
+		if OriginalCss::rule_fingerprint(rule) == node.fingerprint
+		{
+			self.destination.write_str(original_css.text(start, contents_start))?;
+		}
+		else
+		{
+			OriginalCss::prelude_to_css(rule, self.destination).unwrap()?;
+			self.destination.write_char('{')?;
+		}
+		
+		let trailing_trivia = Some((trailing_trivia_start, node.end));
+		match *rule
+		{
+			Document(ref rule) => self.write_rules(&rule.rules, trailing_trivia),
+			
+			LayerBlock(ref rule) => self.write_rules(&rule.rules, trailing_trivia),
+			
+			Media(ref rule) => self.write_rules(&rule.rules, trailing_trivia),
+			
+			Style(ref rule) => self.write_property_declarations(&rule.property_declarations, trailing_trivia),
+			
+			Supports(ref rule) => self.write_rules(&rule.rules, trailing_trivia),
+			
+			_ => unreachable!("Only rules with a prelude have a block"),
+		}
+	}
+	
+	fn write_property_declarations<I: HasImportance>(&mut self, property_declarations: &PropertyDeclarations<I>, trailing_trivia: Option<(usize, usize)>) -> fmt::Result
+	{
+		let original_css = self.original_css;
+		
+		// The last property declaration in a block need not have a trailing semicolon, and then its original text includes any whitespace before the `}`.
+		// If another property declaration follows, the semicolon is written before that whitespace.
+		let mut needsSemicolon = false;
+		let mut pendingWhitespace = "";
+		for property_declaration in property_declarations.0.iter()
+		{
+			if needsSemicolon
+			{
+				self.destination.write_char(';')?;
+			}
+			self.destination.write_str(pendingWhitespace)?;
+			pendingWhitespace = "";
+			
+			needsSemicolon = match original_css.node(property_declaration.source_range)
+			{
+				None =>
+				{
+					property_declaration.to_css_without_trailing_semicolon(self.destination)?;
+					true
+				}
+				
+				Some((start, node)) =>
+				{
+					self.destination.write_str(original_css.text(node.trivia_start, start))?;
+					
+					let original = original_css.text(start, node.end);
+					let hadTrailingSemicolon = original.ends_with(';');
+					if OriginalCss::property_declaration_fingerprint(property_declaration) == node.fingerprint
+					{
+						let withoutWhitespace = original.trim_end();
+						self.destination.write_str(withoutWhitespace)?;
+						pendingWhitespace = &original[withoutWhitespace.len() .. ];
+					}
+					else
+					{
+						property_declaration.to_css_without_trailing_semicolon(self.destination)?;
+						if hadTrailingSemicolon
+						{
+							self.destination.write_char(';')?;
+						}
+					}
+					!hadTrailingSemicolon
+				}
+			};
+		}
+		self.destination.write_str(pendingWhitespace)?;
+		
+		self.write_trailing_trivia(trailing_trivia)
+	}
+	
+	/// Writes the whitespace and comments after the last original rule or property declaration in a block, and the block's closing `}`.
+	#[inline(always)]
+	fn write_trailing_trivia(&mut self, trailing_trivia: Option<(usize, usize)>) -> fmt::Result
+	{
+		if let Some((trailing_trivia_start, end)) = trailing_trivia
+		{
+			self.destination.write_str(self.original_css.text(trailing_trivia_start, end))?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,201 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// The original CSS of a stylesheet parsed with `ParseOptions::lossless`, and where each of its rules and property declarations was in it.
+///
+/// Used by `Stylesheet::to_css_lossless()` to write out rules and property declarations that have not been modified exactly as they were parsed, including the comments and whitespace before them.
+#[derive(Default, Debug, Clone)]
+pub struct OriginalCss
+{
+	css: String,
+	
+	/// Keyed by the byte offset of the start of the rule or property declaration.
+	nodes: HashMap<usize, OriginalCssNode>,
+	
+	/// Byte offsets of the start of the whitespace and comments after the last rule or property declaration of a block, keyed by the byte offset of the start of the block's contents (zero for the stylesheet itself).
+	blocks: BTreeMap<usize, usize>,
+}
+
+impl OriginalCss
+{
+	/// The original CSS.
+	#[inline(always)]
+	pub fn css(&self) -> &str
+	{
+		&self.css
+	}
+	
+	#[inline(always)]
+	pub(crate) fn record_node(&mut self, trivia_start: usize, start: usize, end: usize)
+	{
+		self.nodes.insert(start, OriginalCssNode
+		{
+			trivia_start,
+			end,
+			fingerprint: 0,
+		});
+	}
+	
+	#[inline(always)]
+	pub(crate) fn record_block(&mut self, contents_start: usize, trailing_trivia_start: usize)
+	{
+		self.blocks.insert(contents_start, trailing_trivia_start);
+	}
+	
+	/// Called once parsing is complete to keep `css` and fingerprint the parsed `rules`.
+	pub(crate) fn finish(&mut self, css: &str, rules: &CssRules)
+	{
+		self.css = css.to_owned();
+		self.fingerprint_rules(rules);
+	}
+	
+	/// The recorded node for a rule or property declaration, if it has one and has a source range that matches.
+	#[inline(always)]
+	pub(crate) fn node(&self, source_range: Option<SourceRange>) -> Option<(usize, OriginalCssNode)>
+	{
+		source_range.and_then(|source_range|
+		{
+			let start = source_range.start.byte_offset;
+			match self.nodes.get(&start)
+			{
+				Some(node) if node.end == source_range.end.byte_offset => Some((start, *node)),
+				_ => None,
+			}
+		})
+	}
+	
+	/// For a rule with a block, the byte offsets of the start of the block's contents and of the whitespace and comments after its last rule or property declaration.
+	#[inline(always)]
+	pub(crate) fn block(&self, start: usize, node: &OriginalCssNode) -> Option<(usize, usize)>
+	{
+		self.blocks.range((start + 1) .. node.end).next().map(|(&contents_start, &trailing_trivia_start)| (contents_start, trailing_trivia_start))
+	}
+	
+	/// The byte offset of the start of the whitespace and comments after the stylesheet's last rule.
+	#[inline(always)]
+	pub(crate) fn stylesheet_trailing_trivia_start(&self) -> Option<usize>
+	{
+		const StylesheetContentsStart: usize = 0;
+		
+		self.blocks.get(&StylesheetContentsStart).cloned()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn text(&self, from: usize, to: usize) -> &str
+	{
+		&self.css[from .. to]
+	}
+	
+	/// Fingerprints a rule; for a rule with a block of rules or property declarations, only the rule's prelude is fingerprinted, as the contents are fingerprinted individually.
+	pub(crate) fn rule_fingerprint(rule: &CssRule) -> u64
+	{
+		Self::fingerprint(|dest|
+		{
+			match Self::prelude_to_css(rule, dest)
+			{
+				None => rule.to_css(dest),
+				Some(result) => result,
+			}
+		})
+	}
+	
+	/// Document, layer block, media, style and supports rules have a block of rules or property declarations, which are written out individually.
+	#[inline(always)]
+	pub(crate) fn has_block_of_rules_or_property_declarations(rule: &CssRule) -> bool
+	{
+		use self::CssRule::*;
+		
+		matches!(*rule, Document(_) | LayerBlock(_) | Media(_) | Style(_) | Supports(_))
+	}
+	
+	/// For a rule with a block of rules or property declarations, serializes everything before the block's `{`; otherwise returns `None`.
+	pub(crate) fn prelude_to_css<W: fmt::Write>(rule: &CssRule, dest: &mut W) -> Option<fmt::Result>
+	{
+		use self::CssRule::*;
+		
+		let result = match *rule
+		{
+			Document(ref rule) =>
+			{
+				dest.write_str("@").and_then(|_|
+				{
+					if let Some(ref vendor_prefix) = rule.vendor_prefix
+					{
+						vendor_prefix.to_css(dest)?;
+					}
+					dest.write_str("document ")?;
+					rule.condition.to_css(dest)
+				})
+			}
+			
+			LayerBlock(ref rule) =>
+			{
+				dest.write_str("@layer").and_then(|_|
+				{
+					if let Some(ref name) = rule.name
+					{
+						dest.write_char(' ')?;
+						name.to_css(dest)?;
+					}
+					Ok(())
+				})
+			}
+			
+			Media(ref rule) => dest.write_str("@media ").and_then(|_| rule.media_queries.to_css(dest)),
+			
+			Style(ref rule) => rule.selectors.to_css(dest),
+			
+			Supports(ref rule) => dest.write_str("@supports ").and_then(|_| rule.condition.to_css(dest)),
+			
+			_ => return None,
+		};
+		
+		Some(result)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn property_declaration_fingerprint<I: HasImportance>(property_declaration: &PropertyDeclaration<I>) -> u64
+	{
+		Self::fingerprint(|dest| property_declaration.to_css_without_trailing_semicolon(dest))
+	}
+	
+	#[inline(always)]
+	fn fingerprint<F: FnOnce(&mut String) -> fmt::Result>(to_css: F) -> u64
+	{
+		let mut css = String::new();
+		to_css(&mut css).expect("Writing to a String can not fail");
+		let mut hasher = DefaultHasher::new();
+		hasher.write(css.as_bytes());
+		hasher.finish()
+	}
+	
+	fn fingerprint_rules(&mut self, rules: &CssRules)
+	{
+		use self::CssRule::*;
+		
+		for rule in rules.0.iter()
+		{
+			if let Some((start, _)) = self.node(rule.source_range())
+			{
+				self.nodes.get_mut(&start).unwrap().fingerprint = Self::rule_fingerprint(rule);
+			}
+			
+			match *rule
+			{
+				Document(DocumentAtRule { ref rules, .. }) | LayerBlock(LayerBlockAtRule { ref rules, .. }) | Media(MediaAtRule { ref rules, .. }) | Supports(SupportsAtRule { ref rules, .. }) => self.fingerprint_rules(rules),
+				
+				Style(ref rule) => for property_declaration in rule.property_declarations.0.iter()
+				{
+					if let Some((start, _)) = self.node(property_declaration.source_range)
+					{
+						self.nodes.get_mut(&start).unwrap().fingerprint = Self::property_declaration_fingerprint(property_declaration);
+					}
+				},
+				
+				_ => (),
+			}
+		}
+	}
+}
@@ -0,0 +1,19 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Where a rule or property declaration was in the original CSS.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct OriginalCssNode
+{
+	/// Byte offset of the whitespace and comments before the rule or property declaration.
+	pub(crate) trivia_start: usize,
+	
+	/// Byte offset just after the rule or property declaration.
+	pub(crate) end: usize,
+	
+	/// A hash of the rule or property declaration's serialization when parsed; for a rule with a block of rules or property declarations, just that of the rule's prelude.
+	/// If this differs when serializing, the rule or property declaration has been modified.
+	pub(crate) fingerprint: u64,
+}
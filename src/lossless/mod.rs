@@ -0,0 +1,20 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+use super::*;
+use super::domain::atRules::document::DocumentAtRule;
+use super::domain::atRules::layer::LayerBlockAtRule;
+use super::domain::atRules::media::MediaAtRule;
+use super::domain::atRules::supports::SupportsAtRule;
+use super::domain::properties::*;
+use ::std::collections::BTreeMap;
+use ::std::collections::HashMap;
+use ::std::collections::hash_map::DefaultHasher;
+use ::std::hash::Hasher;
+
+
+include!("LosslessWriter.rs");
+include!("OriginalCss.rs");
+include!("OriginalCssNode.rs");
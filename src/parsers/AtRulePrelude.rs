@@ -26,11 +26,14 @@ pub enum AtRuleBlockPrelude
 	/// A @keyframes rule, with its animation name and vendor prefix if exists.
 	Keyframes(Option<VendorPrefix>, KeyframesName),
 	
+	/// A @layer rule prelude, with its layer names.
+	Layer(Vec<LayerName>),
+	
 	/// A @media rule prelude, with its media queries.
 	Media(MediaList),
 	
-	/// A @page rule prelude.
-	Page(Option<PageSelectorPseudoClass>),
+	/// A @page rule prelude, with its page selectors.
+	Page(Vec<PageSelector>),
 	
 	/// An @supports rule, with its conditional
 	Supports(SupportsCondition),
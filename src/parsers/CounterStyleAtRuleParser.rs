@@ -3,14 +3,14 @@
 
 
 // NOTE: impl<'a, 'b, 'i> DeclarationParser<'i> for CounterStyleRuleParser<'a, 'b> is in the macro definition 'counter_style_descriptors' in 'CounterStyleAtRule.rs'
-pub(crate) struct CounterStyleAtRuleParser<'a>
+pub(crate) struct CounterStyleAtRuleParser<'a, 'i>
 {
-	pub(crate) context: &'a ParserContext,
+	pub(crate) context: &'a ParserContext<'i>,
 	pub(crate) rule: &'a mut CounterStyleAtRule,
 }
 
 /// Default methods reject all at rules.
-impl<'a, 'i> AtRuleParser<'i> for CounterStyleAtRuleParser<'a>
+impl<'a, 'i> AtRuleParser<'i> for CounterStyleAtRuleParser<'a, 'i>
 {
 	type Prelude = ();
 	
@@ -19,7 +19,7 @@ impl<'a, 'i> AtRuleParser<'i> for CounterStyleAtRuleParser<'a>
 	type Error = CustomParseError<'i>;
 }
 
-impl<'a, 'i> DeclarationParser<'i> for CounterStyleAtRuleParser<'a>
+impl<'a, 'i> DeclarationParser<'i> for CounterStyleAtRuleParser<'a, 'i>
 {
 	type Declaration = ();
 	
@@ -2,14 +2,14 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-pub(crate) struct FontFaceAtRuleParser<'a>
+pub(crate) struct FontFaceAtRuleParser<'a, 'i>
 {
-	pub(crate) context: &'a ParserContext,
+	pub(crate) context: &'a ParserContext<'i>,
 	pub(crate) rule: &'a mut FontFaceAtRule,
 }
 
 /// Default methods reject all at rules.
-impl<'a, 'i> AtRuleParser<'i> for FontFaceAtRuleParser<'a>
+impl<'a, 'i> AtRuleParser<'i> for FontFaceAtRuleParser<'a, 'i>
 {
 	type Prelude = ();
 	
@@ -18,7 +18,7 @@ impl<'a, 'i> AtRuleParser<'i> for FontFaceAtRuleParser<'a>
 	type Error = CustomParseError<'i>;
 }
 
-impl<'a, 'i> DeclarationParser<'i> for FontFaceAtRuleParser<'a>
+impl<'a, 'i> DeclarationParser<'i> for FontFaceAtRuleParser<'a, 'i>
 {
 	type Declaration = ();
 	
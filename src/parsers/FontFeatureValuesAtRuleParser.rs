@@ -8,14 +8,14 @@
 /// }
 /// <feature-type> = @stylistic | @historical-forms | @styleset |
 /// @character-variant | @swash | @ornaments | @annotation
-pub(crate) struct FontFeatureValuesAtRuleParser<'a>
+pub(crate) struct FontFeatureValuesAtRuleParser<'a, 'i>
 {
-	pub(crate) context: &'a ParserContext,
+	pub(crate) context: &'a ParserContext<'i>,
 	pub(crate) rule: &'a mut FontFeatureValuesAtRule,
 }
 
 /// Default methods reject all qualified rules.
-impl<'a, 'i> QualifiedRuleParser<'i> for FontFeatureValuesAtRuleParser<'a>
+impl<'a, 'i> QualifiedRuleParser<'i> for FontFeatureValuesAtRuleParser<'a, 'i>
 {
 	type Prelude = ();
 	
@@ -24,7 +24,7 @@ impl<'a, 'i> QualifiedRuleParser<'i> for FontFeatureValuesAtRuleParser<'a>
 	type Error = CustomParseError<'i>;
 }
 
-impl<'a, 'i> AtRuleParser<'i> for FontFeatureValuesAtRuleParser<'a>
+impl<'a, 'i> AtRuleParser<'i> for FontFeatureValuesAtRuleParser<'a, 'i>
 {
 	type Prelude = FontFeatureValuesBlockType;
 	
@@ -4,14 +4,14 @@
 
 /// @font-feature-values inside block parser. Parses a list of `FontFeatureValuesDeclaration`.
 /// (`<ident>: <integer>+`)
-struct FontFeatureValuesDeclarationsParser<'a, T: 'a + Parse + ToCss>
+struct FontFeatureValuesDeclarationsParser<'a, 'i, T: 'a + Parse + ToCss>
 {
-	context: &'a ParserContext,
+	context: &'a ParserContext<'i>,
 	declarations: &'a mut Vec<FontFeatureValuesDeclaration<T>>,
 }
 
 /// Default methods reject all at rules.
-impl<'a, 'i, T: 'a + Parse + ToCss> AtRuleParser<'i> for FontFeatureValuesDeclarationsParser<'a, T>
+impl<'a, 'i, T: 'a + Parse + ToCss> AtRuleParser<'i> for FontFeatureValuesDeclarationsParser<'a, 'i, T>
 {
 	type Prelude = ();
 	
@@ -20,7 +20,7 @@ impl<'a, 'i, T: 'a + Parse + ToCss> AtRuleParser<'i> for FontFeatureValuesDeclar
 	type Error = CustomParseError<'i>;
 }
 
-impl<'a, 'i, T: 'a + Parse + ToCss> DeclarationParser<'i> for FontFeatureValuesDeclarationsParser<'a, T>
+impl<'a, 'i, T: 'a + Parse + ToCss> DeclarationParser<'i> for FontFeatureValuesDeclarationsParser<'a, 'i, T>
 {
 	type Declaration = ();
 	
@@ -40,9 +40,9 @@ impl<'a, 'i, T: 'a + Parse + ToCss> DeclarationParser<'i> for FontFeatureValuesD
 	}
 }
 
-impl<'a, 'b: 'a, T: 'a + Parse + ToCss> FontFeatureValuesDeclarationsParser<'a, T>
+impl<'a, 'i, T: 'a + Parse + ToCss> FontFeatureValuesDeclarationsParser<'a, 'i, T>
 {
-	pub(crate) fn parseBlock<'i, 't>(input: &mut Parser<'i, 't>, context: &'a ParserContext, declarations: &'a mut Vec<FontFeatureValuesDeclaration<T>>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parseBlock<'t>(input: &mut Parser<'i, 't>, context: &'a ParserContext<'i>, declarations: &'a mut Vec<FontFeatureValuesDeclaration<T>>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
 	{
 		let parser = Self
 		{
@@ -53,7 +53,7 @@ impl<'a, 'b: 'a, T: 'a + Parse + ToCss> FontFeatureValuesDeclarationsParser<'a,
 		let mut iter = DeclarationListParser::new(input, parser);
 		while let Some(possiblePreciseParseError) = iter.next()
 		{
-			possiblePreciseParseError.map_err(|(error, _)| error)?;
+			context.recover(possiblePreciseParseError)?;
 		}
 		
 		Ok(())
@@ -10,13 +10,13 @@
 /// 40%, 60%, 100% {
 ///     width: 100%;
 /// }
-pub(crate) struct KeyframeListParser<'a>
+pub(crate) struct KeyframeListParser<'a, 'i>
 {
-	context: &'a ParserContext,
+	context: &'a ParserContext<'i>,
 }
 
 // Default methods reject all @ rules.
-impl<'a, 'i> AtRuleParser<'i> for KeyframeListParser<'a>
+impl<'a, 'i> AtRuleParser<'i> for KeyframeListParser<'a, 'i>
 {
 	type Prelude = ();
 	
@@ -25,7 +25,7 @@ impl<'a, 'i> AtRuleParser<'i> for KeyframeListParser<'a>
 	type Error = CustomParseError<'i>;
 }
 
-impl<'a, 'i> QualifiedRuleParser<'i> for KeyframeListParser<'a>
+impl<'a, 'i> QualifiedRuleParser<'i> for KeyframeListParser<'a, 'i>
 {
 	type Prelude = KeyframeSelectorParserPrelude;
 	
@@ -64,10 +64,10 @@ impl<'a, 'i> QualifiedRuleParser<'i> for KeyframeListParser<'a>
 	}
 }
 
-impl<'a> KeyframeListParser<'a>
+impl<'a, 'i> KeyframeListParser<'a, 'i>
 {
 	/// Parses a keyframe list from CSS input.
-	pub(crate) fn parse_keyframe_list<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Vec<Keyframe>, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_keyframe_list<'t>(context: &'a ParserContext<'i>, input: &mut Parser<'i, 't>) -> Result<Vec<Keyframe>, ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut iter = RuleListParser::new_for_nested_rule(input, KeyframeListParser
 		{
@@ -76,13 +76,17 @@ impl<'a> KeyframeListParser<'a>
 		
 		let mut keyframes = Vec::new();
 		
-		while let Some(keyframe) = iter.next()
+		loop
 		{
-			match keyframe
+			let before = iter.input.position();
+			let keyframe = match iter.next()
 			{
-				Ok(keyframe) => keyframes.push(keyframe),
-				
-				Err((preciseParseError, _)) => return Err(preciseParseError),
+				None => break,
+				Some(keyframe) => keyframe,
+			};
+			if let Some(keyframe) = context.recover_rule(iter.input, before, keyframe)?
+			{
+				keyframes.push(keyframe);
 			}
 		}
 		
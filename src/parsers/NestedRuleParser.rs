@@ -3,13 +3,13 @@
 
 
 #[derive(Clone)]
-pub(crate) struct NestedRuleParser<'a>
+pub(crate) struct NestedRuleParser<'a, 'i>
 {
-	context: &'a ParserContext,
+	context: &'a ParserContext<'i>,
 	namespaces: Rc<Namespaces>,
 }
 
-impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
+impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'i>
 {
 	type Prelude = AtRuleBlockPrelude;
 	
@@ -42,11 +42,13 @@ impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
 			
 			"-moz-keyframes" => Ok(Keyframes(Some(moz), KeyframesName::parse(input)?)),
 			
+			"layer" => Ok(Layer(LayerName::parse_layer_name_list(input)?)),
+			
 			"media" => Ok(Media(MediaList::parse_media_query_list(self.context, input, false)?)),
 			
-			"page" => Ok(Page(PageSelectorPseudoClass::parse(input)?)),
+			"page" => Ok(Page(PageSelector::parse_page_selector_list(input)?)),
 			
-			"supports" => Ok(Supports(SupportsCondition::parse(input)?)),
+			"supports" => Ok(Supports(SupportsCondition::parse_condition(&self.namespaces, input)?)),
 			
 			"viewport" => Ok(Viewport(None)),
 			
@@ -58,6 +60,23 @@ impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
 		}
 	}
 	
+	fn rule_without_block(&mut self, prelude: Self::Prelude, _: &ParserState) -> Result<Self::AtRule, ()>
+	{
+		match prelude
+		{
+			AtRuleBlockPrelude::Layer(names) => if names.is_empty()
+			{
+				Err(())
+			}
+			else
+			{
+				Ok(CssRule::LayerStatement(LayerStatementAtRule { names, source_range: None }))
+			},
+			
+			_ => Err(()),
+		}
+	}
+	
 	fn parse_block<'t>(&mut self, prelude: Self::Prelude, _: &ParserState, input: &mut Parser<'i, 't>) -> Result<Self::AtRule, ParseError<'i, Self::Error>>
 	{
 		use self::AtRuleBlockPrelude::*;
@@ -75,6 +94,7 @@ impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
 				vendor_prefix,
 				condition,
 				rules: self.parse_nested_rules(input, CssRuleType::Document)?,
+				source_range: None,
 			}),
 			
 			FontFace => CssRule::FontFace(FontFaceAtRule::parse_body(&CssRuleType::FontFace.context(self), input)?),
@@ -86,24 +106,38 @@ impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
 				vendor_prefix,
 				name,
 				keyframes: KeyframeListParser::parse_keyframe_list(&CssRuleType::Keyframes.context(self), input)?,
+				source_range: None,
 			}),
 			
+			Layer(mut names) =>
+			{
+				if names.len() > 1
+				{
+					return Err(input.new_custom_error(CustomParseError::AtRuleLayerBlockCanNotHaveMoreThanOneName));
+				}
+				
+				CssRule::LayerBlock(LayerBlockAtRule
+				{
+					name: names.pop(),
+					rules: self.parse_nested_rules(input, CssRuleType::LayerBlock)?,
+					source_range: None,
+				})
+			}
+			
 			Media(media_queries) => CssRule::Media(MediaAtRule
 			{
 				media_queries,
 				rules: self.parse_nested_rules(input, CssRuleType::Media)?,
+				source_range: None,
 			}),
 			
-			Page(page_selector_pseudo_class) => CssRule::Page(PageAtRule
-			{
-				page_selector_pseudo_class,
-				property_declarations: PropertyDeclarations::parse_property_declaration_list(&CssRuleType::Page.context(self), input)?,
-			}),
+			Page(page_selectors) => CssRule::Page(PageAtRule::parse_body(&CssRuleType::Page.context(self), input, page_selectors)?),
 			
 			Supports(condition) => CssRule::Supports(SupportsAtRule
 			{
 				condition,
 				rules: self.parse_nested_rules(input, CssRuleType::Supports)?,
+				source_range: None,
 			}),
 			
 			Viewport(vendor_prefix) => CssRule::Viewport(ViewportAtRule::parse_body(vendor_prefix, &CssRuleType::Viewport.context(self), input)?),
@@ -113,7 +147,7 @@ impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
 	}
 }
 
-impl<'a, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a>
+impl<'a, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a, 'i>
 {
 	type Prelude = QualifiedRuleParserPrelude;
 	
@@ -123,22 +157,23 @@ impl<'a, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a>
 	
 	fn parse_prelude<'t>(&mut self, input: &mut Parser<'i, 't>) -> Result<Self::Prelude, ParseError<'i, Self::Error>>
 	{
-		let applyVendorPrefixToPseudoClasses = HashMap::default();
-		let applyVendorPrefixToPseudoElements = HashMap::default();
-		let ourSelectorParser = OurSelectorParser
+		let start = if self.context.source_ranges
 		{
-			namespaces: self.namespaces.clone(),
-			applyVendorPrefixToPseudoClasses: &applyVendorPrefixToPseudoClasses,
-			applyVendorPrefixToPseudoElements: &applyVendorPrefixToPseudoElements,
+			Some(input.state())
+		}
+		else
+		{
+			None
 		};
 		
-		let selectors = ourSelectorParser.parse(input)?;
+		let selectors = OurSelectorParser::with_stylesheet_selector_parser(&self.namespaces, |ourSelectorParser| ourSelectorParser.parse(input))?;
 		
 		Ok
 		(
 			QualifiedRuleParserPrelude
 			{
 				selectors,
+				selectors_source_range: ParserContext::source_range(start, input),
 			}
 		)
 	}
@@ -151,21 +186,23 @@ impl<'a, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a>
 		{
 			selectors: prelude.selectors,
 			property_declarations: PropertyDeclarations::parse_property_declaration_list(&context, input)?,
+			selectors_source_range: prelude.selectors_source_range,
+			source_range: None,
 		};
 		
 		Ok(CssRule::Style(styleRule))
 	}
 }
 
-impl<'a> NestedRuleParser<'a>
+impl<'a, 'i> NestedRuleParser<'a, 'i>
 {
 	#[inline(always)]
-	pub(crate) fn context_new_with_rule_type(&self, cssRuleType: CssRuleType) -> ParserContext
+	pub(crate) fn context_new_with_rule_type(&self, cssRuleType: CssRuleType) -> ParserContext<'i>
 	{
 		ParserContext::new_with_rule_type(self.context, cssRuleType)
 	}
 	
-	fn parse_nested_rules<'i: 't, 't>(&mut self, input: &mut Parser<'i, 't>, rule_type: CssRuleType) -> Result<CssRules, ParseError<'i, CustomParseError<'i>>>
+	fn parse_nested_rules<'t>(&mut self, input: &mut Parser<'i, 't>, rule_type: CssRuleType) -> Result<CssRules, ParseError<'i, CustomParseError<'i>>>
 	{
 		let context = rule_type.context(self);
 		
@@ -177,14 +214,32 @@ impl<'a> NestedRuleParser<'a>
 		
 		let mut iter = RuleListParser::new_for_nested_rule(input, nested_parser);
 		let mut rules = Vec::new();
-		while let Some(result) = iter.next()
+		let contents_start = iter.input.position();
+		let mut trailing_trivia_start = contents_start;
+		loop
 		{
-			match result
+			let trivia_start = iter.input.position();
+			if let Some(comment) = context.parse_important_comment(iter.input, trivia_start)
+			{
+				rules.push(comment);
+				trailing_trivia_start = iter.input.position();
+				continue;
+			}
+			let start = context.source_range_start(iter.input, |_| false);
+			let result = match iter.next()
+			{
+				None => break,
+				Some(result) => result,
+			};
+			if let Some(mut rule) = context.recover_rule(iter.input, trivia_start, result)?
 			{
-				Ok(rule) => rules.push(rule),
-				Err((preciseParseError, _)) => return Err(preciseParseError),
+				*rule.source_range_mut() = ParserContext::source_range(start.clone(), iter.input);
+				context.record_original_css_node(trivia_start, &start, iter.input);
+				rules.push(rule);
 			}
+			trailing_trivia_start = iter.input.position();
 		}
+		context.record_original_css_block(contents_start, trailing_trivia_start);
 		Ok(CssRules(rules))
 	}
 }
@@ -61,6 +61,21 @@ impl<'a> OurSelectorParser<'a>
 		self.parse_internal(input, |_| false)
 	}
 	
+	/// Calls `parse` with the selector parser used for a stylesheet's style rules, so that anything else parsing selectors in a stylesheet, such as `selector()` in `@supports`, parses them identically.
+	#[inline(always)]
+	pub(crate) fn with_stylesheet_selector_parser<R, F: FnOnce(&OurSelectorParser) -> R>(namespaces: &Rc<Namespaces>, parse: F) -> R
+	{
+		let applyVendorPrefixToPseudoClasses = HashMap::default();
+		let applyVendorPrefixToPseudoElements = HashMap::default();
+		let ourSelectorParser = OurSelectorParser
+		{
+			namespaces: namespaces.clone(),
+			applyVendorPrefixToPseudoClasses: &applyVendorPrefixToPseudoClasses,
+			applyVendorPrefixToPseudoElements: &applyVendorPrefixToPseudoElements,
+		};
+		parse(&ourSelectorParser)
+	}
+	
 	#[inline(always)]
 	pub(crate) fn parse_internal<'i, 't, F: Fn(&OurSelector) -> bool>(&self, input: &mut Parser<'i, 't>, isInvalidSelector: F) -> Result<DeduplicatedSelectors, ParseError<'i, CustomParseError<'i>>>
 	{
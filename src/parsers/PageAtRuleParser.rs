@@ -0,0 +1,63 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+pub(crate) struct PageAtRuleParser<'a, 'i>
+{
+	pub(crate) context: &'a ParserContext<'i>,
+	pub(crate) rule: &'a mut PageAtRule,
+}
+
+/// Page-margin at-rules, such as `@top-left`.
+impl<'a, 'i> AtRuleParser<'i> for PageAtRuleParser<'a, 'i>
+{
+	type Prelude = PageMarginBox;
+	
+	type AtRule = ();
+	
+	type Error = CustomParseError<'i>;
+	
+	fn parse_prelude<'t>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<Self::Prelude, ParseError<'i, Self::Error>>
+	{
+		let margin_box = PageMarginBox::from_ident(&name).map_err(|name| input.new_custom_error(CustomParseError::UnsupportedPageMarginAtRule(name)))?;
+		input.expect_exhausted()?;
+		Ok(margin_box)
+	}
+	
+	fn parse_block<'t>(&mut self, prelude: Self::Prelude, _: &ParserState, input: &mut Parser<'i, 't>) -> Result<Self::AtRule, ParseError<'i, Self::Error>>
+	{
+		let context = ParserContext::new_with_rule_type(self.context, CssRuleType::Margin);
+		
+		self.rule.margin_rules.push
+		(
+			PageMarginAtRule
+			{
+				margin_box: prelude,
+				property_declarations: PropertyDeclarations::parse_property_declaration_list(&context, input)?,
+			}
+		);
+		
+		Ok(())
+	}
+}
+
+impl<'a, 'i> DeclarationParser<'i> for PageAtRuleParser<'a, 'i>
+{
+	type Declaration = ();
+	
+	type Error = CustomParseError<'i>;
+	
+	fn parse_value<'t>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<Self::Declaration, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut propertyDeclarationParser = PropertyDeclarationParser
+		{
+			context: self.context,
+			marker: PhantomData,
+		};
+		
+		let propertyDeclaration = propertyDeclarationParser.parse_value(name, input)?;
+		self.rule.property_declarations.0.push(propertyDeclaration);
+		
+		Ok(())
+	}
+}
@@ -0,0 +1,72 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The result of parsing a run of a stylesheet's top-level rules, as used by `Stylesheet::parse_parallel()`.
+/// Unlike the parser, it can be sent between threads.
+pub(crate) struct ParsedRunOfTopLevelRules
+{
+	pub(crate) rules: Vec<CssRule>,
+	
+	pub(crate) source_map_url: Option<String>,
+	
+	pub(crate) source_url: Option<String>,
+	
+	/// The state of the parser after the last rule.
+	pub(crate) state: State,
+	
+	/// The namespaces known after the last rule.
+	pub(crate) namespaces: Namespaces,
+}
+
+impl ParsedRunOfTopLevelRules
+{
+	/// Parses the rules in `range` of `css`, which starts on the (zero-based) `line` after `column_offset` UTF-16 code units, with the parser in `state` and knowing `namespaces`.
+	/// Any error is reported at its location in `css`.
+	pub(crate) fn parse(css: &str, range: Range<usize>, line: u32, column_offset: u32, state: State, namespaces: &Namespaces) -> Result<Self, CssError>
+	{
+		let mut parserInput = ParserInput::new_with_line_number_offset(&css[range], line);
+		let mut input = Parser::new(&mut parserInput);
+		
+		let topLevelRuleParser = TopLevelRuleParser
+		{
+			context: ParserContext
+			{
+				rule_type: None,
+				parsing_mode: ParsingMode::Default,
+				diagnostics: None,
+				source_ranges: false,
+				original_css: None,
+				strip_important_comments: false,
+			},
+			state,
+			namespaces: Rc::new(namespaces.clone()),
+		};
+		
+		let mut rules = Vec::new();
+		let topLevelRuleParser = match topLevelRuleParser.parse_rules(&mut input, &mut rules)
+		{
+			Ok(topLevelRuleParser) => topLevelRuleParser,
+			Err(mut error) =>
+			{
+				if error.location.line == line
+				{
+					error.location.column += column_offset;
+				}
+				return Err(CssError::new(error, css))
+			}
+		};
+		
+		Ok
+		(
+			Self
+			{
+				rules,
+				source_map_url: input.current_source_map_url().map(String::from),
+				source_url: input.current_source_url().map(String::from),
+				state: topLevelRuleParser.state,
+				namespaces: Rc::try_unwrap(topLevelRuleParser.namespaces).unwrap_or_else(|namespaces| (*namespaces).clone()),
+			}
+		)
+	}
+}
@@ -3,31 +3,63 @@
 
 
 /// The data that the parser needs from outside in order to parse a stylesheet.
-pub struct ParserContext
+pub struct ParserContext<'i>
 {
 	/// The current rule type, if any.
 	pub(crate) rule_type: Option<CssRuleType>,
 	
 	/// The mode to use when parsing.
 	pub(crate) parsing_mode: ParsingMode,
+	
+	/// If present, rules and declarations that fail to parse are skipped and recorded here, rather than failing the parse.
+	pub(crate) diagnostics: Option<Rc<RefCell<Vec<Diagnostic<'i>>>>>,
+	
+	/// Whether to record the source range of rules, selectors and property declarations.
+	pub(crate) source_ranges: bool,
+	
+	/// If present, where rules and declarations (and the whitespace and comments before them) were in the original CSS is recorded here.
+	pub(crate) original_css: Option<Rc<RefCell<OriginalCss>>>,
+	
+	/// Whether to discard important comments (`/*! ... */`) in lists of rules.
+	pub(crate) strip_important_comments: bool,
 }
 
-impl ParserContext
+impl<'i> ParserContext<'i>
 {
+	/// Creates a parser context for parsing something other than a whole stylesheet, such as a `style` attribute or a media query list.
+	#[inline(always)]
+	pub(crate) fn new(rule_type: CssRuleType) -> ParserContext<'i>
+	{
+		Self
+		{
+			rule_type: Some(rule_type),
+			parsing_mode: ParsingMode::Default,
+			diagnostics: None,
+			source_ranges: false,
+			original_css: None,
+			strip_important_comments: false,
+		}
+	}
+	
 	// Creates a parser context based on a previous context, but with a modified rule type.
-	pub(crate) fn new_with_rule_type(context: &ParserContext, rule_type: CssRuleType) -> ParserContext
+	pub(crate) fn new_with_rule_type(context: &ParserContext<'i>, rule_type: CssRuleType) -> ParserContext<'i>
 	{
 		Self
 		{
 			rule_type: Some(rule_type),
 			parsing_mode: context.parsing_mode,
+			diagnostics: context.diagnostics.clone(),
+			source_ranges: context.source_ranges,
+			original_css: context.original_css.clone(),
+			strip_important_comments: context.strip_important_comments,
 		}
 	}
 	
+	/// Page-margin rules are within a page rule.
 	#[inline(always)]
 	pub(crate) fn isInPageRule(&self) -> bool
 	{
-		self.rule_type.map_or(false, |rule_type| rule_type == CssRuleType::Page)
+		matches!(self.rule_type, Some(CssRuleType::Page) | Some(CssRuleType::Margin))
 	}
 	
 	#[inline(always)]
@@ -40,4 +72,172 @@ impl ParserContext
 	{
 		self.parsing_mode.allows_unitless_lengths()
 	}
+	
+	/// Handles the result of parsing a rule or declaration in a list.
+	///
+	/// When recovering from errors, a rule or declaration that failed to parse is recorded as a diagnostic and skipped, as per https://drafts.csswg.org/css-syntax-3/#error-handling; otherwise its error fails the parse.
+	#[inline(always)]
+	pub(crate) fn recover<T>(&self, result: Result<T, (ParseError<'i, CustomParseError<'i>>, &'i str)>) -> Result<Option<T>, ParseError<'i, CustomParseError<'i>>>
+	{
+		match result
+		{
+			Ok(value) => Ok(Some(value)),
+			
+			Err((error, span)) => match self.diagnostics
+			{
+				None => Err(error),
+				
+				Some(ref diagnostics) =>
+				{
+					diagnostics.borrow_mut().push(Diagnostic::new(error, span));
+					Ok(None)
+				}
+			}
+		}
+	}
+	
+	/// As `recover()`, but for a rule in a list of rules, which started at or after `before`.
+	///
+	/// When a rule's prelude is invalid, cssparser only skips its block once the next rule is parsed, so the span it reports ends at (or before) the `{`; the span is extended here to cover the whole rule.
+	pub(crate) fn recover_rule<T>(&self, input: &mut Parser<'i, '_>, before: SourcePosition, result: Result<T, (ParseError<'i, CustomParseError<'i>>, &'i str)>) -> Result<Option<T>, ParseError<'i, CustomParseError<'i>>>
+	{
+		let result = result.map_err(|(error, span)|
+		{
+			// Skipping whitespace also skips the rest of any block the rule was in the middle of; the input is then reset, so that the block is still skipped by cssparser and the whitespace and comments after the rule are not lost.
+			let state = input.state();
+			input.skip_whitespace();
+			let consumedAndSkipped = input.slice_from(before);
+			let skipped = input.slice_from(state.position());
+			input.reset(&state);
+			
+			let spanStart = span.as_ptr() as usize - consumedAndSkipped.as_ptr() as usize;
+			let consumed = consumedAndSkipped.len() - skipped.len();
+			let spanEnd = max(spanStart + span.len(), consumed + Self::length_of_rest_of_block(skipped));
+			(error, &consumedAndSkipped[spanStart .. spanEnd])
+		});
+		
+		self.recover(result)
+	}
+	
+	/// The length of `css` up to and including the `}` that closes the block it is in, or zero if it is just whitespace and comments.
+	fn length_of_rest_of_block(css: &str) -> usize
+	{
+		let mut parserInput = ParserInput::new(css);
+		let mut input = Parser::new(&mut parserInput);
+		let start = input.position();
+		let mut isJustWhitespaceAndComments = true;
+		loop
+		{
+			match input.next_including_whitespace_and_comments()
+			{
+				Err(_) => return if isJustWhitespaceAndComments { 0 } else { css.len() },
+				
+				Ok(&Token::CloseCurlyBracket) => return input.slice_from(start).len(),
+				
+				Ok(&Token::WhiteSpace(_)) | Ok(&Token::Comment(_)) => (),
+				
+				Ok(_) => isJustWhitespaceAndComments = false,
+			}
+		}
+	}
+	
+	/// If recording source ranges, skips whitespace, comments and any tokens for which `isIgnorable` is true, so that the returned state is at the start of the next rule or declaration in a list.
+	#[inline(always)]
+	pub(crate) fn source_range_start<'t>(&self, input: &mut Parser<'i, 't>, isIgnorable: fn(&Token) -> bool) -> Option<ParserState>
+	{
+		if !self.source_ranges
+		{
+			return None;
+		}
+		
+		loop
+		{
+			input.skip_whitespace();
+			let state = input.state();
+			let ignorable = match input.next_including_whitespace_and_comments()
+			{
+				Ok(token) => isIgnorable(token),
+				Err(_) => false,
+			};
+			if !ignorable
+			{
+				input.reset(&state);
+				return Some(state);
+			}
+		}
+	}
+	
+	/// The source range from `start` (obtained from `source_range_start()`) to the current position of `input`.
+	#[inline(always)]
+	pub(crate) fn source_range(start: Option<ParserState>, input: &Parser<'i, '_>) -> Option<SourceRange>
+	{
+		start.map(|start| SourceRange::new(&start, input))
+	}
+	
+	/// If keeping the original CSS, records where a rule or declaration in a list was, and where the whitespace and comments before it started.
+	#[inline(always)]
+	pub(crate) fn record_original_css_node(&self, trivia_start: SourcePosition, start: &Option<ParserState>, input: &Parser<'i, '_>)
+	{
+		if let (Some(original_css), Some(start)) = (&self.original_css, start)
+		{
+			original_css.borrow_mut().record_node(trivia_start.byte_index(), start.position().byte_index(), input.position().byte_index());
+		}
+	}
+	
+	/// If keeping the original CSS, records where the whitespace and comments after the last rule or declaration in a block (or stylesheet) started.
+	#[inline(always)]
+	pub(crate) fn record_original_css_block(&self, contents_start: SourcePosition, trailing_trivia_start: SourcePosition)
+	{
+		if let Some(ref original_css) = self.original_css
+		{
+			original_css.borrow_mut().record_block(contents_start.byte_index(), trailing_trivia_start.byte_index());
+		}
+	}
+	
+	/// Unless stripping them, parses an important comment (`/*! ... */`) if there is one before the next rule in a list of rules.
+	/// Whitespace and other comments before it are skipped.
+	pub(crate) fn parse_important_comment<'t>(&self, input: &mut Parser<'i, 't>, trivia_start: SourcePosition) -> Option<CssRule>
+	{
+		if self.strip_important_comments
+		{
+			return None;
+		}
+		
+		loop
+		{
+			let start = input.state();
+			let text = match input.next_including_whitespace_and_comments()
+			{
+				Ok(&Token::WhiteSpace(_)) => continue,
+				
+				Ok(&Token::Comment(text)) => match text.strip_prefix('!')
+				{
+					Some(text) => text.to_owned(),
+					None => continue,
+				},
+				
+				_ =>
+				{
+					input.reset(&start);
+					return None;
+				}
+			};
+			
+			let start = if self.source_ranges
+			{
+				Some(start)
+			}
+			else
+			{
+				None
+			};
+			self.record_original_css_node(trivia_start, &start, input);
+			
+			return Some(CssRule::Comment(ImportantComment
+			{
+				text,
+				source_range: Self::source_range(start, input),
+			}));
+		}
+	}
 }
@@ -3,14 +3,14 @@
 
 
 /// A struct to parse property declarations.
-pub(crate) struct PropertyDeclarationParser<'a, I: 'a + HasImportance>
+pub(crate) struct PropertyDeclarationParser<'a, 'i, I: 'a + HasImportance>
 {
-	pub(crate) context: &'a ParserContext,
+	pub(crate) context: &'a ParserContext<'i>,
 	pub(crate) marker: PhantomData<&'a I>,
 }
 
 /// In theory, @rules may be present. In practice, none are currently defined (Sep 2017)
-impl<'a, 'i, I: HasImportance> AtRuleParser<'i> for PropertyDeclarationParser<'a, I>
+impl<'a, 'i, I: HasImportance> AtRuleParser<'i> for PropertyDeclarationParser<'a, 'i, I>
 {
 	type Prelude = ();
 	
@@ -19,7 +19,7 @@ impl<'a, 'i, I: HasImportance> AtRuleParser<'i> for PropertyDeclarationParser<'a
 	type Error = CustomParseError<'i>;
 }
 
-impl<'a, 'i, I: HasImportance> DeclarationParser<'i> for PropertyDeclarationParser<'a, I>
+impl<'a, 'i, I: HasImportance> DeclarationParser<'i> for PropertyDeclarationParser<'a, 'i, I>
 {
 	type Declaration = PropertyDeclaration<I>;
 	
@@ -27,21 +27,19 @@ impl<'a, 'i, I: HasImportance> DeclarationParser<'i> for PropertyDeclarationPars
 	
 	fn parse_value<'t>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<Self::Declaration, ParseError<'i, Self::Error>>
 	{
-		let (vendor_prefix, unprefixedPropertyName) = VendorPrefix::findPrefixIfAnyForAsciiLowerCaseName(name.to_ascii_lowercase());
+		// Custom property names are case-sensitive, so that `var(--Foo)` does not reference `--foo`.
+		let (vendor_prefix, unprefixedPropertyName) = if name.starts_with("--")
+		{
+			(None, name.to_string())
+		}
+		else
+		{
+			VendorPrefix::findPrefixIfAnyForAsciiLowerCaseName(name.to_ascii_lowercase())
+		};
 		
 		let name = Atom::from(unprefixedPropertyName);
 		
-		let value = input.parse_until_before(Delimiter::Bang, |input|
-		{
-			if let Ok(cssWideKeyword) = input.r#try(|input| CssWideKeyword::parse(input))
-			{
-				Ok(UnparsedPropertyValue::CssWideKeyword(cssWideKeyword))
-			}
-			else
-			{
-				Ok(UnparsedPropertyValue::SpecifiedValue(SpecifiedValue::parse(self.context, input)?))
-			}
-		})?;
+		let value = input.parse_until_before(Delimiter::Bang, |input| self.parse_unparsed_value(&vendor_prefix, &name, input))?;
 		
 		let importance = I::validateParsedImportance(Importance::parse(input)).map_err(|error| input.new_custom_error(error))?;
 		
@@ -55,7 +53,39 @@ impl<'a, 'i, I: HasImportance> DeclarationParser<'i> for PropertyDeclarationPars
 				name,
 				value,
 				importance,
+				source_range: None,
 			}
 		)
 	}
 }
+
+impl<'a, 'i, I: HasImportance> PropertyDeclarationParser<'a, 'i, I>
+{
+	/// Parses the value of a property declaration, without any importance; `name` is unprefixed.
+	pub(crate) fn parse_unparsed_value<'t>(&self, vendor_prefix: &Option<VendorPrefix>, name: &Atom, input: &mut Parser<'i, 't>) -> Result<UnparsedPropertyValue, ParseError<'i, CustomParseError<'i>>>
+	{
+		if let Ok(cssWideKeyword) = input.r#try(|input| CssWideKeyword::parse(input))
+		{
+			Ok(UnparsedPropertyValue::CssWideKeyword(cssWideKeyword))
+		}
+		else
+		{
+			let start = input.state();
+			let specifiedValue = SpecifiedValue::parse_with_references(self.context, input)?;
+			
+			// Values using `var()` can only be checked once the variables are substituted.
+			if vendor_prefix.is_none() && !specifiedValue.hasReferences()
+			{
+				let end = input.state();
+				input.reset(&start);
+				if let Ok(Some(typedPropertyValue)) = input.r#try(|input| TypedPropertyValue::parse(name, self.context, input))
+				{
+					return Ok(UnparsedPropertyValue::Typed(typedPropertyValue))
+				}
+				input.reset(&end);
+			}
+			
+			Ok(UnparsedPropertyValue::SpecifiedValue(specifiedValue))
+		}
+	}
+}
@@ -5,4 +5,5 @@
 pub(crate) struct QualifiedRuleParserPrelude
 {
 	selectors: DeduplicatedSelectors,
+	selectors_source_range: Option<SourceRange>,
 }
@@ -3,10 +3,10 @@
 
 
 /// The parser for the top-level rules in a stylesheet.
-pub(crate) struct TopLevelRuleParser
+pub(crate) struct TopLevelRuleParser<'i>
 {
 	/// This won't contain any namespaces, and only nested parsers created with `ParserContext::new_with_rule_type` will.
-	pub(crate) context: ParserContext,
+	pub(crate) context: ParserContext<'i>,
 	
 	/// The current state of the parser.
 	pub(crate) state: State,
@@ -14,7 +14,96 @@ pub(crate) struct TopLevelRuleParser
 	pub(crate) namespaces: Rc<Namespaces>,
 }
 
-impl<'i> AtRuleParser<'i> for TopLevelRuleParser
+impl<'i> TopLevelRuleParser<'i>
+{
+	/// Parses the rules of a stylesheet (or of a run of its top-level rules), returning this parser so that its state and namespaces can be inspected.
+	pub(crate) fn parse_rules<'t>(self, input: &mut Parser<'i, 't>, rules: &mut Vec<CssRule>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut iter = RuleListParser::new_for_stylesheet(input, self);
+		
+		let contents_start = iter.input.position();
+		let mut trailing_trivia_start = contents_start;
+		let mut anyRuleSoFar = false;
+		let mut charsetTriviaStart = None;
+		let mut charsetSkipped = false;
+		loop
+		{
+			let trivia_start = charsetTriviaStart.take().unwrap_or_else(|| iter.input.position());
+			if let Some(comment) = iter.parser.context.parse_important_comment(iter.input, trivia_start)
+			{
+				rules.push(comment);
+				trailing_trivia_start = iter.input.position();
+				continue;
+			}
+			
+			// cssparser skips a first `@charset` rule along with the whitespace and comments after it, which would lose any important comments there; so, until cssparser has parsed a rule, `@charset` rules are parsed here instead.
+			if !anyRuleSoFar
+			{
+				if let Some(start) = Self::skip_charset_rule(iter.input)
+				{
+					if charsetSkipped
+					{
+						let error = ParseError
+						{
+							kind: ParseErrorKind::Custom(CustomParseError::UnexpectedCharsetAtRule),
+							location: start.source_location(),
+						};
+						let span = iter.input.slice_from(start.position());
+						iter.parser.context.recover::<()>(Err((error, span)))?;
+					}
+					else
+					{
+						charsetSkipped = true;
+						charsetTriviaStart = Some(trivia_start);
+					}
+					continue;
+				}
+			}
+			
+			let start = iter.parser.context.source_range_start(iter.input, |token| *token == Token::CDO || *token == Token::CDC);
+			let result = match iter.next()
+			{
+				None => break,
+				Some(result) => result,
+			};
+			anyRuleSoFar = true;
+			if let Some(mut rule) = iter.parser.context.recover_rule(iter.input, trivia_start, result)?
+			{
+				*rule.source_range_mut() = ParserContext::source_range(start.clone(), iter.input);
+				iter.parser.context.record_original_css_node(trivia_start, &start, iter.input);
+				rules.push(rule);
+			}
+			trailing_trivia_start = iter.input.position();
+		}
+		iter.parser.context.record_original_css_block(contents_start, trailing_trivia_start);
+		
+		Ok(iter.parser)
+	}
+	
+	/// Skips a `@charset` rule, if it is next, returning where it started.
+	fn skip_charset_rule<'t>(input: &mut Parser<'i, 't>) -> Option<ParserState>
+	{
+		let start = input.state();
+		let isCharsetRule = match input.next()
+		{
+			Ok(Token::AtKeyword(name)) => name.eq_ignore_ascii_case("charset"),
+			_ => false,
+		};
+		
+		if isCharsetRule
+		{
+			let _: Result<(), ParseError<'i, CustomParseError<'i>>> = input.parse_until_after(Delimiter::Semicolon | Delimiter::CurlyBracketBlock, |_| Ok(()));
+			Some(start)
+		}
+		else
+		{
+			input.reset(&start);
+			None
+		}
+	}
+}
+
+impl<'i> AtRuleParser<'i> for TopLevelRuleParser<'i>
 {
 	type Prelude = AtRuleBlockPrelude;
 	
@@ -30,7 +119,7 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 			
 			"charset" =>
 			{
-				// @charset is removed by cssparser if it’s the first rule in the stylesheet; anything left is invalid.
+				// The first @charset is removed by `parse_rules()`; anything left is invalid.
 				Err(input.new_custom_error(CustomParseError::UnexpectedCharsetAtRule))
 			},
 			
@@ -55,6 +144,19 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 				Ok(AtRuleBlockPrelude::Namespace(self.parseNamespaceAtRule(input)?))
 			},
 			
+			"layer" =>
+			{
+				if self.state > State::Body
+				{
+					self.state = State::Invalid;
+					return Err(input.new_custom_error(CustomParseError::InvalidParseState));
+				}
+				
+				// A @layer statement may come before @import and @namespace rules, so the state is only changed once it is known whether this rule has a block.
+				let mut nested = self.nested();
+				<NestedRuleParser as AtRuleParser>::parse_prelude(&mut nested, name.clone(), input)
+			},
+			
 			_ =>
 			{
 				// Don't allow starting with an invalid state
@@ -72,7 +174,7 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 	}
 
 	#[inline]
-	fn rule_without_block(&mut self, prelude: Self::Prelude, _: &ParserState) -> Result<Self::AtRule, ()>
+	fn rule_without_block(&mut self, prelude: Self::Prelude, start: &ParserState) -> Result<Self::AtRule, ()>
 	{
 		use self::State::*;
 		use self::AtRuleBlockPrelude::*;
@@ -88,6 +190,15 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 				self.state = Namespaces;
 				Ok(CssRule::Namespace(rule))
 			},
+			
+			Layer(names) => {
+				if self.state > Imports
+				{
+					self.state = Body;
+				}
+				let mut nested = self.nested();
+				<NestedRuleParser as AtRuleParser>::rule_without_block(&mut nested, Layer(names), start)
+			},
 
 			_ => Err(()),
 		}
@@ -113,7 +224,7 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 	}
 }
 
-impl<'i> QualifiedRuleParser<'i> for TopLevelRuleParser
+impl<'i> QualifiedRuleParser<'i> for TopLevelRuleParser<'i>
 {
 	type Prelude = QualifiedRuleParserPrelude;
 	
@@ -148,10 +259,10 @@ impl<'i> QualifiedRuleParser<'i> for TopLevelRuleParser
 	}
 }
 
-impl TopLevelRuleParser
+impl<'i> TopLevelRuleParser<'i>
 {
 	#[inline(always)]
-	fn nested<'a>(&'a self) -> NestedRuleParser<'a>
+	fn nested<'a>(&'a self) -> NestedRuleParser<'a, 'i>
 	{
 		NestedRuleParser
 		{
@@ -161,20 +272,33 @@ impl TopLevelRuleParser
 	}
 	
 	#[inline(always)]
-	fn parseImportAtRule<'i, 't>(&self, input: &mut Parser<'i, 't>) -> Result<ImportAtRule, ParseError<'i, CustomParseError<'i>>>
+	fn parseImportAtRule<'t>(&self, input: &mut Parser<'i, 't>) -> Result<ImportAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
+		let url = SpecifiedUrl(input.expect_url_or_string()?.as_ref().to_owned());
+		
+		let layer = input.r#try(ImportLayer::parse).ok();
+		
+		let supports = input.r#try(|input|
+		{
+			input.expect_function_matching("supports")?;
+			input.parse_nested_block(|input| SupportsCondition::parse_condition_or_declaration(&self.namespaces, input))
+		}).ok();
+		
 		Ok
 		(
 			ImportAtRule
 			{
-				url: SpecifiedUrl(input.expect_url_or_string()?.as_ref().to_owned()),
+				url,
+				layer,
+				supports,
 				media_list: MediaList::parse_media_query_list(&self.context, input, false)?,
+				source_range: None,
 			}
 		)
 	}
 	
 	#[inline(always)]
-	fn parseNamespaceAtRule<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<NamespaceAtRule, ParseError<'i, CustomParseError<'i>>>
+	fn parseNamespaceAtRule<'t>(&mut self, input: &mut Parser<'i, 't>) -> Result<NamespaceAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
 		let prefix: Result<_, ParseError<CustomParseError>> = input.r#try(|i|
 		{
@@ -202,6 +326,7 @@ impl TopLevelRuleParser
 			{
 				prefix,
 				url,
+				source_range: None,
 			}
 		)
 	}
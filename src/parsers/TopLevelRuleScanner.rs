@@ -0,0 +1,364 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A fast pre-scan of a stylesheet that finds where its top-level rules end, without tokenizing or parsing them.
+/// It follows the block-matching rules of the CSS Syntax specification (strings, comments, escapes, `url()`s and mismatched closing brackets), so that the boundaries are those the parser would find.
+pub(crate) struct TopLevelRuleScanner<'a>
+{
+	css: &'a [u8],
+	position: usize,
+}
+
+impl<'a> TopLevelRuleScanner<'a>
+{
+	/// Returns the boundaries of the top-level rules of `css`, in order.
+	/// Returns `None` if the boundaries can not be found reliably (eg a `@charset` rule that is not the first rule, or an escaped `url(` or at-rule name); the stylesheet should then be parsed sequentially.
+	pub(crate) fn scan(css: &'a str) -> Option<Vec<TopLevelRuleBoundary>>
+	{
+		let mut scanner = Self
+		{
+			css: css.as_bytes(),
+			position: 0,
+		};
+		
+		let mut boundaries = Vec::new();
+		loop
+		{
+			scanner.skip_whitespace_comments_and_cdo_cdc();
+			if scanner.position == scanner.css.len()
+			{
+				return Some(boundaries);
+			}
+			
+			let isAtRule = scanner.peek(0) == Some(b'@');
+			let mut isImportOrNamespace = false;
+			if isAtRule
+			{
+				let name = scanner.at_rule_name()?;
+				if name.eq_ignore_ascii_case(b"charset") && !boundaries.is_empty()
+				{
+					return None;
+				}
+				isImportOrNamespace = name.eq_ignore_ascii_case(b"import") || name.eq_ignore_ascii_case(b"namespace");
+			}
+			
+			scanner.skip_rule(isAtRule)?;
+			
+			boundaries.push
+			(
+				TopLevelRuleBoundary
+				{
+					end: scanner.position,
+					is_import_or_namespace: isImportOrNamespace,
+				}
+			);
+		}
+	}
+	
+	#[inline(always)]
+	fn peek(&self, offset: usize) -> Option<u8>
+	{
+		self.css.get(self.position + offset).cloned()
+	}
+	
+	#[inline(always)]
+	fn starts_with(&self, bytes: &[u8]) -> bool
+	{
+		self.css[self.position..].starts_with(bytes)
+	}
+	
+	#[inline(always)]
+	fn isWhitespace(byte: u8) -> bool
+	{
+		matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | b'\x0C')
+	}
+	
+	#[inline(always)]
+	fn isNameCharacter(byte: u8) -> bool
+	{
+		match byte
+		{
+			b'a' ..= b'z' | b'A' ..= b'Z' | b'0' ..= b'9' | b'-' | b'_' => true,
+			_ => byte >= 0x80,
+		}
+	}
+	
+	fn skip_whitespace_comments_and_cdo_cdc(&mut self)
+	{
+		while let Some(byte) = self.peek(0)
+		{
+			if Self::isWhitespace(byte)
+			{
+				self.position += 1;
+			}
+			else if self.starts_with(b"/*")
+			{
+				self.skip_comment();
+			}
+			else if self.starts_with(b"<!--")
+			{
+				self.position += 4;
+			}
+			else if self.starts_with(b"-->")
+			{
+				self.position += 3;
+			}
+			else
+			{
+				break;
+			}
+		}
+	}
+	
+	/// Returns the name of the at-rule starting at the current position, or `None` if it is escaped.
+	fn at_rule_name(&self) -> Option<&'a [u8]>
+	{
+		let start = self.position + 1;
+		let mut end = start;
+		while end < self.css.len() && Self::isNameCharacter(self.css[end])
+		{
+			end += 1;
+		}
+		
+		if self.css.get(end) == Some(&b'\\')
+		{
+			None
+		}
+		else
+		{
+			Some(&self.css[start .. end])
+		}
+	}
+	
+	/// Skips a rule; an at-rule ends at a semicolon outside of any block, or at the end of its block; a qualified rule ends only at the end of its block.
+	fn skip_rule(&mut self, isAtRule: bool) -> Option<()>
+	{
+		let mut expectedClosingBrackets = Vec::new();
+		
+		// The start of the identifier (or at-keyword or hash) being scanned, and whether it contains escapes; used to recognise `url(`.
+		let mut identifier: Option<(usize, bool)> = None;
+		
+		while let Some(byte) = self.peek(0)
+		{
+			match byte
+			{
+				b'/' if self.peek(1) == Some(b'*') =>
+				{
+					self.skip_comment();
+					identifier = None;
+				}
+				
+				b'"' | b'\'' =>
+				{
+					self.skip_string(byte);
+					identifier = None;
+				}
+				
+				b'\\' => match self.peek(1)
+				{
+					None | Some(b'\n') | Some(b'\r') | Some(b'\x0C') =>
+					{
+						self.position += 1;
+						identifier = None;
+					}
+					
+					Some(_) =>
+					{
+						let start = identifier.map(|(start, _)| start).unwrap_or(self.position);
+						identifier = Some((start, true));
+						self.skip_escape();
+					}
+				},
+				
+				b'(' =>
+				{
+					let isUrl = match identifier
+					{
+						None => false,
+						Some((_, true)) => return None,
+						Some((start, false)) => self.css[start .. self.position].eq_ignore_ascii_case(b"url"),
+					};
+					identifier = None;
+					self.position += 1;
+					
+					if !(isUrl && self.skip_unquoted_url())
+					{
+						expectedClosingBrackets.push(b')');
+					}
+				}
+				
+				b'[' =>
+				{
+					expectedClosingBrackets.push(b']');
+					identifier = None;
+					self.position += 1;
+				}
+				
+				b'{' =>
+				{
+					expectedClosingBrackets.push(b'}');
+					identifier = None;
+					self.position += 1;
+				}
+				
+				b')' | b']' | b'}' =>
+				{
+					identifier = None;
+					self.position += 1;
+					
+					// Closing brackets that do not match the innermost open block are ignored.
+					if expectedClosingBrackets.last() == Some(&byte)
+					{
+						expectedClosingBrackets.pop();
+						if byte == b'}' && expectedClosingBrackets.is_empty()
+						{
+							return Some(());
+						}
+					}
+				}
+				
+				b';' if isAtRule && expectedClosingBrackets.is_empty() =>
+				{
+					self.position += 1;
+					return Some(());
+				}
+				
+				b'@' | b'#' =>
+				{
+					identifier = Some((self.position, false));
+					self.position += 1;
+				}
+				
+				_ =>
+				{
+					if Self::isNameCharacter(byte)
+					{
+						if identifier.is_none()
+						{
+							identifier = Some((self.position, false));
+						}
+					}
+					else
+					{
+						identifier = None;
+					}
+					self.position += 1;
+				}
+			}
+		}
+		
+		Some(())
+	}
+	
+	fn skip_comment(&mut self)
+	{
+		let contents = &self.css[self.position + 2 ..];
+		self.position = match contents.windows(2).position(|window| window == b"*/")
+		{
+			None => self.css.len(),
+			Some(index) => self.position + 2 + index + 2,
+		};
+	}
+	
+	/// A string ends at its closing quote, at an unescaped newline (a bad string) or at the end of the stylesheet.
+	fn skip_string(&mut self, quote: u8)
+	{
+		self.position += 1;
+		while let Some(byte) = self.peek(0)
+		{
+			match byte
+			{
+				b'\n' | b'\r' | b'\x0C' => return,
+				
+				b'\\' => self.position += if self.peek(1) == Some(b'\r') && self.peek(2) == Some(b'\n')
+				{
+					3
+				}
+				else
+				{
+					min(2, self.css.len() - self.position)
+				},
+				
+				_ =>
+				{
+					self.position += 1;
+					if byte == quote
+					{
+						return;
+					}
+				}
+			}
+		}
+	}
+	
+	/// Skips a backslash and the escaped code point, or up to six hexadecimal digits and an optional whitespace.
+	fn skip_escape(&mut self)
+	{
+		self.position += 1;
+		
+		let mut numberOfHexadecimalDigits = 0;
+		while numberOfHexadecimalDigits < 6 && self.peek(0).map(|byte| byte.is_ascii_hexdigit()).unwrap_or(false)
+		{
+			self.position += 1;
+			numberOfHexadecimalDigits += 1;
+		}
+		
+		if numberOfHexadecimalDigits == 0
+		{
+			self.position += 1;
+		}
+		else if self.starts_with(b"\r\n")
+		{
+			self.position += 2;
+		}
+		else if self.peek(0).map(Self::isWhitespace).unwrap_or(false)
+		{
+			self.position += 1;
+		}
+	}
+	
+	/// Called after `url(`; returns false if this is a `url()` function with a quoted string argument rather than an unquoted URL.
+	fn skip_unquoted_url(&mut self) -> bool
+	{
+		while self.peek(0).map(Self::isWhitespace).unwrap_or(false)
+		{
+			self.position += 1;
+		}
+		
+		match self.peek(0)
+		{
+			Some(b'"') | Some(b'\'') => return false,
+			_ => (),
+		}
+		
+		while let Some(byte) = self.peek(0)
+		{
+			match byte
+			{
+				b')' =>
+				{
+					self.position += 1;
+					break;
+				}
+				
+				b'\\' => self.position += min(2, self.css.len() - self.position),
+				
+				_ => self.position += 1,
+			}
+		}
+		
+		true
+	}
+}
+
+/// Where a top-level rule ends, as found by `TopLevelRuleScanner`.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct TopLevelRuleBoundary
+{
+	/// The byte index just after the rule.
+	pub(crate) end: usize,
+	
+	/// Whether the rule is an `@import` or `@namespace` rule.
+	pub(crate) is_import_or_namespace: bool,
+}
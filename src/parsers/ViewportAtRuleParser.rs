@@ -2,12 +2,12 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-pub(crate) struct ViewportAtRuleParser<'a>
+pub(crate) struct ViewportAtRuleParser<'a, 'i>
 {
-	pub(crate) context: &'a ParserContext
+	pub(crate) context: &'a ParserContext<'i>
 }
 
-impl<'a, 'i> AtRuleParser<'i> for ViewportAtRuleParser<'a>
+impl<'a, 'i> AtRuleParser<'i> for ViewportAtRuleParser<'a, 'i>
 {
 	type Prelude = ();
 	
@@ -16,7 +16,7 @@ impl<'a, 'i> AtRuleParser<'i> for ViewportAtRuleParser<'a>
 	type Error = CustomParseError<'i>;
 }
 
-impl<'a, 'i> DeclarationParser<'i> for ViewportAtRuleParser<'a>
+impl<'a, 'i> DeclarationParser<'i> for ViewportAtRuleParser<'a, 'i>
 {
 	type Declaration = ViewportDescriptorDeclaration;
 	
@@ -33,7 +33,7 @@ impl<'a, 'i> DeclarationParser<'i> for ViewportAtRuleParser<'a>
 		}
 		
 		#[inline(always)]
-		fn parse_shorthand_property<'a, 'i, 't, Constructor: FnOnce(ViewportLength, Option<ViewportLength>) -> ViewportDescriptor>(input: &mut Parser<'i, 't>, this: &ViewportAtRuleParser<'a>, constructor: Constructor) -> Result<ViewportDescriptorDeclaration, ParseError<'i, CustomParseError<'i>>>
+		fn parse_shorthand_property<'a, 'i, 't, Constructor: FnOnce(ViewportLength, Option<ViewportLength>) -> ViewportDescriptor>(input: &mut Parser<'i, 't>, this: &ViewportAtRuleParser<'a, 'i>, constructor: Constructor) -> Result<ViewportDescriptorDeclaration, ParseError<'i, CustomParseError<'i>>>
 		{
 			let minimum = this.parseViewportLength(input)?;
 			let maximum = match input.r#try(|input| this.parseViewportLength(input))
@@ -75,7 +75,7 @@ impl<'a, 'i> DeclarationParser<'i> for ViewportAtRuleParser<'a>
 	}
 }
 
-impl<'a> ViewportAtRuleParser<'a>
+impl<'a, 'c> ViewportAtRuleParser<'a, 'c>
 {
 	#[inline(always)]
 	fn parseViewportLength<'i, 't>(&self, input: &mut Parser<'i, 't>) -> Result<ViewportLength, ParseError<'i, CustomParseError<'i>>>
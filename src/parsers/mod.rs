@@ -9,6 +9,7 @@ use super::domain::atRules::fontFace::*;
 use super::domain::atRules::fontFeatureValues::*;
 use super::domain::atRules::import::*;
 use super::domain::atRules::keyframes::*;
+use super::domain::atRules::layer::*;
 use super::domain::atRules::media::*;
 use super::domain::atRules::namespace::*;
 use super::domain::atRules::page::*;
@@ -20,8 +21,10 @@ use ::ordermap::OrderMap;
 use crate::selectors::parser::SelectorImpl;
 use crate::selectors::parser::SelectorList;
 use ::smallvec::SmallVec;
+use ::std::cell::RefCell;
 use ::std::collections::HashMap;
 use ::std::marker::PhantomData;
+use ::std::ops::Range;
 use ::std::rc::Rc;
 
 
@@ -38,6 +41,8 @@ include!("KeyframeListParser.rs");
 include!("KeyframeSelectorParserPrelude.rs");
 include!("NestedRuleParser.rs");
 include!("OurSelectorParser.rs");
+include!("PageAtRuleParser.rs");
+include!("ParsedRunOfTopLevelRules.rs");
 include!("Parse.rs");
 include!("ParserContext.rs");
 include!("ParsingMode.rs");
@@ -45,4 +50,5 @@ include!("PropertyDeclarationParser.rs");
 include!("QualifiedRuleParserPrelude.rs");
 include!("State.rs");
 include!("TopLevelRuleParser.rs");
+include!("TopLevelRuleScanner.rs");
 include!("ViewportAtRuleParser.rs");
@@ -0,0 +1,150 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// A rule, at-rule statement or property declaration in (minified) CSS being formatted.
+#[derive(Debug, Clone)]
+pub(crate) enum FormattedItem<'a>
+{
+	/// An at-rule without a block (eg `@import`) or a property declaration (or descriptor).
+	Statement(&'a str),
+	
+	/// A rule with a block, such as a style rule or `@media`; the prelude is everything before the block's `{`.
+	Block(&'a str, Vec<FormattedItem<'a>>),
+	
+	/// An important comment (`/*! ... */`) between rules.
+	Comment(&'a str),
+}
+
+impl<'a> FormattedItem<'a>
+{
+	#[inline(always)]
+	pub(crate) fn is_block(&self) -> bool
+	{
+		match *self
+		{
+			FormattedItem::Block(..) => true,
+			FormattedItem::Statement(..) | FormattedItem::Comment(..) => false,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn is_property_declaration(&self) -> bool
+	{
+		match *self
+		{
+			FormattedItem::Statement(statement) => !statement.starts_with('@'),
+			FormattedItem::Block(..) | FormattedItem::Comment(..) => false,
+		}
+	}
+	
+	/// Splits minified CSS into items, nesting the contents of blocks.
+	///
+	/// Strings, comments and parenthesized or bracketed content are never split, so only a `{`, `}` or `;` outside of them ends an item.
+	/// The value of a custom property (eg `--x:{a:b}`) is opaque, so any braces in it are treated as brackets rather than as a block.
+	/// A comment at the start of an item is an item of its own.
+	pub(crate) fn parse_items(css: &'a str, position: &mut usize) -> Vec<FormattedItem<'a>>
+	{
+		let bytes = css.as_bytes();
+		let mut items = Vec::new();
+		let mut start = *position;
+		let mut depth = 0usize;
+		
+		while *position < bytes.len()
+		{
+			match bytes[*position]
+			{
+				quote @ b'"' | quote @ b'\'' =>
+				{
+					*position += 1;
+					while *position < bytes.len() && bytes[*position] != quote
+					{
+						if bytes[*position] == b'\\'
+						{
+							*position += 1;
+						}
+						*position += 1;
+					}
+				}
+				
+				b'\\' => *position += 1,
+				
+				b'/' if bytes.get(*position + 1) == Some(&b'*') =>
+				{
+					let commentStart = *position;
+					*position = match css[(*position + 2) .. ].find("*/")
+					{
+						None => bytes.len(),
+						Some(index) => *position + 2 + index + 2,
+					};
+					
+					if css[start .. commentStart].trim().is_empty()
+					{
+						items.push(FormattedItem::Comment(&css[commentStart .. *position]));
+						start = *position;
+					}
+					continue;
+				}
+				
+				b'(' | b'[' => depth += 1,
+				
+				b'{' if depth != 0 || Self::is_custom_property_declaration(&css[start .. *position]) => depth += 1,
+				
+				b')' | b']' => depth = depth.saturating_sub(1),
+				
+				b'}' if depth != 0 => depth -= 1,
+				
+				b'{' =>
+				{
+					let prelude = css[start .. *position].trim();
+					*position += 1;
+					let contents = Self::parse_items(css, position);
+					items.push(FormattedItem::Block(prelude, contents));
+					start = *position;
+					continue;
+				}
+				
+				b';' if depth == 0 =>
+				{
+					Self::push_statement(&mut items, &css[start .. *position]);
+					*position += 1;
+					start = *position;
+					continue;
+				}
+				
+				b'}' =>
+				{
+					Self::push_statement(&mut items, &css[start .. *position]);
+					*position += 1;
+					return items;
+				}
+				
+				_ => (),
+			}
+			*position += 1;
+		}
+		
+		*position = bytes.len();
+		Self::push_statement(&mut items, &css[start .. ]);
+		items
+	}
+	
+	/// Is `item` the start of a declaration of a custom property, such as `--x:`?
+	#[inline(always)]
+	pub(crate) fn is_custom_property_declaration(item: &str) -> bool
+	{
+		let item = item.trim_start();
+		item.starts_with("--") && item.contains(':')
+	}
+	
+	#[inline(always)]
+	fn push_statement(items: &mut Vec<FormattedItem<'a>>, statement: &'a str)
+	{
+		let statement = statement.trim();
+		if !statement.is_empty()
+		{
+			items.push(FormattedItem::Statement(statement));
+		}
+	}
+}
@@ -0,0 +1,30 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// How to write hex colours, such as `#ff00AA`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum HexColourCase
+{
+	/// Write hex colours in lowercase, eg `#ff00aa`.
+	Lowercase,
+	
+	/// Write hex colours in uppercase, eg `#FF00AA`.
+	Uppercase,
+}
+
+impl HexColourCase
+{
+	#[inline(always)]
+	pub(crate) fn convert(self, hexDigit: char) -> char
+	{
+		use self::HexColourCase::*;
+		
+		match self
+		{
+			Lowercase => hexDigit.to_ascii_lowercase(),
+			Uppercase => hexDigit.to_ascii_uppercase(),
+		}
+	}
+}
@@ -0,0 +1,39 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Whether to indent with spaces or tabs.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum IndentStyle
+{
+	/// Indent with spaces.
+	Spaces,
+	
+	/// Indent with tabs.
+	Tabs,
+}
+
+impl Default for IndentStyle
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		IndentStyle::Spaces
+	}
+}
+
+impl IndentStyle
+{
+	#[inline(always)]
+	pub(crate) fn character(self) -> char
+	{
+		use self::IndentStyle::*;
+		
+		match self
+		{
+			Spaces => ' ',
+			Tabs => '\t',
+		}
+	}
+}
@@ -0,0 +1,120 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Writes a stylesheet formatted to be read (and edited), rather than minified as by `Stylesheet::to_css()`.
+///
+/// The output is idempotent: formatting it again (with the same options) does not change it, so `PrettyPrinter` can be used as a code formatter.
+/// Comments are not kept.
+#[derive(Default, Debug, Clone)]
+pub struct PrettyPrinter
+{
+	/// Formatting options.
+	pub options: PrettyPrinterOptions,
+}
+
+impl PrettyPrinter
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(options: PrettyPrinterOptions) -> Self
+	{
+		Self
+		{
+			options,
+		}
+	}
+	
+	/// Writes a formatted stylesheet.
+	pub fn write<W: fmt::Write>(&self, stylesheet: &Stylesheet, destination: &mut W) -> fmt::Result
+	{
+		let css = stylesheet.rules.to_css_string();
+		
+		let mut position = 0;
+		let items = FormattedItem::parse_items(&css, &mut position);
+		
+		PrettyPrintingWriter::new(destination, &self.options).write_items(&items, 0)
+	}
+	
+	/// Formats a stylesheet as a string.
+	#[inline(always)]
+	pub fn to_string(&self, stylesheet: &Stylesheet) -> String
+	{
+		let mut css = String::new();
+		self.write(stylesheet, &mut css).expect("Writing to a String can not fail");
+		css
+	}
+	
+	/// Parses and formats CSS.
+	#[inline(always)]
+	pub fn format<'i>(&self, css: &'i str) -> Result<String, ParseError<'i, CustomParseError<'i>>>
+	{
+		Stylesheet::parse(css).map(|stylesheet| self.to_string(&stylesheet))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	const Css: &str = "@charset \"utf-8\";@import url(a.css) screen;@namespace svg url(http://www.w3.org/2000/svg);a,b>c,d[title='x,y']{color:#AbCdEf;background:url(#FFF) #fff!important;margin:0 auto}@media (min-width:10px){.x{}.y{top:1px}}@font-face{font-family:X;src:url(x.woff)}@keyframes k{from{left:0}to{left:1px}}@page :first{margin:1in;@top-left{content:'a;b'}}";
+	
+	fn assert_idempotent(options: PrettyPrinterOptions)
+	{
+		let prettyPrinter = PrettyPrinter::new(options);
+		let once = prettyPrinter.format(Css).unwrap();
+		let twice = prettyPrinter.format(&once).unwrap();
+		assert_eq!(once, twice);
+	}
+	
+	#[test]
+	fn formatting_is_idempotent_with_default_options()
+	{
+		assert_idempotent(PrettyPrinterOptions::default());
+	}
+	
+	#[test]
+	fn formatting_is_idempotent_with_compact_options()
+	{
+		assert_idempotent(PrettyPrinterOptions
+		{
+			indent_style: IndentStyle::Tabs,
+			indent_width: 1,
+			one_selector_per_line: false,
+			one_declaration_per_line: false,
+			blank_lines_between_rules: false,
+			space_after_colon: false,
+			hex_colour_case: Some(HexColourCase::Lowercase),
+		});
+	}
+	
+	#[test]
+	fn formatting_is_idempotent_with_uppercase_hex_colours()
+	{
+		assert_idempotent(PrettyPrinterOptions
+		{
+			hex_colour_case: Some(HexColourCase::Uppercase),
+			.. PrettyPrinterOptions::default()
+		});
+	}
+	
+	#[test]
+	fn hex_colours_in_urls_are_not_converted()
+	{
+		let css = PrettyPrinter::new(PrettyPrinterOptions { hex_colour_case: Some(HexColourCase::Lowercase), .. PrettyPrinterOptions::default() }).format("a{background:url(#FFF) #FFF}").unwrap();
+		assert_eq!(css, "a {\n    background: url(#FFF) #fff;\n}\n");
+	}
+	
+	#[test]
+	fn custom_property_values_are_not_formatted()
+	{
+		let prettyPrinter = PrettyPrinter::new(PrettyPrinterOptions
+		{
+			hex_colour_case: Some(HexColourCase::Uppercase),
+			..PrettyPrinterOptions::default()
+		});
+		assert_eq!(prettyPrinter.format("a{--x:{a:b;c:#abc};color:#abc}").unwrap(), "a {\n    --x: {a:b;c:#abc};\n    color: #ABC;\n}\n");
+	}
+}
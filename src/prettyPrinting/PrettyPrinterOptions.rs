@@ -0,0 +1,49 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Options for `PrettyPrinter`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PrettyPrinterOptions
+{
+	/// Whether to indent with spaces or tabs.
+	pub indent_style: IndentStyle,
+	
+	/// How many spaces (or tabs) to indent each level of nesting by.
+	pub indent_width: usize,
+	
+	/// Put each selector in a selector list on its own line.
+	pub one_selector_per_line: bool,
+	
+	/// Put each property declaration on its own line.
+	/// If false, a block that only contains property declarations is written on one line, eg `a { color: red; margin: 0; }`.
+	pub one_declaration_per_line: bool,
+	
+	/// Put a blank line between rules.
+	pub blank_lines_between_rules: bool,
+	
+	/// Put a space after the colon in a property declaration, eg `color: red` rather than `color:red`.
+	pub space_after_colon: bool,
+	
+	/// If present, hex colours in property values are converted to this case; otherwise, they are left as they are.
+	pub hex_colour_case: Option<HexColourCase>,
+}
+
+impl Default for PrettyPrinterOptions
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			indent_style: IndentStyle::Spaces,
+			indent_width: 4,
+			one_selector_per_line: true,
+			one_declaration_per_line: true,
+			blank_lines_between_rules: true,
+			space_after_colon: true,
+			hex_colour_case: None,
+		}
+	}
+}
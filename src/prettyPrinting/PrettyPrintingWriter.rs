@@ -0,0 +1,306 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Writes `FormattedItem`s using `PrettyPrinterOptions`.
+pub(crate) struct PrettyPrintingWriter<'a, W: 'a + fmt::Write>
+{
+	destination: &'a mut W,
+	options: &'a PrettyPrinterOptions,
+}
+
+impl<'a, W: 'a + fmt::Write> PrettyPrintingWriter<'a, W>
+{
+	#[inline(always)]
+	pub(crate) fn new(destination: &'a mut W, options: &'a PrettyPrinterOptions) -> Self
+	{
+		Self
+		{
+			destination,
+			options,
+		}
+	}
+	
+	pub(crate) fn write_items(&mut self, items: &[FormattedItem], depth: usize) -> fmt::Result
+	{
+		let mut previousWasBlock = None;
+		for item in items.iter()
+		{
+			if let Some(previousWasBlock) = previousWasBlock
+			{
+				if self.options.blank_lines_between_rules && (previousWasBlock || item.is_block())
+				{
+					self.destination.write_char('\n')?;
+				}
+			}
+			previousWasBlock = Some(item.is_block());
+			
+			self.write_indent(depth)?;
+			match *item
+			{
+				FormattedItem::Comment(comment) =>
+				{
+					self.destination.write_str(comment)?;
+					self.destination.write_char('\n')?;
+				}
+				
+				FormattedItem::Statement(statement) =>
+				{
+					self.write_statement(statement)?;
+					self.destination.write_str(";\n")?;
+				}
+				
+				FormattedItem::Block(prelude, ref contents) =>
+				{
+					self.write_prelude(prelude, depth)?;
+					
+					if contents.is_empty()
+					{
+						self.destination.write_str(" {}\n")?;
+					}
+					else if !self.options.one_declaration_per_line && contents.iter().all(FormattedItem::is_property_declaration)
+					{
+						self.destination.write_str(" {")?;
+						for item in contents.iter()
+						{
+							if let FormattedItem::Statement(statement) = *item
+							{
+								self.destination.write_char(' ')?;
+								self.write_statement(statement)?;
+								self.destination.write_char(';')?;
+							}
+						}
+						self.destination.write_str(" }\n")?;
+					}
+					else
+					{
+						self.destination.write_str(" {\n")?;
+						self.write_items(contents, depth + 1)?;
+						self.write_indent(depth)?;
+						self.destination.write_str("}\n")?;
+					}
+				}
+			}
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn write_indent(&mut self, depth: usize) -> fmt::Result
+	{
+		let character = self.options.indent_style.character();
+		for _ in 0 .. (depth * self.options.indent_width)
+		{
+			self.destination.write_char(character)?;
+		}
+		Ok(())
+	}
+	
+	/// At-rule preludes are written as they are; selector lists are split into one selector per line, if so configured.
+	fn write_prelude(&mut self, prelude: &str, depth: usize) -> fmt::Result
+	{
+		if prelude.starts_with('@')
+		{
+			return self.destination.write_str(prelude);
+		}
+		
+		let mut isFirst = true;
+		for selector in Self::split_at_top_level_commas(prelude)
+		{
+			if isFirst
+			{
+				isFirst = false;
+			}
+			else if self.options.one_selector_per_line
+			{
+				self.destination.write_str(",\n")?;
+				self.write_indent(depth)?;
+			}
+			else
+			{
+				self.destination.write_str(", ")?;
+			}
+			self.destination.write_str(selector.trim())?;
+		}
+		Ok(())
+	}
+	
+	/// At-rule statements are written as they are; property declarations are normalized to `name: value !important`.
+	/// The values of custom properties are written as they are.
+	fn write_statement(&mut self, statement: &str) -> fmt::Result
+	{
+		if statement.starts_with('@')
+		{
+			return self.destination.write_str(statement);
+		}
+		
+		let colon = match statement.find(':')
+		{
+			None => return self.destination.write_str(statement),
+			Some(colon) => colon,
+		};
+		
+		let name = statement[ .. colon].trim();
+		let mut value = statement[(colon + 1) .. ].trim();
+		
+		const Important: &str = "!important";
+		let isImportant = value.len() >= Important.len() && value.is_char_boundary(value.len() - Important.len()) && value[(value.len() - Important.len()) .. ].eq_ignore_ascii_case(Important);
+		if isImportant
+		{
+			value = value[ .. (value.len() - Important.len())].trim_end();
+		}
+		
+		self.destination.write_str(name)?;
+		self.destination.write_char(':')?;
+		if self.options.space_after_colon && !value.is_empty()
+		{
+			self.destination.write_char(' ')?;
+		}
+		if name.starts_with("--")
+		{
+			self.destination.write_str(value)?;
+		}
+		else
+		{
+			self.write_value(value)?;
+		}
+		if isImportant
+		{
+			if !value.is_empty()
+			{
+				self.destination.write_char(' ')?;
+			}
+			self.destination.write_str(Important)?;
+		}
+		Ok(())
+	}
+	
+	/// Writes a property value, converting the case of hex colours (but not of anything in strings, comments or `url()`s) if so configured.
+	fn write_value(&mut self, value: &str) -> fmt::Result
+	{
+		let hex_colour_case = match self.options.hex_colour_case
+		{
+			None => return self.destination.write_str(value),
+			Some(hex_colour_case) => hex_colour_case,
+		};
+		
+		#[inline(always)]
+		fn isNameCharacter(character: char) -> bool
+		{
+			character.is_ascii_alphanumeric() || character == '-' || character == '_' || !character.is_ascii()
+		}
+		
+		let mut characters = value.char_indices().peekable();
+		let mut previous = None;
+		while let Some((index, character)) = characters.next()
+		{
+			match character
+			{
+				'"' | '\'' =>
+				{
+					self.destination.write_char(character)?;
+					while let Some((_, next)) = characters.next()
+					{
+						self.destination.write_char(next)?;
+						if next == '\\'
+						{
+							if let Some((_, escaped)) = characters.next()
+							{
+								self.destination.write_char(escaped)?;
+							}
+						}
+						else if next == character
+						{
+							break;
+						}
+					}
+				}
+				
+				'/' if value[index .. ].starts_with("/*") =>
+				{
+					let end = value[(index + 2) .. ].find("*/").map_or(value.len(), |end| index + 2 + end + 2);
+					self.destination.write_str(&value[index .. end])?;
+					while characters.peek().is_some_and(|&(next, _)| next < end)
+					{
+						characters.next();
+					}
+				}
+				
+				'(' if value[ .. index].to_ascii_lowercase().ends_with("url") =>
+				{
+					let end = value[index .. ].find(')').map_or(value.len(), |end| index + end + 1);
+					self.destination.write_str(&value[index .. end])?;
+					while characters.peek().is_some_and(|&(next, _)| next < end)
+					{
+						characters.next();
+					}
+				}
+				
+				'#' if !previous.is_some_and(isNameCharacter) =>
+				{
+					let digits = value[(index + 1) .. ].chars().take_while(|&character| isNameCharacter(character)).collect::<String>();
+					self.destination.write_char('#')?;
+					if (digits.len() == 3 || digits.len() == 4 || digits.len() == 6 || digits.len() == 8) && digits.chars().all(|digit| digit.is_ascii_hexdigit())
+					{
+						for digit in digits.chars()
+						{
+							self.destination.write_char(hex_colour_case.convert(digit))?;
+						}
+					}
+					else
+					{
+						self.destination.write_str(&digits)?;
+					}
+					for _ in 0 .. digits.chars().count()
+					{
+						characters.next();
+					}
+				}
+				
+				_ => self.destination.write_char(character)?,
+			}
+			previous = Some(character);
+		}
+		
+		Ok(())
+	}
+	
+	fn split_at_top_level_commas(prelude: &str) -> Vec<&str>
+	{
+		let mut parts = Vec::new();
+		let mut depth = 0usize;
+		let mut quote = None;
+		let mut isEscaped = false;
+		let mut start = 0;
+		for (index, character) in prelude.char_indices()
+		{
+			if isEscaped
+			{
+				isEscaped = false;
+				continue;
+			}
+			
+			match (quote, character)
+			{
+				(_, '\\') => isEscaped = true,
+				(Some(open), _) => if character == open
+				{
+					quote = None;
+				},
+				(None, '"') | (None, '\'') => quote = Some(character),
+				(None, '(') | (None, '[') => depth += 1,
+				(None, ')') | (None, ']') => depth = depth.saturating_sub(1),
+				(None, ',') if depth == 0 =>
+				{
+					parts.push(&prelude[start .. index]);
+					start = index + 1;
+				}
+				_ => (),
+			}
+		}
+		parts.push(&prelude[start .. ]);
+		parts
+	}
+}
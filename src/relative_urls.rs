@@ -0,0 +1,172 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Does `url` start with a scheme, such as `https:` or `data:`?
+/// Single letter schemes are not treated as schemes, so that Windows paths such as `C:\\styles` are not mistaken for URLs.
+pub(crate) fn url_has_scheme(url: &str) -> bool
+{
+	match url.find(':')
+	{
+		None => false,
+		Some(index) =>
+		{
+			let scheme = &url[.. index];
+			scheme.len() > 1 && scheme.starts_with(|character: char| character.is_ascii_alphabetic()) && scheme.chars().all(|character| character.is_ascii_alphanumeric() || character == '+' || character == '-' || character == '.')
+		}
+	}
+}
+
+/// Resolves `url` relative to `base_url`, for URLs and paths that use `/` as a separator.
+/// `url` is returned unchanged if it has a scheme or is protocol-relative (starts with `//`).
+pub(crate) fn resolve_relative_url(base_url: &str, url: &str) -> String
+{
+	if url_has_scheme(url) || url.starts_with("//")
+	{
+		return url.to_owned();
+	}
+	
+	let (origin, base_path) = split_url_origin(base_url);
+	let (path, query_and_fragment) = split_url_query_and_fragment(url);
+	
+	let mut resolved = String::with_capacity(base_url.len() + url.len());
+	resolved.push_str(origin);
+	if path.is_empty()
+	{
+		resolved.push_str(split_url_query_and_fragment(base_path).0);
+	}
+	else if path.starts_with('/')
+	{
+		resolved.push_str(&normalize_url_path(path));
+	}
+	else
+	{
+		let directory = url_directory(split_url_query_and_fragment(base_path).0);
+		resolved.push_str(&normalize_url_path(&format!("{}{}", directory, path)));
+	}
+	resolved.push_str(query_and_fragment);
+	resolved
+}
+
+/// Makes `url` relative to `base_url`, so that `resolve_relative_url(base_url, relative_url(base_url, url)) == url` for normalized URLs.
+/// `url` is returned unchanged if it has a different origin or can not otherwise be made relative.
+pub(crate) fn relative_url(base_url: &str, url: &str) -> String
+{
+	let (base_origin, base_path) = split_url_origin(base_url);
+	let (origin, path) = split_url_origin(url);
+	if base_origin != origin || base_path.starts_with('/') != path.starts_with('/')
+	{
+		return url.to_owned();
+	}
+	
+	let (path, query_and_fragment) = split_url_query_and_fragment(path);
+	let base_directory: Vec<&str> = url_directory(split_url_query_and_fragment(base_path).0).split('/').filter(|segment| !segment.is_empty()).collect();
+	let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+	
+	let (file_name, directory) = match segments.split_last()
+	{
+		None => return url.to_owned(),
+		Some((file_name, directory)) => (*file_name, directory),
+	};
+	
+	let common = base_directory.iter().zip(directory.iter()).take_while(|&(left, right)| left == right).count();
+	
+	// A '..' in the base can not be undone.
+	if base_directory[common ..].contains(&"..")
+	{
+		return url.to_owned();
+	}
+	
+	let mut relative = String::new();
+	for _ in common .. base_directory.len()
+	{
+		relative.push_str("../");
+	}
+	for segment in directory[common ..].iter()
+	{
+		relative.push_str(segment);
+		relative.push('/');
+	}
+	relative.push_str(file_name);
+	relative.push_str(query_and_fragment);
+	relative
+}
+
+/// Splits into `scheme://authority` (or empty) and the remainder.
+#[inline(always)]
+fn split_url_origin(url: &str) -> (&str, &str)
+{
+	if url_has_scheme(url)
+	{
+		let afterScheme = url.find(':').unwrap() + 1;
+		if url[afterScheme ..].starts_with("//")
+		{
+			let authorityStart = afterScheme + 2;
+			let pathStart = url[authorityStart ..].find('/').map_or(url.len(), |index| authorityStart + index);
+			return url.split_at(pathStart);
+		}
+		return url.split_at(afterScheme);
+	}
+	("", url)
+}
+
+#[inline(always)]
+fn split_url_query_and_fragment(url: &str) -> (&str, &str)
+{
+	match url.find(['?', '#'])
+	{
+		None => (url, ""),
+		Some(index) => url.split_at(index),
+	}
+}
+
+/// Everything up to and including the last `/`.
+#[inline(always)]
+fn url_directory(path: &str) -> &str
+{
+	match path.rfind('/')
+	{
+		None => "",
+		Some(index) => &path[.. index + 1],
+	}
+}
+
+/// Removes `.` segments and `..` segments (with the segment before them).
+fn normalize_url_path(path: &str) -> String
+{
+	let isAbsolute = path.starts_with('/');
+	let isDirectory = path.ends_with('/') || path.ends_with("/.") || path.ends_with("/..");
+	
+	let mut segments: Vec<&str> = Vec::new();
+	for segment in path.split('/')
+	{
+		match segment
+		{
+			"" | "." => (),
+			".." => match segments.last()
+			{
+				Some(&last) if last != ".." =>
+				{
+					segments.pop();
+				}
+				_ => if !isAbsolute
+				{
+					segments.push("..");
+				},
+			},
+			_ => segments.push(segment),
+		}
+	}
+	
+	let mut normalized = String::with_capacity(path.len());
+	if isAbsolute
+	{
+		normalized.push('/');
+	}
+	normalized.push_str(&segments.join("/"));
+	if isDirectory && !segments.is_empty()
+	{
+		normalized.push('/');
+	}
+	normalized
+}
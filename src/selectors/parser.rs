@@ -1733,8 +1733,8 @@ fn parse_simple_pseudo_class<'i, P, E, Impl>(parser: &P, name: CowRcStr<'i>)
 // NB: pub module in order to access the DummyParser
 #[cfg(test)]
 pub mod tests {
-    use selectors::parser;
-    use selectors::builder::HAS_PSEUDO_BIT;
+    use crate::selectors::parser;
+    use crate::selectors::builder::HAS_PSEUDO_BIT;
     use cssparser::{Parser as CssParser, ToCss, serialize_identifier, ParserInput};
     use std::collections::HashMap;
     use std::fmt;
@@ -1879,7 +1879,7 @@ pub mod tests {
                                                               ParseError<'i, SelectorParseError<'i, ()>>> {
             match_ignore_ascii_case! { &name,
                 "lang" => Ok(PseudoClass::Lang(parser.expect_ident_or_string()?.as_ref().to_owned())),
-                _ => Err(SelectorParseError::Custom(()).into())
+                _ => Err(parser.new_custom_error(SelectorParseError::Custom(())))
             }
         }
 
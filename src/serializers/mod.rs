@@ -3,9 +3,14 @@
 
 
 use super::*;
+use super::domain::expressions::CalculablePropertyValue;
 use super::domain::numbers::CssNumber;
+use super::domain::numbers::CssNumberNewType;
+use super::domain::units::LengthOrPercentageUnit;
 
 
 include!("serialize_dimension.rs");
+include!("serialize_length_or_percentage.rs");
+include!("serialize_number.rs");
 include!("serialize_percentage.rs");
 
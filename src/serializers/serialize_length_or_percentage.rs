@@ -0,0 +1,14 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Writes a length or percentage, writing a constant zero length as `0` rather than, eg, `0px`, as units are optional for zero lengths.
+/// A zero percentage keeps its `%`, as for some properties (eg `flex-basis`) it is not equivalent to a zero length.
+pub(crate) fn serialize_length_or_percentage<W: fmt::Write, Number: CssNumber>(value: &CalculablePropertyValue<LengthOrPercentageUnit<Number>>, dest: &mut W) -> fmt::Result
+{
+	match *value
+	{
+		CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsLength(ref length)) if length.is_zero() => dest.write_char('0'),
+		_ => value.to_css(dest),
+	}
+}
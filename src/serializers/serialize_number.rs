@@ -0,0 +1,26 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+
+/// Serialize a number as cssparser does, but without a leading zero (eg `.5` rather than `0.5`).
+pub(crate) fn serialize_number<W: fmt::Write>(value: f32, dest: &mut W) -> fmt::Result
+{
+	let mut number = String::new();
+	value.to_css(&mut number)?;
+	
+	if let Some(fraction) = number.strip_prefix("0.")
+	{
+		dest.write_char('.')?;
+		dest.write_str(fraction)
+	}
+	else if let Some(fraction) = number.strip_prefix("-0.")
+	{
+		dest.write_str("-.")?;
+		dest.write_str(fraction)
+	}
+	else
+	{
+		dest.write_str(&number)
+	}
+}
@@ -5,6 +5,6 @@
 /// Serialize a normalized value into percentage.
 pub(crate) fn serialize_percentage<W: fmt::Write, Number: CssNumber>(value: Number, dest: &mut W) -> fmt::Result
 {
-	(value.to_f32() * 100.).to_css(dest)?;
+	serialize_number(value.to_f32() * 100., dest)?;
 	dest.write_str("%")
 }
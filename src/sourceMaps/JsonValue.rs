@@ -0,0 +1,290 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Just enough JSON to read and write source maps.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue
+{
+	Null,
+	Boolean(bool),
+	Number(f64),
+	String(String),
+	Array(Vec<JsonValue>),
+	Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue
+{
+	/// Parses a complete JSON document; on failure, returns the byte offset of the error.
+	pub(crate) fn parse(json: &str) -> Result<Self, usize>
+	{
+		let mut parser = JsonParser
+		{
+			json: json.as_bytes(),
+			position: 0,
+		};
+		
+		let value = parser.parse_value()?;
+		parser.skip_whitespace();
+		if parser.position != parser.json.len()
+		{
+			return Err(parser.position);
+		}
+		Ok(value)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn field(&self, name: &str) -> Option<&JsonValue>
+	{
+		match *self
+		{
+			JsonValue::Object(ref fields) => fields.iter().find(|(key, _)| key == name).map(|(_, value)| value),
+			_ => None,
+		}
+	}
+	
+	/// Writes `value` as a JSON string, including the surrounding quotes.
+	pub(crate) fn write_string(value: &str, destination: &mut String)
+	{
+		destination.push('"');
+		for character in value.chars()
+		{
+			match character
+			{
+				'"' => destination.push_str("\\\""),
+				'\\' => destination.push_str("\\\\"),
+				'\n' => destination.push_str("\\n"),
+				'\r' => destination.push_str("\\r"),
+				'\t' => destination.push_str("\\t"),
+				'\u{0000}' ..= '\u{001F}' => write!(destination, "\\u{:04x}", character as u32).unwrap(),
+				_ => destination.push(character),
+			}
+		}
+		destination.push('"');
+	}
+}
+
+struct JsonParser<'a>
+{
+	json: &'a [u8],
+	position: usize,
+}
+
+impl<'a> JsonParser<'a>
+{
+	fn parse_value(&mut self) -> Result<JsonValue, usize>
+	{
+		self.skip_whitespace();
+		match self.peek()
+		{
+			Some(b'{') => self.parse_object(),
+			Some(b'[') => self.parse_array(),
+			Some(b'"') => self.parse_string().map(JsonValue::String),
+			Some(b't') => self.parse_literal("true", JsonValue::Boolean(true)),
+			Some(b'f') => self.parse_literal("false", JsonValue::Boolean(false)),
+			Some(b'n') => self.parse_literal("null", JsonValue::Null),
+			Some(b'-') | Some(b'0' ..= b'9') => self.parse_number(),
+			_ => Err(self.position),
+		}
+	}
+	
+	fn parse_object(&mut self) -> Result<JsonValue, usize>
+	{
+		self.position += 1;
+		let mut fields = Vec::new();
+		self.skip_whitespace();
+		if self.peek() == Some(b'}')
+		{
+			self.position += 1;
+			return Ok(JsonValue::Object(fields));
+		}
+		
+		loop
+		{
+			self.skip_whitespace();
+			if self.peek() != Some(b'"')
+			{
+				return Err(self.position);
+			}
+			let key = self.parse_string()?;
+			self.skip_whitespace();
+			self.expect(b':')?;
+			let value = self.parse_value()?;
+			fields.push((key, value));
+			self.skip_whitespace();
+			match self.next()
+			{
+				Some(b',') => continue,
+				Some(b'}') => return Ok(JsonValue::Object(fields)),
+				_ => return Err(self.position),
+			}
+		}
+	}
+	
+	fn parse_array(&mut self) -> Result<JsonValue, usize>
+	{
+		self.position += 1;
+		let mut elements = Vec::new();
+		self.skip_whitespace();
+		if self.peek() == Some(b']')
+		{
+			self.position += 1;
+			return Ok(JsonValue::Array(elements));
+		}
+		
+		loop
+		{
+			elements.push(self.parse_value()?);
+			self.skip_whitespace();
+			match self.next()
+			{
+				Some(b',') => continue,
+				Some(b']') => return Ok(JsonValue::Array(elements)),
+				_ => return Err(self.position),
+			}
+		}
+	}
+	
+	fn parse_string(&mut self) -> Result<String, usize>
+	{
+		self.position += 1;
+		let mut string = String::new();
+		loop
+		{
+			let start = self.position;
+			while let Some(byte) = self.peek()
+			{
+				if byte == b'"' || byte == b'\\' || byte < 0x20
+				{
+					break;
+				}
+				self.position += 1;
+			}
+			// The input was a str and we only stop at ASCII bytes, so this slice is always valid UTF-8.
+			string.push_str(::std::str::from_utf8(&self.json[start .. self.position]).unwrap());
+			
+			match self.next()
+			{
+				Some(b'"') => return Ok(string),
+				Some(b'\\') => match self.next()
+				{
+					Some(b'"') => string.push('"'),
+					Some(b'\\') => string.push('\\'),
+					Some(b'/') => string.push('/'),
+					Some(b'b') => string.push('\u{0008}'),
+					Some(b'f') => string.push('\u{000C}'),
+					Some(b'n') => string.push('\n'),
+					Some(b'r') => string.push('\r'),
+					Some(b't') => string.push('\t'),
+					Some(b'u') =>
+					{
+						let first = self.parse_hexadecimal_code_unit()?;
+						let codePoint = if (0xD800 .. 0xDC00).contains(&first)
+						{
+							let position = self.position;
+							if self.next() != Some(b'\\') || self.next() != Some(b'u')
+							{
+								return Err(position);
+							}
+							let second = self.parse_hexadecimal_code_unit()?;
+							if !(0xDC00 .. 0xE000).contains(&second)
+							{
+								return Err(position);
+							}
+							0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00)
+						}
+						else
+						{
+							first
+						};
+						string.push(::std::char::from_u32(codePoint).unwrap_or('\u{FFFD}'));
+					}
+					_ => return Err(self.position - 1),
+				},
+				_ => return Err(self.position.saturating_sub(1)),
+			}
+		}
+	}
+	
+	fn parse_hexadecimal_code_unit(&mut self) -> Result<u32, usize>
+	{
+		let start = self.position;
+		let end = start + 4;
+		if end > self.json.len()
+		{
+			return Err(start);
+		}
+		let digits = ::std::str::from_utf8(&self.json[start .. end]).map_err(|_| start)?;
+		let codeUnit = u32::from_str_radix(digits, 16).map_err(|_| start)?;
+		self.position = end;
+		Ok(codeUnit)
+	}
+	
+	fn parse_number(&mut self) -> Result<JsonValue, usize>
+	{
+		let start = self.position;
+		while let Some(byte) = self.peek()
+		{
+			match byte
+			{
+				b'-' | b'+' | b'.' | b'e' | b'E' | b'0' ..= b'9' => self.position += 1,
+				_ => break,
+			}
+		}
+		let number = ::std::str::from_utf8(&self.json[start .. self.position]).unwrap();
+		number.parse::<f64>().map(JsonValue::Number).map_err(|_| start)
+	}
+	
+	fn parse_literal(&mut self, literal: &'static str, value: JsonValue) -> Result<JsonValue, usize>
+	{
+		if self.json[self.position ..].starts_with(literal.as_bytes())
+		{
+			self.position += literal.len();
+			Ok(value)
+		}
+		else
+		{
+			Err(self.position)
+		}
+	}
+	
+	#[inline(always)]
+	fn expect(&mut self, byte: u8) -> Result<(), usize>
+	{
+		if self.next() == Some(byte)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(self.position - 1)
+		}
+	}
+	
+	#[inline(always)]
+	fn skip_whitespace(&mut self)
+	{
+		while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek()
+		{
+			self.position += 1;
+		}
+	}
+	
+	#[inline(always)]
+	fn peek(&self) -> Option<u8>
+	{
+		self.json.get(self.position).cloned()
+	}
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<u8>
+	{
+		let byte = self.peek();
+		if byte.is_some()
+		{
+			self.position += 1;
+		}
+		byte
+	}
+}
@@ -0,0 +1,361 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A [Source Map version 3](https://sourcemaps.info/spec.html).
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SourceMap
+{
+	/// The name of the generated file, if known.
+	pub file: Option<String>,
+	
+	/// Prepended to each of `sources`, if present.
+	pub source_root: Option<String>,
+	
+	/// The original sources.
+	pub sources: Vec<String>,
+	
+	/// The content of each of `sources`, if known; either empty or the same length as `sources`.
+	pub sources_content: Vec<Option<String>>,
+	
+	/// Symbol names referenced by mappings.
+	pub names: Vec<String>,
+	
+	/// Mappings, sorted by generated line and then generated column.
+	pub mappings: Vec<SourceMapMapping>,
+}
+
+impl SourceMap
+{
+	/// Parses a source map from JSON, eg one produced by a preprocessor.
+	pub fn parse(json: &str) -> Result<Self, SourceMapError>
+	{
+		use self::SourceMapError::*;
+		
+		let json = JsonValue::parse(json).map_err(InvalidJson)?;
+		
+		match json.field("version")
+		{
+			Some(&JsonValue::Number(version)) => if version != 3.0
+			{
+				return Err(UnsupportedVersion(version))
+			},
+			_ => return Err(InvalidField("version")),
+		}
+		
+		if json.field("sections").is_some()
+		{
+			return Err(IndexMapsAreNotSupported);
+		}
+		
+		let file = Self::parse_optional_string_field(&json, "file")?;
+		let source_root = Self::parse_optional_string_field(&json, "sourceRoot")?;
+		let sources = Self::parse_strings_field(&json, "sources")?.into_iter().map(|source| source.unwrap_or_default()).collect();
+		let sources_content = match json.field("sourcesContent")
+		{
+			None => Vec::new(),
+			Some(_) => Self::parse_strings_field(&json, "sourcesContent")?,
+		};
+		let names = match json.field("names")
+		{
+			None => Vec::new(),
+			Some(_) => Self::parse_strings_field(&json, "names")?.into_iter().map(|name| name.unwrap_or_default()).collect(),
+		};
+		let mappings = match json.field("mappings")
+		{
+			Some(JsonValue::String(mappings)) => Self::decode_mappings(mappings)?,
+			_ => return Err(InvalidField("mappings")),
+		};
+		
+		Ok
+		(
+			Self
+			{
+				file,
+				source_root,
+				sources,
+				sources_content,
+				names,
+				mappings,
+			}
+		)
+	}
+	
+	/// Serializes this source map as JSON.
+	pub fn to_json(&self) -> String
+	{
+		let mut json = String::new();
+		json.push_str("{\"version\":3");
+		
+		if let Some(ref file) = self.file
+		{
+			json.push_str(",\"file\":");
+			JsonValue::write_string(file, &mut json);
+		}
+		
+		if let Some(ref source_root) = self.source_root
+		{
+			json.push_str(",\"sourceRoot\":");
+			JsonValue::write_string(source_root, &mut json);
+		}
+		
+		json.push_str(",\"sources\":[");
+		for (index, source) in self.sources.iter().enumerate()
+		{
+			if index != 0
+			{
+				json.push(',');
+			}
+			JsonValue::write_string(source, &mut json);
+		}
+		json.push(']');
+		
+		if !self.sources_content.is_empty()
+		{
+			json.push_str(",\"sourcesContent\":[");
+			for (index, source_content) in self.sources_content.iter().enumerate()
+			{
+				if index != 0
+				{
+					json.push(',');
+				}
+				match *source_content
+				{
+					None => json.push_str("null"),
+					Some(ref source_content) => JsonValue::write_string(source_content, &mut json),
+				}
+			}
+			json.push(']');
+		}
+		
+		json.push_str(",\"names\":[");
+		for (index, name) in self.names.iter().enumerate()
+		{
+			if index != 0
+			{
+				json.push(',');
+			}
+			JsonValue::write_string(name, &mut json);
+		}
+		json.push(']');
+		
+		json.push_str(",\"mappings\":\"");
+		self.encode_mappings(&mut json);
+		json.push_str("\"}");
+		
+		json
+	}
+	
+	/// Finds the original position of a position in the generated file.
+	/// This is the original of the closest mapping at or before `generated_column` on `generated_line`, if any.
+	pub fn original_position_for(&self, generated_line: u32, generated_column: u32) -> Option<SourceMapOriginalPosition>
+	{
+		let index = match self.mappings.binary_search_by(|mapping| (mapping.generated_line, mapping.generated_column).cmp(&(generated_line, generated_column)))
+		{
+			Ok(index) => index,
+			Err(0) => return None,
+			Err(insertion_index) => insertion_index - 1,
+		};
+		
+		let mapping = unsafe { self.mappings.get_unchecked(index) };
+		if mapping.generated_line == generated_line
+		{
+			mapping.original
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	fn encode_mappings(&self, destination: &mut String)
+	{
+		let mut current_line = 0;
+		let mut previous_generated_column = 0i64;
+		let mut previous_source_index = 0i64;
+		let mut previous_original_line = 0i64;
+		let mut previous_original_column = 0i64;
+		let mut previous_name_index = 0i64;
+		let mut first_in_line = true;
+		
+		for mapping in self.mappings.iter()
+		{
+			while current_line < mapping.generated_line
+			{
+				destination.push(';');
+				current_line += 1;
+				previous_generated_column = 0;
+				first_in_line = true;
+			}
+			
+			if !first_in_line
+			{
+				destination.push(',');
+			}
+			first_in_line = false;
+			
+			let generated_column = mapping.generated_column as i64;
+			vlq_encode(generated_column - previous_generated_column, destination);
+			previous_generated_column = generated_column;
+			
+			if let Some(ref original) = mapping.original
+			{
+				let source_index = original.source_index as i64;
+				vlq_encode(source_index - previous_source_index, destination);
+				previous_source_index = source_index;
+				
+				let original_line = original.line as i64;
+				vlq_encode(original_line - previous_original_line, destination);
+				previous_original_line = original_line;
+				
+				let original_column = original.column as i64;
+				vlq_encode(original_column - previous_original_column, destination);
+				previous_original_column = original_column;
+				
+				if let Some(name_index) = original.name_index
+				{
+					let name_index = name_index as i64;
+					vlq_encode(name_index - previous_name_index, destination);
+					previous_name_index = name_index;
+				}
+			}
+		}
+	}
+	
+	fn decode_mappings(mappings: &str) -> Result<Vec<SourceMapMapping>, SourceMapError>
+	{
+		let bytes = mappings.as_bytes();
+		let mut decoded = Vec::new();
+		
+		let mut generated_line = 0;
+		let mut previous_generated_column = 0i64;
+		let mut previous_source_index = 0i64;
+		let mut previous_original_line = 0i64;
+		let mut previous_original_column = 0i64;
+		let mut previous_name_index = 0i64;
+		
+		let mut position = 0;
+		while position < bytes.len()
+		{
+			match bytes[position]
+			{
+				b';' =>
+				{
+					generated_line += 1;
+					previous_generated_column = 0;
+					position += 1;
+				}
+				
+				b',' => position += 1,
+				
+				_ =>
+				{
+					let segment_start = position;
+					let mut fields = [0i64; 5];
+					let mut number_of_fields = 0;
+					while position < bytes.len() && bytes[position] != b',' && bytes[position] != b';'
+					{
+						if number_of_fields == fields.len()
+						{
+							return Err(SourceMapError::InvalidMappings(position));
+						}
+						let (value, consumed) = vlq_decode(&bytes[position ..]).ok_or(SourceMapError::InvalidMappings(position))?;
+						fields[number_of_fields] = value;
+						number_of_fields += 1;
+						position += consumed;
+					}
+					
+					let invalid = || SourceMapError::InvalidMappings(segment_start);
+					
+					previous_generated_column += fields[0];
+					let generated_column = Self::to_u32(previous_generated_column).ok_or_else(invalid)?;
+					
+					let original = match number_of_fields
+					{
+						1 => None,
+						
+						4 | 5 =>
+						{
+							previous_source_index += fields[1];
+							previous_original_line += fields[2];
+							previous_original_column += fields[3];
+							let name_index = if number_of_fields == 5
+							{
+								previous_name_index += fields[4];
+								Some(Self::to_u32(previous_name_index).ok_or_else(invalid)?)
+							}
+							else
+							{
+								None
+							};
+							
+							Some
+							(
+								SourceMapOriginalPosition
+								{
+									source_index: Self::to_u32(previous_source_index).ok_or_else(invalid)?,
+									line: Self::to_u32(previous_original_line).ok_or_else(invalid)?,
+									column: Self::to_u32(previous_original_column).ok_or_else(invalid)?,
+									name_index,
+								}
+							)
+						}
+						
+						_ => return Err(invalid()),
+					};
+					
+					decoded.push
+					(
+						SourceMapMapping
+						{
+							generated_line,
+							generated_column,
+							original,
+						}
+					);
+				}
+			}
+		}
+		
+		decoded.sort();
+		Ok(decoded)
+	}
+	
+	#[inline(always)]
+	fn to_u32(value: i64) -> Option<u32>
+	{
+		if value >= 0 && value <= u32::MAX as i64
+		{
+			Some(value as u32)
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	fn parse_optional_string_field(json: &JsonValue, name: &'static str) -> Result<Option<String>, SourceMapError>
+	{
+		match json.field(name)
+		{
+			None | Some(&JsonValue::Null) => Ok(None),
+			Some(JsonValue::String(value)) => Ok(Some(value.clone())),
+			_ => Err(SourceMapError::InvalidField(name)),
+		}
+	}
+	
+	fn parse_strings_field(json: &JsonValue, name: &'static str) -> Result<Vec<Option<String>>, SourceMapError>
+	{
+		match json.field(name)
+		{
+			Some(JsonValue::Array(elements)) => elements.iter().map(|element| match *element
+			{
+				JsonValue::Null => Ok(None),
+				JsonValue::String(ref value) => Ok(Some(value.clone())),
+				_ => Err(SourceMapError::InvalidField(name)),
+			}).collect(),
+			
+			_ => Err(SourceMapError::InvalidField(name)),
+		}
+	}
+}
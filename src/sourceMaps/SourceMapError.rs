@@ -0,0 +1,46 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+quick_error!
+{
+	/// Represents all the things that can go wrong when reading a source map.
+	#[derive(Debug, Clone, PartialEq)]
+	pub enum SourceMapError
+	{
+		/// The source map is not valid JSON.
+		InvalidJson(byte_offset: usize)
+		{
+			description("invalid JSON")
+			display("Source map was not valid JSON at byte offset {}", byte_offset)
+		}
+		
+		/// A field is missing, or has the wrong type.
+		InvalidField(name: &'static str)
+		{
+			description("invalid field")
+			display("Source map field '{}' was missing or invalid", name)
+		}
+		
+		/// Only version 3 source maps are supported.
+		UnsupportedVersion(version: f64)
+		{
+			description("unsupported version")
+			display("Source map version {} is not supported; only version 3 is", version)
+		}
+		
+		/// Index maps (those with `sections`) are not supported.
+		IndexMapsAreNotSupported
+		{
+			description("index maps are not supported")
+			display("Source map index maps (with 'sections') are not supported")
+		}
+		
+		/// The `mappings` field is not valid.
+		InvalidMappings(byte_offset: usize)
+		{
+			description("invalid mappings")
+			display("Source map 'mappings' was invalid at byte offset {}", byte_offset)
+		}
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A mapping from a position in the generated CSS to a position in an original source.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceMapMapping
+{
+	/// Zero-based line in the generated CSS.
+	pub generated_line: u32,
+	
+	/// Zero-based column in the generated CSS, in UTF-16 code units.
+	pub generated_column: u32,
+	
+	/// The original position; if absent, the generated position has no original.
+	pub original: Option<SourceMapOriginalPosition>,
+}
@@ -0,0 +1,20 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A position in one of the original sources of a source map.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceMapOriginalPosition
+{
+	/// Index into `SourceMap.sources`.
+	pub source_index: u32,
+	
+	/// Zero-based line.
+	pub line: u32,
+	
+	/// Zero-based column, in UTF-16 code units.
+	pub column: u32,
+	
+	/// Index into `SourceMap.names`, if any.
+	pub name_index: Option<u32>,
+}
@@ -0,0 +1,133 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Passes CSS through to a destination, tracking the generated line and column so that mappings can be recorded.
+pub(crate) struct SourceMapWriter<'a, 'm, W: 'a + fmt::Write>
+{
+	destination: &'a mut W,
+	generated_line: u32,
+	generated_column: u32,
+	previous_was_carriage_return: bool,
+	input_source_map: Option<&'m SourceMap>,
+	mappings: Vec<SourceMapMapping>,
+}
+
+impl<'a, 'm, W: 'a + fmt::Write> fmt::Write for SourceMapWriter<'a, 'm, W>
+{
+	fn write_str(&mut self, s: &str) -> fmt::Result
+	{
+		for character in s.chars()
+		{
+			// https://drafts.csswg.org/css-syntax-3/#newline treats CR LF as one newline.
+			match character
+			{
+				'\n' if self.previous_was_carriage_return => (),
+				'\n' | '\r' | '\u{000C}' =>
+				{
+					self.generated_line += 1;
+					self.generated_column = 0;
+				}
+				_ => self.generated_column += character.len_utf16() as u32,
+			}
+			self.previous_was_carriage_return = character == '\r';
+		}
+		
+		self.destination.write_str(s)
+	}
+}
+
+impl<'a, 'm, W: 'a + fmt::Write> SourceMapWriter<'a, 'm, W>
+{
+	#[inline(always)]
+	pub(crate) fn new(destination: &'a mut W, input_source_map: Option<&'m SourceMap>) -> Self
+	{
+		Self
+		{
+			destination,
+			generated_line: 0,
+			generated_column: 0,
+			previous_was_carriage_return: false,
+			input_source_map,
+			mappings: Vec::new(),
+		}
+	}
+	
+	/// Maps the current generated position to the start of `source_range`, if known.
+	/// If there is an input source map, the start is itself mapped through it; if it has no original there, no mapping is recorded.
+	pub(crate) fn add_mapping(&mut self, source_range: Option<SourceRange>)
+	{
+		let start = match source_range
+		{
+			None => return,
+			Some(source_range) => source_range.start,
+		};
+		
+		// SourceOffset columns are one-based, as for cssparser.
+		let line = start.line;
+		let column = start.column.saturating_sub(1);
+		
+		let original = match self.input_source_map
+		{
+			None => SourceMapOriginalPosition
+			{
+				source_index: 0,
+				line,
+				column,
+				name_index: None,
+			},
+			
+			Some(input_source_map) => match input_source_map.original_position_for(line, column)
+			{
+				None => return,
+				Some(original) => original,
+			},
+		};
+		
+		if let Some(last) = self.mappings.last()
+		{
+			if last.generated_line == self.generated_line && last.generated_column == self.generated_column
+			{
+				return;
+			}
+		}
+		
+		self.mappings.push
+		(
+			SourceMapMapping
+			{
+				generated_line: self.generated_line,
+				generated_column: self.generated_column,
+				original: Some(original),
+			}
+		);
+	}
+	
+	/// Creates the source map for everything written.
+	/// `source` names the original CSS; it is not used if there is an input source map, as the sources of that are used instead.
+	pub(crate) fn into_source_map(self, source: &str) -> SourceMap
+	{
+		match self.input_source_map
+		{
+			None => SourceMap
+			{
+				file: None,
+				source_root: None,
+				sources: vec![source.to_owned()],
+				sources_content: Vec::new(),
+				names: Vec::new(),
+				mappings: self.mappings,
+			},
+			
+			Some(input_source_map) => SourceMap
+			{
+				file: None,
+				source_root: input_source_map.source_root.clone(),
+				sources: input_source_map.sources.clone(),
+				sources_content: input_source_map.sources_content.clone(),
+				names: input_source_map.names.clone(),
+				mappings: self.mappings,
+			},
+		}
+	}
+}
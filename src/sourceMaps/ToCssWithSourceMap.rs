@@ -0,0 +1,186 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Serializes as for `ToCss`, recording a source map mapping at the start of each rule and property declaration that has a source range.
+pub(crate) trait ToCssWithSourceMap
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result;
+}
+
+impl ToCssWithSourceMap for CssRules
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		for cssRule in self.0.iter()
+		{
+			cssRule.to_css_with_source_map(dest)?;
+		}
+		
+		Ok(())
+	}
+}
+
+impl ToCssWithSourceMap for CssRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		use self::CssRule::*;
+		
+		dest.add_mapping(self.source_range());
+		
+		match *self
+		{
+			Document(ref rule) => rule.to_css_with_source_map(dest),
+			
+			Keyframes(ref rule) => rule.to_css_with_source_map(dest),
+			
+			LayerBlock(ref rule) => rule.to_css_with_source_map(dest),
+			
+			Media(ref rule) => rule.to_css_with_source_map(dest),
+			
+			Page(ref rule) => rule.to_css_with_source_map(dest),
+			
+			Style(ref rule) => rule.to_css_with_source_map(dest),
+			
+			Supports(ref rule) => rule.to_css_with_source_map(dest),
+			
+			_ => self.to_css(dest),
+		}
+	}
+}
+
+impl ToCssWithSourceMap for DocumentAtRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		dest.write_str("@")?;
+		if let Some(ref vendor_prefix) = self.vendor_prefix
+		{
+			vendor_prefix.to_css(dest)?;
+		}
+		dest.write_str("document ")?;
+		self.condition.to_css(dest)?;
+		dest.write_char('{')?;
+		self.rules.to_css_with_source_map(dest)?;
+		dest.write_char('}')
+	}
+}
+
+impl ToCssWithSourceMap for KeyframesAtRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		dest.write_str("@")?;
+		if let Some(ref vendor_prefix) = self.vendor_prefix
+		{
+			vendor_prefix.to_css(dest)?;
+		}
+		dest.write_str("keyframes ")?;
+		self.name.to_css(dest)?;
+		dest.write_char('{')?;
+		for keyframe in self.keyframes.iter()
+		{
+			keyframe.selector.to_css(dest)?;
+			dest.write_char('{')?;
+			keyframe.property_declarations.to_css_with_source_map(dest)?;
+			dest.write_char('}')?;
+		}
+		dest.write_char('}')
+	}
+}
+
+impl ToCssWithSourceMap for LayerBlockAtRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		dest.write_str("@layer")?;
+		if let Some(ref name) = self.name
+		{
+			dest.write_char(' ')?;
+			name.to_css(dest)?;
+		}
+		dest.write_char('{')?;
+		self.rules.to_css_with_source_map(dest)?;
+		dest.write_char('}')
+	}
+}
+
+impl ToCssWithSourceMap for MediaAtRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		dest.write_str("@media ")?;
+		self.media_queries.to_css(dest)?;
+		dest.write_char('{')?;
+		self.rules.to_css_with_source_map(dest)?;
+		dest.write_char('}')
+	}
+}
+
+impl ToCssWithSourceMap for PageAtRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		self.page_selectors_to_css(dest)?;
+		dest.write_char('{')?;
+		self.property_declarations.to_css_with_source_map(dest)?;
+		if !self.property_declarations.is_empty() && !self.margin_rules.is_empty()
+		{
+			dest.write_char(';')?;
+		}
+		for margin_rule in self.margin_rules.iter()
+		{
+			dest.write_char('@')?;
+			margin_rule.margin_box.to_css(dest)?;
+			dest.write_char('{')?;
+			margin_rule.property_declarations.to_css_with_source_map(dest)?;
+			dest.write_char('}')?;
+		}
+		dest.write_char('}')
+	}
+}
+
+impl ToCssWithSourceMap for StyleRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		self.selectors.to_css(dest)?;
+		dest.write_char('{')?;
+		self.property_declarations.to_css_with_source_map(dest)?;
+		dest.write_char('}')
+	}
+}
+
+impl ToCssWithSourceMap for SupportsAtRule
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		dest.write_str("@supports ")?;
+		self.condition.to_css(dest)?;
+		dest.write_char('{')?;
+		self.rules.to_css_with_source_map(dest)?;
+		dest.write_char('}')
+	}
+}
+
+impl<I: HasImportance> ToCssWithSourceMap for PropertyDeclarations<I>
+{
+	fn to_css_with_source_map<'a, 'm, W: 'a + fmt::Write>(&self, dest: &mut SourceMapWriter<'a, 'm, W>) -> fmt::Result
+	{
+		let length = self.0.len();
+		for (index, propertyDeclaration) in self.0.iter().enumerate()
+		{
+			dest.add_mapping(propertyDeclaration.source_range);
+			if index + 1 == length
+			{
+				propertyDeclaration.to_css_without_trailing_semicolon(dest)?;
+			}
+			else
+			{
+				propertyDeclaration.to_css(dest)?;
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,23 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::domain::atRules::document::DocumentAtRule;
+use super::domain::atRules::keyframes::*;
+use super::domain::atRules::layer::LayerBlockAtRule;
+use super::domain::atRules::media::MediaAtRule;
+use super::domain::atRules::page::PageAtRule;
+use super::domain::atRules::supports::SupportsAtRule;
+use super::domain::properties::*;
+use ::std::fmt::Write;
+
+
+include!("JsonValue.rs");
+include!("SourceMap.rs");
+include!("SourceMapError.rs");
+include!("SourceMapMapping.rs");
+include!("SourceMapOriginalPosition.rs");
+include!("SourceMapWriter.rs");
+include!("ToCssWithSourceMap.rs");
+include!("vlq.rs");
@@ -0,0 +1,80 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+const Base64Alphabet: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const VlqBaseShift: u32 = 5;
+
+const VlqBase: i64 = 1 << VlqBaseShift;
+
+const VlqBaseMask: i64 = VlqBase - 1;
+
+const VlqContinuationBit: i64 = VlqBase;
+
+/// Appends `value` as a Base64 VLQ, as used in the `mappings` of a source map.
+pub(crate) fn vlq_encode(value: i64, destination: &mut String)
+{
+	// The sign is stored in the least significant bit.
+	let mut remaining = if value < 0
+	{
+		((-value) << 1) | 1
+	}
+	else
+	{
+		value << 1
+	};
+	
+	loop
+	{
+		let mut digit = remaining & VlqBaseMask;
+		remaining >>= VlqBaseShift;
+		if remaining > 0
+		{
+			digit |= VlqContinuationBit;
+		}
+		destination.push(Base64Alphabet[digit as usize] as char);
+		if remaining == 0
+		{
+			return;
+		}
+	}
+}
+
+/// Decodes a Base64 VLQ from the start of `bytes`, returning the value and the number of bytes consumed.
+pub(crate) fn vlq_decode(bytes: &[u8]) -> Option<(i64, usize)>
+{
+	let mut result: i64 = 0;
+	let mut shift = 0;
+	let mut consumed = 0;
+	
+	loop
+	{
+		let byte = *bytes.get(consumed)?;
+		consumed += 1;
+		
+		let digit = match byte
+		{
+			b'A' ..= b'Z' => byte - b'A',
+			b'a' ..= b'z' => byte - b'a' + 26,
+			b'0' ..= b'9' => byte - b'0' + 52,
+			b'+' => 62,
+			b'/' => 63,
+			_ => return None,
+		} as i64;
+		
+		if shift > 60
+		{
+			return None;
+		}
+		result += (digit & VlqBaseMask) << shift;
+		shift += VlqBaseShift;
+		
+		if digit & VlqContinuationBit == 0
+		{
+			let negative = result & 1 == 1;
+			let magnitude = result >> 1;
+			return Some((if negative { -magnitude } else { magnitude }, consumed));
+		}
+	}
+}